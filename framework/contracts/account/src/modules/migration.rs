@@ -249,6 +249,7 @@ pub fn replace_adapter(
     // Makes sure we already have the adapter installed
     let AuthorizedAddressesResponse {
         addresses: authorized_addresses,
+        scopes,
     } = deps.querier.query_wasm_smart(
         old_adapter_addr.to_string(),
         &<AdapterQuery<Empty>>::Base(BaseQueryMsg::AuthorizedAddresses {
@@ -265,6 +266,7 @@ pub fn replace_adapter(
         AdapterBaseMsg::UpdateAuthorizedAddresses {
             to_add: vec![],
             to_remove: authorized_to_migrate.clone(),
+            scopes: vec![],
         },
     )?);
     // Add authorized addresses to new
@@ -273,6 +275,10 @@ pub fn replace_adapter(
         AdapterBaseMsg::UpdateAuthorizedAddresses {
             to_add: authorized_to_migrate,
             to_remove: vec![],
+            scopes: scopes
+                .into_iter()
+                .map(|(addr, scope)| (addr.into_string(), scope))
+                .collect(),
         },
     )?);
     // Replace adapter permissions from old to new address to account
@@ -63,7 +63,10 @@ fn installing_one_adapter_should_succeed() -> AResult {
     let authorized = staking_adapter.authorized_addresses(account.addr_str()?)?;
     assert_eq!(
         authorized,
-        adapter::AuthorizedAddressesResponse { addresses: vec![] }
+        adapter::AuthorizedAddressesResponse {
+            addresses: vec![],
+            scopes: vec![]
+        }
     );
 
     take_storage_snapshot!(chain, "install_one_adapter");
@@ -508,6 +511,7 @@ fn account_adapter_ownership() -> AResult {
             msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                 to_add: vec![chain.addr_make("123").to_string()],
                 to_remove: vec![],
+                scopes: vec![],
             },
         }),
         &[],
@@ -520,6 +524,7 @@ fn account_adapter_ownership() -> AResult {
             msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                 to_add: vec![chain.addr_make("234").to_string()],
                 to_remove: vec![],
+                scopes: vec![],
             },
         }))?,
         &[],
@@ -534,6 +539,7 @@ fn account_adapter_ownership() -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![chain.addr_make("456").to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             }),
             &[],
@@ -549,6 +555,7 @@ fn account_adapter_ownership() -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![chain.addr_make("345").to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             }),
             &[],
@@ -631,6 +638,7 @@ fn subaccount_adapter_ownership() -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![chain.addr_make("456").to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             }),
             &[],
@@ -668,6 +676,7 @@ fn subaccount_adapter_ownership() -> AResult {
             msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                 to_add: vec![chain.addr_make("123").to_string()],
                 to_remove: vec![],
+                scopes: vec![],
             },
         }),
         &[],
@@ -679,6 +688,7 @@ fn subaccount_adapter_ownership() -> AResult {
             msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                 to_add: vec![chain.addr_make("234").to_string()],
                 to_remove: vec![],
+                scopes: vec![],
             },
         }))?,
         &[],
@@ -693,6 +703,7 @@ fn subaccount_adapter_ownership() -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![chain.addr_make("345").to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             }),
             &[],
@@ -708,6 +719,7 @@ fn subaccount_adapter_ownership() -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![chain.addr_make("345").to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             }),
             &[],
@@ -1,20 +1,21 @@
 use abstract_sdk::execute_update_ownership;
 use abstract_std::{
-    ans_host::{state::*, AssetPair, ExecuteMsg},
+    ans_host::{state::*, AnsChange, AssetPair, ExecuteMsg, PoolRecord},
     objects::{
         pool_id::{PoolAddress, UncheckedPoolAddress},
         pool_metadata::PoolMetadata,
         pool_reference::PoolReference,
-        AssetEntry, DexAssetPairing, DexName, UncheckedChannelEntry, UncheckedContractEntry,
-        UniquePoolId,
+        AssetEntry, ChannelEntry, ContractEntry, DexAssetPairing, DexName, OracleSource,
+        UncheckedChannelEntry, UncheckedContractEntry, UniquePoolId,
     },
 };
-use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, StdError, StdResult, Storage};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Order, StdError, StdResult, Storage};
 use cw_asset::AssetInfoUnchecked;
 
 use crate::{
     contract::{AnsHostResponse, AnsHostResult},
     error::{AnsHostError, AnsHostError::InvalidAssetCount},
+    history::{change_set_response, record_change_set, revert_change_set},
 };
 
 const MIN_POOL_ASSETS: usize = 2;
@@ -29,20 +30,24 @@ pub fn handle_message(
 ) -> AnsHostResult {
     match message {
         ExecuteMsg::UpdateContractAddresses { to_add, to_remove } => {
-            update_contract_addresses(deps, info, to_add, to_remove)
+            update_contract_addresses(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateAssetAddresses { to_add, to_remove } => {
-            update_asset_addresses(deps, info, to_add, to_remove)
+            update_asset_addresses(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateChannels { to_add, to_remove } => {
-            update_channels(deps, info, to_add, to_remove)
+            update_channels(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateDexes { to_add, to_remove } => {
-            update_dex_registry(deps, info, to_add, to_remove)
+            update_dex_registry(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdatePools { to_add, to_remove } => {
-            update_pools(deps, info, to_add, to_remove)
+            update_pools(deps, env, info, to_add, to_remove)
         }
+        ExecuteMsg::UpdateOracleSources { to_add, to_remove } => {
+            update_oracle_sources(deps, env, info, to_add, to_remove)
+        }
+        ExecuteMsg::RevertChangeSet { id } => revert_change_set(deps, env, info, id),
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership!(AnsHostResponse, deps, env, info, action)
         }
@@ -56,6 +61,7 @@ pub fn handle_message(
 /// Adds, updates or removes provided addresses.
 pub fn update_contract_addresses(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(UncheckedContractEntry, String)>,
     to_remove: Vec<UncheckedContractEntry>,
@@ -63,27 +69,85 @@ pub fn update_contract_addresses(
     // Only Admin can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
+    let mut changes = vec![];
     for (key, new_address) in to_add.into_iter() {
         let key = key.check();
         // validate addr
         let addr = deps.as_ref().api.addr_validate(&new_address)?;
 
-        // Update function for new or existing keys
-        let insert = |_| -> StdResult<Addr> { Ok(addr) };
-        CONTRACT_ADDRESSES.update(deps.storage, &key, insert)?;
+        let old = save_contract_address(deps.storage, &key, &addr)?;
+        changes.push(AnsChange::Contract {
+            entry: key,
+            old,
+            new: Some(addr),
+        });
     }
 
     for key in to_remove {
         let key = key.check();
-        CONTRACT_ADDRESSES.remove(deps.storage, &key);
+        let old = remove_contract_address(deps.storage, &key)?;
+        changes.push(AnsChange::Contract {
+            entry: key,
+            old,
+            new: None,
+        });
     }
 
-    Ok(AnsHostResponse::action("update_contract_addresses"))
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response(
+        "update_contract_addresses",
+        change_set_id,
+    ))
+}
+
+/// Registers the address of a contract and its reverse lookup.
+/// Returns the previously registered address.
+pub(crate) fn save_contract_address(
+    storage: &mut dyn Storage,
+    entry: &ContractEntry,
+    address: &Addr,
+) -> StdResult<Option<Addr>> {
+    let old = remove_contract_address(storage, entry)?;
+    CONTRACT_ADDRESSES.save(storage, entry, address)?;
+    REV_CONTRACT_ADDRESSES.save(storage, address, entry)?;
+    Ok(old)
+}
+
+/// Removes the address of a contract. The reverse lookup is kept while another entry has the same address.
+/// Returns the removed address.
+pub(crate) fn remove_contract_address(
+    storage: &mut dyn Storage,
+    entry: &ContractEntry,
+) -> StdResult<Option<Addr>> {
+    let old = CONTRACT_ADDRESSES.may_load(storage, entry)?;
+    if let Some(address) = &old {
+        CONTRACT_ADDRESSES.remove(storage, entry);
+        // Another entry might have been registered for the same address afterwards
+        if REV_CONTRACT_ADDRESSES.may_load(storage, address)?.as_ref() == Some(entry) {
+            // Point the reverse lookup to an entry that still has this address, if any
+            let other_entry = CONTRACT_ADDRESSES
+                .range(storage, None, None, Order::Ascending)
+                .find_map(|item| match item {
+                    Ok((other_entry, other_address)) if &other_address == address => {
+                        Some(Ok(other_entry))
+                    }
+                    Ok(_) => None,
+                    Err(err) => Some(Err(err)),
+                })
+                .transpose()?;
+            match other_entry {
+                Some(other_entry) => REV_CONTRACT_ADDRESSES.save(storage, address, &other_entry)?,
+                None => REV_CONTRACT_ADDRESSES.remove(storage, address),
+            }
+        }
+    }
+    Ok(old)
 }
 
 /// Adds, updates or removes provided addresses.
 pub fn update_asset_addresses(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(String, AssetInfoUnchecked)>,
     to_remove: Vec<String>,
@@ -91,14 +155,21 @@ pub fn update_asset_addresses(
     // Only Admin can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
+    let mut changes = vec![];
     for (name, new_asset) in to_add.into_iter() {
         // validate asset
         let asset = new_asset.check(deps.as_ref().api, None)?;
 
         let entry = AssetEntry::from(name);
 
+        let old = ASSET_ADDRESSES.may_load(deps.storage, &entry)?;
         ASSET_ADDRESSES.save(deps.storage, &entry, &asset)?;
         REV_ASSET_ADDRESSES.save(deps.storage, &asset, &entry)?;
+        changes.push(AnsChange::Asset {
+            entry,
+            old,
+            new: Some(asset),
+        });
     }
 
     for name in to_remove {
@@ -107,15 +178,22 @@ pub fn update_asset_addresses(
         if let Some(asset) = maybe_asset {
             ASSET_ADDRESSES.remove(deps.storage, &entry);
             REV_ASSET_ADDRESSES.remove(deps.storage, &asset);
+            changes.push(AnsChange::Asset {
+                entry,
+                old: Some(asset),
+                new: None,
+            });
         }
     }
 
-    Ok(AnsHostResponse::action("update_asset_addresses"))
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response("update_asset_addresses", change_set_id))
 }
 
 /// Adds, updates or removes provided addresses.
 pub fn update_channels(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(UncheckedChannelEntry, String)>,
     to_remove: Vec<UncheckedChannelEntry>,
@@ -123,24 +201,65 @@ pub fn update_channels(
     // Only Admin can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
+    let mut changes = vec![];
     for (key, new_channel) in to_add.into_iter() {
         let key = key.check()?;
-        // Update function for new or existing keys
-        let insert = |_| -> StdResult<String> { Ok(new_channel) };
-        CHANNELS.update(deps.storage, &key, insert)?;
+        let old = save_channel(deps.storage, &key, &new_channel)?;
+        changes.push(AnsChange::Channel {
+            entry: key,
+            old,
+            new: Some(new_channel),
+        });
     }
 
     for key in to_remove {
         let key = key.check()?;
-        CHANNELS.remove(deps.storage, &key);
+        let old = remove_channel(deps.storage, &key)?;
+        changes.push(AnsChange::Channel {
+            entry: key,
+            old,
+            new: None,
+        });
     }
 
-    Ok(AnsHostResponse::action("update_channels"))
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response("update_channels", change_set_id))
+}
+
+/// Registers the id of a channel and its reverse lookup.
+/// Returns the previously registered channel id.
+pub(crate) fn save_channel(
+    storage: &mut dyn Storage,
+    entry: &ChannelEntry,
+    channel_id: &String,
+) -> StdResult<Option<String>> {
+    let old = remove_channel(storage, entry)?;
+    CHANNELS.save(storage, entry, channel_id)?;
+    REV_CHANNELS.save(storage, channel_id, entry)?;
+    Ok(old)
+}
+
+/// Removes the id of a channel and its reverse lookup.
+/// Returns the removed channel id.
+pub(crate) fn remove_channel(
+    storage: &mut dyn Storage,
+    entry: &ChannelEntry,
+) -> StdResult<Option<String>> {
+    let old = CHANNELS.may_load(storage, entry)?;
+    if let Some(channel_id) = &old {
+        CHANNELS.remove(storage, entry);
+        // Another entry might have been registered for the same channel afterwards
+        if REV_CHANNELS.may_load(storage, channel_id)?.as_ref() == Some(entry) {
+            REV_CHANNELS.remove(storage, channel_id);
+        }
+    }
+    Ok(old)
 }
 
 /// Updates the dex registry with additions and removals
 fn update_dex_registry(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<String>,
     to_remove: Vec<String>,
@@ -148,6 +267,8 @@ fn update_dex_registry(
     // Only Admin can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
+    let old_dexes = REGISTERED_DEXES.load(deps.storage)?;
+
     if !to_add.is_empty() {
         let register_dex = |mut dexes: Vec<String>| -> StdResult<Vec<String>> {
             for dex in to_add {
@@ -171,11 +292,17 @@ fn update_dex_registry(
         REGISTERED_DEXES.update(deps.storage, deregister_dex)?;
     }
 
-    Ok(AnsHostResponse::action("update_dexes"))
+    let changes = vec![AnsChange::Dexes {
+        old: old_dexes,
+        new: REGISTERED_DEXES.load(deps.storage)?,
+    }];
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response("update_dexes", change_set_id))
 }
 
 fn update_pools(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(UncheckedPoolAddress, PoolMetadata)>,
     to_remove: Vec<UniquePoolId>,
@@ -193,6 +320,7 @@ fn update_pools(
         REGISTERED_DEXES.load(deps.storage)?
     };
 
+    let mut changes = vec![];
     for (pool_id, mut pool_metadata) in to_add.into_iter() {
         let pool_id = pool_id.check(deps.api)?;
 
@@ -205,9 +333,23 @@ fn update_pools(
         }
 
         // Register each pair of assets as a pairing and link it to the pool id
-        register_pool_pairings(deps.storage, next_unique_pool_id, pool_id, assets, &dex)?;
+        register_pool_pairings(
+            deps.storage,
+            next_unique_pool_id,
+            pool_id.clone(),
+            assets,
+            &dex,
+        )?;
 
         POOL_METADATA.save(deps.storage, next_unique_pool_id, &pool_metadata)?;
+        changes.push(AnsChange::Pool {
+            id: next_unique_pool_id,
+            old: None,
+            new: Some(PoolRecord {
+                address: pool_id,
+                metadata: pool_metadata,
+            }),
+        });
 
         // Increment the unique pool id for the next pool
         next_unique_pool_id.increment();
@@ -223,6 +365,8 @@ fn update_pools(
             None => continue,
         };
 
+        let pool_address = load_pool_address(deps.storage, pool_id_to_remove, &pool_metadata)?;
+
         remove_pool_pairings(
             deps.storage,
             pool_id_to_remove,
@@ -232,6 +376,14 @@ fn update_pools(
 
         // remove the pool metadata
         POOL_METADATA.remove(deps.storage, pool_id_to_remove);
+        changes.push(AnsChange::Pool {
+            id: pool_id_to_remove,
+            old: Some(PoolRecord {
+                address: pool_address,
+                metadata: pool_metadata,
+            }),
+            new: None,
+        });
     }
 
     // Only update the next pool id if necessary
@@ -242,7 +394,140 @@ fn update_pools(
         })?;
     }
 
-    Ok(AnsHostResponse::action("update_pools"))
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response("update_pools", change_set_id))
+}
+
+/// Load the address of a registered pool from its asset pairings
+pub(crate) fn load_pool_address(
+    storage: &dyn Storage,
+    pool_id: UniquePoolId,
+    metadata: &PoolMetadata,
+) -> AnsHostResult<PoolAddress> {
+    let pairing = DexAssetPairing::new(
+        metadata.assets[0].clone(),
+        metadata.assets[1].clone(),
+        &metadata.dex,
+    );
+    ASSET_PAIRINGS
+        .load(storage, &pairing)?
+        .into_iter()
+        .find(|reference| reference.unique_id == pool_id)
+        .map(|reference| reference.pool_address)
+        .ok_or_else(|| AnsHostError::UnregisteredPool {
+            pool: pool_id.to_string(),
+        })
+}
+
+/// Adds, updates or removes the oracle sources of asset pairs.
+fn update_oracle_sources(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    to_add: Vec<(AssetPair, OracleSource)>,
+    to_remove: Vec<AssetPair>,
+) -> AnsHostResult {
+    // Only Admin can call this method
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    let mut changes = vec![];
+    for ((mut base, mut quote), source) in to_add.into_iter() {
+        base.format();
+        quote.format();
+        validate_oracle_source(deps.storage, &base, &quote, &source)?;
+
+        let old = ORACLE_SOURCES.may_load(deps.storage, (&base, &quote))?;
+        ORACLE_SOURCES.save(deps.storage, (&base, &quote), &source)?;
+        changes.push(AnsChange::OracleSource {
+            pair: (base, quote),
+            old,
+            new: Some(source),
+        });
+    }
+
+    for (mut base, mut quote) in to_remove {
+        base.format();
+        quote.format();
+        let old = ORACLE_SOURCES.may_load(deps.storage, (&base, &quote))?;
+        ORACLE_SOURCES.remove(deps.storage, (&base, &quote));
+        changes.push(AnsChange::OracleSource {
+            pair: (base, quote),
+            old,
+            new: None,
+        });
+    }
+
+    let change_set_id = record_change_set(deps.storage, &env, &msg_info.sender, changes, None)?;
+    Ok(change_set_response("update_oracle_sources", change_set_id))
+}
+
+/// Validate that the oracle source of a (base, quote) pair only references registered entries.
+fn validate_oracle_source(
+    storage: &dyn Storage,
+    base: &AssetEntry,
+    quote: &AssetEntry,
+    source: &OracleSource,
+) -> Result<(), AnsHostError> {
+    let invalid_source = |reason: &str| AnsHostError::InvalidOracleSource {
+        base: base.to_string(),
+        quote: quote.to_string(),
+        reason: reason.to_owned(),
+    };
+
+    if base == quote {
+        return Err(invalid_source("base and quote asset must differ"));
+    }
+
+    for asset in [base, quote] {
+        if !ASSET_ADDRESSES.has(storage, asset) {
+            return Err(AnsHostError::UnregisteredAsset {
+                asset: asset.to_string(),
+            });
+        }
+    }
+
+    match source {
+        OracleSource::Pyth {
+            contract,
+            price_feed_id,
+            max_confidence,
+            ..
+        } => {
+            if !CONTRACT_ADDRESSES.has(storage, contract) {
+                return Err(AnsHostError::UnregisteredContract {
+                    contract: contract.to_string(),
+                });
+            }
+            if price_feed_id.is_empty() {
+                return Err(invalid_source("pyth price feed id can't be empty"));
+            }
+            if max_confidence.is_zero() {
+                return Err(invalid_source("pyth max confidence can't be zero"));
+            }
+        }
+        OracleSource::DexTwap {
+            pool_id,
+            window_seconds,
+        } => {
+            let metadata = POOL_METADATA.may_load(storage, *pool_id)?.ok_or_else(|| {
+                AnsHostError::UnregisteredPool {
+                    pool: pool_id.to_string(),
+                }
+            })?;
+            if !metadata.assets.contains(base) || !metadata.assets.contains(quote) {
+                return Err(invalid_source("pool does not contain both assets"));
+            }
+            if *window_seconds == 0 {
+                return Err(invalid_source("twap window can't be zero"));
+            }
+        }
+        OracleSource::Fixed { price } => {
+            if price.is_zero() {
+                return Err(invalid_source("fixed price can't be zero"));
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Execute an action on every asset pairing in the list of assets
@@ -265,7 +550,7 @@ where
     Ok(())
 }
 
-fn register_pool_pairings(
+pub(crate) fn register_pool_pairings(
     storage: &mut dyn Storage,
     next_pool_id: UniquePoolId,
     pool_address: PoolAddress,
@@ -304,7 +589,7 @@ fn register_asset_pairing(
 }
 
 /// Remove the unique_pool_id (which is getting removed) from the list of pool ids for each asset pairing
-fn remove_pool_pairings(
+pub(crate) fn remove_pool_pairings(
     storage: &mut dyn Storage,
     pool_id_to_remove: UniquePoolId,
     dex: &DexName,
@@ -659,6 +944,102 @@ mod test {
             map_tester.test_remove_nonexistent(&mut deps)
         }
 
+        #[coverage_helper::test]
+        fn updates_reverse_lookup() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            let old_addr = deps.api.addr_make("old_address");
+            let new_addr = deps.api.addr_make("new_address");
+            let entry = ContractEntry {
+                protocol: "test_namespace".to_owned(),
+                contract: "test_contract".to_owned(),
+            };
+
+            let msg = update_contract_addresses_msg_builder(
+                vec![mock_contract_map_entry(&old_addr)],
+                vec![],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert_eq!(
+                REV_CONTRACT_ADDRESSES.load(&deps.storage, &old_addr)?,
+                entry
+            );
+
+            // Overwriting the address removes the old reverse entry
+            let msg = update_contract_addresses_msg_builder(
+                vec![mock_contract_map_entry(&new_addr)],
+                vec![],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert!(!REV_CONTRACT_ADDRESSES.has(&deps.storage, &old_addr));
+            assert_eq!(
+                REV_CONTRACT_ADDRESSES.load(&deps.storage, &new_addr)?,
+                entry
+            );
+
+            let msg = update_contract_addresses_msg_builder(
+                vec![],
+                vec![contract_entry("test_namespace", "test_contract")],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert!(!REV_CONTRACT_ADDRESSES.has(&deps.storage, &new_addr));
+
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn keeps_reverse_lookup_of_shared_address() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            let shared_addr = deps.api.addr_make("shared_address");
+            let first = ContractEntry {
+                protocol: "test_namespace".to_owned(),
+                contract: "first".to_owned(),
+            };
+            let second = ContractEntry {
+                protocol: "test_namespace".to_owned(),
+                contract: "second".to_owned(),
+            };
+
+            let msg = update_contract_addresses_msg_builder(
+                vec![
+                    contract_address_map_entry("test_namespace", "first", &shared_addr),
+                    contract_address_map_entry("test_namespace", "second", &shared_addr),
+                ],
+                vec![],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert_eq!(
+                REV_CONTRACT_ADDRESSES.load(&deps.storage, &shared_addr)?,
+                second
+            );
+
+            // The reverse lookup moves to the entry that still has the address
+            let msg = update_contract_addresses_msg_builder(
+                vec![],
+                vec![contract_entry("test_namespace", "second")],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert_eq!(
+                REV_CONTRACT_ADDRESSES.load(&deps.storage, &shared_addr)?,
+                first
+            );
+
+            // And is removed with the last entry
+            let msg = update_contract_addresses_msg_builder(
+                vec![],
+                vec![contract_entry("test_namespace", "first")],
+            );
+            execute_helper(&mut deps, msg, &abstr.owner)?;
+            assert!(!REV_CONTRACT_ADDRESSES.has(&deps.storage, &shared_addr));
+
+            Ok(())
+        }
+
         #[coverage_helper::test]
         fn add_multiple_contract_addresses() -> AnsHostTestResult {
             let mut deps = mock_dependencies();
@@ -1404,6 +1785,241 @@ mod test {
         }
     }
 
+    mod update_oracle_sources {
+        use super::*;
+
+        use abstract_std::objects::{ContractEntry, PoolType};
+        use cosmwasm_std::Decimal;
+
+        fn execute_update(
+            deps: &mut MockDeps,
+            to_add: Vec<(AssetPair, OracleSource)>,
+            to_remove: Vec<AssetPair>,
+            owner: &Addr,
+        ) -> AnsHostTestResult {
+            let msg = ExecuteMsg::UpdateOracleSources { to_add, to_remove };
+            execute_helper(deps, msg, owner)
+        }
+
+        fn pair(base: &str, quote: &str) -> AssetPair {
+            (AssetEntry::new(base), AssetEntry::new(quote))
+        }
+
+        fn fixed(percent: u64) -> OracleSource {
+            OracleSource::Fixed {
+                price: Decimal::percent(percent),
+            }
+        }
+
+        #[coverage_helper::test]
+        fn add_and_remove_fixed_source() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            register_assets_helper(&mut deps, vec!["juno".into(), "usd".into()], &abstr.owner)?;
+
+            execute_update(
+                &mut deps,
+                vec![(pair("JUNO", "usd"), fixed(150))],
+                vec![],
+                &abstr.owner,
+            )?;
+
+            let juno = AssetEntry::new("juno");
+            let usd = AssetEntry::new("usd");
+            let source = ORACLE_SOURCES.load(&deps.storage, (&juno, &usd))?;
+            assert_eq!(source, fixed(150));
+
+            execute_update(&mut deps, vec![], vec![pair("juno", "usd")], &abstr.owner)?;
+            assert!(!ORACLE_SOURCES.has(&deps.storage, (&juno, &usd)));
+
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn only_admin() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let not_owner = deps.api.addr_make("not_owner");
+
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), fixed(150))],
+                vec![],
+                &not_owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::Ownership(
+                    cw_ownable::OwnershipError::NotOwner
+                ))
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn invalid_sources() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            register_assets_helper(&mut deps, vec!["juno".into(), "usd".into()], &abstr.owner)?;
+
+            // unregistered asset
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("osmo", "usd"), fixed(150))],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredAsset {
+                    asset: "osmo".to_string(),
+                })
+            );
+
+            // same asset
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("usd", "usd"), fixed(100))],
+                vec![],
+                &abstr.owner,
+            );
+            assert!(matches!(res, Err(AnsHostError::InvalidOracleSource { .. })));
+
+            // zero price
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), fixed(0))],
+                vec![],
+                &abstr.owner,
+            );
+            assert!(matches!(res, Err(AnsHostError::InvalidOracleSource { .. })));
+
+            // unregistered pyth contract
+            let pyth = OracleSource::Pyth {
+                contract: ContractEntry {
+                    protocol: "pyth".to_string(),
+                    contract: "oracle".to_string(),
+                },
+                price_feed_id: "abcd".to_string(),
+                base_decimals: 6,
+                quote_decimals: 6,
+                max_confidence: Decimal::percent(1),
+            };
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), pyth)],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredContract {
+                    contract: "pyth:oracle".to_string(),
+                })
+            );
+
+            // pyth price without a confidence margin
+            let pyth_entry = ContractEntry {
+                protocol: "pyth".to_string(),
+                contract: "oracle".to_string(),
+            };
+            CONTRACT_ADDRESSES.save(&mut deps.storage, &pyth_entry, &deps.api.addr_make("pyth"))?;
+            let pyth = OracleSource::Pyth {
+                contract: pyth_entry,
+                price_feed_id: "abcd".to_string(),
+                base_decimals: 6,
+                quote_decimals: 6,
+                max_confidence: Decimal::zero(),
+            };
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), pyth)],
+                vec![],
+                &abstr.owner,
+            );
+            assert!(matches!(res, Err(AnsHostError::InvalidOracleSource { .. })));
+
+            // unregistered pool
+            let twap = OracleSource::DexTwap {
+                pool_id: UniquePoolId::new(1),
+                window_seconds: 600,
+            };
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), twap)],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredPool {
+                    pool: "1".to_string(),
+                })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn dex_twap_pool_must_contain_pair() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps).unwrap();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            register_assets_helper(
+                &mut deps,
+                vec!["juno".into(), "usd".into(), "osmo".into()],
+                &abstr.owner,
+            )?;
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateDexes {
+                    to_add: vec!["junoswap".into()],
+                    to_remove: vec![],
+                },
+                &abstr.owner,
+            )?;
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdatePools {
+                    to_add: vec![(
+                        UncheckedPoolAddress::id(1u64),
+                        PoolMetadata::new(
+                            "junoswap",
+                            PoolType::ConstantProduct,
+                            vec!["juno", "usd"],
+                        ),
+                    )],
+                    to_remove: vec![],
+                },
+                &abstr.owner,
+            )?;
+
+            let twap = OracleSource::DexTwap {
+                pool_id: UniquePoolId::new(1),
+                window_seconds: 600,
+            };
+            let res = execute_update(
+                &mut deps,
+                vec![(pair("osmo", "usd"), twap.clone())],
+                vec![],
+                &abstr.owner,
+            );
+            assert!(matches!(res, Err(AnsHostError::InvalidOracleSource { .. })));
+
+            execute_update(
+                &mut deps,
+                vec![(pair("juno", "usd"), twap.clone())],
+                vec![],
+                &abstr.owner,
+            )?;
+            let juno = AssetEntry::new("juno");
+            let usd = AssetEntry::new("usd");
+            assert_eq!(ORACLE_SOURCES.load(&deps.storage, (&juno, &usd))?, twap);
+            Ok(())
+        }
+    }
+
     mod validate_pool_assets {
         use super::*;
 
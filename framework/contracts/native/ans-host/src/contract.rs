@@ -2,13 +2,16 @@ use abstract_macros::abstract_response;
 use abstract_sdk::query_ownership;
 use abstract_std::{
     ans_host::{
-        state::{Config, CONFIG, REGISTERED_DEXES},
+        state::{
+            Config, CHANNELS, CONFIG, CONTRACT_ADDRESSES, REGISTERED_DEXES, REV_CHANNELS,
+            REV_CONTRACT_ADDRESSES,
+        },
         ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
     },
     objects::module_version::assert_contract_upgrade,
     ANS_HOST,
 };
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult};
 use cw2::set_contract_version;
 use semver::Version;
 
@@ -74,12 +77,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
             filter: _filter,
         } => queries::query_contract_list(deps, start_after, limit),
+        QueryMsg::ContractAddresses { addresses } => {
+            queries::query_contract_addresses(deps, addresses)
+        }
         QueryMsg::Channels { entries: names } => queries::query_channels(deps, env, names),
         QueryMsg::ChannelList {
             start_after,
             limit,
             filter: _filter,
         } => queries::query_channel_list(deps, start_after, limit),
+        QueryMsg::ChannelIds { ids } => queries::query_channel_ids(deps, ids),
         QueryMsg::RegisteredDexes {} => queries::query_registered_dexes(deps, env),
         QueryMsg::PoolList {
             filter,
@@ -94,6 +101,19 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => queries::list_pool_metadata_entries(deps, filter, start_after, limit),
+        QueryMsg::OracleSources { pairs } => queries::query_oracle_sources(deps, pairs),
+        QueryMsg::OracleSourceList { start_after, limit } => {
+            queries::query_oracle_source_list(deps, start_after, limit)
+        }
+        QueryMsg::ChangeSets { ids } => queries::query_change_sets(deps, ids),
+        QueryMsg::ChangeSetList { start_after, limit } => {
+            queries::query_change_set_list(deps, start_after, limit)
+        }
+        QueryMsg::EntryHistory {
+            entry,
+            start_after,
+            limit,
+        } => queries::query_entry_history(deps, entry, start_after, limit),
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
 }
@@ -110,6 +130,20 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> AnsHostResult {
             assert_contract_upgrade(deps.storage, ANS_HOST, version)?;
             set_contract_version(deps.storage, ANS_HOST, CONTRACT_VERSION)?;
 
+            // Populate the reverse lookups of entries registered before they existed
+            let contracts = CONTRACT_ADDRESSES
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for (entry, address) in contracts {
+                REV_CONTRACT_ADDRESSES.save(deps.storage, &address, &entry)?;
+            }
+            let channels = CHANNELS
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for (entry, channel_id) in channels {
+                REV_CHANNELS.save(deps.storage, &channel_id, &entry)?;
+            }
+
             Ok(AnsHostResponse::action("migrate"))
         }
     }
@@ -29,4 +29,30 @@ pub enum AnsHostError {
 
     #[error("Asset {} is not registered", asset)]
     UnregisteredAsset { asset: String },
+
+    #[error("Contract {} is not registered", contract)]
+    UnregisteredContract { contract: String },
+
+    #[error("Pool {} is not registered", pool)]
+    UnregisteredPool { pool: String },
+
+    #[error("Invalid oracle source for {}/{}: {}", base, quote, reason)]
+    InvalidOracleSource {
+        base: String,
+        quote: String,
+        reason: String,
+    },
+
+    #[error("Change set {} does not exist", id)]
+    ChangeSetNotFound { id: u64 },
+
+    #[error("Change set {} was already reverted by change set {}", id, reverted_by)]
+    AlreadyReverted { id: u64, reverted_by: u64 },
+
+    #[error(
+        "Can't revert change set {}: entry {} was changed afterwards",
+        id,
+        entry
+    )]
+    RevertConflict { id: u64, entry: String },
 }
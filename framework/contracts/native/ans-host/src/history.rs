@@ -0,0 +1,412 @@
+use abstract_std::ans_host::{
+    state::{
+        ASSET_ADDRESSES, CHANGE_SETS, CHANGE_SET_SEQUENCE, CHANNELS, CONTRACT_ADDRESSES,
+        ENTRY_CHANGE_SETS, ORACLE_SOURCES, POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES,
+    },
+    AnsChange, ChangeSet, PoolRecord,
+};
+use cosmwasm_std::{Addr, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Storage};
+
+use crate::{
+    commands::{
+        load_pool_address, register_pool_pairings, remove_channel, remove_contract_address,
+        remove_pool_pairings, save_channel, save_contract_address,
+    },
+    contract::{AnsHostResponse, AnsHostResult},
+    error::AnsHostError,
+};
+
+/// Stores the changes applied by an execution as a new change set.
+/// Changes that didn't modify their entry are dropped and no change set is stored if nothing changed.
+/// Returns the id of the stored change set.
+pub fn record_change_set(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    changes: Vec<AnsChange>,
+    reverts: Option<u64>,
+) -> StdResult<Option<u64>> {
+    let changes: Vec<AnsChange> = changes
+        .into_iter()
+        .filter(|change| !change.is_noop())
+        .collect();
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    let id = CHANGE_SET_SEQUENCE.may_load(storage)?.unwrap_or_default() + 1;
+    CHANGE_SET_SEQUENCE.save(storage, &id)?;
+
+    for change in &changes {
+        ENTRY_CHANGE_SETS.save(storage, (change.entry().key().as_str(), id), &Empty {})?;
+    }
+
+    CHANGE_SETS.save(
+        storage,
+        id,
+        &ChangeSet {
+            sender: sender.clone(),
+            block_height: env.block.height,
+            block_time: env.block.time,
+            changes,
+            reverts,
+            reverted_by: None,
+        },
+    )?;
+    Ok(Some(id))
+}
+
+/// Response for an action, including the id of the change set it stored, if any.
+pub fn change_set_response(action: &str, change_set_id: Option<u64>) -> Response {
+    match change_set_id {
+        Some(id) => AnsHostResponse::new(action, vec![("change_set_id", id.to_string())]),
+        None => AnsHostResponse::action(action),
+    }
+}
+
+/// Restores the values that were overwritten by a change set.
+/// Fails if any of the changed entries was modified after the change set.
+pub fn revert_change_set(deps: DepsMut, env: Env, info: MessageInfo, id: u64) -> AnsHostResult {
+    // Only Admin can call this method
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut change_set = CHANGE_SETS
+        .may_load(deps.storage, id)?
+        .ok_or(AnsHostError::ChangeSetNotFound { id })?;
+    if let Some(reverted_by) = change_set.reverted_by {
+        return Err(AnsHostError::AlreadyReverted { id, reverted_by });
+    }
+
+    // Undo the changes in reverse order to restore the original state
+    let mut reverse_changes = Vec::with_capacity(change_set.changes.len());
+    for change in change_set.changes.iter().rev() {
+        reverse_changes.push(revert_change(deps.storage, id, change)?);
+    }
+
+    let revert_id = record_change_set(deps.storage, &env, &info.sender, reverse_changes, Some(id))?;
+    change_set.reverted_by = revert_id;
+    CHANGE_SETS.save(deps.storage, id, &change_set)?;
+
+    Ok(change_set_response("revert_change_set", revert_id)
+        .add_attribute("reverted", id.to_string()))
+}
+
+/// Restores the old value of a single change and returns the change that was applied to do so.
+fn revert_change(
+    storage: &mut dyn Storage,
+    change_set_id: u64,
+    change: &AnsChange,
+) -> AnsHostResult<AnsChange> {
+    let conflict = || AnsHostError::RevertConflict {
+        id: change_set_id,
+        entry: change.entry().key(),
+    };
+
+    let reverse = match change {
+        AnsChange::Contract { entry, old, new } => {
+            if &CONTRACT_ADDRESSES.may_load(storage, entry)? != new {
+                return Err(conflict());
+            }
+            match old {
+                Some(address) => save_contract_address(storage, entry, address)?,
+                None => remove_contract_address(storage, entry)?,
+            };
+            AnsChange::Contract {
+                entry: entry.clone(),
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+        AnsChange::Asset { entry, old, new } => {
+            if &ASSET_ADDRESSES.may_load(storage, entry)? != new {
+                return Err(conflict());
+            }
+            if let Some(info) = new {
+                REV_ASSET_ADDRESSES.remove(storage, info);
+            }
+            match old {
+                Some(info) => {
+                    ASSET_ADDRESSES.save(storage, entry, info)?;
+                    REV_ASSET_ADDRESSES.save(storage, info, entry)?;
+                }
+                None => ASSET_ADDRESSES.remove(storage, entry),
+            }
+            AnsChange::Asset {
+                entry: entry.clone(),
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+        AnsChange::Channel { entry, old, new } => {
+            if &CHANNELS.may_load(storage, entry)? != new {
+                return Err(conflict());
+            }
+            match old {
+                Some(channel) => save_channel(storage, entry, channel)?,
+                None => remove_channel(storage, entry)?,
+            };
+            AnsChange::Channel {
+                entry: entry.clone(),
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+        AnsChange::Dexes { old, new } => {
+            if &REGISTERED_DEXES.load(storage)? != new {
+                return Err(conflict());
+            }
+            REGISTERED_DEXES.save(storage, old)?;
+            AnsChange::Dexes {
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+        AnsChange::Pool { id, old, new } => {
+            let current = match POOL_METADATA.may_load(storage, *id)? {
+                Some(metadata) => Some(PoolRecord {
+                    address: load_pool_address(storage, *id, &metadata)?,
+                    metadata,
+                }),
+                None => None,
+            };
+            if &current != new {
+                return Err(conflict());
+            }
+            if let Some(PoolRecord { metadata, .. }) = new {
+                remove_pool_pairings(storage, *id, &metadata.dex, &metadata.assets)?;
+                POOL_METADATA.remove(storage, *id);
+            }
+            if let Some(PoolRecord { address, metadata }) = old {
+                register_pool_pairings(
+                    storage,
+                    *id,
+                    address.clone(),
+                    &metadata.assets,
+                    &metadata.dex.to_ascii_lowercase(),
+                )?;
+                POOL_METADATA.save(storage, *id, metadata)?;
+            }
+            AnsChange::Pool {
+                id: *id,
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+        AnsChange::OracleSource { pair, old, new } => {
+            let (base, quote) = pair;
+            if &ORACLE_SOURCES.may_load(storage, (base, quote))? != new {
+                return Err(conflict());
+            }
+            match old {
+                Some(source) => ORACLE_SOURCES.save(storage, (base, quote), source)?,
+                None => ORACLE_SOURCES.remove(storage, (base, quote)),
+            }
+            AnsChange::OracleSource {
+                pair: pair.clone(),
+                old: new.clone(),
+                new: old.clone(),
+            }
+        }
+    };
+    Ok(reverse)
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+    use abstract_std::{
+        ans_host::{state::ASSET_PAIRINGS, ExecuteMsg},
+        objects::{
+            pool_id::UncheckedPoolAddress, AssetEntry, DexAssetPairing, PoolMetadata, PoolType,
+            UncheckedContractEntry, UniquePoolId,
+        },
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::testing::*;
+    use cw_asset::{AssetInfo, AssetInfoUnchecked};
+
+    use super::*;
+    use crate::{contract, test_common::*};
+
+    type AnsHostTestResult = Result<(), AnsHostError>;
+
+    fn execute_helper(deps: &mut MockDeps, msg: ExecuteMsg, sender: &Addr) -> AnsHostResult {
+        let env = mock_env_validated(deps.api);
+        contract::execute(deps.as_mut(), env, message_info(sender, &[]), msg)
+    }
+
+    fn update_asset(deps: &mut MockDeps, name: &str, denom: &str, owner: &Addr) -> AnsHostResult {
+        let msg = ExecuteMsg::UpdateAssetAddresses {
+            to_add: vec![(name.to_owned(), AssetInfoUnchecked::native(denom))],
+            to_remove: vec![],
+        };
+        execute_helper(deps, msg, owner)
+    }
+
+    fn revert(deps: &mut MockDeps, id: u64, sender: &Addr) -> AnsHostResult {
+        execute_helper(deps, ExecuteMsg::RevertChangeSet { id }, sender)
+    }
+
+    #[coverage_helper::test]
+    fn records_change_sets() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let res = update_asset(&mut deps, "juno", "ujuno", &abstr.owner)?;
+        assert!(res.events[0]
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "change_set_id" && attr.value == "1"));
+
+        let change_set = CHANGE_SETS.load(&deps.storage, 1)?;
+        assert_eq!(change_set.sender, abstr.owner);
+        assert_eq!(
+            change_set.changes,
+            vec![AnsChange::Asset {
+                entry: AssetEntry::new("juno"),
+                old: None,
+                new: Some(AssetInfo::native("ujuno")),
+            }]
+        );
+
+        // Removing a missing entry doesn't change anything, so no change set is recorded
+        let msg = ExecuteMsg::UpdateContractAddresses {
+            to_add: vec![],
+            to_remove: vec![UncheckedContractEntry::new("protocol", "contract")],
+        };
+        execute_helper(&mut deps, msg, &abstr.owner)?;
+        assert_eq!(CHANGE_SET_SEQUENCE.load(&deps.storage)?, 1);
+        assert!(!CHANGE_SETS.has(&deps.storage, 2));
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn reverts_asset_update() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let juno = AssetEntry::new("juno");
+
+        update_asset(&mut deps, "juno", "ujuno", &abstr.owner)?;
+        update_asset(&mut deps, "juno", "ibc/juno", &abstr.owner)?;
+
+        // Can't revert the first change set, the entry was changed afterwards
+        let res = revert(&mut deps, 1, &abstr.owner);
+        assert_eq!(
+            res,
+            Err(AnsHostError::RevertConflict {
+                id: 1,
+                entry: "asset:juno".to_owned(),
+            })
+        );
+
+        revert(&mut deps, 2, &abstr.owner)?;
+        assert_eq!(
+            ASSET_ADDRESSES.load(&deps.storage, &juno)?,
+            AssetInfo::native("ujuno")
+        );
+        assert_eq!(
+            REV_ASSET_ADDRESSES.load(&deps.storage, &AssetInfo::native("ujuno"))?,
+            juno
+        );
+        assert!(!REV_ASSET_ADDRESSES.has(&deps.storage, &AssetInfo::native("ibc/juno")));
+
+        let reverted = CHANGE_SETS.load(&deps.storage, 2)?;
+        assert_eq!(reverted.reverted_by, Some(3));
+        let revert_set = CHANGE_SETS.load(&deps.storage, 3)?;
+        assert_eq!(revert_set.reverts, Some(2));
+
+        let res = revert(&mut deps, 2, &abstr.owner);
+        assert_eq!(
+            res,
+            Err(AnsHostError::AlreadyReverted {
+                id: 2,
+                reverted_by: 3
+            })
+        );
+
+        // The first change set can be reverted now
+        revert(&mut deps, 1, &abstr.owner)?;
+        assert!(!ASSET_ADDRESSES.has(&deps.storage, &juno));
+        assert!(!REV_ASSET_ADDRESSES.has(&deps.storage, &AssetInfo::native("ujuno")));
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn reverts_pool_removal() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        update_asset(&mut deps, "juno", "ujuno", &abstr.owner)?;
+        update_asset(&mut deps, "osmo", "uosmo", &abstr.owner)?;
+        let msg = ExecuteMsg::UpdateDexes {
+            to_add: vec!["junoswap".into()],
+            to_remove: vec![],
+        };
+        execute_helper(&mut deps, msg, &abstr.owner)?;
+
+        let pool_addr = deps.api.addr_make("pool");
+        let metadata = PoolMetadata::new("junoswap", PoolType::Weighted, vec!["juno", "osmo"]);
+        let msg = ExecuteMsg::UpdatePools {
+            to_add: vec![(UncheckedPoolAddress::contract(&pool_addr), metadata.clone())],
+            to_remove: vec![],
+        };
+        execute_helper(&mut deps, msg, &abstr.owner)?;
+
+        let pool_id = UniquePoolId::new(1);
+        let msg = ExecuteMsg::UpdatePools {
+            to_add: vec![],
+            to_remove: vec![pool_id],
+        };
+        let res = execute_helper(&mut deps, msg, &abstr.owner)?;
+        let removal_id = res.events[0]
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "change_set_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap();
+        assert!(!POOL_METADATA.has(&deps.storage, pool_id));
+
+        revert(&mut deps, removal_id, &abstr.owner)?;
+        assert_eq!(POOL_METADATA.load(&deps.storage, pool_id)?, metadata);
+        let pairing = DexAssetPairing::new("juno".into(), "osmo".into(), "junoswap");
+        let references = ASSET_PAIRINGS.load(&deps.storage, &pairing)?;
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].unique_id, pool_id);
+        assert_eq!(
+            references[0].pool_address,
+            UncheckedPoolAddress::contract(&pool_addr).check(&deps.api)?
+        );
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn revert_validation() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let not_owner = deps.api.addr_make("not_owner");
+
+        update_asset(&mut deps, "juno", "ujuno", &abstr.owner)?;
+
+        let res = revert(&mut deps, 1, &not_owner);
+        assert_eq!(
+            res,
+            Err(AnsHostError::Ownership(
+                cw_ownable::OwnershipError::NotOwner
+            ))
+        );
+
+        let res = revert(&mut deps, 5, &abstr.owner);
+        assert_eq!(res, Err(AnsHostError::ChangeSetNotFound { id: 5 }));
+
+        Ok(())
+    }
+}
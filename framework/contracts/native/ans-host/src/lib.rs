@@ -3,6 +3,7 @@
 pub mod commands;
 pub mod contract;
 pub mod error;
+pub mod history;
 pub mod queries;
 
 #[cfg(test)]
@@ -1,15 +1,18 @@
 use abstract_std::{
     ans_host::{
         state::{
-            Config, ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONFIG, CONTRACT_ADDRESSES,
-            POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES,
+            Config, ASSET_ADDRESSES, ASSET_PAIRINGS, CHANGE_SETS, CHANNELS, CONFIG,
+            CONTRACT_ADDRESSES, ENTRY_CHANGE_SETS, ORACLE_SOURCES, POOL_METADATA, REGISTERED_DEXES,
+            REV_ASSET_ADDRESSES, REV_CHANNELS, REV_CONTRACT_ADDRESSES,
         },
-        AssetInfoListResponse, AssetInfoMapEntry, AssetInfosResponse, AssetListResponse,
-        AssetMapEntry, AssetPairingFilter, AssetPairingMapEntry, AssetsResponse,
-        ChannelListResponse, ChannelMapEntry, ChannelsResponse, ConfigResponse,
-        ContractListResponse, ContractMapEntry, ContractsResponse, PoolAddressListResponse,
-        PoolMetadataFilter, PoolMetadataListResponse, PoolMetadataMapEntry, PoolMetadatasResponse,
-        PoolsResponse, RegisteredDexesResponse,
+        AnsHistoryEntry, AssetInfoListResponse, AssetInfoMapEntry, AssetInfosResponse,
+        AssetListResponse, AssetMapEntry, AssetPair, AssetPairingFilter, AssetPairingMapEntry,
+        AssetsResponse, ChangeSetListResponse, ChangeSetMapEntry, ChangeSetsResponse,
+        ChannelIdsResponse, ChannelListResponse, ChannelMapEntry, ChannelsResponse, ConfigResponse,
+        ContractAddressesResponse, ContractListResponse, ContractMapEntry, ContractsResponse,
+        OracleSourceListResponse, OracleSourceMapEntry, OracleSourcesResponse,
+        PoolAddressListResponse, PoolMetadataFilter, PoolMetadataListResponse,
+        PoolMetadataMapEntry, PoolMetadatasResponse, PoolsResponse, RegisteredDexesResponse,
     },
     objects::{
         AssetEntry, ChannelEntry, ContractEntry, DexAssetPairing, DexName, PoolMetadata,
@@ -146,6 +149,19 @@ pub fn query_contract_list(
     to_json_binary(&ContractListResponse { contracts: res? })
 }
 
+pub fn query_contract_addresses(deps: Deps, addresses: Vec<String>) -> StdResult<Binary> {
+    let contracts = addresses
+        .into_iter()
+        .map(|address| {
+            let address = deps.api.addr_validate(&address)?;
+            let entry = REV_CONTRACT_ADDRESSES.load(deps.storage, &address)?;
+            Ok((address, entry))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ContractAddressesResponse { contracts })
+}
+
 pub fn query_channel_list(
     deps: Deps,
     last_channel: Option<ChannelEntry>,
@@ -162,6 +178,18 @@ pub fn query_channel_list(
     to_json_binary(&ChannelListResponse { channels: res? })
 }
 
+pub fn query_channel_ids(deps: Deps, ids: Vec<String>) -> StdResult<Binary> {
+    let channels = ids
+        .into_iter()
+        .map(|id| {
+            let entry = REV_CHANNELS.load(deps.storage, &id)?;
+            Ok((id, entry))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ChannelIdsResponse { channels })
+}
+
 pub fn query_registered_dexes(deps: Deps, _env: Env) -> StdResult<Binary> {
     let dexes = REGISTERED_DEXES.load(deps.storage)?;
 
@@ -297,6 +325,86 @@ fn load_pool_metadata_entry(
     Ok((key, value))
 }
 
+pub fn query_oracle_sources(deps: Deps, pairs: Vec<AssetPair>) -> StdResult<Binary> {
+    let sources = pairs
+        .into_iter()
+        .map(|(base, quote)| {
+            let value = ORACLE_SOURCES.load(deps.storage, (&base, &quote))?;
+            Ok(((base, quote), value))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&OracleSourcesResponse { sources })
+}
+
+pub fn query_oracle_source_list(
+    deps: Deps,
+    start_after: Option<AssetPair>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(base, quote)| Bound::exclusive((base, quote)));
+
+    let res: Result<Vec<OracleSourceMapEntry>, _> = ORACLE_SOURCES
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect();
+
+    to_json_binary(&OracleSourceListResponse { sources: res? })
+}
+
+pub fn query_change_sets(deps: Deps, ids: Vec<u64>) -> StdResult<Binary> {
+    let change_sets = ids
+        .into_iter()
+        .map(|id| Ok((id, CHANGE_SETS.load(deps.storage, id)?)))
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ChangeSetsResponse { change_sets })
+}
+
+pub fn query_change_set_list(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.map(Bound::exclusive);
+
+    let res: Result<Vec<ChangeSetMapEntry>, _> = CHANGE_SETS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect();
+
+    to_json_binary(&ChangeSetListResponse { change_sets: res? })
+}
+
+/// Lists the change sets that modified an entry, only including the changes of that entry.
+pub fn query_entry_history(
+    deps: Deps,
+    entry: AnsHistoryEntry,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.map(Bound::exclusive);
+
+    let change_sets = ENTRY_CHANGE_SETS
+        .prefix(&entry.key())
+        .keys(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|id| {
+            let id = id?;
+            let mut change_set = CHANGE_SETS.load(deps.storage, id)?;
+            change_set.changes.retain(|change| change.entry() == entry);
+            Ok((id, change_set))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&ChangeSetListResponse { change_sets })
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::needless_borrows_for_generic_args)]
@@ -309,10 +417,10 @@ mod test {
     };
     use abstract_std::{
         ans_host::*,
-        objects::{pool_id::PoolAddressBase, PoolType, TruncatedChainId},
+        objects::{pool_id::PoolAddressBase, OracleSource, PoolType, TruncatedChainId},
     };
     use abstract_testing::{addresses::AbstractMockAddrs, mock_env_validated};
-    use cosmwasm_std::{from_json, testing::*, Addr, DepsMut, OwnedDeps};
+    use cosmwasm_std::{from_json, testing::*, Addr, Decimal, DepsMut, OwnedDeps};
     use cw_asset::AssetInfo;
     use std::str::FromStr;
 
@@ -465,8 +573,8 @@ mod test {
     ) -> Result<(), cosmwasm_std::StdError> {
         for (key, new_address) in to_add.into_iter() {
             let addr = deps.as_ref().api.addr_validate(&new_address)?;
-            let insert = |_| -> StdResult<Addr> { Ok(addr) };
-            CONTRACT_ADDRESSES.update(deps.storage, &key, insert)?;
+            CONTRACT_ADDRESSES.save(deps.storage, &key, &addr)?;
+            REV_CONTRACT_ADDRESSES.save(deps.storage, &addr, &key)?;
         }
         Ok(())
     }
@@ -476,9 +584,8 @@ mod test {
         to_add: Vec<ChannelMapEntry>,
     ) -> Result<(), cosmwasm_std::StdError> {
         for (key, new_channel) in to_add.into_iter() {
-            // Update function for new or existing keys
-            let insert = |_| -> StdResult<String> { Ok(new_channel) };
-            CHANNELS.update(deps.storage, &key, insert)?;
+            CHANNELS.save(deps.storage, &key, &new_channel)?;
+            REV_CHANNELS.save(deps.storage, &new_channel, &key)?;
         }
         Ok(())
     }
@@ -685,6 +792,66 @@ mod test {
         Ok(())
     }
 
+    #[coverage_helper::test]
+    fn test_query_contract_addresses() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+
+        let foo_addr = deps.api.addr_make("foo");
+        let to_add = create_contract_entry_and_string(vec![
+            ("foo", "foo", &foo_addr),
+            ("bar", "bar", &deps.api.addr_make("bar")),
+        ]);
+        update_contract_addresses(deps.as_mut(), to_add)?;
+
+        let msg = QueryMsg::ContractAddresses {
+            addresses: vec![foo_addr.to_string()],
+        };
+        let res: ContractAddressesResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(
+            res.contracts,
+            vec![(
+                foo_addr,
+                create_contract_entry(vec![("foo", "foo")]).remove(0)
+            )]
+        );
+
+        // Unknown address
+        let msg = QueryMsg::ContractAddresses {
+            addresses: vec![deps.api.addr_make("baz").to_string()],
+        };
+        assert!(query_helper(&deps, msg).is_err());
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn test_query_channel_ids() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+
+        let to_add = create_channel_entry_and_string(vec![
+            ("foo", "foo", "channel-1"),
+            ("bar", "bar", "channel-2"),
+        ]);
+        update_channels(deps.as_mut(), to_add.clone())?;
+
+        let msg = QueryMsg::ChannelIds {
+            ids: vec!["channel-2".to_owned()],
+        };
+        let res: ChannelIdsResponse = from_json(query_helper(&deps, msg)?)?;
+        let (bar_entry, bar_id) = to_add[1].clone();
+        assert_eq!(res.channels, vec![(bar_id, bar_entry)]);
+
+        // Unknown channel id
+        let msg = QueryMsg::ChannelIds {
+            ids: vec!["channel-3".to_owned()],
+        };
+        assert!(query_helper(&deps, msg).is_err());
+
+        Ok(())
+    }
+
     #[coverage_helper::test]
     fn test_query_asset_list() -> AnsHostTestResult {
         // arrange mocks
@@ -1312,4 +1479,146 @@ mod test {
 
         Ok(())
     }
+
+    #[coverage_helper::test]
+    fn test_query_oracle_sources() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+
+        let foo = AssetEntry::new("foo");
+        let bar = AssetEntry::new("bar");
+        let baz = AssetEntry::new("baz");
+        let foo_bar = OracleSource::Fixed {
+            price: Decimal::percent(150),
+        };
+        let baz_bar = OracleSource::DexTwap {
+            pool_id: UniquePoolId::new(1),
+            window_seconds: 600,
+        };
+        ORACLE_SOURCES.save(&mut deps.storage, (&foo, &bar), &foo_bar)?;
+        ORACLE_SOURCES.save(&mut deps.storage, (&baz, &bar), &baz_bar)?;
+
+        let msg = QueryMsg::OracleSources {
+            pairs: vec![(foo.clone(), bar.clone())],
+        };
+        let res: OracleSourcesResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(
+            res.sources,
+            vec![((foo.clone(), bar.clone()), foo_bar.clone())]
+        );
+
+        // Reverse pair is not registered
+        let res = query_helper(
+            &deps,
+            QueryMsg::OracleSources {
+                pairs: vec![(bar.clone(), foo.clone())],
+            },
+        );
+        assert!(res.is_err());
+
+        let msg = QueryMsg::OracleSourceList {
+            start_after: None,
+            limit: None,
+        };
+        let res: OracleSourceListResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(
+            res.sources,
+            vec![
+                ((baz.clone(), bar.clone()), baz_bar),
+                ((foo.clone(), bar.clone()), foo_bar.clone()),
+            ]
+        );
+
+        // Start after
+        let msg = QueryMsg::OracleSourceList {
+            start_after: Some((baz, bar.clone())),
+            limit: None,
+        };
+        let res: OracleSourceListResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(res.sources, vec![((foo, bar), foo_bar)]);
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn test_query_change_history() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let env = mock_env_validated(deps.api);
+
+        let juno = AssetEntry::new("juno");
+        let osmo = AssetEntry::new("osmo");
+        for (to_add, to_remove) in [
+            (
+                vec![
+                    ("juno".to_owned(), AssetInfoUnchecked::native("ujuno")),
+                    ("osmo".to_owned(), AssetInfoUnchecked::native("uosmo")),
+                ],
+                vec![],
+            ),
+            (vec![], vec!["osmo".to_owned()]),
+        ] {
+            contract::execute(
+                deps.as_mut(),
+                env.clone(),
+                message_info(&abstr.owner, &[]),
+                ExecuteMsg::UpdateAssetAddresses { to_add, to_remove },
+            )?;
+        }
+
+        let msg = QueryMsg::ChangeSets { ids: vec![2] };
+        let res: ChangeSetsResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(res.change_sets.len(), 1);
+        assert_eq!(
+            res.change_sets[0].1.changes,
+            vec![AnsChange::Asset {
+                entry: osmo.clone(),
+                old: Some(AssetInfo::native("uosmo")),
+                new: None,
+            }]
+        );
+
+        let msg = QueryMsg::ChangeSetList {
+            start_after: Some(1),
+            limit: None,
+        };
+        let res: ChangeSetListResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(
+            res.change_sets
+                .iter()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        // Only the changes of the requested entry are returned
+        let msg = QueryMsg::EntryHistory {
+            entry: AnsHistoryEntry::Asset(osmo),
+            start_after: None,
+            limit: None,
+        };
+        let res: ChangeSetListResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(res.change_sets.len(), 2);
+        assert!(res
+            .change_sets
+            .iter()
+            .all(|(_, change_set)| change_set.changes.len() == 1));
+
+        let msg = QueryMsg::EntryHistory {
+            entry: AnsHistoryEntry::Asset(juno),
+            start_after: None,
+            limit: None,
+        };
+        let res: ChangeSetListResponse = from_json(query_helper(&deps, msg)?)?;
+        assert_eq!(
+            res.change_sets
+                .iter()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+
+        Ok(())
+    }
 }
@@ -10,23 +10,24 @@ use abstract_std::{
     ibc::{polytone_callbacks::CallbackRequest, Callback, ModuleQuery},
     ibc_client::{
         state::{
-            AccountCallbackPayload, IbcInfrastructure, ACCOUNTS, IBC_INFRA,
-            ICS20_ACCOUNT_CALLBACK_PAYLOAD, REVERSE_POLYTONE_NOTE,
+            route_key, AccountCallbackPayload, IbcInfrastructure, ACCOUNTS, IBC_INFRA,
+            ICS20_ACCOUNT_CALLBACK_PAYLOAD, REVERSE_POLYTONE_NOTE, ROUTED_ACCOUNTS,
         },
-        IbcClientCallback, InstalledModuleIdentification, PolytoneNoteExecuteMsg,
+        ForwardedCallback, IbcClientCallback, InstalledModuleIdentification,
+        PolytoneNoteExecuteMsg,
     },
     ibc_host::{self, HostAction, InternalAction},
     native_addrs,
     objects::{
-        module::ModuleInfo, module_reference::ModuleReference, AccountId, ChannelEntry,
-        TruncatedChainId,
+        account::AccountTrace, module::ModuleInfo, module_reference::ModuleReference, AccountId,
+        ChannelEntry, TruncatedChainId,
     },
     registry::Account,
-    IBC_CLIENT, ICS20,
+    IBC_CLIENT, IBC_HOST, ICS20,
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, wasm_execute, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
-    IbcMsg, MessageInfo, QueryRequest, SubMsg, WasmQuery,
+    ensure, ensure_eq, to_json_binary, wasm_execute, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty,
+    Env, IbcMsg, MessageInfo, QueryRequest, SubMsg, WasmQuery,
 };
 use cw_storage_plus::Item;
 
@@ -378,6 +379,85 @@ pub fn execute_register_account(
     Ok(IbcClientResponse::action("handle_register").add_message(note_message))
 }
 
+/// Handles a callback that's forwarded by the ibc host.
+/// Callbacks of local accounts are applied, others are forwarded further to the chain the account originates from.
+pub fn execute_forward_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account_id: AccountId,
+    route: Vec<TruncatedChainId>,
+    callback: ForwardedCallback,
+) -> IbcClientResult {
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+
+    // Only the ibc host can forward callbacks
+    let ibc_host = registry
+        .query_module(ModuleInfo::from_id_latest(IBC_HOST)?, &deps.querier)?
+        .reference
+        .unwrap_native()?;
+    ensure_eq!(info.sender, ibc_host, IbcClientError::Unauthorized {});
+
+    let response = IbcClientResponse::action("forward_callback")
+        .add_attribute("account_id", account_id.to_string())
+        .add_attribute("route", route_key(&route));
+
+    if account_id.trace() != &AccountTrace::Local {
+        let forward_msg = forward_callback_msg(deps.as_ref(), &env, account_id, route, callback)?;
+        return Ok(response.add_messages(forward_msg));
+    }
+
+    match callback {
+        ForwardedCallback::AccountCreated { account_address } => {
+            // Keyed by the full route, so it doesn't overwrite a direct remote account on the same chain
+            ROUTED_ACCOUNTS.save(
+                deps.storage,
+                (account_id.seq(), &route_key(&route)),
+                &account_address,
+            )?;
+            Ok(response.add_attribute("account_address", account_address))
+        }
+    }
+}
+
+/// Message that forwards the callback of a remote account to the chain the account originates from.
+/// Returns `None` if that chain isn't connected.
+pub(crate) fn forward_callback_msg(
+    deps: Deps,
+    env: &Env,
+    account_id: AccountId,
+    route: Vec<TruncatedChainId>,
+    callback: ForwardedCallback,
+) -> IbcClientResult<Option<CosmosMsg>> {
+    // The last chain of the trace is the chain the account got created from
+    let AccountTrace::Remote(trace) = account_id.trace() else {
+        return Ok(None);
+    };
+    let Some(origin_chain) = trace.last().cloned() else {
+        return Ok(None);
+    };
+    if !IBC_INFRA.has(deps.storage, &origin_chain) {
+        return Ok(None);
+    }
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps, abstract_code_id)?;
+    let account = registry.account(&account_id, &deps.querier)?;
+
+    send_remote_host_action(
+        deps,
+        account_id,
+        account,
+        origin_chain,
+        HostAction::Internal(InternalAction::ForwardCallback { route, callback }),
+        None,
+    )
+    .map(Some)
+}
+
 pub fn execute_send_funds(
     deps: DepsMut,
     env: Env,
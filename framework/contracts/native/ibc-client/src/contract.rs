@@ -78,6 +78,11 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             commands::execute_remove_host(deps, info, host_chain)
         }
         ExecuteMsg::Callback(c) => ibc::receive_action_callback(deps, env, info, c),
+        ExecuteMsg::ForwardCallback {
+            account_id,
+            route,
+            callback,
+        } => commands::execute_forward_callback(deps, env, info, account_id, route, callback),
         ExecuteMsg::ModuleIbcAction {
             host_chain,
             target_module,
@@ -110,6 +115,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> IbcClientResult<QueryRespon
             chain_name,
             account_id,
         } => to_json_binary(&queries::account(deps, chain_name, account_id)?),
+        QueryMsg::RoutedAccount { route, account_id } => {
+            to_json_binary(&queries::routed_account(deps, route, account_id)?)
+        }
         QueryMsg::ListAccounts { start, limit } => {
             to_json_binary(&queries::list_accounts(deps, start, limit)?)
         }
@@ -522,7 +530,7 @@ mod tests {
         use abstract_std::{
             account,
             ibc_host::{self, HostAction, InternalAction},
-            objects::{registry::RegistryError, TruncatedChainId},
+            objects::{registry::RegistryError, AccountId, AccountTrace, TruncatedChainId},
         };
 
         use cosmwasm_std::wasm_execute;
@@ -661,6 +669,75 @@ mod tests {
             );
             Ok(())
         }
+
+        #[coverage_helper::test]
+        fn send_packet_from_remote_account_without_callback() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            let remote_account_id = AccountId::new(
+                1,
+                AccountTrace::Remote(vec![TruncatedChainId::from_str("juno")?]),
+            )?;
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, remote_account_id.clone())
+                .build();
+            mock_init(&mut deps)?;
+
+            let chain_name = TruncatedChainId::from_str(TEST_CHAIN)?;
+            let note_addr = deps.api.addr_make("note");
+            let remote_ibc_host = String::from("test_remote_host");
+
+            IBC_INFRA.save(
+                deps.as_mut().storage,
+                &chain_name,
+                &IbcInfrastructure {
+                    polytone_note: note_addr.clone(),
+                    remote_abstract_host: remote_ibc_host.clone(),
+                    remote_proxy: None,
+                },
+            )?;
+
+            let action = HostAction::Dispatch {
+                account_msgs: vec![account::ExecuteMsg::UpdateInfo {
+                    name: None,
+                    description: None,
+                    link: None,
+                }],
+            };
+
+            let msg = ExecuteMsg::RemoteAction {
+                host_chain: chain_name,
+                action: action.clone(),
+            };
+
+            let res = execute_as(&mut deps, account.addr(), msg)?;
+
+            // Only account creations are forwarded to juno, actions don't request a callback
+            let note_message = wasm_execute(
+                note_addr.to_string(),
+                &PolytoneNoteExecuteMsg::Execute {
+                    msgs: vec![wasm_execute(
+                        remote_ibc_host,
+                        &ibc_host::ExecuteMsg::Execute {
+                            account_address: account.addr().to_string(),
+                            account_id: remote_account_id,
+                            action,
+                        },
+                        vec![],
+                    )?
+                    .into()],
+                    callback: None,
+                    timeout_seconds: PACKET_LIFETIME.into(),
+                },
+                vec![],
+            )?;
+
+            assert_eq!(
+                IbcClientResponse::action("handle_send_msgs").add_message(note_message),
+                res
+            );
+            Ok(())
+        }
     }
 
     mod send_funds {
@@ -5,7 +5,7 @@ use abstract_std::{
     },
     ibc_client::{
         state::{ACCOUNTS, IBC_INFRA, REVERSE_POLYTONE_NOTE},
-        IbcClientCallback,
+        ForwardedCallback, IbcClientCallback,
     },
     objects::TruncatedChainId,
     ABSTRACT_EVENT_TYPE,
@@ -13,6 +13,7 @@ use abstract_std::{
 use cosmwasm_std::{from_json, Attribute, DepsMut, Env, MessageInfo};
 
 use crate::{
+    commands::forward_callback_msg,
     contract::{IbcClientResponse, IbcClientResult},
     error::IbcClientError,
 };
@@ -59,35 +60,48 @@ pub fn receive_action_callback(
         }
         IbcClientCallback::CreateAccount { account_id } => {
             // We need to get the address of the remote proxy from the account creation response
-            if let PolytoneCallback::Execute(Ok(response)) = &polytone_callback.result {
-                let account_creation_result = response.result[0].clone();
+            let remote_account_address =
+                if let PolytoneCallback::Execute(Ok(response)) = &polytone_callback.result {
+                    let account_creation_result = response.result[0].clone();
 
-                let wasm_abstract_attributes: Vec<Attribute> = account_creation_result
-                    .events
-                    .into_iter()
-                    .filter(|e| e.ty == ABSTRACT_EVENT_TYPE)
-                    .flat_map(|e| e.attributes)
-                    .collect();
+                    let wasm_abstract_attributes: Vec<Attribute> = account_creation_result
+                        .events
+                        .into_iter()
+                        .filter(|e| e.ty == ABSTRACT_EVENT_TYPE)
+                        .flat_map(|e| e.attributes)
+                        .collect();
 
-                let remote_account_address = &wasm_abstract_attributes
-                    .iter()
-                    .find(|e| e.key == "account_address")
-                    .ok_or(IbcClientError::IbcFailed(polytone_callback))?
-                    .value;
+                    let remote_account_address = &wasm_abstract_attributes
+                        .iter()
+                        .find(|e| e.key == "account_address")
+                        .ok_or(IbcClientError::IbcFailed(polytone_callback))?
+                        .value;
 
-                // We need to store the account address in the IBC client for interactions that may need it locally
-                ACCOUNTS.save(
-                    deps.storage,
-                    (account_id.trace(), account_id.seq(), &host_chain),
-                    remote_account_address,
-                )?;
-            } else {
-                return Err(IbcClientError::IbcFailed(polytone_callback));
-            }
+                    // We need to store the account address in the IBC client for interactions that may need it locally
+                    ACCOUNTS.save(
+                        deps.storage,
+                        (account_id.trace(), account_id.seq(), &host_chain),
+                        remote_account_address,
+                    )?;
+                    remote_account_address.clone()
+                } else {
+                    return Err(IbcClientError::IbcFailed(polytone_callback));
+                };
+            // Let the origin of a remote account know about the new account
+            let forward_msg = forward_callback_msg(
+                deps.as_ref(),
+                &env,
+                account_id.clone(),
+                vec![host_chain.clone()],
+                ForwardedCallback::AccountCreated {
+                    account_address: remote_account_address,
+                },
+            )?;
             Ok(
                 IbcClientResponse::action("acknowledge_remote_account_registration")
                     .add_attribute("account_id", account_id.to_string())
-                    .add_attribute("chain", host_chain.to_string()),
+                    .add_attribute("chain", host_chain.to_string())
+                    .add_messages(forward_msg),
             )
         }
         IbcClientCallback::ModuleRemoteAction {
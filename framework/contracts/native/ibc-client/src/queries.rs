@@ -3,7 +3,7 @@ use std::str::FromStr;
 use abstract_sdk::feature_objects::{AnsHost, RegistryContract};
 use abstract_std::{
     ibc_client::{
-        state::{ACCOUNTS, IBC_INFRA},
+        state::{route_key, ACCOUNTS, IBC_INFRA, ROUTED_ACCOUNTS},
         AccountResponse, ConfigResponse, HostResponse, ListAccountsResponse,
         ListIbcInfrastructureResponse, ListRemoteAccountsResponse, ListRemoteHostsResponse,
     },
@@ -128,3 +128,24 @@ pub fn account(
         remote_account_addr,
     })
 }
+
+pub fn routed_account(
+    deps: Deps,
+    route: Vec<TruncatedChainId>,
+    account_id: AccountId,
+) -> IbcClientResult<AccountResponse> {
+    for chain in &route {
+        chain.verify()?;
+    }
+
+    // Only local accounts keep track of the accounts they control through others
+    let remote_account_addr = match account_id.trace() {
+        AccountTrace::Local => {
+            ROUTED_ACCOUNTS.may_load(deps.storage, (account_id.seq(), &route_key(&route)))?
+        }
+        AccountTrace::Remote(_) => None,
+    };
+    Ok(AccountResponse {
+        remote_account_addr,
+    })
+}
@@ -1,24 +1,37 @@
 use abstract_sdk::{
     feature_objects::{AnsHost, RegistryContract},
-    std::{objects::ChannelEntry, ICS20},
-    Resolve,
+    std::{
+        objects::{ChannelEntry, ContractEntry},
+        ICS20, ICS20_CW20,
+    },
+    PfmMemoBuilder, Resolve,
 };
 use abstract_std::{
     account::{self, ModuleInstallConfig},
+    ans_host::{self, AssetInfoListResponse},
     ibc::PACKET_LIFETIME,
+    ibc_client::{self, ForwardedCallback},
     native_addrs,
-    objects::{module::ModuleInfo, module_reference::ModuleReference, AccountId, TruncatedChainId},
+    objects::{
+        account::AccountTrace, module::ModuleInfo, module_reference::ModuleReference, AccountId,
+        AssetEntry, TruncatedChainId,
+    },
     registry::Account,
-    ACCOUNT,
+    ACCOUNT, IBC_CLIENT,
 };
 use cosmwasm_std::{
-    instantiate2_address, to_json_binary, wasm_execute, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
-    IbcMsg, Response, SubMsg, WasmMsg,
+    instantiate2_address, to_json_binary, wasm_execute, Addr, Coin, CosmosMsg, Deps, DepsMut,
+    Empty, Env, IbcMsg, Response, StdError, SubMsg, WasmMsg,
 };
+use cw_asset::{Asset, AssetInfo};
 
 use crate::{
+    anybuf::ibc::{DenomTrace, QueryDenomTraceRequest},
     contract::{HostResponse, HostResult},
-    endpoints::reply::{INIT_BEFORE_ACTION_REPLY_ID, RESPONSE_REPLY_ID},
+    endpoints::{
+        packet::client_to_host_module_account_id,
+        reply::{INIT_BEFORE_ACTION_REPLY_ID, RESPONSE_REPLY_ID},
+    },
     HostError,
 };
 
@@ -124,6 +137,52 @@ pub fn receive_dispatch(
     Ok(response)
 }
 
+/// Passes the forwarded callback of a remote account to the local ibc client.
+/// The account has to originate from this chain. The source chain is added in front of the route.
+pub fn receive_forward_callback(
+    deps: DepsMut,
+    env: Env,
+    src_chain: TruncatedChainId,
+    received_account_id: AccountId,
+    mut route: Vec<TruncatedChainId>,
+    callback: ForwardedCallback,
+) -> HostResult {
+    let current_chain = TruncatedChainId::from_chain_id(&env.block.chain_id);
+    let AccountTrace::Remote(trace) = received_account_id.trace() else {
+        return Err(HostError::InvalidCallbackForward(received_account_id));
+    };
+    if trace.last() != Some(&current_chain) {
+        return Err(HostError::InvalidCallbackForward(received_account_id));
+    }
+    // Drop this chain from the trace to get the account id on this chain
+    let account_id = client_to_host_module_account_id(&env, src_chain.clone(), received_account_id);
+    route.insert(0, src_chain);
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
+    let ibc_client = registry
+        .query_module(ModuleInfo::from_id_latest(IBC_CLIENT)?, &deps.querier)?
+        .reference
+        .unwrap_native()?;
+
+    let msg = wasm_execute(
+        ibc_client,
+        &ibc_client::ExecuteMsg::ForwardCallback {
+            account_id,
+            route,
+            callback,
+        },
+        vec![],
+    )?;
+    Ok(HostResponse::action("receive_forward_callback").add_message(msg))
+}
+
+/// Most ANS-registered cw20 tokens checked by SendAllBack, bounds the gas of the balance queries.
+pub const MAX_SEND_ALL_BACK_CW20S: usize = 50;
+/// Page size of the ANS asset list queries
+const ASSET_PAGE_LIMIT: u8 = 25;
+
 /// processes PacketMsg::SendAllBack variant
 pub fn receive_send_all_back(
     deps: DepsMut,
@@ -132,24 +191,87 @@ pub fn receive_send_all_back(
     client_account_address: String,
     src_chain: TruncatedChainId,
 ) -> HostResult {
-    let wasm_msg = send_all_back(
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let ans = AnsHost::new(deps.as_ref(), abstract_code_id)?;
+
+    let coins = deps.querier.query_all_balances(account.addr())?;
+    let cw20s = registered_cw20_balances(deps.as_ref(), &ans, account.addr())?;
+    let wasm_msg = send_back(
         deps.as_ref(),
         env,
         account,
         client_account_address,
         src_chain,
+        coins,
+        cw20s,
     )?;
 
     Ok(HostResponse::action("receive_dispatch").add_message(wasm_msg))
 }
 
-/// construct the msg to send all the assets back
-pub fn send_all_back(
-    deps: Deps,
+/// processes PacketMsg::SendBack variant
+pub fn receive_send_back(
+    deps: DepsMut,
     env: Env,
     account: Account,
     client_account_address: String,
     src_chain: TruncatedChainId,
+    assets: Vec<AssetEntry>,
+    destination: Option<String>,
+) -> HostResult {
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let ans = AnsHost::new(deps.as_ref(), abstract_code_id)?;
+
+    let mut coins = vec![];
+    let mut cw20s = vec![];
+    for entry in assets {
+        match ans.query_asset(&deps.querier, &entry)? {
+            AssetInfo::Native(denom) => {
+                coins.push(deps.querier.query_balance(account.addr(), denom)?)
+            }
+            AssetInfo::Cw20(addr) => {
+                let token = AssetInfo::Cw20(addr);
+                let balance = token.query_balance(&deps.querier, account.addr())?;
+                cw20s.push((entry, Asset::new(token, balance)))
+            }
+            // No ICS20 route for other asset types, these have to be swapped first
+            _ => return Err(HostError::UntransferableAsset(entry.to_string())),
+        }
+    }
+
+    let wasm_msg = send_back(
+        deps.as_ref(),
+        env,
+        account,
+        destination.unwrap_or(client_account_address),
+        src_chain,
+        coins,
+        cw20s,
+    )?;
+
+    Ok(HostResponse::action("receive_dispatch").add_message(wasm_msg))
+}
+
+/// Transfer message of the ICS20-cw20 contract
+#[cosmwasm_schema::cw_serde]
+struct Cw20TransferMsg {
+    channel: String,
+    remote_address: String,
+    timeout: Option<u64>,
+    memo: Option<String>,
+}
+
+/// construct the msg to send the coins and cw20 tokens of the account back
+pub fn send_back(
+    deps: Deps,
+    env: Env,
+    account: Account,
+    receiver: String,
+    src_chain: TruncatedChainId,
+    coins: Vec<Coin>,
+    mut cw20s: Vec<(AssetEntry, Asset)>,
 ) -> Result<CosmosMsg, HostError> {
     // get the ICS20 channel information
     let abstract_code_id =
@@ -157,26 +279,48 @@ pub fn send_all_back(
 
     let ans = AnsHost::new(deps, abstract_code_id)?;
     let ics20_channel_entry = ChannelEntry {
-        connected_chain: src_chain,
+        connected_chain: src_chain.clone(),
         protocol: ICS20.to_string(),
     };
     let ics20_channel_id = ics20_channel_entry.resolve(&deps.querier, &ans)?;
-    // get all the coins for the account
-    let coins = deps.querier.query_all_balances(account.addr())?;
-    // Construct ics20 messages to send all the coins back
+
+    // Construct ics20 messages to send the coins back
     let mut msgs: Vec<CosmosMsg> = vec![];
     for coin in coins {
+        if coin.amount.is_zero() {
+            continue;
+        }
+        let (channel_id, to_address, memo) =
+            match unwind_path(deps, &ans, &coin.denom, &ics20_channel_id, &src_chain) {
+                Some(hops) => unwind_transfer(&hops, &receiver)?,
+                None => (ics20_channel_id.clone(), receiver.clone(), None),
+            };
         msgs.push(
             IbcMsg::Transfer {
-                channel_id: ics20_channel_id.clone(),
-                to_address: client_account_address.to_string(),
+                channel_id,
+                to_address,
                 amount: coin,
                 timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
-                memo: None,
+                memo,
             }
             .into(),
         )
     }
+    cw20s.retain(|(_, token)| !token.amount.is_zero());
+    if !cw20s.is_empty() {
+        let Some((contract, channel)) = cw20_route(deps, &ans, &src_chain) else {
+            return Err(HostError::NoCw20Route(cw20s[0].0.to_string()));
+        };
+        let transfer_msg = to_json_binary(&Cw20TransferMsg {
+            channel,
+            remote_address: receiver,
+            timeout: Some(PACKET_LIFETIME),
+            memo: None,
+        })?;
+        for (_, token) in cw20s {
+            msgs.push(token.send_msg(&contract, transfer_msg.clone())?);
+        }
+    }
     // call the message to send everything back through the account
     let account_msg = wasm_execute(
         account.into_addr(),
@@ -186,6 +330,119 @@ pub fn send_all_back(
     Ok(account_msg.into())
 }
 
+/// Cw20 tokens registered in ANS that the account holds, at most [`MAX_SEND_ALL_BACK_CW20S`] tokens are checked.
+fn registered_cw20_balances(
+    deps: Deps,
+    ans: &AnsHost,
+    account: &Addr,
+) -> Result<Vec<(AssetEntry, Asset)>, HostError> {
+    let mut balances = vec![];
+    let mut checked = 0;
+    let mut start_after = None;
+    loop {
+        let AssetInfoListResponse { infos } = deps.querier.query_wasm_smart(
+            &ans.address,
+            &ans_host::QueryMsg::AssetInfoList {
+                filter: None,
+                start_after,
+                limit: Some(ASSET_PAGE_LIMIT),
+            },
+        )?;
+        let Some((last, _)) = infos.last() else {
+            break;
+        };
+        start_after = Some(last.clone().into());
+        for (info, entry) in infos {
+            // Cw20 infos are listed before the native ones
+            let AssetInfo::Cw20(_) = info else {
+                return Ok(balances);
+            };
+            if checked == MAX_SEND_ALL_BACK_CW20S {
+                return Ok(balances);
+            }
+            checked += 1;
+            let balance = info.query_balance(&deps.querier, account)?;
+            if !balance.is_zero() {
+                balances.push((entry, Asset::new(info, balance)));
+            }
+        }
+    }
+    Ok(balances)
+}
+
+/// ICS20-cw20 contract and channel to the source chain, if registered in ANS
+fn cw20_route(deps: Deps, ans: &AnsHost, src_chain: &TruncatedChainId) -> Option<(Addr, String)> {
+    let contract = ContractEntry {
+        protocol: ICS20.to_owned(),
+        contract: "cw20".to_owned(),
+    }
+    .resolve(&deps.querier, ans)
+    .ok()?;
+    let channel = ChannelEntry {
+        connected_chain: src_chain.clone(),
+        protocol: ICS20_CW20.to_owned(),
+    }
+    .resolve(&deps.querier, ans)
+    .ok()?;
+    Some((contract, channel))
+}
+
+/// Channels to unwind an ICS20 token along its denom trace, so it arrives as the native token of the source chain.
+/// Returns `None` when the token should be sent back directly over the ICS20 channel.
+fn unwind_path(
+    deps: Deps,
+    ans: &AnsHost,
+    denom: &str,
+    ics20_channel_id: &str,
+    src_chain: &TruncatedChainId,
+) -> Option<Vec<String>> {
+    let hash = denom.strip_prefix("ibc/")?;
+    let trace_response = deps
+        .querier
+        .query_grpc(
+            QueryDenomTraceRequest::path(),
+            QueryDenomTraceRequest {
+                hash: hash.to_owned(),
+            }
+            .to_anybuf()
+            .into_vec()
+            .into(),
+        )
+        .ok()?;
+    let hops = DenomTrace::decode_response(&trace_response)
+        .ok()?
+        .channels()?;
+    // Token came in over the ICS20 channel of the source chain
+    if hops.first().map(String::as_str) == Some(ics20_channel_id) {
+        return None;
+    }
+    // Only unwind tokens that are native to the source chain
+    let entry = ans
+        .query_asset_reverse(&deps.querier, &AssetInfo::native(denom))
+        .ok()?;
+    (entry.src_chain().ok()? == src_chain.as_str()).then_some(hops)
+}
+
+/// Channel, receiver and forwarding memo of the transfer that unwinds a token along its hops.
+/// The first hop is the channel on this chain, the others are forwarded with PFM.
+fn unwind_transfer(
+    hops: &[String],
+    receiver: &str,
+) -> Result<(String, String, Option<String>), HostError> {
+    let transfer = match hops {
+        [] => return Err(StdError::generic_err("no hops to unwind").into()),
+        [first] => (first.clone(), receiver.to_owned(), None),
+        [first, second, rest @ ..] => {
+            let memo = rest
+                .iter()
+                .fold(PfmMemoBuilder::new(second), |memo, hop| memo.hop(hop))
+                .build(receiver)?;
+            (first.clone(), "pfm".to_owned(), Some(memo))
+        }
+    };
+    Ok(transfer)
+}
+
 /// get the account from the registry contract
 pub fn get_account(deps: Deps, env: &Env, account_id: &AccountId) -> Result<Account, HostError> {
     let abstract_code_id =
@@ -195,3 +452,61 @@ pub fn get_account(deps: Deps, env: &Env, account_id: &AccountId) -> Result<Acco
     let account = registry.account(account_id, &deps.querier)?;
     Ok(account)
 }
+
+#[cfg(test)]
+mod test {
+    use anybuf::Anybuf;
+    use cosmwasm_std::Binary;
+
+    use super::*;
+
+    fn hops(channels: &[&str]) -> Vec<String> {
+        channels.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[coverage_helper::test]
+    fn unwind_single_hop() {
+        let transfer = unwind_transfer(&hops(&["channel-3"]), "juno1receiver").unwrap();
+        assert_eq!(
+            transfer,
+            ("channel-3".to_owned(), "juno1receiver".to_owned(), None)
+        );
+    }
+
+    #[coverage_helper::test]
+    fn unwind_with_packet_forwarding() {
+        let (channel, to_address, memo) = unwind_transfer(
+            &hops(&["channel-3", "channel-14", "channel-0"]),
+            "juno1receiver",
+        )
+        .unwrap();
+        assert_eq!(channel, "channel-3");
+        assert_eq!(to_address, "pfm");
+        assert_eq!(
+            memo.unwrap(),
+            r#"{"forward":{"channel":"channel-14","next":{"forward":{"channel":"channel-0","port":"transfer","receiver":"juno1receiver"}},"port":"transfer","receiver":"pfm"}}"#
+        );
+
+        assert!(unwind_transfer(&[], "juno1receiver").is_err());
+    }
+
+    #[coverage_helper::test]
+    fn denom_trace_channels() {
+        let response = Anybuf::new().append_message(
+            1,
+            &Anybuf::new()
+                .append_string(1, "transfer/channel-3/transfer/channel-14")
+                .append_string(2, "ujuno"),
+        );
+        let trace = DenomTrace::decode_response(&Binary::new(response.into_vec())).unwrap();
+        assert_eq!(trace.base_denom, "ujuno");
+        assert_eq!(trace.channels(), Some(hops(&["channel-3", "channel-14"])));
+
+        // Traces over other ports aren't unwound
+        let trace = DenomTrace {
+            path: "transfer/channel-3/wasm.juno1contract/channel-1".to_owned(),
+            base_denom: "ujuno".to_owned(),
+        };
+        assert_eq!(trace.channels(), None);
+    }
+}
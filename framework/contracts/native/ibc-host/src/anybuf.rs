@@ -0,0 +1,55 @@
+use anybuf::Anybuf;
+
+pub mod ibc {
+    use super::*;
+
+    /// Denom trace query by hash
+    pub struct QueryDenomTraceRequest {
+        /// hash (in hex format) or denom (full denom with ibc prefix) of the denomination trace information
+        pub hash: String, // 1
+    }
+
+    impl QueryDenomTraceRequest {
+        pub fn path() -> String {
+            "/ibc.applications.transfer.v1.Query/DenomTrace".to_owned()
+        }
+
+        pub fn to_anybuf(&self) -> Anybuf {
+            Anybuf::new().append_string(1, &self.hash)
+        }
+    }
+
+    pub struct DenomTrace {
+        /// the chain of port/channel identifiers used for tracing the source of the fungible token
+        pub path: String, // 1
+        /// base denomination of the relayed fungible token
+        pub base_denom: String, // 2
+    }
+
+    impl DenomTrace {
+        /// Decodes the `QueryDenomTraceResponse` wrapping the trace
+        pub fn decode_response(data: &cosmwasm_std::Binary) -> Result<Self, anybuf::BufanyError> {
+            let bufany = anybuf::Bufany::deserialize(data.as_ref())?;
+            let trace = bufany
+                .message(1)
+                .ok_or(anybuf::BufanyError::UnexpectedEndOfData)?;
+            Ok(Self {
+                path: trace.string(1).unwrap_or_default(),
+                base_denom: trace.string(2).unwrap_or_default(),
+            })
+        }
+
+        /// Channels the token travelled through, starting at the last hop (the channel on this chain).
+        /// Returns `None` when the trace goes through a port other than `transfer`.
+        pub fn channels(&self) -> Option<Vec<String>> {
+            let parts: Vec<&str> = self.path.split('/').collect();
+            if parts.len() % 2 != 0 {
+                return None;
+            }
+            parts
+                .chunks(2)
+                .map(|hop| (hop[0] == "transfer").then(|| hop[1].to_owned()))
+                .collect()
+        }
+    }
+}
@@ -14,8 +14,8 @@ use crate::{
     endpoints::{
         self,
         reply::{
-            reply_execute_action, reply_forward_response_data, INIT_BEFORE_ACTION_REPLY_ID,
-            RESPONSE_REPLY_ID,
+            reply_check_outflow, reply_execute_action, reply_forward_response_data,
+            INIT_BEFORE_ACTION_REPLY_ID, OUTFLOW_CHECK_REPLY_ID, RESPONSE_REPLY_ID,
         },
     },
     error::HostError,
@@ -52,6 +52,8 @@ pub fn reply(deps: DepsMut, env: Env, reply_msg: Reply) -> HostResult {
         reply_execute_action(deps, env, reply_msg)
     } else if reply_msg.id == RESPONSE_REPLY_ID {
         reply_forward_response_data(reply_msg)
+    } else if reply_msg.id == OUTFLOW_CHECK_REPLY_ID {
+        reply_check_outflow(deps, env, reply_msg)
     } else {
         Err(HostError::Std(StdError::generic_err("Not implemented")))
     }
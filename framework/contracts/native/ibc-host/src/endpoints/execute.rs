@@ -1,15 +1,18 @@
 use abstract_sdk::std::ibc_host::ExecuteMsg;
 use abstract_std::{
-    ibc_host::state::{CHAIN_PROXIES, REVERSE_CHAIN_PROXIES},
+    ibc_host::{
+        state::{CHAIN_PROXIES, PAUSED_CHAINS, RATE_LIMITS, REVERSE_CHAIN_PROXIES},
+        RateLimit,
+    },
     objects::TruncatedChainId,
 };
-use cosmwasm_std::{BankMsg, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{BankMsg, DepsMut, Empty, Env, MessageInfo, Response};
 
 use super::packet::{handle_host_action, handle_module_execute};
 use crate::{
     account_commands::{self, receive_register},
     contract::{HostResponse, HostResult},
-    HostError,
+    rate_limit, HostError,
 };
 
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> HostResult {
@@ -18,6 +21,10 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             register_chain_proxy(deps, info, chain, proxy)
         }
         ExecuteMsg::RemoveChainProxy { chain } => remove_chain_proxy(deps, info, chain),
+        ExecuteMsg::SetRateLimit { chain, rate_limit } => {
+            set_rate_limit(deps, info, chain, rate_limit)
+        }
+        ExecuteMsg::SetChainPaused { chain, paused } => set_chain_paused(deps, info, chain, paused),
         ExecuteMsg::Execute {
             account_address,
             account_id,
@@ -26,6 +33,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
             // This endpoint retrieves the chain name from the executor of the message
             let src_chain: TruncatedChainId =
                 REVERSE_CHAIN_PROXIES.load(deps.storage, &info.sender)?;
+            rate_limit::register_packet(deps.storage, &env, &src_chain)?;
 
             handle_host_action(deps, env, src_chain, account_address, account_id, action)
         }
@@ -40,6 +48,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> H
         } => {
             let src_chain: TruncatedChainId =
                 REVERSE_CHAIN_PROXIES.load(deps.storage, &info.sender)?;
+            rate_limit::register_packet(deps.storage, &env, &src_chain)?;
 
             handle_module_execute(deps, env, src_chain, source_module, target_module, msg)
         }
@@ -123,3 +132,49 @@ fn remove_chain_proxy(deps: DepsMut, info: MessageInfo, chain: TruncatedChainId)
     CHAIN_PROXIES.remove(deps.storage, &chain);
     Ok(HostResponse::action("register_chain_client"))
 }
+
+/// Set or remove the rate limit of a client chain
+fn set_rate_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    rate_limit: Option<RateLimit>,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+
+    match rate_limit {
+        Some(rate_limit) => {
+            rate_limit::validate(&rate_limit)?;
+            RATE_LIMITS.save(deps.storage, &chain, &rate_limit)?;
+        }
+        None => RATE_LIMITS.remove(deps.storage, &chain),
+    }
+    Ok(HostResponse::new(
+        "set_rate_limit",
+        vec![("chain", chain.to_string())],
+    ))
+}
+
+/// Pausing a chain rejects all its packets without removing its proxy
+fn set_chain_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    chain: TruncatedChainId,
+    paused: bool,
+) -> HostResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    chain.verify()?;
+
+    if paused {
+        PAUSED_CHAINS.save(deps.storage, &chain, &Empty {})?;
+    } else {
+        PAUSED_CHAINS.remove(deps.storage, &chain);
+    }
+    Ok(HostResponse::new(
+        "set_chain_paused",
+        vec![("chain", chain.to_string()), ("paused", paused.to_string())],
+    ))
+}
@@ -19,16 +19,19 @@ use cosmwasm_std::{
 };
 
 use crate::{
-    account_commands::{self, receive_dispatch, receive_register, receive_send_all_back},
+    account_commands::{
+        self, receive_dispatch, receive_forward_callback, receive_register, receive_send_all_back,
+        receive_send_back,
+    },
     contract::HostResult,
-    HostError,
+    rate_limit, HostError,
 };
 
 /// Handle actions that are passed to the IBC host contract
 /// This function is not permissioned and access control needs to be handled outside of it
 /// Usually the `src_chain` argument needs to be derived from the message sender
 pub fn handle_host_action(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     src_chain: TruncatedChainId,
     account_address: String,
@@ -62,25 +65,49 @@ pub fn handle_host_action(
             false,
             vec![],
         ),
+        HostAction::Internal(InternalAction::ForwardCallback { route, callback }) => {
+            receive_forward_callback(deps, env, src_chain, received_account_id, route, callback)
+        }
 
         action => {
             // If this account already exists, we can propagate the action
             if let Ok(account) = account_commands::get_account(deps.as_ref(), &env, &account_id) {
-                match action {
+                let account_addr = account.addr().clone();
+                let response = match action {
                     HostAction::Dispatch { account_msgs } => {
-                        receive_dispatch(deps, account, account_msgs)
+                        receive_dispatch(deps.branch(), account, account_msgs)
                     }
                     HostAction::Helpers(helper_action) => match helper_action {
-                        HelperAction::SendAllBack => {
-                            receive_send_all_back(deps, env, account, account_address, src_chain)
-                        }
+                        HelperAction::SendAllBack => receive_send_all_back(
+                            deps.branch(),
+                            env,
+                            account,
+                            account_address,
+                            src_chain.clone(),
+                        ),
+                        HelperAction::SendBack {
+                            assets,
+                            destination,
+                        } => receive_send_back(
+                            deps.branch(),
+                            env,
+                            account,
+                            account_address,
+                            src_chain.clone(),
+                            assets,
+                            destination,
+                        ),
                         _ => unimplemented!(""),
                     },
-                    HostAction::Internal(InternalAction::Register { .. }) => {
+                    HostAction::Internal(
+                        InternalAction::Register { .. } | InternalAction::ForwardCallback { .. },
+                    ) => {
                         unreachable!("This action is handled above")
                     }
                     _ => unimplemented!(""),
-                }
+                }?;
+                // Funds leaving the account count towards the outflow limit of the chain
+                rate_limit::guard_outflow(deps, &src_chain, &account_addr, response)
             } else {
                 // If no account is created already, we create one and execute the action on reply
                 // The account metadata are not set with this call
@@ -141,6 +168,7 @@ pub fn handle_module_execute(
             .account_id
             .map(|a| client_to_host_module_account_id(&env, src_chain.clone(), a)),
     };
+    let target_account_id = target_module.account_id.clone();
 
     let target_module_resolved = target_module.addr(deps.as_ref(), registry)?;
 
@@ -160,15 +188,30 @@ pub fn handle_module_execute(
         target_module_resolved.address,
         &MiddlewareExecMsg::ModuleIbc::<Empty, Empty>(ModuleIbcMsg {
             src_module_info: ModuleIbcInfo {
-                chain: src_chain,
+                chain: src_chain.clone(),
                 module: source_module.module_info,
             },
             msg,
         }),
         vec![],
     )?;
+    let response = response.add_message(msg);
 
-    Ok(response.add_message(msg))
+    // Funds the module moves out of the account count towards the outflow limit of the chain
+    let target_account = target_account_id.and_then(|account_id| {
+        account_commands::get_account(deps.as_ref(), &env, &account_id).ok()
+    });
+    match target_account {
+        Some(account) => {
+            let account_addr = account.into_addr();
+            rate_limit::guard_outflow(deps, &src_chain, &account_addr, response)
+        }
+        // Funds moved without a source account can't be attributed to an account
+        None if rate_limit::has_outflow_limit(deps.storage, &src_chain)? => {
+            Err(HostError::UnguardedModuleCall(src_chain))
+        }
+        None => Ok(response),
+    }
 }
 
 /// Handle actions that are passed to the IBC host contract and originate from a registered module
@@ -12,7 +12,7 @@ use abstract_std::{
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
 use cw_storage_plus::Bound;
 
-use crate::{contract::HostResult, HostError};
+use crate::{contract::HostResult, rate_limit, HostError};
 
 use super::packet;
 
@@ -23,6 +23,9 @@ pub fn query(deps: Deps, env: Env, query: QueryMsg) -> HostResult<Binary> {
             to_json_binary(&registered_chains(deps, start_after, limit)?)
         }
         QueryMsg::ClientProxy { chain } => to_json_binary(&associated_client(deps, chain)?),
+        QueryMsg::RateLimit { chain } => {
+            to_json_binary(&rate_limit::query_rate_limit(deps, &env, chain)?)
+        }
         QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
         QueryMsg::ModuleQuery { target_module, msg } => {
             return packet::handle_host_module_query(deps, env, target_module, msg);
@@ -5,11 +5,12 @@ use cw_utils::MsgExecuteContractResponse;
 use super::packet::handle_host_action;
 use crate::{
     contract::{HostResponse, HostResult},
-    HostError,
+    rate_limit, HostError,
 };
 
 pub const INIT_BEFORE_ACTION_REPLY_ID: u64 = 28379;
 pub const RESPONSE_REPLY_ID: u64 = 362738;
+pub const OUTFLOW_CHECK_REPLY_ID: u64 = 362739;
 
 /// Handle reply after the Account is created, reply with the proxy address of the created account.
 pub fn reply_execute_action(deps: DepsMut, env: Env, _reply: Reply) -> Result<Response, HostError> {
@@ -27,6 +28,12 @@ pub fn reply_execute_action(deps: DepsMut, env: Env, _reply: Reply) -> Result<Re
     )
 }
 
+/// Check the outflow limit after the last message of an action and forward its data
+pub fn reply_check_outflow(deps: DepsMut, env: Env, reply: Reply) -> HostResult {
+    rate_limit::record_outflow(deps, &env)?;
+    reply_forward_response_data(reply)
+}
+
 /// Add the message's data to the response, if any
 pub fn reply_forward_response_data(reply: Reply) -> HostResult {
     // get the result from the reply
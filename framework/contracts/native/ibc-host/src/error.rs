@@ -1,8 +1,9 @@
 use abstract_std::{
-    objects::{ans_host::AnsHostError, registry::RegistryError},
+    objects::{ans_host::AnsHostError, registry::RegistryError, AccountId, TruncatedChainId},
     AbstractError,
 };
 use cosmwasm_std::{Instantiate2AddressError, StdError};
+use cw_asset::AssetError;
 use cw_ownable::OwnershipError;
 use thiserror::Error;
 
@@ -23,6 +24,9 @@ pub enum HostError {
     #[error(transparent)]
     AnsHostError(#[from] AnsHostError),
 
+    #[error(transparent)]
+    AssetError(#[from] AssetError),
+
     #[error(transparent)]
     Instantiate2AddressError(#[from] Instantiate2AddressError),
 
@@ -34,6 +38,33 @@ pub enum HostError {
 
     #[error("Can't send a module-to-module packet to {0}, wrong module type")]
     WrongModuleAction(String),
+
+    #[error("No ICS20-cw20 route registered in ANS for {0}")]
+    NoCw20Route(String),
+
+    #[error("Can't send {0} back over ICS20, swap it to a transferable asset first")]
+    UntransferableAsset(String),
+
+    #[error("Can't forward callback of account {0}, it doesn't originate from this chain")]
+    InvalidCallbackForward(AccountId),
+
+    #[error("Invalid rate limit: {0}")]
+    InvalidRateLimit(String),
+
+    #[error("Packets of chain {0} are paused")]
+    ChainPaused(TruncatedChainId),
+
+    #[error("Packet limit of chain {0} reached for the current window")]
+    PacketLimitReached(TruncatedChainId),
+
+    #[error("Outflow limit of {denom} for chain {chain} reached for the current period")]
+    OutflowLimitReached {
+        chain: TruncatedChainId,
+        denom: String,
+    },
+
+    #[error("Module calls from chain {0} need a source account while an outflow limit is set")]
+    UnguardedModuleCall(TruncatedChainId),
 }
 
 impl From<semver::Error> for HostError {
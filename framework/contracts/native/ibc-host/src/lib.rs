@@ -3,8 +3,10 @@
 pub use error::HostError;
 
 pub mod account_commands;
+mod anybuf;
 pub mod chains;
 pub mod endpoints;
 pub mod error;
+pub mod rate_limit;
 
 pub mod contract;
@@ -0,0 +1,218 @@
+//! Per client chain packet limits, outflow limits and pause switch.
+
+use abstract_std::{
+    ibc_host::{
+        state::{
+            OutflowCheckCache, OUTFLOW_USAGE, PACKET_USAGE, PAUSED_CHAINS, RATE_LIMITS,
+            TEMP_OUTFLOW_CHECK,
+        },
+        OutflowLimit, OutflowUsage, PacketUsage, RateLimit, RateLimitResponse,
+    },
+    objects::TruncatedChainId,
+};
+use cosmwasm_std::{Addr, Coin, Deps, DepsMut, Env, ReplyOn, Response, Storage, Uint128};
+
+use crate::{endpoints::reply::OUTFLOW_CHECK_REPLY_ID, HostError};
+
+/// Validate the settings of a rate limit
+pub fn validate(rate_limit: &RateLimit) -> Result<(), HostError> {
+    if let Some(packets) = &rate_limit.packets {
+        if packets.window_blocks == 0 {
+            return Err(HostError::InvalidRateLimit(
+                "packet window can't be zero blocks".to_owned(),
+            ));
+        }
+    }
+    if let Some(outflow) = &rate_limit.outflow {
+        if outflow.period_seconds == 0 {
+            return Err(HostError::InvalidRateLimit(
+                "outflow period can't be zero seconds".to_owned(),
+            ));
+        }
+        let mut denoms: Vec<&str> = outflow
+            .max_amounts
+            .iter()
+            .map(|c| c.denom.as_str())
+            .collect();
+        denoms.sort_unstable();
+        if denoms.windows(2).any(|w| w[0] == w[1]) {
+            return Err(HostError::InvalidRateLimit(
+                "duplicate outflow denom".to_owned(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reject the packet if the chain is paused and count it against the packet limit of the chain
+pub fn register_packet(
+    storage: &mut dyn Storage,
+    env: &Env,
+    chain: &TruncatedChainId,
+) -> Result<(), HostError> {
+    if PAUSED_CHAINS.has(storage, chain) {
+        return Err(HostError::ChainPaused(chain.clone()));
+    }
+    let Some(limit) = RATE_LIMITS
+        .may_load(storage, chain)?
+        .and_then(|rate_limit| rate_limit.packets)
+    else {
+        return Ok(());
+    };
+
+    let height = env.block.height;
+    let mut usage = PACKET_USAGE
+        .may_load(storage, chain)?
+        .filter(|usage| height < usage.window_start + limit.window_blocks)
+        .unwrap_or(PacketUsage {
+            window_start: height,
+            packets: 0,
+        });
+    if usage.packets >= limit.max_packets {
+        return Err(HostError::PacketLimitReached(chain.clone()));
+    }
+    usage.packets += 1;
+    PACKET_USAGE.save(storage, chain, &usage)?;
+    Ok(())
+}
+
+/// Whether the chain has an outflow limit set
+pub fn has_outflow_limit(
+    storage: &dyn Storage,
+    chain: &TruncatedChainId,
+) -> Result<bool, HostError> {
+    Ok(RATE_LIMITS
+        .may_load(storage, chain)?
+        .is_some_and(|rate_limit| rate_limit.outflow.is_some()))
+}
+
+/// Snapshot the balances of the account when the chain has an outflow limit
+/// and check the outflow after the last message of the action is executed.
+pub fn guard_outflow(
+    deps: DepsMut,
+    chain: &TruncatedChainId,
+    account: &Addr,
+    mut response: Response,
+) -> Result<Response, HostError> {
+    let Some(limit) = RATE_LIMITS
+        .may_load(deps.storage, chain)?
+        .and_then(|rate_limit| rate_limit.outflow)
+    else {
+        return Ok(response);
+    };
+    let Some(last_msg) = response.messages.last_mut() else {
+        return Ok(response);
+    };
+
+    let balances = limit
+        .max_amounts
+        .iter()
+        .map(|max| deps.querier.query_balance(account, &max.denom))
+        .collect::<Result<Vec<_>, _>>()?;
+    TEMP_OUTFLOW_CHECK.save(
+        deps.storage,
+        &OutflowCheckCache {
+            chain: chain.clone(),
+            account: account.clone(),
+            balances,
+        },
+    )?;
+
+    // The reply of the last message checks the outflow of the whole action
+    last_msg.id = OUTFLOW_CHECK_REPLY_ID;
+    last_msg.reply_on = ReplyOn::Success;
+    Ok(response)
+}
+
+/// Add the funds that left the account since [`guard_outflow`] to the outflow of the chain
+pub fn record_outflow(deps: DepsMut, env: &Env) -> Result<(), HostError> {
+    let OutflowCheckCache {
+        chain,
+        account,
+        balances,
+    } = TEMP_OUTFLOW_CHECK.load(deps.storage)?;
+    TEMP_OUTFLOW_CHECK.remove(deps.storage);
+
+    // Limit could be removed in the meantime
+    let Some(limit) = RATE_LIMITS
+        .may_load(deps.storage, &chain)?
+        .and_then(|rate_limit| rate_limit.outflow)
+    else {
+        return Ok(());
+    };
+
+    for before in balances {
+        let Some(max) = limit.max_amounts.iter().find(|c| c.denom == before.denom) else {
+            continue;
+        };
+        let after = deps.querier.query_balance(&account, &before.denom)?;
+        let outflow = before.amount.saturating_sub(after.amount);
+        if outflow.is_zero() {
+            continue;
+        }
+
+        let mut usage = current_outflow(deps.storage, env, &chain, &limit, &before.denom)?;
+        usage.amount += outflow;
+        if usage.amount > max.amount {
+            return Err(HostError::OutflowLimitReached {
+                chain,
+                denom: before.denom,
+            });
+        }
+        OUTFLOW_USAGE.save(deps.storage, (&chain, &before.denom), &usage)?;
+    }
+    Ok(())
+}
+
+fn current_outflow(
+    storage: &dyn Storage,
+    env: &Env,
+    chain: &TruncatedChainId,
+    limit: &OutflowLimit,
+    denom: &str,
+) -> Result<OutflowUsage, HostError> {
+    let usage = OUTFLOW_USAGE
+        .may_load(storage, (chain, denom))?
+        .filter(|usage| env.block.time < usage.period_start.plus_seconds(limit.period_seconds))
+        .unwrap_or(OutflowUsage {
+            period_start: env.block.time,
+            amount: Uint128::zero(),
+        });
+    Ok(usage)
+}
+
+/// Rate limit of the chain with its usage in the current window and period
+pub fn query_rate_limit(
+    deps: Deps,
+    env: &Env,
+    chain: TruncatedChainId,
+) -> Result<RateLimitResponse, HostError> {
+    let rate_limit = RATE_LIMITS.may_load(deps.storage, &chain)?;
+
+    let packets = match rate_limit.as_ref().and_then(|r| r.packets.as_ref()) {
+        Some(limit) => PACKET_USAGE
+            .may_load(deps.storage, &chain)?
+            .filter(|usage| env.block.height < usage.window_start + limit.window_blocks)
+            .map(|usage| usage.packets)
+            .unwrap_or_default(),
+        None => 0,
+    };
+    let outflows = match rate_limit.as_ref().and_then(|r| r.outflow.as_ref()) {
+        Some(limit) => limit
+            .max_amounts
+            .iter()
+            .map(|max| {
+                current_outflow(deps.storage, env, &chain, limit, &max.denom)
+                    .map(|usage| Coin::new(usage.amount, max.denom.clone()))
+            })
+            .collect::<Result<_, _>>()?,
+        None => vec![],
+    };
+
+    Ok(RateLimitResponse {
+        paused: PAUSED_CHAINS.has(deps.storage, &chain),
+        rate_limit,
+        packets,
+        outflows,
+    })
+}
@@ -5,8 +5,10 @@ use abstract_interface::{
 };
 use abstract_std::{
     account::ModuleInstallConfig,
+    ibc_client::{ForwardedCallback, InstalledModuleIdentification, QueryMsgFns as _},
     ibc_host::{
-        ClientProxyResponse, ConfigResponse, ExecuteMsgFns, HostAction, InternalAction, QueryMsgFns,
+        ClientProxyResponse, ConfigResponse, ExecuteMsgFns, HostAction, InternalAction,
+        OutflowLimit, PacketLimit, QueryMsgFns, RateLimit,
     },
     objects::{
         gov_type::{GovAction, GovernanceDetails},
@@ -15,11 +17,14 @@ use abstract_std::{
     },
     ACCOUNT, ICS20, REGISTRY,
 };
-use cosmwasm_std::Event;
+use cosmwasm_std::{coin, coins, to_json_binary, BankMsg, Event};
 use cw_orch::prelude::*;
 use cw_ownable::OwnershipError;
 
-use crate::mock_adapter::{MockAdapter, MOCK_ADAPTER_ID};
+use crate::{
+    fund_mover::{interface::FundMoverI, FUND_MOVER_ID},
+    mock_adapter::{MockAdapter, MOCK_ADAPTER_ID},
+};
 
 mod mock_adapter {
     use abstract_adapter::gen_adapter_mock;
@@ -30,6 +35,35 @@ mod mock_adapter {
     gen_adapter_mock!(MockAdapter, MOCK_ADAPTER_ID, "1.0.0", &[]);
 }
 
+mod fund_mover {
+    use abstract_adapter::mock::{MockAdapterContract, MockInitMsg};
+    use abstract_std::account;
+    use cosmwasm_std::{coins, from_json, wasm_execute, BankMsg, Empty, Response};
+
+    pub const FUND_MOVER_ID: &str = "abstract:fund-mover";
+
+    /// Sends funds out of the account named in the module IBC message
+    pub const FUND_MOVER: MockAdapterContract =
+        MockAdapterContract::new(FUND_MOVER_ID, "1.0.0", None).with_module_ibc(
+            |_, _, _, _, msg| {
+                let (account, to_address): (String, String) = from_json(msg)?;
+                let send = BankMsg::Send {
+                    to_address,
+                    amount: coins(60, "ujuno"),
+                };
+                Ok(Response::new().add_message(wasm_execute(
+                    account,
+                    &account::ExecuteMsg::<Empty>::Execute {
+                        msgs: vec![send.into()],
+                    },
+                    vec![],
+                )?))
+            },
+        );
+
+    abstract_adapter::cw_orch_interface!(FUND_MOVER, MockAdapterContract, MockInitMsg, FundMoverI);
+}
+
 #[test]
 fn account_creation() -> anyhow::Result<()> {
     let chain = MockBech32::new("mock");
@@ -415,3 +449,582 @@ fn execute_send_all_back_action() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn instantiate_cw20(mock: &MockBech32, holder: &Addr, amount: u128) -> anyhow::Result<Addr> {
+    let code_id = mock
+        .upload_custom(
+            "cw20",
+            Box::new(ContractWrapper::new_with_empty(
+                cw20_base::contract::execute,
+                cw20_base::contract::instantiate,
+                cw20_base::contract::query,
+            )),
+        )?
+        .uploaded_code_id()?;
+    let response = mock.instantiate(
+        code_id,
+        &cw20_base::msg::InstantiateMsg {
+            name: "Token".to_owned(),
+            symbol: "TOKEN".to_owned(),
+            decimals: 6,
+            initial_balances: vec![cw20::Cw20Coin {
+                address: holder.to_string(),
+                amount: amount.into(),
+            }],
+            mint: None,
+            marketing: None,
+        },
+        Some("cw20"),
+        None,
+        &[],
+    )?;
+    Ok(response.instantiated_contract_address()?)
+}
+
+#[test]
+fn send_back_cw20_without_route() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let chain: TruncatedChainId = "juno".parse()?;
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+
+    abstr.ans_host.update_channels(
+        vec![(
+            UncheckedChannelEntry {
+                connected_chain: chain.to_string(),
+                protocol: ICS20.to_owned(),
+            },
+            String::from("juno"),
+        )],
+        vec![],
+    )?;
+
+    let proxy_addr = mock.addr_make("proxy_address");
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        HostAction::Internal(InternalAction::Register {
+            name: None,
+            description: None,
+            link: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+    )?;
+    let remote_account = abstr.registry.account(AccountId::new(
+        1,
+        AccountTrace::Remote(vec![chain.clone()]),
+    )?)?;
+
+    // Register a cw20 token held by the account without an ICS20-cw20 route
+    let token = instantiate_cw20(&mock, remote_account.addr(), 1_000)?;
+    let empty_token = instantiate_cw20(&mock, &mock.sender_addr(), 1_000)?;
+    abstr.ans_host.update_asset_addresses(
+        vec![
+            (
+                "juno>juno".to_owned(),
+                cw_asset::AssetInfoUnchecked::native("ujuno"),
+            ),
+            (
+                "juno>token".to_owned(),
+                cw_asset::AssetInfoUnchecked::cw20(token),
+            ),
+            (
+                "juno>empty".to_owned(),
+                cw_asset::AssetInfoUnchecked::cw20(empty_token),
+            ),
+        ],
+        vec![],
+    )?;
+
+    // Sending native coins to another receiver works
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        HostAction::Helpers(abstract_std::ibc_host::HelperAction::SendBack {
+            assets: vec!["juno>juno".into()],
+            destination: Some("juno1receiver".to_owned()),
+        }),
+    )?;
+
+    // Cw20 tokens without a balance don't need a route
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        HostAction::Helpers(abstract_std::ibc_host::HelperAction::SendBack {
+            assets: vec!["juno>empty".into()],
+            destination: None,
+        }),
+    )?;
+
+    // Requesting the cw20 token fails
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            proxy_addr.to_string(),
+            AccountId::local(1),
+            HostAction::Helpers(abstract_std::ibc_host::HelperAction::SendBack {
+                assets: vec!["juno>juno".into(), "juno>token".into()],
+                destination: None,
+            }),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::NoCw20Route("juno>token".to_owned()),
+        err.downcast()?
+    );
+
+    // Sending everything back includes the registered cw20 token
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            proxy_addr.to_string(),
+            AccountId::local(1),
+            HostAction::Helpers(abstract_std::ibc_host::HelperAction::SendAllBack),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::NoCw20Route("juno>token".to_owned()),
+        err.downcast()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn forward_callback_to_origin() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy("juno".parse().unwrap(), polytone_proxy.to_string())?;
+
+    let current_chain = TruncatedChainId::from_chain_id(&mock.block_info()?.chain_id);
+    let juno: TruncatedChainId = "juno".parse()?;
+    let stargaze = TruncatedChainId::from_chain_id("stargaze-1");
+
+    // Account on juno that got created from this chain registered an account on stargaze
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        mock.addr_make("juno_account").to_string(),
+        AccountId::new(1, AccountTrace::Remote(vec![current_chain]))?,
+        HostAction::Internal(InternalAction::ForwardCallback {
+            route: vec![stargaze.clone()],
+            callback: ForwardedCallback::AccountCreated {
+                account_address: "stargaze_account".to_owned(),
+            },
+        }),
+    )?;
+
+    // The ibc client knows the account of the origin on stargaze through juno
+    let remote_account = abstr
+        .ibc
+        .client
+        .routed_remote_account(AccountId::local(1), vec![juno, stargaze.clone()])?;
+    assert_eq!(
+        remote_account.remote_account_addr,
+        Some("stargaze_account".to_owned())
+    );
+    // It's not mistaken for a direct remote account on stargaze
+    let remote_account = abstr
+        .ibc
+        .client
+        .remote_account(AccountId::local(1), stargaze.clone())?;
+    assert_eq!(remote_account.remote_account_addr, None);
+
+    // Accounts that don't originate from this chain can't forward callbacks
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            mock.addr_make("juno_account").to_string(),
+            AccountId::local(1),
+            HostAction::Internal(InternalAction::ForwardCallback {
+                route: vec![stargaze],
+                callback: ForwardedCallback::AccountCreated {
+                    account_address: "stargaze_account".to_owned(),
+                },
+            }),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::InvalidCallbackForward(AccountId::local(1)),
+        err.downcast()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn paused_chain_rejects_packets() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let chain: TruncatedChainId = "juno".parse()?;
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+
+    // Only the owner can pause a chain
+    let err: CwOrchError = abstr
+        .ibc
+        .host
+        .call_as(&mock.addr_make("user"))
+        .set_chain_paused(chain.clone(), true)
+        .unwrap_err();
+    assert_eq!(
+        HostError::OwnershipError(OwnershipError::NotOwner),
+        err.downcast()?
+    );
+
+    abstr.ibc.host.set_chain_paused(chain.clone(), true)?;
+    assert!(abstr.ibc.host.rate_limit(chain.clone())?.paused);
+
+    let register_action = HostAction::Internal(InternalAction::Register {
+        name: None,
+        description: None,
+        link: None,
+        namespace: None,
+        install_modules: vec![],
+    });
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            mock.addr_make("proxy_address").to_string(),
+            AccountId::local(1),
+            register_action.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(HostError::ChainPaused(chain.clone()), err.downcast()?);
+
+    // Resumed chains can act again, the proxy was never removed
+    abstr.ibc.host.set_chain_paused(chain.clone(), false)?;
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        mock.addr_make("proxy_address").to_string(),
+        AccountId::local(1),
+        register_action,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn packet_limit_per_window() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let chain: TruncatedChainId = "juno".parse()?;
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+
+    // A window can't be empty
+    let err = abstr
+        .ibc
+        .host
+        .set_rate_limit(
+            chain.clone(),
+            Some(RateLimit {
+                packets: Some(PacketLimit {
+                    max_packets: 1,
+                    window_blocks: 0,
+                }),
+                outflow: None,
+            }),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast::<HostError>()?,
+        HostError::InvalidRateLimit(_)
+    ));
+
+    abstr.ibc.host.set_rate_limit(
+        chain.clone(),
+        Some(RateLimit {
+            packets: Some(PacketLimit {
+                max_packets: 1,
+                window_blocks: 10,
+            }),
+            outflow: None,
+        }),
+    )?;
+
+    let proxy_addr = mock.addr_make("proxy_address");
+    let update_info = HostAction::Dispatch {
+        account_msgs: vec![abstract_std::account::ExecuteMsg::UpdateInfo {
+            name: Some("remote".to_owned()),
+            description: None,
+            link: None,
+        }],
+    };
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        update_info.clone(),
+    )?;
+    assert_eq!(abstr.ibc.host.rate_limit(chain.clone())?.packets, 1);
+
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(
+            proxy_addr.to_string(),
+            AccountId::local(1),
+            update_info.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::PacketLimitReached(chain.clone()),
+        err.downcast()?
+    );
+
+    // Next window
+    mock.wait_blocks(10)?;
+    assert_eq!(abstr.ibc.host.rate_limit(chain.clone())?.packets, 0);
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        update_info,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn outflow_limit_per_period() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let chain: TruncatedChainId = "juno".parse()?;
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_rate_limit(
+        chain.clone(),
+        Some(RateLimit {
+            packets: None,
+            outflow: Some(OutflowLimit {
+                max_amounts: coins(100, "ujuno"),
+                period_seconds: 3600,
+            }),
+        }),
+    )?;
+
+    let proxy_addr = mock.addr_make("proxy_address");
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        HostAction::Internal(InternalAction::Register {
+            name: None,
+            description: None,
+            link: None,
+            namespace: None,
+            install_modules: vec![],
+        }),
+    )?;
+    let remote_account = abstr.registry.account(AccountId::new(
+        1,
+        AccountTrace::Remote(vec![chain.clone()]),
+    )?)?;
+    mock.add_balance(remote_account.addr(), coins(1_000, "ujuno"))?;
+
+    let send = HostAction::Dispatch {
+        account_msgs: vec![abstract_std::account::ExecuteMsg::Execute {
+            msgs: vec![BankMsg::Send {
+                to_address: mock.addr_make("receiver").to_string(),
+                amount: coins(60, "ujuno"),
+            }
+            .into()],
+        }],
+    };
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        send.clone(),
+    )?;
+    assert_eq!(
+        abstr.ibc.host.rate_limit(chain.clone())?.outflows,
+        vec![coin(60, "ujuno")]
+    );
+
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .ibc_execute(proxy_addr.to_string(), AccountId::local(1), send.clone())
+        .unwrap_err();
+    assert_eq!(
+        HostError::OutflowLimitReached {
+            chain: chain.clone(),
+            denom: "ujuno".to_owned()
+        },
+        err.downcast()?
+    );
+    assert_eq!(
+        mock.balance(remote_account.addr(), Some("ujuno".to_owned()))?,
+        coins(940, "ujuno")
+    );
+
+    // Next period
+    mock.wait_seconds(3600)?;
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        proxy_addr.to_string(),
+        AccountId::local(1),
+        send,
+    )?;
+    assert_eq!(
+        abstr.ibc.host.rate_limit(chain)?.outflows,
+        vec![coin(60, "ujuno")]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn outflow_limit_applies_to_module_calls() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let abstr = Abstract::deploy_on(mock.clone(), ())?;
+
+    let chain: TruncatedChainId = "juno".parse()?;
+    let polytone_proxy = mock.addr_make("polytone_proxy");
+    abstr
+        .ibc
+        .host
+        .register_chain_proxy(chain.clone(), polytone_proxy.to_string())?;
+    abstr.ibc.host.set_rate_limit(
+        chain.clone(),
+        Some(RateLimit {
+            packets: None,
+            outflow: Some(OutflowLimit {
+                max_amounts: coins(100, "ujuno"),
+                period_seconds: 3600,
+            }),
+        }),
+    )?;
+
+    FundMoverI::new(FUND_MOVER_ID, mock.clone()).deploy(
+        "1.0.0".parse()?,
+        MockInitMsg {},
+        DeployStrategy::Try,
+    )?;
+    abstr.ibc.host.call_as(&polytone_proxy).ibc_execute(
+        mock.addr_make("proxy_address").to_string(),
+        AccountId::local(1),
+        HostAction::Internal(InternalAction::Register {
+            name: None,
+            description: None,
+            link: None,
+            namespace: None,
+            install_modules: vec![ModuleInstallConfig::new(
+                ModuleInfo::from_id_latest(FUND_MOVER_ID)?,
+                None,
+            )],
+        }),
+    )?;
+    let remote_account = abstr.registry.account(AccountId::new(
+        1,
+        AccountTrace::Remote(vec![chain.clone()]),
+    )?)?;
+    mock.add_balance(remote_account.addr(), coins(1_000, "ujuno"))?;
+
+    // The fund mover sends 60ujuno out of the remote account on every call
+    let drain = to_json_binary(&(
+        remote_account.addr().to_string(),
+        mock.addr_make("receiver").to_string(),
+    ))?;
+    let source_module = InstalledModuleIdentification {
+        module_info: ModuleInfo::from_id_latest(FUND_MOVER_ID)?,
+        account_id: Some(AccountId::local(1)),
+    };
+    let target_module = ModuleInfo::from_id_latest(FUND_MOVER_ID)?;
+    abstr.ibc.host.call_as(&polytone_proxy).module_execute(
+        drain.clone(),
+        source_module.clone(),
+        target_module.clone(),
+    )?;
+    assert_eq!(
+        abstr.ibc.host.rate_limit(chain.clone())?.outflows,
+        vec![coin(60, "ujuno")]
+    );
+
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .module_execute(drain.clone(), source_module.clone(), target_module.clone())
+        .unwrap_err();
+    assert_eq!(
+        HostError::OutflowLimitReached {
+            chain: chain.clone(),
+            denom: "ujuno".to_owned()
+        },
+        err.downcast()?
+    );
+    assert_eq!(
+        mock.balance(remote_account.addr(), Some("ujuno".to_owned()))?,
+        coins(940, "ujuno")
+    );
+
+    // Calls without a source account can't be attributed to an account
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .module_execute(
+            drain.clone(),
+            InstalledModuleIdentification {
+                module_info: ModuleInfo::from_id_latest(FUND_MOVER_ID)?,
+                account_id: None,
+            },
+            target_module.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        HostError::UnguardedModuleCall(chain.clone()),
+        err.downcast()?
+    );
+
+    // Module calls are rejected while the chain is paused
+    mock.wait_seconds(3600)?;
+    abstr.ibc.host.set_chain_paused(chain.clone(), true)?;
+    let err = abstr
+        .ibc
+        .host
+        .call_as(&polytone_proxy)
+        .module_execute(drain, source_module, target_module)
+        .unwrap_err();
+    assert_eq!(HostError::ChainPaused(chain), err.downcast()?);
+    assert_eq!(
+        mock.balance(remote_account.addr(), Some("ujuno".to_owned()))?,
+        coins(940, "ujuno")
+    );
+
+    Ok(())
+}
@@ -1,16 +1,21 @@
 use abstract_sdk::{
     base::{ExecuteEndpoint, Handler, IbcCallbackEndpoint, ModuleIbcEndpoint},
-    features::ModuleIdentification,
+    features::{AbstractNameService, ModuleIdentification},
     AbstractResponse, AccountVerification,
 };
 use abstract_std::{
     account::state::ACCOUNT_MODULES,
-    adapter::{AdapterBaseMsg, AdapterExecuteMsg, AdapterRequestMsg, BaseExecuteMsg, ExecuteMsg},
+    adapter::{
+        AdapterBaseMsg, AdapterExecuteMsg, AdapterRequestMsg, AuthorizedScope, BaseExecuteMsg,
+        ExecuteMsg, RequestScope,
+    },
     objects::ownership::nested_admin::query_top_level_owner_addr,
 };
-use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdResult};
+use cosmwasm_std::{
+    from_json, Addr, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdResult,
+};
 use schemars::JsonSchema;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     error::AdapterError,
@@ -24,8 +29,9 @@ impl<
         CustomExecMsg: Serialize + JsonSchema + AdapterExecuteMsg,
         CustomQueryMsg,
         SudoMsg,
+        AccountConfig: Serialize + DeserializeOwned,
     > ExecuteEndpoint
-    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     type ExecuteMsg = ExecuteMsg<CustomExecMsg>;
 
@@ -53,8 +59,14 @@ fn is_top_level_owner(querier: &QuerierWrapper, account: Addr, sender: &Addr) ->
 }
 
 /// The api-contract base implementation.
-impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
-    AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+impl<
+        Error: ContractError,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        SudoMsg,
+        AccountConfig,
+    > AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     fn base_execute(
         &mut self,
@@ -62,11 +74,15 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         env: Env,
         info: MessageInfo,
         message: BaseExecuteMsg,
-    ) -> AdapterResult {
+    ) -> AdapterResult
+    where
+        AccountConfig: Serialize + DeserializeOwned,
+    {
         let BaseExecuteMsg {
             account_address,
             msg,
         } = message;
+        let is_config_update = matches!(msg, AdapterBaseMsg::UpdateAccountConfig { .. });
         let account_registry = self.account_registry(deps.as_ref())?;
         let account = account_registry
             .assert_is_account_admin(&env, &info.sender)
@@ -80,7 +96,23 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                     Some(requested_account) => {
                         let account_address = deps.api.addr_validate(&requested_account)?;
                         let account = account_registry.assert_is_account(&account_address)?;
-                        if is_top_level_owner(&deps.querier, account.addr().clone(), &info.sender)
+                        // Authorized addresses without a restricted scope are allowed to configure the Account on this adapter
+                        let is_authorized_configurer = is_config_update
+                            && self
+                                .authorized_addresses
+                                .may_load(deps.storage, account_address.clone())?
+                                .is_some_and(|authorized| authorized.contains(&info.sender))
+                            && self
+                                .authorized_scopes
+                                .may_load(deps.storage, (account_address, info.sender.clone()))?
+                                .unwrap_or_default()
+                                == AuthorizedScope::default();
+                        if is_authorized_configurer
+                            || is_top_level_owner(
+                                &deps.querier,
+                                account.addr().clone(),
+                                &info.sender,
+                            )
                             .unwrap_or(false)
                         {
                             Ok(account)
@@ -98,8 +130,13 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
 
         self.target_account = Some(account);
         match msg {
-            AdapterBaseMsg::UpdateAuthorizedAddresses { to_add, to_remove } => {
-                self.update_authorized_addresses(deps, info, to_add, to_remove)
+            AdapterBaseMsg::UpdateAuthorizedAddresses {
+                to_add,
+                to_remove,
+                scopes,
+            } => self.update_authorized_addresses(deps, info, to_add, to_remove, scopes),
+            AdapterBaseMsg::UpdateAccountConfig { config } => {
+                self.update_account_config(deps, config)
             }
         }
     }
@@ -108,13 +145,18 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
     /// Two success scenarios are possible:
     /// 1. The sender is an authorized address of the given account address and has provided the account address in the message.
     /// 2. The sender is a account of the given account address.
+    ///
+    /// Authorized addresses with a scope can only execute requests allowed by that scope.
     fn handle_app_msg(
         mut self,
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
         request: AdapterRequestMsg<CustomExecMsg>,
-    ) -> Result<Response, Error> {
+    ) -> Result<Response, Error>
+    where
+        CustomExecMsg: AdapterExecuteMsg,
+    {
         let sender = &info.sender;
         let unauthorized_sender = || AdapterError::UnauthorizedAddressAdapterRequest {
             adapter: self.module_id().to_string(),
@@ -137,14 +179,36 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                     // If not, we load the authorized addresses for the given account address.
                     let authorized = self
                         .authorized_addresses
-                        .load(deps.storage, account_address)
+                        .load(deps.storage, account_address.clone())
                         .unwrap_or_default();
-                    if authorized.contains(sender)
-                        || is_top_level_owner(&deps.querier, requested_core.addr().clone(), sender)
-                            .unwrap_or(false)
+                    if authorized.contains(sender) {
+                        // If the sender is an authorized address, its scope must allow the request.
+                        let scope = self
+                            .authorized_scopes
+                            .may_load(deps.storage, (account_address, sender.clone()))?;
+                        if let Some(scope) = scope {
+                            let mut request_scope = request.request.request_scope();
+                            if !scope.assets.is_empty() {
+                                self.resolve_raw_assets(deps.as_ref(), &mut request_scope)?;
+                            }
+                            if !scope.allows(&request_scope) {
+                                return Err(AdapterError::UnauthorizedScope {
+                                    adapter: self.module_id().to_string(),
+                                    sender: sender.to_string(),
+                                    action: request_scope.action,
+                                }
+                                .into());
+                            }
+                        }
+                        requested_core
+                    } else if is_top_level_owner(
+                        &deps.querier,
+                        requested_core.addr().clone(),
+                        sender,
+                    )
+                    .unwrap_or(false)
                     {
-                        // If the sender is an authorized address,
-                        // or top level account return the account.
+                        // If the sender is the top level account owner return the account.
                         requested_core
                     } else {
                         // If not, we error, this call is not permitted
@@ -160,6 +224,29 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         self.execute_handler()?(deps, env, info, self, request.request)
     }
 
+    /// Resolve the raw assets of a request to their ANS name.
+    /// Raw assets that aren't registered in the ANS stay unresolved, so asset scopes don't allow them.
+    fn resolve_raw_assets(
+        &self,
+        deps: Deps,
+        request_scope: &mut RequestScope,
+    ) -> AdapterResult<()> {
+        let ans_host = self.ans_host(deps)?;
+        let mut unresolved = vec![];
+        for raw_asset in std::mem::take(&mut request_scope.raw_assets) {
+            let entry = raw_asset
+                .check(deps.api, None)
+                .ok()
+                .and_then(|info| ans_host.query_asset_reverse(&deps.querier, &info).ok());
+            match entry {
+                Some(entry) => request_scope.assets.push(entry),
+                None => unresolved.push(raw_asset),
+            }
+        }
+        request_scope.raw_assets = unresolved;
+        Ok(())
+    }
+
     /// Update authorized addresses from the adapter.
     fn update_authorized_addresses(
         &self,
@@ -167,6 +254,7 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         info: MessageInfo,
         to_add: Vec<String>,
         to_remove: Vec<String>,
+        scopes: Vec<(String, AuthorizedScope)>,
     ) -> AdapterResult {
         let account = self.target_account.as_ref().unwrap();
         let account_addr = account.addr().clone();
@@ -207,7 +295,28 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 });
             } else {
                 authorized_addrs.retain(|addr| deauthorized_addr.ne(addr));
+                self.authorized_scopes
+                    .remove(deps.storage, (account_addr.clone(), deauthorized_addr));
+            }
+        }
+
+        // Handle the scopes of authorized addresses
+        for (scoped, scope) in scopes {
+            let scoped_addr = get_addr_from_module_id_or_addr(
+                deps.as_ref(),
+                info.sender.clone(),
+                scoped.clone(),
+            )?;
+            if !authorized_addrs.contains(&scoped_addr) {
+                return Err(AdapterError::AuthorizedAddressOrModuleIdNotPresent {
+                    addr_or_module_id: scoped,
+                });
             }
+            self.authorized_scopes.save(
+                deps.storage,
+                (account_addr.clone(), scoped_addr),
+                &scope,
+            )?;
         }
 
         if authorized_addrs.len() > MAXIMUM_AUTHORIZED_ADDRESSES as usize {
@@ -223,6 +332,28 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             vec![("account", account_addr.as_str())],
         ))
     }
+
+    /// Set or reset the config of the target Account.
+    fn update_account_config(&self, deps: DepsMut, config: Option<Binary>) -> AdapterResult
+    where
+        AccountConfig: Serialize + DeserializeOwned,
+    {
+        let account_addr = self.target()?.clone();
+        match config {
+            Some(config) => {
+                let config: AccountConfig = from_json(config)?;
+                self.account_configs
+                    .save(deps.storage, account_addr.clone(), &config)?;
+            }
+            None => self
+                .account_configs
+                .remove(deps.storage, account_addr.clone()),
+        }
+        Ok(self.custom_response(
+            "update_account_config",
+            vec![("account", account_addr.as_str())],
+        ))
+    }
 }
 
 /// This function is a helper to get a contract address from a module ir or from an address.
@@ -299,6 +430,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![deps.api.addr_make(TEST_AUTHORIZED_ADDR).to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
                 account_address: None,
             };
@@ -335,6 +467,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![deps.api.addr_make(TEST_AUTHORIZED_ADDR).to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             };
 
@@ -349,6 +482,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![],
                     to_remove: vec![deps.api.addr_make(TEST_AUTHORIZED_ADDR).to_string()],
+                    scopes: vec![],
                 },
             };
 
@@ -375,6 +509,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![deps.api.addr_make(TEST_AUTHORIZED_ADDR).to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             };
 
@@ -385,6 +520,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![deps.api.addr_make(TEST_AUTHORIZED_ADDR).to_string()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             };
 
@@ -420,6 +556,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![TEST_MODULE_ID.into()],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             };
 
@@ -455,6 +592,7 @@ mod tests {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![],
                     to_remove: vec![test_authorized_address_string.clone()],
+                    scopes: vec![],
                 },
             };
 
@@ -472,6 +610,170 @@ mod tests {
         }
     }
 
+    mod update_account_config {
+        use super::*;
+        use crate::mock::TEST_AUTHORIZED_ADDR;
+        use cosmwasm_std::{to_json_binary, Empty};
+
+        fn update_config_msg(
+            account_address: Option<String>,
+            config: Option<Binary>,
+        ) -> BaseExecuteMsg {
+            BaseExecuteMsg {
+                account_address,
+                msg: AdapterBaseMsg::UpdateAccountConfig { config },
+            }
+        }
+
+        #[coverage_helper::test]
+        fn set_and_reset_config() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+
+            mock_init(&mut deps)?;
+
+            let config = to_json_binary(&Empty {})?;
+            base_execute_as(
+                &mut deps,
+                account.addr(),
+                update_config_msg(None, Some(config)),
+            )?;
+            assert_eq!(
+                MOCK_ADAPTER
+                    .account_configs
+                    .may_load(&deps.storage, account.addr().clone())?,
+                Some(Empty {})
+            );
+
+            // Reset to default
+            base_execute_as(&mut deps, account.addr(), update_config_msg(None, None))?;
+            assert!(MOCK_ADAPTER.account_configs.is_empty(&deps.storage));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn invalid_config() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+
+            mock_init(&mut deps)?;
+
+            let config = to_json_binary(&"not a config")?;
+            let res = base_execute_as(
+                &mut deps,
+                account.addr(),
+                update_config_msg(None, Some(config)),
+            );
+            assert!(matches!(res, Err(MockError::Adapter(AdapterError::Std(_)))));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn authorized_address_can_configure() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+
+            mock_init(&mut deps)?;
+
+            let authorized = deps.api.addr_make(TEST_AUTHORIZED_ADDR);
+            let config = to_json_binary(&Empty {})?;
+            let msg = update_config_msg(Some(account.addr().to_string()), Some(config));
+
+            // Not authorized yet
+            let res = base_execute_as(&mut deps, &authorized, msg.clone());
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::UnauthorizedAdapterRequest {
+                        adapter: TEST_MODULE_ID.to_owned(),
+                        sender: authorized.to_string(),
+                    }
+                ))
+            );
+
+            base_execute_as(
+                &mut deps,
+                account.addr(),
+                BaseExecuteMsg {
+                    account_address: None,
+                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                        to_add: vec![authorized.to_string()],
+                        to_remove: vec![],
+                        scopes: vec![],
+                    },
+                },
+            )?;
+
+            base_execute_as(&mut deps, &authorized, msg)?;
+            assert!(MOCK_ADAPTER
+                .account_configs
+                .has(&deps.storage, account.addr().clone()));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn scoped_authorized_address_cant_configure() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+
+            mock_init(&mut deps)?;
+
+            let authorized = deps.api.addr_make(TEST_AUTHORIZED_ADDR);
+            base_execute_as(
+                &mut deps,
+                account.addr(),
+                BaseExecuteMsg {
+                    account_address: None,
+                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                        to_add: vec![authorized.to_string()],
+                        to_remove: vec![],
+                        scopes: vec![(
+                            authorized.to_string(),
+                            AuthorizedScope {
+                                actions: vec!["swap".to_owned()],
+                                ..Default::default()
+                            },
+                        )],
+                    },
+                },
+            )?;
+
+            let config = to_json_binary(&Empty {})?;
+            let res = base_execute_as(
+                &mut deps,
+                &authorized,
+                update_config_msg(Some(account.addr().to_string()), Some(config)),
+            );
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::UnauthorizedAdapterRequest {
+                        adapter: TEST_MODULE_ID.to_owned(),
+                        sender: authorized.to_string(),
+                    }
+                ))
+            );
+            assert!(MOCK_ADAPTER.account_configs.is_empty(&deps.storage));
+            Ok(())
+        }
+    }
+
     mod execute_app {
         use super::*;
 
@@ -501,6 +803,7 @@ mod tests {
                         .map(|addr| deps.api.addr_make(addr).to_string())
                         .collect(),
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             };
 
@@ -605,6 +908,90 @@ mod tests {
             assert!(res.is_ok());
         }
 
+        #[coverage_helper::test]
+        fn scoped_authorized_address() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let account = test_account(deps.api);
+            deps.querier = MockQuerierBuilder::new(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .set_account_admin_call_to(&account)
+                .build();
+
+            setup_with_authorized_addresses(&mut deps, vec![TEST_AUTHORIZED_ADDR]);
+            let authorized = deps.api.addr_make(TEST_AUTHORIZED_ADDR);
+
+            let set_scope = |deps: &mut MockDeps, scope: AuthorizedScope| {
+                let msg = BaseExecuteMsg {
+                    account_address: None,
+                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                        to_add: vec![],
+                        to_remove: vec![],
+                        scopes: vec![(authorized.to_string(), scope)],
+                    },
+                };
+                base_execute_as(deps, account.addr(), msg)
+            };
+            let msg = ExecuteMsg::Module(AdapterRequestMsg {
+                account_address: Some(account.addr().to_string()),
+                request: MockExecMsg {},
+            });
+
+            // Only allowed to swap
+            let swap_only = AuthorizedScope {
+                actions: vec!["swap".to_owned()],
+                ..Default::default()
+            };
+            set_scope(&mut deps, swap_only.clone())?;
+            let res = execute_as(&mut deps, &authorized, msg.clone());
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(AdapterError::UnauthorizedScope {
+                    adapter: TEST_MODULE_ID.to_owned(),
+                    sender: authorized.to_string(),
+                    action: String::new(),
+                }))
+            );
+
+            let scope = MOCK_ADAPTER
+                .authorized_scopes
+                .load(&deps.storage, (account.addr().clone(), authorized.clone()))?;
+            assert_eq!(scope, swap_only);
+
+            // Unrestricted scope
+            set_scope(&mut deps, AuthorizedScope::default())?;
+            let res = execute_as(&mut deps, &authorized, msg);
+            assert!(res.is_ok());
+
+            // Removing the authorized address also removes its scope
+            base_execute_as(
+                &mut deps,
+                account.addr(),
+                BaseExecuteMsg {
+                    account_address: None,
+                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                        to_add: vec![],
+                        to_remove: vec![authorized.to_string()],
+                        scopes: vec![],
+                    },
+                },
+            )?;
+            assert!(!MOCK_ADAPTER
+                .authorized_scopes
+                .has(&deps.storage, (account.addr().clone(), authorized.clone())));
+
+            // Scopes can only be set on authorized addresses
+            let res = set_scope(&mut deps, AuthorizedScope::default());
+            assert_eq!(
+                res,
+                Err(MockError::Adapter(
+                    AdapterError::AuthorizedAddressOrModuleIdNotPresent {
+                        addr_or_module_id: authorized.to_string()
+                    }
+                ))
+            );
+            Ok(())
+        }
+
         #[coverage_helper::test]
         fn executing_as_authorized_address_on_diff_account_should_err() {
             let mut deps = mock_dependencies();
@@ -7,9 +7,15 @@ use cosmwasm_std::{Addr, Deps};
 
 use crate::{state::ContractError, AdapterContract};
 
-impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
-    IbcCallbackEndpoint
-    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+impl<
+        Error: ContractError,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        SudoMsg,
+        AccountConfig,
+    > IbcCallbackEndpoint
+    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     fn ibc_client_addr(&self, deps: Deps) -> Result<Addr, Self::Error> {
         let registry_query_result = self
@@ -16,8 +16,9 @@ impl<
         CustomExecMsg,
         CustomQueryMsg,
         SudoMsg,
+        AccountConfig,
     > InstantiateEndpoint
-    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     type InstantiateMsg = InstantiateMsg<CustomInitMsg>;
     /// Instantiate the api
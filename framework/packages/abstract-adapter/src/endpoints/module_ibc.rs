@@ -6,8 +6,15 @@ use abstract_std::{
 };
 use cosmwasm_std::Addr;
 
-impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg> ModuleIbcEndpoint
-    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+impl<
+        Error: ContractError,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        SudoMsg,
+        AccountConfig,
+    > ModuleIbcEndpoint
+    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     fn ibc_host(&self, deps: cosmwasm_std::Deps) -> Result<Addr, Self::Error> {
         let registry_query_result = self
@@ -4,11 +4,14 @@ use abstract_sdk::{
 };
 use abstract_std::{
     adapter::{
-        AdapterConfigResponse, AdapterQueryMsg, AuthorizedAddressesResponse, BaseQueryMsg, QueryMsg,
+        AccountConfigResponse, AdapterConfigResponse, AdapterQueryMsg, AuthorizedAddressesResponse,
+        BaseQueryMsg, QueryMsg,
     },
     objects::module_version::{ModuleDataResponse, MODULE},
 };
-use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, StdError, StdResult};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Order, StdError, StdResult};
+
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::state::{AdapterContract, ContractError};
 
@@ -20,8 +23,9 @@ impl<
         CustomExecMsg,
         CustomQueryMsg: AdapterQueryMsg,
         SudoMsg,
+        AccountConfig: Serialize + DeserializeOwned,
     > QueryEndpoint
-    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+    for AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     type QueryMsg = QueryMsg<CustomQueryMsg>;
     fn query(&self, deps: Deps, env: Env, msg: Self::QueryMsg) -> Result<Binary, Error> {
@@ -32,10 +36,19 @@ impl<
     }
 }
 
-impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
-    AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+impl<
+        Error: ContractError,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        SudoMsg,
+        AccountConfig,
+    > AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
-    fn base_query(&self, deps: Deps, _env: Env, query: BaseQueryMsg) -> Result<Binary, Error> {
+    fn base_query(&self, deps: Deps, _env: Env, query: BaseQueryMsg) -> Result<Binary, Error>
+    where
+        AccountConfig: Serialize + DeserializeOwned,
+    {
         match query {
             BaseQueryMsg::BaseConfig {} => {
                 to_json_binary(&self.dapp_config(deps).map_err(Error::from)?).map_err(Into::into)
@@ -44,14 +57,29 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 let account_address = deps.api.addr_validate(&account_address)?;
                 let authorized_addrs: Vec<Addr> = self
                     .authorized_addresses
-                    .may_load(deps.storage, account_address)?
+                    .may_load(deps.storage, account_address.clone())?
                     .unwrap_or_default();
+                let scopes = self
+                    .authorized_scopes
+                    .prefix(account_address)
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .collect::<StdResult<_>>()?;
 
                 to_json_binary(&AuthorizedAddressesResponse {
                     addresses: authorized_addrs,
+                    scopes,
                 })
                 .map_err(Into::into)
             }
+            BaseQueryMsg::AccountConfig { account_address } => {
+                let account_address = deps.api.addr_validate(&account_address)?;
+                // Return the stored bytes, the config is only typed inside the adapter
+                let config = deps
+                    .storage
+                    .get(&self.account_configs.key(account_address))
+                    .map(Binary::from);
+                to_json_binary(&AccountConfigResponse { config }).map_err(Into::into)
+            }
             BaseQueryMsg::ModuleData {} => {
                 to_json_binary(&self.module_data(deps).map_err(Error::from)?).map_err(Into::into)
             }
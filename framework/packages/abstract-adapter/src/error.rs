@@ -36,4 +36,11 @@ pub enum AdapterError {
 
     #[error("Maximum authorized addresses ({}) reached", max)]
     TooManyAuthorizedAddresses { max: u32 },
+
+    #[error("Authorized address {sender} is not allowed to execute {action} on {adapter}")]
+    UnauthorizedScope {
+        adapter: String,
+        sender: String,
+        action: String,
+    },
 }
@@ -3,8 +3,8 @@ use cosmwasm_std::Empty;
 
 use crate::{state::ContractError, AdapterContract};
 
-impl<Error: ContractError, InitMsg, ExecMsg, QueryMsg, SudoMsg> Handler
-    for AdapterContract<Error, InitMsg, ExecMsg, QueryMsg, SudoMsg>
+impl<Error: ContractError, InitMsg, ExecMsg, QueryMsg, SudoMsg, AccountConfig> Handler
+    for AdapterContract<Error, InitMsg, ExecMsg, QueryMsg, SudoMsg, AccountConfig>
 {
     type Error = Error;
     type CustomInitMsg = InitMsg;
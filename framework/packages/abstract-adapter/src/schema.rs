@@ -8,7 +8,7 @@ use abstract_std::{
 use cosmwasm_schema::{export_schema_with_title, schema_for, write_api, QueryResponses};
 use cosmwasm_std::Empty;
 use schemars::JsonSchema;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{AdapterContract, AdapterError};
 
@@ -22,7 +22,8 @@ impl<
         CustomInitMsg: Serialize + JsonSchema,
         CustomQueryMsg: Serialize + JsonSchema + AdapterQueryMsg + QueryResponses,
         SudoMsg,
-    > AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+        AccountConfig: Serialize + DeserializeOwned,
+    > AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     pub fn export_schema(out_dir: &Path) {
         write_api! {
@@ -9,16 +9,19 @@ use abstract_sdk::{
     AbstractSdkError,
 };
 use abstract_std::{
-    adapter::AdapterState,
+    adapter::{AdapterState, AuthorizedScope},
     objects::{dependency::StaticDependency, module::ModuleInfo},
     AbstractError, AbstractResult,
 };
 use cosmwasm_std::{Addr, Empty, StdError, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::AdapterError;
 
 pub const AUTHORIZED_ADDRESSES_NAMESPACE: &str = "authorized_addresses";
+pub const AUTHORIZED_SCOPES_NAMESPACE: &str = "authorized_scopes";
+pub const ACCOUNT_CONFIGS_NAMESPACE: &str = "account_configs";
 pub const MAXIMUM_AUTHORIZED_ADDRESSES: u32 = 15;
 
 pub trait ContractError:
@@ -45,6 +48,7 @@ pub struct AdapterContract<
     CustomExecMsg: 'static,
     CustomQueryMsg: 'static,
     SudoMsg: 'static = Empty,
+    AccountConfig: 'static = Empty,
 > where
     Self: Handler,
 {
@@ -52,13 +56,23 @@ pub struct AdapterContract<
     pub(crate) base_state: Item<AdapterState>,
     /// Map AccountAddr -> AuthorizedAddrs
     pub authorized_addresses: Map<Addr, Vec<Addr>>,
+    /// Map (AccountAddr, AuthorizedAddr) -> Scope the authorized address is restricted to
+    pub authorized_scopes: Map<(Addr, Addr), AuthorizedScope>,
+    /// Map AccountAddr -> Config of the Account on this adapter
+    pub account_configs: Map<Addr, AccountConfig>,
     /// The Account on which commands are executed. Set each time in the [`abstract_std::adapter::ExecuteMsg::Base`] handler.
     pub target_account: Option<Account>,
 }
 
 /// Constructor
-impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
-    AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg>
+impl<
+        Error: ContractError,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        SudoMsg,
+        AccountConfig,
+    > AdapterContract<Error, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg, AccountConfig>
 {
     pub const fn new(
         name: &'static str,
@@ -69,6 +83,8 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             contract: AbstractContract::new(name, version, metadata),
             base_state: Item::new(BASE_STATE),
             authorized_addresses: Map::new(AUTHORIZED_ADDRESSES_NAMESPACE),
+            authorized_scopes: Map::new(AUTHORIZED_SCOPES_NAMESPACE),
+            account_configs: Map::new(ACCOUNT_CONFIGS_NAMESPACE),
             target_account: None,
         }
     }
@@ -94,6 +110,17 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
             .ok_or_else(|| StdError::generic_err("No target Account specified to execute on."))?
             .addr())
     }
+
+    /// Return the config of the target Account, or the default config if it didn't set one.
+    pub fn account_config(&self, store: &dyn Storage) -> Result<AccountConfig, AdapterError>
+    where
+        AccountConfig: Serialize + DeserializeOwned + Default,
+    {
+        Ok(self
+            .account_configs
+            .may_load(store, self.target()?.clone())?
+            .unwrap_or_default())
+    }
     /// add dependencies to the contract
     pub const fn with_dependencies(mut self, dependencies: &'static [StaticDependency]) -> Self {
         self.contract = self.contract.with_dependencies(dependencies);
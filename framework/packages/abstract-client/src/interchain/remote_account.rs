@@ -36,13 +36,16 @@ use crate::{
 };
 
 /// A builder for creating [`RemoteAccounts`](RemoteAccount).
-/// Get the builder from the [`AbstractClient::Account`](crate::Account)
+/// Get the builder from the [`AbstractClient::Account`](crate::Account),
+/// or from a [`RemoteAccount`] for an account that's multiple hops away,
 /// and create the account with the `build` method.
 pub struct RemoteAccountBuilder<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> {
     pub(crate) ibc_env: IBC,
     pub(crate) host_chain: Chain,
     namespace: Option<Namespace>,
-    owner_account: Account<Chain>,
+    owner_account: AccountI<Chain>,
+    /// Remote account that creates the account, if it's not created by the owner account directly
+    parent: Option<RemoteAccount<Chain, IBC>>,
     install_modules: Vec<ModuleInstallConfig>,
 }
 
@@ -53,7 +56,12 @@ impl<Chain: IbcQueryHandler> Account<Chain> {
         interchain_env: IBC,
         host_abstract: &AbstractClient<Chain>,
     ) -> RemoteAccountBuilder<Chain, IBC> {
-        RemoteAccountBuilder::new(self.clone(), interchain_env, host_abstract.environment())
+        RemoteAccountBuilder::new(
+            self.abstr_account.clone(),
+            None,
+            interchain_env,
+            host_abstract.environment(),
+        )
     }
 
     /// Get [`RemoteAccount`] of this account
@@ -92,6 +100,7 @@ impl<Chain: IbcQueryHandler> Account<Chain> {
         Ok(RemoteAccount::new(
             owner_account,
             remote_account_id,
+            vec![],
             host_chain,
             interchain_env,
         ))
@@ -99,12 +108,18 @@ impl<Chain: IbcQueryHandler> Account<Chain> {
 }
 
 impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccountBuilder<Chain, IBC> {
-    pub(crate) fn new(owner_account: Account<Chain>, ibc_env: IBC, host_chain: Chain) -> Self {
+    pub(crate) fn new(
+        owner_account: AccountI<Chain>,
+        parent: Option<RemoteAccount<Chain, IBC>>,
+        ibc_env: IBC,
+        host_chain: Chain,
+    ) -> Self {
         Self {
             ibc_env,
             host_chain,
             namespace: None,
             owner_account,
+            parent,
             install_modules: vec![],
         }
     }
@@ -165,31 +180,47 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccountBuilder<Cha
             ));
         }
 
-        let account_details = AccountDetails {
-            namespace: self.namespace.as_ref().map(ToString::to_string),
-            install_modules,
-            ..Default::default()
-        };
         let host_chain_id = TruncatedChainId::from_chain_id(&host_env_info.chain_id);
 
-        let response = owner_account
-            .abstr_account
-            .create_remote_account(account_details, host_chain_id)?;
-        self.ibc_env
-            .await_and_check_packets(&env_info.chain_id, response)?;
-
-        let remote_account_id = {
-            let mut id = owner_account.id()?;
-            let chain_name = TruncatedChainId::from_chain_id(
-                &owner_account.abstr_account.environment().chain_id(),
-            );
-            id.push_chain(chain_name);
-            id
+        let (remote_account_id, hops) = match self.parent {
+            None => {
+                let account_details = AccountDetails {
+                    namespace: self.namespace.as_ref().map(ToString::to_string),
+                    install_modules,
+                    ..Default::default()
+                };
+                let response =
+                    owner_account.create_remote_account(account_details, host_chain_id)?;
+                self.ibc_env
+                    .await_and_check_packets(&env_info.chain_id, response)?;
+
+                let mut id = owner_account.id()?;
+                id.push_chain(TruncatedChainId::from_chain_id(&env_info.chain_id));
+                (id, vec![])
+            }
+            Some(parent) => {
+                // The parent registers the account through its own ibc client
+                parent.ibc_client_execute(
+                    ibc_client::ExecuteMsg::Register {
+                        host_chain: host_chain_id,
+                        namespace: self.namespace.as_ref().map(ToString::to_string),
+                        install_modules,
+                    },
+                    vec![],
+                )?;
+
+                let mut id = parent.id();
+                id.push_chain(parent.host_chain_id());
+                let mut hops = parent.hops.clone();
+                hops.push(parent.host_chain_id());
+                (id, hops)
+            }
         };
 
         Ok(RemoteAccount::new(
-            owner_account.abstr_account,
+            owner_account,
             remote_account_id,
+            hops,
             host_chain,
             self.ibc_env,
         ))
@@ -206,6 +237,8 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccountBuilder<Cha
 pub struct RemoteAccount<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> {
     pub(crate) abstr_owner_account: AccountI<Chain>,
     remote_account_id: AccountId,
+    /// Chains of the remote accounts between the owner account and this account
+    hops: Vec<TruncatedChainId>,
     host_chain: Chain,
     ibc_env: IBC,
 }
@@ -214,12 +247,14 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
     pub(crate) fn new(
         abstr_owner_account: AccountI<Chain>,
         remote_account_id: AccountId,
+        hops: Vec<TruncatedChainId>,
         host_chain: Chain,
         ibc_env: IBC,
     ) -> Self {
         Self {
             abstr_owner_account,
             remote_account_id,
+            hops,
             host_chain,
             ibc_env,
         }
@@ -230,6 +265,60 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         self.remote_account_id.clone()
     }
 
+    /// Chains of the remote accounts that control this account on behalf of the owner account.
+    /// Empty if the owner account controls this account directly.
+    pub fn hops(&self) -> &[TruncatedChainId] {
+        &self.hops
+    }
+
+    /// Builder for creating a new [`RemoteAccount`] of this remote account.
+    /// The new account is controlled by the owner account through this account,
+    /// so the host chain doesn't need a direct connection to the chain of the owner account.
+    pub fn remote_account_builder(
+        &self,
+        host_abstract: &AbstractClient<Chain>,
+    ) -> RemoteAccountBuilder<Chain, IBC> {
+        RemoteAccountBuilder::new(
+            self.abstr_owner_account.clone(),
+            Some(self.clone()),
+            self.ibc_env.clone(),
+            host_abstract.environment(),
+        )
+    }
+
+    /// Get the [`RemoteAccount`] of this remote account on `host_chain`
+    pub fn remote_account(
+        &self,
+        host_chain: Chain,
+    ) -> AbstractClientResult<RemoteAccount<Chain, IBC>> {
+        let host_chain_name = TruncatedChainId::from_chain_id(&host_chain.chain_id());
+        let ibc_client = self.host_abstract()?.ibc.client;
+
+        // Check it exists first
+        let remote_account_response =
+            ibc_client.remote_account(self.id(), host_chain_name.clone())?;
+        if remote_account_response.remote_account_addr.is_none() {
+            return Err(AbstractClientError::RemoteAccountNotFound {
+                account_id: self.id(),
+                chain: host_chain_name,
+                ibc_client_addr: ibc_client.address()?,
+            });
+        }
+
+        let mut remote_account_id = self.id();
+        remote_account_id.push_chain(self.host_chain_id());
+        let mut hops = self.hops.clone();
+        hops.push(self.host_chain_id());
+
+        Ok(RemoteAccount::new(
+            self.abstr_owner_account.clone(),
+            remote_account_id,
+            hops,
+            host_chain,
+            self.ibc_env.clone(),
+        ))
+    }
+
     /// Truncated chain id of the host chain
     pub fn host_chain_id(&self) -> TruncatedChainId {
         TruncatedChainId::from_chain_id(&self.host_chain().env_info().chain_id)
@@ -537,11 +626,16 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         Abstract::load_from(self.host_chain.clone()).map_err(Into::into)
     }
 
+    /// Executes `msg` on the ibc client of the account that controls this account.
+    /// For accounts that are multiple hops away the funds are taken from the account on the last hop.
     pub(crate) fn ibc_client_execute(
         &self,
         msg: ibc_client::ExecuteMsg,
         funds: Vec<Coin>,
     ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        let (msg, funds) = msg
+            .route_through(&self.hops, funds)
+            .map_err(Into::<CwOrchError>::into)?;
         let exec_msg = to_json_binary(&msg).unwrap();
         let msg = account::ExecuteMsg::ExecuteOnModule {
             module_id: IBC_CLIENT.to_owned(),
@@ -104,6 +104,7 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>, M: ContractInstance<Chai
                         msg: adapter::AdapterBaseMsg::UpdateAuthorizedAddresses {
                             to_add: vec![],
                             to_remove: vec![],
+                            scopes: vec![],
                         },
                     },
                 ))
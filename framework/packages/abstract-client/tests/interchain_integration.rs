@@ -1,7 +1,11 @@
 #![cfg(feature = "interchain")]
 use abstract_client::AbstractClient;
 use abstract_client::GovernanceDetails;
-use abstract_interface::IbcClient;
+use abstract_interface::{Abstract, IbcClient};
+use abstract_std::{
+    ibc_client::QueryMsgFns as _,
+    objects::{AccountTrace, TruncatedChainId},
+};
 use cw_orch::mock::MockBase;
 use cw_orch_interchain::prelude::*;
 
@@ -38,3 +42,82 @@ fn create_remote_account() -> anyhow::Result<()> {
     assert_eq!(governance_type, "abstract-ibc");
     Ok(())
 }
+
+#[test]
+fn create_multi_hop_remote_account() -> anyhow::Result<()> {
+    let mock_interchain = MockBech32InterchainEnv::new(vec![
+        ("juno-1", "juno"),
+        ("osmo-1", "osmo"),
+        ("stargaze-1", "stargaze"),
+    ]);
+
+    let mock_juno = mock_interchain.get_chain("juno-1")?;
+    let mock_osmo = mock_interchain.get_chain("osmo-1")?;
+    let mock_stargaze = mock_interchain.get_chain("stargaze-1")?;
+
+    let juno_abstr = AbstractClient::builder(mock_juno.clone()).build()?;
+    let osmo_abstr = AbstractClient::builder(mock_osmo.clone()).build()?;
+    let stargaze_abstr = AbstractClient::builder(mock_stargaze.clone()).build()?;
+
+    // Juno and Stargaze are not connected directly
+    juno_abstr.connect_to(&osmo_abstr, &mock_interchain)?;
+    osmo_abstr.connect_to(&stargaze_abstr, &mock_interchain)?;
+
+    let juno_account = juno_abstr
+        .account_builder()
+        .install_adapter::<IbcClient<MockBase>>()
+        .build()?;
+    let remote_osmo_account = juno_account
+        .remote_account_builder(mock_interchain.clone(), &osmo_abstr)
+        .build()?;
+    let remote_stargaze_account = remote_osmo_account
+        .remote_account_builder(&stargaze_abstr)
+        .build()?;
+
+    assert_eq!(
+        remote_stargaze_account.hops(),
+        &[TruncatedChainId::from_chain_id("osmo-1")]
+    );
+    assert_eq!(
+        remote_stargaze_account.id().trace(),
+        &AccountTrace::Remote(vec![
+            TruncatedChainId::from_chain_id("juno-1"),
+            TruncatedChainId::from_chain_id("osmo-1"),
+        ])
+    );
+
+    // The account creation got forwarded back to the origin
+    let remote_account_addr = Abstract::load_from(mock_juno)?
+        .ibc
+        .client
+        .routed_remote_account(
+            juno_account.id()?,
+            vec![
+                TruncatedChainId::from_chain_id("osmo-1"),
+                TruncatedChainId::from_chain_id("stargaze-1"),
+            ],
+        )?
+        .remote_account_addr;
+    assert_eq!(
+        remote_account_addr,
+        Some(remote_stargaze_account.address()?.to_string())
+    );
+
+    // The account is controlled from juno through the osmosis account
+    remote_stargaze_account.execute_on_account(vec![
+        abstract_std::account::ExecuteMsg::UpdateInfo {
+            name: Some("stargaze account".to_owned()),
+            description: None,
+            link: None,
+        },
+    ])?;
+    assert_eq!(
+        remote_stargaze_account.info()?.name,
+        Some("stargaze account".to_owned())
+    );
+
+    // And can be loaded again from the osmosis account
+    let loaded = remote_osmo_account.remote_account(mock_stargaze)?;
+    assert_eq!(loaded.id(), remote_stargaze_account.id());
+    Ok(())
+}
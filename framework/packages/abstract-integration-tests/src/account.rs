@@ -429,6 +429,7 @@ pub fn with_response_data<T: MutCwEnv<Sender = Addr>>(mut chain: T) -> AResult {
                 msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
                     to_add: vec![account.addr_str()?],
                     to_remove: vec![],
+                    scopes: vec![],
                 },
             },
         ))?,
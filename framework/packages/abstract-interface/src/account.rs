@@ -331,7 +331,11 @@ impl<Chain: CwEnv> AccountI<Chain> {
             module_id,
             to_json_binary(&adapter::ExecuteMsg::<Empty>::Base(
                 adapter::BaseExecuteMsg {
-                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses { to_add, to_remove },
+                    msg: AdapterBaseMsg::UpdateAuthorizedAddresses {
+                        to_add,
+                        to_remove,
+                        scopes: vec![],
+                    },
                     account_address: None,
                 },
             ))?,
@@ -479,6 +479,28 @@ mod tests {
             test_dne(&ans_host, &not_exist_contract);
         }
 
+        #[coverage_helper::test]
+        fn reverse_lookup() {
+            let mock_api = MockApi::default();
+            let ans_host = mock_ans_host(mock_api);
+
+            let test_contract_entry = ContractEntry {
+                protocol: "protocol".to_string(),
+                contract: "contract".to_string(),
+            };
+            let address = mock_api.addr_make("address");
+            let querier = MockQuerierBuilder::new(mock_api)
+                .contracts(vec![(&test_contract_entry, address.clone())])
+                .build();
+
+            let res = ans_host.query_contract_reverse(&wrap_querier(&querier), &address);
+            assert_eq!(res, Ok(test_contract_entry));
+
+            let unknown = mock_api.addr_make("unknown");
+            let res = ans_host.query_contract_reverse(&wrap_querier(&querier), &unknown);
+            assert!(res.unwrap_err().to_string().contains("not found"));
+        }
+
         #[coverage_helper::test]
         fn array() {
             let mock_api = MockApi::default();
@@ -564,6 +586,26 @@ mod tests {
 
             test_dne(&ans_host, &not_exist_channel);
         }
+
+        #[coverage_helper::test]
+        fn reverse_lookup() {
+            let mock_api = MockApi::default();
+            let ans_host = mock_ans_host(mock_api);
+
+            let test_channel_entry = ChannelEntry {
+                protocol: "protocol".to_string(),
+                connected_chain: TruncatedChainId::from_str("abstract").unwrap(),
+            };
+            let querier = MockQuerierBuilder::new(mock_api)
+                .channels(vec![(&test_channel_entry, "channel-id".to_string())])
+                .build();
+
+            let res = ans_host.query_channel_reverse(&wrap_querier(&querier), "channel-id");
+            assert_eq!(res, Ok(test_channel_entry));
+
+            let res = ans_host.query_channel_reverse(&wrap_querier(&querier), "channel-2");
+            assert!(res.unwrap_err().to_string().contains("not found"));
+        }
     }
 
     mod asset_info_and_asset {
@@ -1,7 +1,12 @@
 #![allow(dead_code)]
 
-use abstract_std::{adapter::AdapterRequestMsg, objects::module::ModuleId};
-use cosmwasm_std::{wasm_execute, CosmosMsg, Deps};
+use abstract_std::{
+    adapter::{
+        AccountConfigResponse, AdapterBaseMsg, AdapterRequestMsg, BaseExecuteMsg, BaseQueryMsg,
+    },
+    objects::module::ModuleId,
+};
+use cosmwasm_std::{from_json, to_json_binary, wasm_execute, CosmosMsg, Deps, Empty};
 use serde::{de::DeserializeOwned, Serialize};
 
 use super::AbstractApi;
@@ -85,6 +90,42 @@ impl<T: AdapterInterface> Adapters<'_, T> {
         Ok(wasm_execute(adapter_address, &adapter_msg, vec![])?.into())
     }
 
+    /// Construct a message that sets the Account's config on an Adapter.
+    /// The config must deserialize into the `AccountConfig` of the Adapter.
+    /// The sender of the message must be the Account or an authorized address on the Adapter.
+    pub fn configure<C: Serialize>(
+        &self,
+        adapter_id: ModuleId,
+        config: &C,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        let modules = self.base.modules(self.deps);
+        modules.assert_module_dependency(adapter_id)?;
+        let adapter_msg = abstract_std::adapter::ExecuteMsg::<Empty>::Base(BaseExecuteMsg {
+            account_address: Some(self.base.account(self.deps)?.into_addr().into_string()),
+            msg: AdapterBaseMsg::UpdateAccountConfig {
+                config: Some(to_json_binary(config)?),
+            },
+        });
+        let adapter_address = modules.module_address(adapter_id)?;
+        Ok(wasm_execute(adapter_address, &adapter_msg, vec![])?.into())
+    }
+
+    /// Query the Account's config on an Adapter.
+    /// Returns None if the Account didn't set a config.
+    pub fn account_config<C: DeserializeOwned>(
+        &self,
+        adapter_id: ModuleId,
+    ) -> AbstractSdkResult<Option<C>> {
+        let account_address = self.base.account(self.deps)?.into_addr().into_string();
+        let response: AccountConfigResponse =
+            self.query::<Empty, _>(adapter_id, BaseQueryMsg::AccountConfig { account_address })?;
+        response
+            .config
+            .map(from_json)
+            .transpose()
+            .map_err(Into::into)
+    }
+
     /// Smart query an Adapter
     pub fn query<Q: Serialize, R: DeserializeOwned>(
         &self,
@@ -164,6 +205,50 @@ mod tests {
         }
     }
 
+    mod configure {
+        use super::*;
+
+        use crate::std::adapter;
+
+        #[coverage_helper::test]
+        fn should_return_err_if_not_dependency() {
+            fail_when_not_dependency_test(
+                |app, deps| {
+                    let mods = app.adapters(deps);
+                    mods.configure(FAKE_MODULE_ID, &Empty {})
+                },
+                FAKE_MODULE_ID,
+            );
+        }
+
+        #[coverage_helper::test]
+        fn expected_configure_msg() {
+            let (deps, account, app) = mock_module_setup();
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            let mods = app.adapters(deps.as_ref());
+
+            let res = mods.configure(TEST_MODULE_ID, &Empty {});
+
+            let expected_msg: adapter::ExecuteMsg =
+                adapter::ExecuteMsg::Base(adapter::BaseExecuteMsg {
+                    account_address: Some(account.addr().to_string()),
+                    msg: adapter::AdapterBaseMsg::UpdateAccountConfig {
+                        config: Some(to_json_binary(&Empty {}).unwrap()),
+                    },
+                });
+
+            assert_eq!(
+                res,
+                Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: abstr.module_address.to_string(),
+                    msg: to_json_binary(&expected_msg).unwrap(),
+                    funds: vec![],
+                }))
+            );
+        }
+    }
+
     mod query_api {
         use super::*;
 
@@ -0,0 +1,320 @@
+//! # Gov
+//! Interacts with the governance module of cosmos.
+//! The Account votes, deposits and submits proposals with its own funds and staking power.
+
+use cosmos_sdk_proto::{
+    cosmos::gov::{v1, v1beta1},
+    traits::{Message, Name},
+    Any,
+};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Deps, StdError, VoteOption, WeightedVoteOption};
+
+use super::stargate::{convert_coins, gov::vote_to_option};
+use crate::{features::AccountExecutor, AbstractSdkError, AbstractSdkResult, AccountAction};
+
+/// Interact with the Cosmos SDK Gov module.
+/// Requires `Stargate` feature.
+pub trait GovInterface: AccountExecutor {
+    /**
+        API for accessing the Cosmos SDK gov module.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::mock_module::MockModule;
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let gov: Gov = module.gov(deps.as_ref(), GovVersion::V1)?;
+        # AbstractSdkResult::Ok(())
+        ```
+    */
+    fn gov(&self, deps: Deps, version: GovVersion) -> AbstractSdkResult<Gov> {
+        Ok(Gov {
+            sender: self.account(deps)?.into_addr(),
+            version,
+        })
+    }
+}
+
+impl<T> GovInterface for T where T: AccountExecutor {}
+
+/// Version of the gov module messages
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovVersion {
+    /// `cosmos.gov.v1`, Cosmos SDK 0.46 and later
+    V1,
+    /// `cosmos.gov.v1beta1`
+    V1Beta1,
+}
+
+/// Content of a proposal
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProposalContent {
+    /// Messages executed by the gov module when the proposal passes, [`GovVersion::V1`] only
+    Messages {
+        messages: Vec<Any>,
+        title: String,
+        summary: String,
+        metadata: String,
+    },
+    /// Legacy proposal content like a `TextProposal`, [`GovVersion::V1Beta1`] only
+    Legacy { content: Any },
+}
+
+/**
+    API for accessing the Cosmos SDK gov module.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::{testing::mock_dependencies, VoteOption};
+    # use abstract_sdk::mock_module::MockModule;
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let gov: Gov = module.gov(deps.as_ref(), GovVersion::V1)?;
+    let vote: AccountAction = gov.vote(1, VoteOption::Yes);
+    # AbstractSdkResult::Ok(())
+    ```
+*/
+#[derive(Clone)]
+pub struct Gov {
+    /// The Account, voter, depositor and proposer of the messages
+    sender: Addr,
+    version: GovVersion,
+}
+
+impl Gov {
+    /// Vote on a proposal with all the staking power of the Account
+    pub fn vote(&self, proposal_id: u64, option: VoteOption) -> AccountAction {
+        match self.version {
+            GovVersion::V1 => stargate_msg(v1::MsgVote {
+                proposal_id,
+                voter: self.sender.to_string(),
+                option: vote_to_option(option),
+                metadata: String::new(),
+            }),
+            GovVersion::V1Beta1 => stargate_msg(v1beta1::MsgVote {
+                proposal_id,
+                voter: self.sender.to_string(),
+                option: vote_to_option(option),
+            }),
+        }
+    }
+
+    /// Split the staking power of the Account over multiple options, the weights have to add up to one
+    pub fn vote_weighted(
+        &self,
+        proposal_id: u64,
+        options: Vec<WeightedVoteOption>,
+    ) -> AbstractSdkResult<AccountAction> {
+        let total_weight = options
+            .iter()
+            .try_fold(Decimal::zero(), |acc, o| acc.checked_add(o.weight))
+            .map_err(StdError::from)?;
+        if total_weight != Decimal::one() {
+            return Err(AbstractSdkError::generic_err(
+                "weights of the vote options must add up to one",
+            ));
+        }
+
+        let msg = match self.version {
+            GovVersion::V1 => stargate_msg(v1::MsgVoteWeighted {
+                proposal_id,
+                voter: self.sender.to_string(),
+                options: options
+                    .into_iter()
+                    .map(|o| v1::WeightedVoteOption {
+                        option: vote_to_option(o.option),
+                        weight: o.weight.to_string(),
+                    })
+                    .collect(),
+                metadata: String::new(),
+            }),
+            GovVersion::V1Beta1 => stargate_msg(v1beta1::MsgVoteWeighted {
+                proposal_id,
+                voter: self.sender.to_string(),
+                options: options
+                    .into_iter()
+                    .map(|o| v1beta1::WeightedVoteOption {
+                        option: vote_to_option(o.option),
+                        // Legacy decimals are encoded as their 18 decimal atomics
+                        weight: o.weight.atomics().to_string(),
+                    })
+                    .collect(),
+            }),
+        };
+        Ok(msg)
+    }
+
+    /// Deposit funds of the Account on a proposal
+    pub fn deposit(&self, proposal_id: u64, amount: Vec<Coin>) -> AccountAction {
+        match self.version {
+            GovVersion::V1 => stargate_msg(v1::MsgDeposit {
+                proposal_id,
+                depositor: self.sender.to_string(),
+                amount: convert_coins(amount),
+            }),
+            GovVersion::V1Beta1 => stargate_msg(v1beta1::MsgDeposit {
+                proposal_id,
+                depositor: self.sender.to_string(),
+                amount: convert_coins(amount),
+            }),
+        }
+    }
+
+    /// Submit a proposal with the Account as proposer
+    pub fn submit_proposal(
+        &self,
+        content: ProposalContent,
+        initial_deposit: Vec<Coin>,
+    ) -> AbstractSdkResult<AccountAction> {
+        let msg = match (self.version, content) {
+            (
+                GovVersion::V1,
+                ProposalContent::Messages {
+                    messages,
+                    title,
+                    summary,
+                    metadata,
+                },
+            ) => stargate_msg(v1::MsgSubmitProposal {
+                messages,
+                initial_deposit: convert_coins(initial_deposit),
+                proposer: self.sender.to_string(),
+                metadata,
+                title,
+                summary,
+                ..Default::default()
+            }),
+            (GovVersion::V1Beta1, ProposalContent::Legacy { content }) => {
+                stargate_msg(v1beta1::MsgSubmitProposal {
+                    content: Some(content),
+                    initial_deposit: convert_coins(initial_deposit),
+                    proposer: self.sender.to_string(),
+                })
+            }
+            (version, _) => {
+                return Err(AbstractSdkError::generic_err(format!(
+                    "proposal content is not supported by gov {version:?}"
+                )))
+            }
+        };
+        Ok(msg)
+    }
+}
+
+fn stargate_msg<M: Message + Name>(msg: M) -> AccountAction {
+    super::stargate_msg(M::type_url(), Binary::new(msg.encode_to_vec())).into()
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+
+    use super::*;
+    use crate::mock_module::*;
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{coins, testing::mock_dependencies};
+
+    #[coverage_helper::test]
+    fn vote() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+
+        let gov = app.gov(deps.as_ref(), GovVersion::V1Beta1)?;
+        let action = gov.vote(1, VoteOption::Yes);
+
+        let expected = crate::apis::stargate_msg(
+            "/cosmos.gov.v1beta1.MsgVote".to_owned(),
+            Binary::new(
+                v1beta1::MsgVote {
+                    proposal_id: 1,
+                    voter: account.addr().to_string(),
+                    option: v1beta1::VoteOption::Yes.into(),
+                }
+                .encode_to_vec(),
+            ),
+        );
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn vote_weighted() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+
+        let gov = app.gov(deps.as_ref(), GovVersion::V1)?;
+        let options = vec![
+            WeightedVoteOption {
+                option: VoteOption::Yes,
+                weight: Decimal::percent(70),
+            },
+            WeightedVoteOption {
+                option: VoteOption::Abstain,
+                weight: Decimal::percent(30),
+            },
+        ];
+        let action = gov.vote_weighted(1, options.clone())?;
+
+        let expected = crate::apis::stargate_msg(
+            "/cosmos.gov.v1.MsgVoteWeighted".to_owned(),
+            Binary::new(
+                v1::MsgVoteWeighted {
+                    proposal_id: 1,
+                    voter: account.addr().to_string(),
+                    options: vec![
+                        v1::WeightedVoteOption {
+                            option: v1::VoteOption::Yes.into(),
+                            weight: "0.7".to_owned(),
+                        },
+                        v1::WeightedVoteOption {
+                            option: v1::VoteOption::Abstain.into(),
+                            weight: "0.3".to_owned(),
+                        },
+                    ],
+                    metadata: String::new(),
+                }
+                .encode_to_vec(),
+            ),
+        );
+        assert_eq!(action.messages(), vec![expected]);
+
+        // Weights have to add up to one
+        let res = gov.vote_weighted(1, options[..1].to_vec());
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn submit_proposal_version_mismatch() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account);
+
+        let gov = app.gov(deps.as_ref(), GovVersion::V1Beta1)?;
+        let messages = ProposalContent::Messages {
+            messages: vec![],
+            title: "title".to_owned(),
+            summary: "summary".to_owned(),
+            metadata: String::new(),
+        };
+        assert!(gov
+            .submit_proposal(messages.clone(), coins(100, "stake"))
+            .is_err());
+
+        let gov = app.gov(deps.as_ref(), GovVersion::V1)?;
+        assert!(gov.submit_proposal(messages, coins(100, "stake")).is_ok());
+        assert!(gov.deposit(1, coins(100, "stake")).messages().len() == 1);
+        Ok(())
+    }
+}
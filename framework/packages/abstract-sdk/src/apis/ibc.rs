@@ -249,6 +249,51 @@ impl<T: IbcInterface + AccountExecutor> IbcClient<'_, T> {
         self.execute(&IbcClientMsg::RemoteAction { host_chain, action }, vec![])
     }
 
+    /// Execute on the ibc client of the remote account at the end of `hops`.
+    /// Each hop is the chain of a remote account of the previous hop, which needs the ibc client installed.
+    /// The funds are taken from the balance of the account on the last hop.
+    pub fn execute_via(
+        &self,
+        hops: &[TruncatedChainId],
+        msg: IbcClientMsg,
+        funds: Vec<Coin>,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        let (msg, funds) = msg.route_through(hops, funds)?;
+        self.execute(&msg, funds)
+    }
+
+    /// Create an account on `host_chain` for the remote account at the end of `hops`.
+    /// This allows controlling accounts on chains that are not directly connected to this one.
+    pub fn create_remote_account_via(
+        &self,
+        hops: &[TruncatedChainId],
+        host_chain: TruncatedChainId,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute_via(
+            hops,
+            IbcClientMsg::Register {
+                host_chain,
+                namespace: None,
+                install_modules: vec![],
+            },
+            vec![],
+        )
+    }
+
+    /// Call a [`HostAction`] on the host of `host_chain` for the remote account at the end of `hops`.
+    pub fn host_action_via(
+        &self,
+        hops: &[TruncatedChainId],
+        host_chain: TruncatedChainId,
+        action: HostAction,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute_via(
+            hops,
+            IbcClientMsg::RemoteAction { host_chain, action },
+            vec![],
+        )
+    }
+
     /// IbcClient the provided coins from the Account to its account on the `receiving_chain`.
     pub fn ics20_transfer(
         &self,
@@ -431,6 +476,51 @@ mod test {
         assert_eq!(msg, Ok(expected));
     }
 
+    /// Tests that a host action is routed through the remote account on the intermediate chain
+    #[coverage_helper::test]
+    fn test_host_action_via() {
+        let (deps, _, stub) = mock_module_setup();
+
+        let client = stub.ibc_client(deps.as_ref());
+        let action = HostAction::Dispatch {
+            account_msgs: vec![abstract_std::account::ExecuteMsg::UpdateStatus {
+                is_suspended: None,
+            }],
+        };
+        let msg = client.host_action_via(
+            &[TEST_HOST_CHAIN.parse().unwrap()],
+            "remotechain".parse().unwrap(),
+            action.clone(),
+        );
+
+        let base = test_account(deps.api);
+        let expected = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: base.addr().to_string(),
+            msg: to_json_binary(&ExecuteMsg::ExecuteOnModule::<cosmwasm_std::Empty> {
+                module_id: IBC_CLIENT.to_owned(),
+                exec_msg: to_json_binary(&IbcClientMsg::RemoteAction {
+                    host_chain: TEST_HOST_CHAIN.parse().unwrap(),
+                    action: HostAction::Dispatch {
+                        account_msgs: vec![ExecuteMsg::ExecuteOnModule {
+                            module_id: IBC_CLIENT.to_owned(),
+                            exec_msg: to_json_binary(&IbcClientMsg::RemoteAction {
+                                host_chain: "remotechain".parse().unwrap(),
+                                action,
+                            })
+                            .unwrap(),
+                            funds: vec![],
+                        }],
+                    },
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+            .unwrap(),
+            funds: vec![],
+        });
+        assert_eq!(msg, Ok(expected));
+    }
+
     #[coverage_helper::test]
     fn abstract_api() {
         let (deps, _, app) = mock_module_setup();
@@ -0,0 +1,333 @@
+//! # Nft
+//! The Nft object queries and moves the cw721 tokens of the Account.
+
+use abstract_std::objects::{
+    ownership::cw721::{Cw721ExecuteMsg, Cw721QueryMsg, TokensResponse},
+    ContractEntry,
+};
+use cosmwasm_std::{to_json_binary, wasm_execute, Addr, Deps};
+use cw20::Expiration;
+use serde::Serialize;
+
+use super::AbstractApi;
+use crate::{
+    ans_resolve::Resolve,
+    cw_helpers::ApiQuery,
+    features::{AbstractNameService, AccountIdentification, ModuleIdentification},
+    AbstractSdkResult, AccountAction,
+};
+
+/// Query and transfer the cw721 tokens of the Abstract Account.
+pub trait NftInterface: AbstractNameService + AccountIdentification + ModuleIdentification {
+    /**
+        API for managing the cw721 tokens of the account.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::mock_module::MockModule;
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let nft: Nft<MockModule> = module.nft(deps.as_ref());
+        ```
+    */
+    fn nft<'a>(&'a self, deps: Deps<'a>) -> Nft<'a, Self> {
+        Nft { base: self, deps }
+    }
+}
+
+impl<T> NftInterface for T where
+    T: AbstractNameService + AccountIdentification + ModuleIdentification
+{
+}
+
+impl<T: NftInterface> AbstractApi<T> for Nft<'_, T> {
+    const API_ID: &'static str = "Nft";
+
+    fn base(&self) -> &T {
+        self.base
+    }
+    fn deps(&self) -> Deps {
+        self.deps
+    }
+}
+
+/**
+    API for managing the cw721 tokens of the account.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::testing::mock_dependencies;
+    # use abstract_sdk::mock_module::MockModule;
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let nft: Nft<MockModule> = module.nft(deps.as_ref());
+    ```
+*/
+#[derive(Clone)]
+pub struct Nft<'a, T: NftInterface> {
+    base: &'a T,
+    deps: Deps<'a>,
+}
+
+impl<T: NftInterface> Nft<'_, T> {
+    /// Resolve the address of a collection registered in the ANS.
+    pub fn collection(&self, entry: &ContractEntry) -> AbstractSdkResult<Addr> {
+        entry
+            .resolve(&self.deps.querier, &self.base.ans_host(self.deps)?)
+            .map_err(|error| self.wrap_query_error(error))
+    }
+
+    /// Ids of the tokens the Account holds in the collection, paginated by the collection.
+    pub fn tokens(
+        &self,
+        collection: &Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> AbstractSdkResult<Vec<String>> {
+        let response: TokensResponse = self.smart_query(
+            collection,
+            &Cw721QueryMsg::Tokens {
+                owner: self.base.account(self.deps)?.into_addr().into_string(),
+                start_after,
+                limit,
+            },
+        )?;
+        Ok(response.tokens)
+    }
+
+    /// Transfer a token of the Account to the recipient.
+    pub fn transfer(
+        &self,
+        collection: &Addr,
+        token_id: impl Into<String>,
+        recipient: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.execute(
+            collection,
+            &Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: token_id.into(),
+            },
+        )
+    }
+
+    /// Send a token of the Account to a contract with a message for its `ReceiveNft` hook.
+    pub fn send<M: Serialize>(
+        &self,
+        collection: &Addr,
+        token_id: impl Into<String>,
+        contract: &Addr,
+        message: &M,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.execute(
+            collection,
+            &Cw721ExecuteMsg::SendNft {
+                contract: contract.to_string(),
+                token_id: token_id.into(),
+                msg: to_json_binary(message)?,
+            },
+        )
+    }
+
+    /// Allow the spender to transfer or send a token of the Account.
+    pub fn approve(
+        &self,
+        collection: &Addr,
+        token_id: impl Into<String>,
+        spender: &Addr,
+        expires: Option<Expiration>,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.execute(
+            collection,
+            &Cw721ExecuteMsg::Approve {
+                spender: spender.to_string(),
+                token_id: token_id.into(),
+                expires,
+            },
+        )
+    }
+
+    /// Remove the approval of the spender on a token of the Account.
+    pub fn revoke(
+        &self,
+        collection: &Addr,
+        token_id: impl Into<String>,
+        spender: &Addr,
+    ) -> AbstractSdkResult<AccountAction> {
+        self.execute(
+            collection,
+            &Cw721ExecuteMsg::Revoke {
+                spender: spender.to_string(),
+                token_id: token_id.into(),
+            },
+        )
+    }
+
+    fn execute(
+        &self,
+        collection: &Addr,
+        msg: &Cw721ExecuteMsg,
+    ) -> AbstractSdkResult<AccountAction> {
+        Ok(wasm_execute(collection, msg, vec![])?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{from_json, CosmosMsg, WasmMsg};
+
+    use super::*;
+    use crate::{apis::traits::test::abstract_api_test, mock_module::*, AbstractSdkError};
+
+    #[coverage_helper::test]
+    fn tokens_of_collection() -> AbstractSdkResult<()> {
+        let (mut deps, account, app) = mock_module_setup();
+        let collection = deps.api.addr_make("collection");
+        let entry = ContractEntry {
+            protocol: "stargaze".to_owned(),
+            contract: "badges".to_owned(),
+        };
+        let owner = account.addr().to_string();
+        deps.querier = abstract_testing::abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .contracts(vec![(&entry, collection.clone())])
+            .with_smart_handler(&collection, move |msg| {
+                match from_json::<Cw721QueryMsg>(msg).unwrap() {
+                    Cw721QueryMsg::Tokens { owner: o, .. } if o == owner => {
+                        to_json_binary(&TokensResponse {
+                            tokens: vec!["1".to_owned(), "2".to_owned()],
+                        })
+                        .map_err(|e| e.to_string())
+                    }
+                    _ => Err("unexpected query".to_owned()),
+                }
+            })
+            .build();
+
+        let nft = app.nft(deps.as_ref());
+        let collection = nft.collection(&entry)?;
+        assert_eq!(nft.tokens(&collection, None, None)?, vec!["1", "2"]);
+
+        // Unknown collection
+        let res = nft.collection(&ContractEntry {
+            protocol: "stargaze".to_owned(),
+            contract: "unknown".to_owned(),
+        });
+        assert!(matches!(res, Err(AbstractSdkError::ApiQuery { .. })));
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn transfer() -> AbstractSdkResult<()> {
+        let (deps, _, app) = mock_module_setup();
+        let collection = deps.api.addr_make("collection");
+        let recipient = deps.api.addr_make("recipient");
+
+        let action = app
+            .nft(deps.as_ref())
+            .transfer(&collection, "1", &recipient)?;
+
+        let expected: CosmosMsg = WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: "1".to_owned(),
+            })?,
+            funds: vec![],
+        }
+        .into();
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn send_with_message() -> AbstractSdkResult<()> {
+        let (deps, _, app) = mock_module_setup();
+        let collection = deps.api.addr_make("collection");
+        let contract = deps.api.addr_make("marketplace");
+
+        let action = app
+            .nft(deps.as_ref())
+            .send(&collection, "1", &contract, &"list")?;
+
+        let expected: CosmosMsg = WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::SendNft {
+                contract: contract.to_string(),
+                token_id: "1".to_owned(),
+                msg: to_json_binary(&"list")?,
+            })?,
+            funds: vec![],
+        }
+        .into();
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn approve() -> AbstractSdkResult<()> {
+        let (deps, _, app) = mock_module_setup();
+        let collection = deps.api.addr_make("collection");
+        let spender = deps.api.addr_make("marketplace");
+
+        let action = app.nft(deps.as_ref()).approve(
+            &collection,
+            "1",
+            &spender,
+            Some(Expiration::AtHeight(100)),
+        )?;
+
+        let expected: CosmosMsg = WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::Approve {
+                spender: spender.to_string(),
+                token_id: "1".to_owned(),
+                expires: Some(Expiration::AtHeight(100)),
+            })?,
+            funds: vec![],
+        }
+        .into();
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn revoke() -> AbstractSdkResult<()> {
+        let (deps, _, app) = mock_module_setup();
+        let collection = deps.api.addr_make("collection");
+        let spender = deps.api.addr_make("marketplace");
+
+        let action = app.nft(deps.as_ref()).revoke(&collection, "1", &spender)?;
+
+        let expected: CosmosMsg = WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::Revoke {
+                spender: spender.to_string(),
+                token_id: "1".to_owned(),
+            })?,
+            funds: vec![],
+        }
+        .into();
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn abstract_api() {
+        let (deps, _, app) = mock_module_setup();
+        let nft = app.nft(deps.as_ref());
+
+        abstract_api_test(nft);
+    }
+}
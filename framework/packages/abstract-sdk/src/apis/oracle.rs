@@ -0,0 +1,704 @@
+//! # Oracle
+//! The Oracle object resolves the value of assets in a quote asset using the price sources registered on the ANS.
+
+use abstract_std::objects::{
+    ans_host::AnsHostError, AnsAsset, AssetEntry, OracleSource, UniquePoolId,
+};
+#[cfg(feature = "stargate")]
+use abstract_std::{
+    constants::osmosis,
+    objects::{DexAssetPairing, PoolAddress, TruncatedChainId},
+};
+use cosmwasm_std::{Decimal, Deps, Env, Int64, Uint128, Uint64};
+use serde::{Deserialize, Serialize};
+
+use super::AbstractApi;
+use crate::{
+    cw_helpers::ApiQuery,
+    features::{AbstractNameService, ModuleIdentification},
+    AbstractSdkError, AbstractSdkResult,
+};
+
+/// Resolve the price of assets through the price sources registered on the ANS.
+pub trait OracleInterface: AbstractNameService + ModuleIdentification {
+    /**
+        API for resolving asset prices and values.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::mock_module::MockModule;
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let oracle: Oracle<MockModule>  = module.oracle(deps.as_ref());
+        ```
+    */
+    fn oracle<'a>(&'a self, deps: Deps<'a>) -> Oracle<'a, Self> {
+        Oracle { base: self, deps }
+    }
+}
+
+impl<T> OracleInterface for T where T: AbstractNameService + ModuleIdentification {}
+
+impl<T: OracleInterface> AbstractApi<T> for Oracle<'_, T> {
+    const API_ID: &'static str = "Oracle";
+
+    fn base(&self) -> &T {
+        self.base
+    }
+    fn deps(&self) -> Deps {
+        self.deps
+    }
+}
+
+/**
+    API for resolving asset prices and values.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::testing::mock_dependencies;
+    # use abstract_sdk::mock_module::MockModule;
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let oracle: Oracle<MockModule>  = module.oracle(deps.as_ref());
+    ```
+*/
+#[derive(Clone)]
+pub struct Oracle<'a, T: OracleInterface> {
+    base: &'a T,
+    deps: Deps<'a>,
+}
+
+impl<T: OracleInterface> Oracle<'_, T> {
+    /// Get the price of one base-unit of `base` expressed in base-units of `quote`.
+    /// If only the inverse pair is registered, its price is inverted.
+    /// Errors if the price was published more than `max_age` seconds ago.
+    pub fn price(
+        &self,
+        env: &Env,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+        max_age: u64,
+    ) -> AbstractSdkResult<Decimal> {
+        if base == quote {
+            return Ok(Decimal::one());
+        }
+        let ans_host = self.base.ans_host(self.deps)?;
+        match ans_host.query_oracle_source(&self.deps.querier, base, quote) {
+            Ok(source) => self.resolve_source(env, base, quote, source, max_age),
+            Err(AnsHostError::OracleSourceNotFound { .. }) => {
+                let source = ans_host
+                    .query_oracle_source(&self.deps.querier, quote, base)
+                    .map_err(|_| {
+                        self.wrap_query_error(AnsHostError::OracleSourceNotFound {
+                            base: base.clone(),
+                            quote: quote.clone(),
+                            ans_host: ans_host.address.clone(),
+                        })
+                    })?;
+                let inverse_price = self.resolve_source(env, quote, base, source, max_age)?;
+                inverse_price
+                    .inv()
+                    .ok_or_else(|| AbstractSdkError::InvalidPrice {
+                        base: base.to_string(),
+                        quote: quote.to_string(),
+                        reason: "inverse price is zero".to_owned(),
+                    })
+            }
+            Err(error) => Err(self.wrap_query_error(error)),
+        }
+    }
+
+    /// Get the value of `asset` expressed in base-units of `quote`.
+    pub fn value(
+        &self,
+        env: &Env,
+        asset: &AnsAsset,
+        quote: &AssetEntry,
+        max_age: u64,
+    ) -> AbstractSdkResult<Uint128> {
+        let price = self.price(env, &asset.name, quote, max_age)?;
+        Ok(asset.amount.mul_floor(price))
+    }
+
+    /// Get the summed value of `assets` expressed in base-units of `quote`.
+    pub fn total_value(
+        &self,
+        env: &Env,
+        assets: &[AnsAsset],
+        quote: &AssetEntry,
+        max_age: u64,
+    ) -> AbstractSdkResult<Uint128> {
+        assets.iter().try_fold(Uint128::zero(), |total, asset| {
+            let value = self.value(env, asset, quote, max_age)?;
+            total.checked_add(value).map_err(Into::into)
+        })
+    }
+
+    fn resolve_source(
+        &self,
+        env: &Env,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+        source: OracleSource,
+        max_age: u64,
+    ) -> AbstractSdkResult<Decimal> {
+        match source {
+            OracleSource::Fixed { price } => Ok(price),
+            OracleSource::Pyth {
+                contract,
+                price_feed_id,
+                base_decimals,
+                quote_decimals,
+                max_confidence,
+            } => {
+                let ans_host = self.base.ans_host(self.deps)?;
+                let pyth_addr = ans_host
+                    .query_contract(&self.deps.querier, &contract)
+                    .map_err(|error| self.wrap_query_error(error))?;
+                let response: PythPriceFeedResponse =
+                    self.smart_query(pyth_addr, &PythQueryMsg::PriceFeed { id: price_feed_id })?;
+                let PythPrice {
+                    price,
+                    conf,
+                    expo,
+                    publish_time,
+                } = response.price_feed.price;
+
+                let age = env
+                    .block
+                    .time
+                    .seconds()
+                    .saturating_sub(publish_time.max(0) as u64);
+                if age > max_age {
+                    return Err(AbstractSdkError::StalePrice {
+                        base: base.to_string(),
+                        quote: quote.to_string(),
+                        age,
+                        max_age,
+                    });
+                }
+
+                // The confidence interval is expressed in the same unit as the price
+                if price.i64() > 0 {
+                    let confidence = Decimal::from_ratio(conf.u64(), price.i64() as u64);
+                    if confidence > max_confidence {
+                        return Err(AbstractSdkError::UncertainPrice {
+                            base: base.to_string(),
+                            quote: quote.to_string(),
+                            confidence,
+                            max_confidence,
+                        });
+                    }
+                }
+
+                let exponent = expo + quote_decimals as i32 - base_decimals as i32;
+                scaled_price(price, exponent).ok_or_else(|| AbstractSdkError::InvalidPrice {
+                    base: base.to_string(),
+                    quote: quote.to_string(),
+                    reason: format!("can't convert pyth price {price} with exponent {exponent}"),
+                })
+            }
+            OracleSource::DexTwap {
+                pool_id,
+                window_seconds,
+            } => {
+                // The average includes prices from the start of the window
+                if window_seconds > max_age {
+                    return Err(AbstractSdkError::StalePrice {
+                        base: base.to_string(),
+                        quote: quote.to_string(),
+                        age: window_seconds,
+                        max_age,
+                    });
+                }
+                self.dex_twap(env, base, quote, pool_id, window_seconds)
+            }
+        }
+    }
+
+    /// Find the address of a pool registered for the pair.
+    #[cfg(feature = "stargate")]
+    fn pool_address(
+        &self,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+        pool_id: UniquePoolId,
+    ) -> AbstractSdkResult<PoolAddress> {
+        let ans_host = self.base.ans_host(self.deps)?;
+        let metadata = ans_host
+            .query_pool_metadata(&self.deps.querier, pool_id)
+            .map_err(|error| self.wrap_query_error(error))?;
+        let pairing = DexAssetPairing::new(base.clone(), quote.clone(), &metadata.dex);
+        let references = ans_host
+            .query_asset_pairing(&self.deps.querier, &pairing)
+            .map_err(|error| self.wrap_query_error(error))?;
+
+        references
+            .into_iter()
+            .find(|reference| reference.unique_id == pool_id)
+            .map(|reference| reference.pool_address)
+            .ok_or_else(|| {
+                self.wrap_query_error(AnsHostError::DexPairingNotFound {
+                    pairing,
+                    ans_host: ans_host.address,
+                })
+            })
+    }
+
+    #[cfg(feature = "stargate")]
+    fn dex_twap(
+        &self,
+        env: &Env,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+        pool_id: UniquePoolId,
+        window_seconds: u64,
+    ) -> AbstractSdkResult<Decimal> {
+        let chain = TruncatedChainId::new(env);
+        if !osmosis::OSMOSIS.contains(&chain.as_str()) {
+            return Err(AbstractSdkError::UnsupportedOracleSource {
+                base: base.to_string(),
+                quote: quote.to_string(),
+                reason: format!("twap is only available on osmosis, not on {chain}"),
+            });
+        }
+        let pool_address = self.pool_address(base, quote, pool_id)?;
+        let PoolAddress::Id(pool_id) = pool_address else {
+            return Err(AbstractSdkError::UnsupportedOracleSource {
+                base: base.to_string(),
+                quote: quote.to_string(),
+                reason: "only pools with a numerical id expose a twap".to_owned(),
+            });
+        };
+        let ans_host = self.base.ans_host(self.deps)?;
+        let native_denom = |entry: &AssetEntry| -> AbstractSdkResult<String> {
+            let info = ans_host
+                .query_asset(&self.deps.querier, entry)
+                .map_err(|error| self.wrap_query_error(error))?;
+            match info {
+                cw_asset::AssetInfo::Native(denom) => Ok(denom),
+                _ => Err(AbstractSdkError::UnsupportedOracleSource {
+                    base: base.to_string(),
+                    quote: quote.to_string(),
+                    reason: "twap is only available for native assets".to_owned(),
+                }),
+            }
+        };
+        let base_denom = native_denom(base)?;
+        let quote_denom = native_denom(quote)?;
+
+        let start_time = env.block.time.minus_seconds(window_seconds);
+        twap::arithmetic_twap_to_now(
+            &self.deps.querier,
+            pool_id,
+            base_denom,
+            quote_denom,
+            start_time,
+        )
+        .map_err(|error| self.wrap_query_error(error))
+    }
+
+    #[cfg(not(feature = "stargate"))]
+    fn dex_twap(
+        &self,
+        _env: &Env,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+        _pool_id: UniquePoolId,
+        _window_seconds: u64,
+    ) -> AbstractSdkResult<Decimal> {
+        Err(AbstractSdkError::UnsupportedOracleSource {
+            base: base.to_string(),
+            quote: quote.to_string(),
+            reason: "dex twap requires the `stargate` feature".to_owned(),
+        })
+    }
+}
+
+/// Scale a pyth price by `10^exponent`. Returns `None` for negative prices or on overflow.
+fn scaled_price(price: Int64, exponent: i32) -> Option<Decimal> {
+    let atomics = Uint128::from(u64::try_from(price.i64()).ok()?);
+    if exponent <= 0 {
+        Decimal::from_atomics(atomics, exponent.unsigned_abs()).ok()
+    } else {
+        let scale = Uint128::new(10).checked_pow(exponent as u32).ok()?;
+        Decimal::from_atomics(atomics.checked_mul(scale).ok()?, 0).ok()
+    }
+}
+
+/// Query message of the pyth contract.
+#[cosmwasm_schema::cw_serde]
+enum PythQueryMsg {
+    PriceFeed { id: String },
+}
+
+/// Price feed response of the pyth contract. Only the fields we rely on are deserialized.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PythPriceFeedResponse {
+    price_feed: PythPriceFeed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PythPriceFeed {
+    id: String,
+    price: PythPrice,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct PythPrice {
+    price: Int64,
+    conf: Uint64,
+    expo: i32,
+    publish_time: i64,
+}
+
+#[cfg(feature = "stargate")]
+mod twap {
+    use std::str::FromStr;
+
+    use cosmos_sdk_proto::prost::{bytes::Buf, encoding};
+    use cosmwasm_std::{Decimal, QuerierWrapper, StdError, StdResult, Timestamp, Uint128};
+
+    use crate::apis::stargate::convert_stamp;
+
+    /// Osmosis-compatible twap query
+    const ARITHMETIC_TWAP_TO_NOW_PATH: &str = "/osmosis.twap.v1beta1.Query/ArithmeticTwapToNow";
+    /// Precision of the `LegacyDec` returned by the twap module
+    const DEC_PRECISION: u32 = 18;
+
+    /// Query the arithmetic twap of a pool from `start_time` until the current block.
+    pub(super) fn arithmetic_twap_to_now(
+        querier: &QuerierWrapper,
+        pool_id: u64,
+        base_denom: String,
+        quote_denom: String,
+        start_time: Timestamp,
+    ) -> StdResult<Decimal> {
+        // ArithmeticTwapToNowRequest { pool_id = 1, base_asset = 2, quote_asset = 3, start_time = 4 }
+        let mut request = Vec::new();
+        encoding::uint64::encode(1, &pool_id, &mut request);
+        encoding::string::encode(2, &base_denom, &mut request);
+        encoding::string::encode(3, &quote_denom, &mut request);
+        encoding::message::encode(4, &convert_stamp(start_time), &mut request);
+
+        let response =
+            querier.query_grpc(ARITHMETIC_TWAP_TO_NOW_PATH.to_owned(), request.into())?;
+
+        // ArithmeticTwapToNowResponse { arithmetic_twap = 1 }
+        let decode_err = |e: encoding::DecodeError| StdError::parse_err("twap", e.to_string());
+        let mut buf = response.as_slice();
+        let mut arithmetic_twap = String::new();
+        while buf.has_remaining() {
+            let (tag, wire_type) = encoding::decode_key(&mut buf).map_err(decode_err)?;
+            if tag == 1 {
+                encoding::string::merge(
+                    wire_type,
+                    &mut arithmetic_twap,
+                    &mut buf,
+                    Default::default(),
+                )
+            } else {
+                encoding::skip_field(wire_type, tag, &mut buf, Default::default())
+            }
+            .map_err(decode_err)?;
+        }
+
+        let atomics = Uint128::from_str(&arithmetic_twap)?;
+        Decimal::from_atomics(atomics, DEC_PRECISION)
+            .map_err(|e| StdError::generic_err(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+    use abstract_std::{
+        ans_host::state::ORACLE_SOURCES,
+        objects::{ContractEntry, OracleSource},
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{testing::mock_dependencies, to_json_binary, Decimal, Uint128};
+    use cw_asset::AssetInfo;
+
+    use super::*;
+    use crate::{apis::traits::test::abstract_api_test, mock_module::MockModule};
+
+    fn pyth_entry() -> ContractEntry {
+        ContractEntry {
+            protocol: "pyth".to_owned(),
+            contract: "oracle".to_owned(),
+        }
+    }
+
+    fn setup(source: OracleSource, publish_time: i64) -> (MockDeps, MockModule) {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let pyth = deps.api.addr_make("pyth");
+        let juno = AssetEntry::new("juno");
+        let usd = AssetEntry::new("usd");
+
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .assets(vec![
+                (&juno, AssetInfo::native("ujuno")),
+                (&usd, AssetInfo::native("uusd")),
+            ])
+            .contracts(vec![(&pyth_entry(), pyth.clone())])
+            .with_contract_map_entry(&abstr.ans_host, ORACLE_SOURCES, ((&juno, &usd), source))
+            .with_smart_handler(&pyth, move |_| {
+                to_json_binary(&PythPriceFeedResponse {
+                    price_feed: PythPriceFeed {
+                        id: "abcd".to_owned(),
+                        price: PythPrice {
+                            // 2.5 juno/usd
+                            price: Int64::new(250_000_000),
+                            // 0.4% of the price
+                            conf: Uint64::new(1_000_000),
+                            expo: -8,
+                            publish_time,
+                        },
+                    },
+                })
+                .map_err(|e| e.to_string())
+            })
+            .build();
+        let module = MockModule::new(deps.api, account);
+        (deps, module)
+    }
+
+    mod price {
+        use super::*;
+
+        #[coverage_helper::test]
+        fn fixed_price_and_inverse() {
+            let (deps, module) = setup(
+                OracleSource::Fixed {
+                    price: Decimal::percent(200),
+                },
+                0,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+            let juno = AssetEntry::new("juno");
+            let usd = AssetEntry::new("usd");
+
+            let price = oracle.price(&env, &juno, &usd, 60).unwrap();
+            assert_eq!(price, Decimal::percent(200));
+
+            let inverse = oracle.price(&env, &usd, &juno, 60).unwrap();
+            assert_eq!(inverse, Decimal::percent(50));
+
+            let same = oracle.price(&env, &usd, &usd, 60).unwrap();
+            assert_eq!(same, Decimal::one());
+        }
+
+        #[coverage_helper::test]
+        fn missing_source() {
+            let (deps, module) = setup(
+                OracleSource::Fixed {
+                    price: Decimal::percent(200),
+                },
+                0,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+
+            let res = oracle.price(&env, &AssetEntry::new("juno"), &AssetEntry::new("eur"), 60);
+            let Err(AbstractSdkError::ApiQuery { api, .. }) = res else {
+                panic!("expected api error");
+            };
+            assert_eq!(api, "Oracle");
+        }
+
+        #[coverage_helper::test]
+        fn pyth_price() {
+            let deps = mock_dependencies();
+            let now = mock_env_validated(deps.api).block.time.seconds() as i64;
+            let (deps, module) = setup(
+                OracleSource::Pyth {
+                    contract: pyth_entry(),
+                    price_feed_id: "abcd".to_owned(),
+                    base_decimals: 6,
+                    quote_decimals: 6,
+                    max_confidence: Decimal::percent(1),
+                },
+                now - 10,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+            let juno = AssetEntry::new("juno");
+            let usd = AssetEntry::new("usd");
+
+            let price = oracle.price(&env, &juno, &usd, 60).unwrap();
+            assert_eq!(price, Decimal::percent(250));
+
+            let res = oracle.price(&env, &juno, &usd, 5);
+            assert_eq!(
+                res,
+                Err(AbstractSdkError::StalePrice {
+                    base: "juno".to_owned(),
+                    quote: "usd".to_owned(),
+                    age: 10,
+                    max_age: 5,
+                })
+            );
+        }
+
+        #[coverage_helper::test]
+        fn pyth_price_uncertain() {
+            let deps = mock_dependencies();
+            let now = mock_env_validated(deps.api).block.time.seconds() as i64;
+            let (deps, module) = setup(
+                OracleSource::Pyth {
+                    contract: pyth_entry(),
+                    price_feed_id: "abcd".to_owned(),
+                    base_decimals: 6,
+                    quote_decimals: 6,
+                    max_confidence: Decimal::permille(1),
+                },
+                now,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+
+            let res = oracle.price(&env, &AssetEntry::new("juno"), &AssetEntry::new("usd"), 60);
+            assert_eq!(
+                res,
+                Err(AbstractSdkError::UncertainPrice {
+                    base: "juno".to_owned(),
+                    quote: "usd".to_owned(),
+                    confidence: Decimal::permille(4),
+                    max_confidence: Decimal::permille(1),
+                })
+            );
+        }
+
+        #[coverage_helper::test]
+        fn twap_window_older_than_max_age() {
+            let (deps, module) = setup(
+                OracleSource::DexTwap {
+                    pool_id: UniquePoolId::new(1),
+                    window_seconds: 600,
+                },
+                0,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+
+            let res = oracle.price(&env, &AssetEntry::new("juno"), &AssetEntry::new("usd"), 60);
+            assert_eq!(
+                res,
+                Err(AbstractSdkError::StalePrice {
+                    base: "juno".to_owned(),
+                    quote: "usd".to_owned(),
+                    age: 600,
+                    max_age: 60,
+                })
+            );
+        }
+
+        #[cfg(feature = "stargate")]
+        #[coverage_helper::test]
+        fn twap_outside_osmosis() {
+            let (deps, module) = setup(
+                OracleSource::DexTwap {
+                    pool_id: UniquePoolId::new(1),
+                    window_seconds: 600,
+                },
+                0,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+
+            let res = oracle.price(&env, &AssetEntry::new("juno"), &AssetEntry::new("usd"), 600);
+            assert_eq!(
+                res,
+                Err(AbstractSdkError::UnsupportedOracleSource {
+                    base: "juno".to_owned(),
+                    quote: "usd".to_owned(),
+                    reason: format!(
+                        "twap is only available on osmosis, not on {}",
+                        TruncatedChainId::new(&env)
+                    ),
+                })
+            );
+        }
+
+        #[coverage_helper::test]
+        fn pyth_price_decimals() {
+            let deps = mock_dependencies();
+            let now = mock_env_validated(deps.api).block.time.seconds() as i64;
+            let (deps, module) = setup(
+                OracleSource::Pyth {
+                    contract: pyth_entry(),
+                    price_feed_id: "abcd".to_owned(),
+                    base_decimals: 6,
+                    quote_decimals: 18,
+                    max_confidence: Decimal::percent(1),
+                },
+                now,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+
+            let price = oracle
+                .price(&env, &AssetEntry::new("juno"), &AssetEntry::new("usd"), 60)
+                .unwrap();
+            // 2.5 * 10^(18 - 6)
+            assert_eq!(
+                price,
+                Decimal::from_atomics(2_500_000_000_000u128, 0).unwrap()
+            );
+        }
+    }
+
+    mod value {
+        use super::*;
+
+        #[coverage_helper::test]
+        fn value_and_total_value() {
+            let (deps, module) = setup(
+                OracleSource::Fixed {
+                    price: Decimal::percent(200),
+                },
+                0,
+            );
+            let env = mock_env_validated(deps.api);
+            let oracle = module.oracle(deps.as_ref());
+            let usd = AssetEntry::new("usd");
+
+            let value = oracle
+                .value(&env, &AnsAsset::new("juno", 100u128), &usd, 60)
+                .unwrap();
+            assert_eq!(value, Uint128::new(200));
+
+            let total = oracle
+                .total_value(
+                    &env,
+                    &[AnsAsset::new("juno", 100u128), AnsAsset::new("usd", 50u128)],
+                    &usd,
+                    60,
+                )
+                .unwrap();
+            assert_eq!(total, Uint128::new(250));
+        }
+    }
+
+    #[coverage_helper::test]
+    fn abstract_api() {
+        let (deps, _, module) = crate::mock_module::mock_module_setup();
+        let oracle = module.oracle(deps.as_ref());
+
+        abstract_api_test(oracle);
+    }
+}
@@ -1,23 +1,29 @@
-#![allow(unused)]
-use abstract_std::objects::AnsAsset;
-use cosmwasm_std::{Addr, CosmosMsg, Deps, Env, StdResult, Uint128};
+//! # Splitter
+//! The Splitter object splits assets held by the account between multiple receivers.
+use abstract_std::objects::{AccountId, AnsAsset};
+use cosmwasm_std::{Addr, Deps, Uint128};
 
 use super::AbstractApi;
 use crate::{
     features::{AccountExecutor, ModuleIdentification},
-    AbstractSdkResult, AccountAction, TransferInterface,
+    AbstractSdkError, AbstractSdkResult, AccountAction, AccountVerification, TransferInterface,
 };
 // ANCHOR: splitter
 // Trait to retrieve the Splitter object
 // Depends on the ability to transfer funds
-pub trait SplitterInterface: TransferInterface + AccountExecutor + ModuleIdentification {
+pub trait SplitterInterface:
+    TransferInterface + AccountExecutor + AccountVerification + ModuleIdentification
+{
     fn splitter<'a>(&'a self, deps: Deps<'a>) -> Splitter<'a, Self> {
         Splitter { base: self, deps }
     }
 }
 
 // Implement for every object that can transfer funds
-impl<T> SplitterInterface for T where T: TransferInterface + AccountExecutor + ModuleIdentification {}
+impl<T> SplitterInterface for T where
+    T: TransferInterface + AccountExecutor + AccountVerification + ModuleIdentification
+{
+}
 
 impl<T: SplitterInterface> AbstractApi<T> for Splitter<'_, T> {
     const API_ID: &'static str = "Splitter";
@@ -30,6 +36,21 @@ impl<T: SplitterInterface> AbstractApi<T> for Splitter<'_, T> {
     }
 }
 
+/// Receiver of a part of the split
+#[cosmwasm_schema::cw_serde]
+pub enum SplitReceiver {
+    Addr(Addr),
+    /// Abstract Account, resolved through the registry
+    Account(AccountId),
+}
+
+/// Receiver that gets `weight / total_weight` of every split asset
+#[cosmwasm_schema::cw_serde]
+pub struct WeightedReceiver {
+    pub receiver: SplitReceiver,
+    pub weight: u64,
+}
+
 #[derive(Clone)]
 pub struct Splitter<'a, T: SplitterInterface> {
     base: &'a T,
@@ -37,33 +58,105 @@ pub struct Splitter<'a, T: SplitterInterface> {
 }
 
 impl<T: SplitterInterface> Splitter<'_, T> {
-    /// Split an asset to multiple users
+    /// Split an asset evenly between users, the rounding remainder goes to the first user
     pub fn split(&self, asset: AnsAsset, receivers: &[Addr]) -> AbstractSdkResult<AccountAction> {
-        // split the asset between all receivers
-        let receives_each = AnsAsset {
-            amount: asset
-                .amount
-                .multiply_ratio(Uint128::one(), Uint128::from(receivers.len() as u128)),
-            ..asset
+        let weighted_receivers: Vec<WeightedReceiver> = receivers
+            .iter()
+            .map(|receiver| WeightedReceiver {
+                receiver: SplitReceiver::Addr(receiver.clone()),
+                weight: 1,
+            })
+            .collect();
+        let Some(first) = weighted_receivers.first() else {
+            return Err(AbstractSdkError::generic_err(
+                "no receivers to split between",
+            ));
         };
 
+        self.split_weighted(vec![asset], &weighted_receivers, &first.receiver)
+    }
+
+    /// Split assets between receivers according to their weights.
+    /// The rounding remainder of every asset is sent to `remainder_receiver`, so the full amounts are distributed.
+    /// Shares of the same address are merged into a single transfer.
+    pub fn split_weighted(
+        &self,
+        assets: Vec<AnsAsset>,
+        receivers: &[WeightedReceiver],
+        remainder_receiver: &SplitReceiver,
+    ) -> AbstractSdkResult<AccountAction> {
+        let total_weight: Uint128 = receivers.iter().map(|r| Uint128::from(r.weight)).sum();
+        if total_weight.is_zero() {
+            return Err(AbstractSdkError::generic_err(
+                "total weight of the split receivers is zero",
+            ));
+        }
+
+        let receivers = receivers
+            .iter()
+            .map(|r| Ok((self.resolve(&r.receiver)?, Uint128::from(r.weight))))
+            .collect::<AbstractSdkResult<Vec<_>>>()?;
+        let remainder_receiver = self.resolve(remainder_receiver)?;
+
+        let mut shares: Vec<(Addr, Vec<AnsAsset>)> = vec![];
+        for asset in assets {
+            let mut remainder = asset.amount;
+            for (receiver, weight) in &receivers {
+                let amount = asset.amount.multiply_ratio(*weight, total_weight);
+                remainder -= amount;
+                add_share(
+                    &mut shares,
+                    receiver,
+                    AnsAsset::new(asset.name.clone(), amount),
+                );
+            }
+            add_share(
+                &mut shares,
+                &remainder_receiver,
+                AnsAsset::new(asset.name, remainder),
+            );
+        }
+
         // Retrieve the bank API
         let bank = self.base.bank(self.deps);
-        receivers
-            .iter()
-            .map(|receiver| {
-                // Construct the transfer message
-                bank.transfer(vec![&receives_each], receiver)
-            })
-            .try_fold(AccountAction::default(), |mut acc, v| match v {
-                Ok(action) => {
-                    // Merge two AccountAction objects
-                    acc.merge(action);
-                    Ok(acc)
-                }
-                Err(e) => Err(e),
+        shares
+            .into_iter()
+            .try_fold(AccountAction::default(), |mut acc, (receiver, assets)| {
+                // Merge two AccountAction objects
+                acc.merge(bank.transfer(assets, &receiver)?);
+                Ok(acc)
             })
     }
+
+    fn resolve(&self, receiver: &SplitReceiver) -> AbstractSdkResult<Addr> {
+        match receiver {
+            SplitReceiver::Addr(addr) => Ok(addr.clone()),
+            SplitReceiver::Account(account_id) => Ok(self
+                .base
+                .account_registry(self.deps)?
+                .account(account_id)?
+                .into_addr()),
+        }
+    }
+}
+
+/// Add the asset to the share of the receiver, skips zero amounts
+fn add_share(shares: &mut Vec<(Addr, Vec<AnsAsset>)>, receiver: &Addr, asset: AnsAsset) {
+    if asset.amount.is_zero() {
+        return;
+    }
+    let index = match shares.iter().position(|(addr, _)| addr == receiver) {
+        Some(index) => index,
+        None => {
+            shares.push((receiver.clone(), vec![]));
+            shares.len() - 1
+        }
+    };
+    let assets = &mut shares[index].1;
+    match assets.iter_mut().find(|a| a.name == asset.name) {
+        Some(existing) => existing.amount += asset.amount,
+        None => assets.push(asset),
+    }
 }
 // ANCHOR_END: splitter
 
@@ -73,24 +166,21 @@ mod test {
     use abstract_std::objects::{AnsAsset, AssetEntry};
     use abstract_testing::{abstract_mock_querier_builder, prelude::*};
     use cosmwasm_std::{
-        coins,
-        testing::{mock_dependencies, mock_env},
-        Addr, BankMsg, CosmosMsg, Empty, Response, StdError, SubMsg, Uint128, WasmMsg,
+        coin, coins, testing::mock_dependencies, BankMsg, CosmosMsg, Empty, Response, SubMsg,
+        Uint128, WasmMsg,
     };
     use cw_asset::AssetInfo;
 
+    use super::*;
     use crate::{
-        apis::{splitter::SplitterInterface, traits::test::abstract_api_test},
-        mock_module::MockModule,
-        AbstractSdkError, Execution, ExecutorMsg,
+        apis::traits::test::abstract_api_test, mock_module::MockModule, AbstractSdkError,
+        Execution, ExecutorMsg,
     };
 
     #[coverage_helper::test]
     fn split() -> Result<(), AbstractSdkError> {
         let mut deps = mock_dependencies();
-        let env = mock_env_validated(deps.api);
         let account = test_account(deps.api);
-        let abstr = AbstractMockAddrs::new(deps.api);
         deps.querier = abstract_mock_querier_builder(deps.api)
             .account(&account, TEST_ACCOUNT_ID)
             .assets(vec![(&AssetEntry::new("usd"), AssetInfo::native("usd"))])
@@ -123,9 +213,10 @@ mod test {
                 contract_addr: account.addr().to_string(),
                 msg: to_json_binary(&abstract_std::account::ExecuteMsg::Execute::<Empty> {
                     msgs: vec![
+                        // Receives the remainder
                         BankMsg::Send {
                             to_address: deps.api.addr_make("receiver1").to_string(),
-                            amount: coins(33, "usd")
+                            amount: coins(34, "usd")
                         }
                         .into(),
                         BankMsg::Send {
@@ -149,11 +240,104 @@ mod test {
     }
 
     #[coverage_helper::test]
-    fn abstract_api() {
+    fn split_weighted() -> Result<(), AbstractSdkError> {
         let mut deps = mock_dependencies();
         let account = test_account(deps.api);
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .assets(vec![
+                (&AssetEntry::new("usd"), AssetInfo::native("usd")),
+                (&AssetEntry::new("eur"), AssetInfo::native("eur")),
+            ])
+            .build();
+        let module = MockModule::new(deps.api, account.clone());
+        let receiver = deps.api.addr_make("receiver");
+        let treasury = deps.api.addr_make("treasury");
+
+        let receivers = vec![
+            WeightedReceiver {
+                receiver: SplitReceiver::Addr(receiver.clone()),
+                weight: 2,
+            },
+            WeightedReceiver {
+                receiver: SplitReceiver::Account(TEST_ACCOUNT_ID),
+                weight: 1,
+            },
+        ];
+        let split_funds = module.splitter(deps.as_ref()).split_weighted(
+            vec![AnsAsset::new("usd", 100u128), AnsAsset::new("eur", 10u128)],
+            &receivers,
+            &SplitReceiver::Addr(treasury.clone()),
+        )?;
+
+        let expected: Vec<CosmosMsg> = vec![
+            BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(66, "usd"),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(6, "eur"),
+            }
+            .into(),
+            // Account id is resolved through the registry
+            BankMsg::Send {
+                to_address: account.addr().to_string(),
+                amount: coins(33, "usd"),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: account.addr().to_string(),
+                amount: coins(3, "eur"),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: treasury.to_string(),
+                amount: vec![coin(1, "usd")],
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: treasury.to_string(),
+                amount: vec![coin(1, "eur")],
+            }
+            .into(),
+        ];
+        assert_eq!(split_funds.messages(), expected);
+
+        // Remainder that goes to one of the receivers is merged into its share
+        let split_funds = module.splitter(deps.as_ref()).split_weighted(
+            vec![AnsAsset::new("usd", 100u128)],
+            &receivers,
+            &SplitReceiver::Addr(receiver.clone()),
+        )?;
+        assert_eq!(split_funds.messages().len(), 2);
+        assert_eq!(
+            split_funds.messages()[0],
+            CosmosMsg::from(BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(67, "usd"),
+            })
+        );
+
+        let no_weight = module.splitter(deps.as_ref()).split_weighted(
+            vec![AnsAsset::new("usd", 100u128)],
+            &[WeightedReceiver {
+                receiver: SplitReceiver::Addr(receiver),
+                weight: 0,
+            }],
+            &SplitReceiver::Addr(treasury),
+        );
+        assert!(no_weight.is_err());
+
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn abstract_api() {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
         let module = MockModule::new(deps.api, account.clone());
-        let env = mock_env_validated(deps.api);
         let splitter = module.splitter(deps.as_ref());
 
         abstract_api_test(splitter);
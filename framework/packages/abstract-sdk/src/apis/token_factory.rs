@@ -0,0 +1,492 @@
+//! # Token Factory
+//! Interacts with the token factory module of chains that run it (Osmosis, Neutron, Kujira, Injective, ...).
+//! The Account is the creator and admin of the denoms it creates.
+
+use anybuf::Anybuf;
+use cosmos_sdk_proto::{cosmos::bank::v1beta1, traits::Message};
+use cosmwasm_std::{Addr, Binary, Coin, DenomMetadata, Deps};
+
+use crate::{features::AccountExecutor, AbstractSdkError, AbstractSdkResult, AccountAction};
+
+/// Interact with the token factory module.
+/// Requires `Stargate` feature.
+pub trait TokenFactoryInterface: AccountExecutor {
+    /**
+        API for accessing the token factory module of the chain.
+
+        # Example
+        ```
+        use abstract_sdk::prelude::*;
+        # use cosmwasm_std::testing::mock_dependencies;
+        # use abstract_sdk::mock_module::MockModule;
+        # use abstract_testing::prelude::*;
+        # let deps = mock_dependencies();
+        # let account = admin_account(deps.api);
+        # let module = MockModule::new(deps.api, account);
+
+        let token_factory: TokenFactory = module.token_factory(deps.as_ref(), TokenFactoryFlavor::Osmosis)?;
+        # AbstractSdkResult::Ok(())
+        ```
+    */
+    fn token_factory(
+        &self,
+        deps: Deps,
+        flavor: TokenFactoryFlavor,
+    ) -> AbstractSdkResult<TokenFactory> {
+        Ok(TokenFactory {
+            sender: self.account(deps)?.into_addr(),
+            flavor,
+        })
+    }
+}
+
+impl<T> TokenFactoryInterface for T where T: AccountExecutor {}
+
+/// Token factory implementation of the chain, determines the type urls of the messages.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenFactoryFlavor {
+    /// `osmosis.tokenfactory.v1beta1`, used by Osmosis, Neutron, Juno, Stargaze and most other chains
+    Osmosis,
+    /// `kujira.denom`, doesn't support denom metadata or before-send hooks
+    Kujira,
+    /// `injective.tokenfactory.v1beta1`, doesn't support before-send hooks
+    Injective,
+    /// Chains with an osmosis compatible token factory under another proto package
+    Custom {
+        /// Proto package of the messages, e.g. `osmosis.tokenfactory.v1beta1`
+        package: String,
+        denom_metadata: bool,
+        before_send_hook: bool,
+    },
+}
+
+impl TokenFactoryFlavor {
+    fn package(&self) -> &str {
+        match self {
+            TokenFactoryFlavor::Osmosis => "osmosis.tokenfactory.v1beta1",
+            TokenFactoryFlavor::Kujira => "kujira.denom",
+            TokenFactoryFlavor::Injective => "injective.tokenfactory.v1beta1",
+            TokenFactoryFlavor::Custom { package, .. } => package,
+        }
+    }
+
+    /// Type url of the message with the given name
+    pub fn type_url(&self, msg_name: &str) -> String {
+        format!("/{}.{msg_name}", self.package())
+    }
+
+    pub fn supports_denom_metadata(&self) -> bool {
+        match self {
+            TokenFactoryFlavor::Osmosis | TokenFactoryFlavor::Injective => true,
+            TokenFactoryFlavor::Kujira => false,
+            TokenFactoryFlavor::Custom { denom_metadata, .. } => *denom_metadata,
+        }
+    }
+
+    pub fn supports_before_send_hook(&self) -> bool {
+        match self {
+            TokenFactoryFlavor::Osmosis => true,
+            TokenFactoryFlavor::Kujira | TokenFactoryFlavor::Injective => false,
+            TokenFactoryFlavor::Custom {
+                before_send_hook, ..
+            } => *before_send_hook,
+        }
+    }
+}
+
+/**
+    API for accessing the token factory module of the chain.
+
+    # Example
+    ```
+    use abstract_sdk::prelude::*;
+    # use cosmwasm_std::testing::mock_dependencies;
+    # use abstract_sdk::mock_module::MockModule;
+    # use abstract_testing::prelude::*;
+    # let deps = mock_dependencies();
+    # let account = admin_account(deps.api);
+    # let module = MockModule::new(deps.api, account);
+
+    let token_factory: TokenFactory = module.token_factory(deps.as_ref(), TokenFactoryFlavor::Osmosis)?;
+    let create_denom: AccountAction = token_factory.create_denom("share")?;
+    # AbstractSdkResult::Ok(())
+    ```
+*/
+#[derive(Clone)]
+pub struct TokenFactory {
+    /// The Account, creator and admin of the denoms
+    sender: Addr,
+    flavor: TokenFactoryFlavor,
+}
+
+impl TokenFactory {
+    /// Full denom of a token created by the Account
+    pub fn denom(&self, subdenom: &str) -> String {
+        format!("factory/{}/{subdenom}", self.sender)
+    }
+
+    /// Create a new denom, `factory/{account}/{subdenom}`.
+    /// The Account becomes the admin of the denom.
+    pub fn create_denom(&self, subdenom: &str) -> AbstractSdkResult<AccountAction> {
+        // Kujira calls the subdenom `nonce`, on the same field
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_string(2, subdenom);
+
+        Ok(self.msg("MsgCreateDenom", msg))
+    }
+
+    /// Mint tokens of a denom the Account is admin of to the recipient
+    pub fn mint(&self, amount: Coin, recipient: &Addr) -> AbstractSdkResult<AccountAction> {
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_message(2, &coin(&amount))
+            .append_string(3, recipient);
+
+        Ok(self.msg("MsgMint", msg))
+    }
+
+    /// Burn tokens held by the Account
+    pub fn burn(&self, amount: Coin) -> AbstractSdkResult<AccountAction> {
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_message(2, &coin(&amount));
+
+        Ok(self.msg("MsgBurn", msg))
+    }
+
+    /// Transfer the admin of a denom to another address
+    pub fn change_admin(&self, denom: &str, new_admin: &Addr) -> AbstractSdkResult<AccountAction> {
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_string(2, denom)
+            .append_string(3, new_admin);
+
+        Ok(self.msg("MsgChangeAdmin", msg))
+    }
+
+    /// Set the bank metadata of a denom
+    pub fn set_denom_metadata(&self, metadata: DenomMetadata) -> AbstractSdkResult<AccountAction> {
+        if !self.flavor.supports_denom_metadata() {
+            return Err(self.unsupported("denom metadata"));
+        }
+        let metadata = v1beta1::Metadata {
+            description: metadata.description,
+            denom_units: metadata
+                .denom_units
+                .into_iter()
+                .map(|unit| v1beta1::DenomUnit {
+                    denom: unit.denom,
+                    exponent: unit.exponent,
+                    aliases: unit.aliases,
+                })
+                .collect(),
+            base: metadata.base,
+            display: metadata.display,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+            uri_hash: metadata.uri_hash,
+        };
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_bytes(2, metadata.encode_to_vec());
+
+        Ok(self.msg("MsgSetDenomMetadata", msg))
+    }
+
+    /// Set the contract that's called before every send of the denom, `None` removes the hook.
+    pub fn set_before_send_hook(
+        &self,
+        denom: &str,
+        contract: Option<&Addr>,
+    ) -> AbstractSdkResult<AccountAction> {
+        if !self.flavor.supports_before_send_hook() {
+            return Err(self.unsupported("before-send hooks"));
+        }
+        let msg = Anybuf::new()
+            .append_string(1, &self.sender)
+            .append_string(2, denom)
+            .append_string(3, contract.map(Addr::as_str).unwrap_or_default());
+
+        Ok(self.msg("MsgSetBeforeSendHook", msg))
+    }
+
+    fn msg(&self, msg_name: &str, msg: Anybuf) -> AccountAction {
+        super::stargate_msg(self.flavor.type_url(msg_name), Binary::new(msg.into_vec())).into()
+    }
+
+    fn unsupported(&self, feature: &str) -> AbstractSdkError {
+        AbstractSdkError::generic_err(format!(
+            "token factory {} doesn't support {feature}",
+            self.flavor.package()
+        ))
+    }
+}
+
+fn coin(coin: &Coin) -> Anybuf {
+    Anybuf::new()
+        .append_string(1, &coin.denom)
+        .append_string(2, coin.amount.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::needless_borrows_for_generic_args)]
+
+    use super::*;
+    use crate::{apis::stargate_msg, mock_module::*};
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{coin, testing::mock_dependencies, CosmosMsg};
+
+    #[coverage_helper::test]
+    fn create_denom() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+
+        let token_factory = app.token_factory(deps.as_ref(), TokenFactoryFlavor::Osmosis)?;
+        let action = token_factory.create_denom("share")?;
+
+        let expected = stargate_msg(
+            "/osmosis.tokenfactory.v1beta1.MsgCreateDenom".to_owned(),
+            Binary::new(
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_string(2, "share")
+                    .into_vec(),
+            ),
+        );
+        assert_eq!(action.messages(), vec![expected]);
+        assert_eq!(
+            token_factory.denom("share"),
+            format!("factory/{}/share", account.addr())
+        );
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn mint_with_flavor_type_url() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let recipient = deps.api.addr_make("recipient");
+
+        let token_factory = app.token_factory(deps.as_ref(), TokenFactoryFlavor::Kujira)?;
+        let action = token_factory.mint(coin(100, "factory/account/share"), &recipient)?;
+
+        let expected = stargate_msg(
+            "/kujira.denom.MsgMint".to_owned(),
+            Binary::new(
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_message(
+                        2,
+                        &Anybuf::new()
+                            .append_string(1, "factory/account/share")
+                            .append_string(2, "100"),
+                    )
+                    .append_string(3, &recipient)
+                    .into_vec(),
+            ),
+        );
+        assert_eq!(action.messages(), vec![expected]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn unsupported_features() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account);
+
+        let kujira = app.token_factory(deps.as_ref(), TokenFactoryFlavor::Kujira)?;
+        assert!(kujira.set_denom_metadata(DenomMetadata::default()).is_err());
+        assert!(kujira.set_before_send_hook("denom", None).is_err());
+
+        let osmosis = app.token_factory(deps.as_ref(), TokenFactoryFlavor::Osmosis)?;
+        assert!(osmosis.set_denom_metadata(DenomMetadata::default()).is_ok());
+        assert!(osmosis.set_before_send_hook("denom", None).is_ok());
+        Ok(())
+    }
+
+    fn flavors() -> Vec<TokenFactoryFlavor> {
+        vec![
+            TokenFactoryFlavor::Osmosis,
+            TokenFactoryFlavor::Kujira,
+            TokenFactoryFlavor::Injective,
+            TokenFactoryFlavor::Custom {
+                package: "cosmwasm.tokenfactory.v1beta1".to_owned(),
+                denom_metadata: true,
+                before_send_hook: true,
+            },
+        ]
+    }
+
+    fn expected_msg(flavor: &TokenFactoryFlavor, msg_name: &str, msg: Anybuf) -> CosmosMsg {
+        stargate_msg(flavor.type_url(msg_name), Binary::new(msg.into_vec()))
+    }
+
+    #[coverage_helper::test]
+    fn burn() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+
+        for flavor in flavors() {
+            let token_factory = app.token_factory(deps.as_ref(), flavor.clone())?;
+            let action = token_factory.burn(coin(100, "factory/account/share"))?;
+
+            let expected = expected_msg(
+                &flavor,
+                "MsgBurn",
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_message(
+                        2,
+                        &Anybuf::new()
+                            .append_string(1, "factory/account/share")
+                            .append_string(2, "100"),
+                    ),
+            );
+            assert_eq!(action.messages(), vec![expected]);
+        }
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn change_admin() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let new_admin = deps.api.addr_make("new_admin");
+
+        for flavor in flavors() {
+            let token_factory = app.token_factory(deps.as_ref(), flavor.clone())?;
+            let action = token_factory.change_admin("factory/account/share", &new_admin)?;
+
+            let expected = expected_msg(
+                &flavor,
+                "MsgChangeAdmin",
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_string(2, "factory/account/share")
+                    .append_string(3, &new_admin),
+            );
+            assert_eq!(action.messages(), vec![expected]);
+        }
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn set_denom_metadata() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let metadata = DenomMetadata {
+            description: "Account shares".to_owned(),
+            denom_units: vec![
+                cosmwasm_std::DenomUnit {
+                    denom: "factory/account/share".to_owned(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                cosmwasm_std::DenomUnit {
+                    denom: "share".to_owned(),
+                    exponent: 6,
+                    aliases: vec!["shares".to_owned()],
+                },
+            ],
+            base: "factory/account/share".to_owned(),
+            display: "share".to_owned(),
+            name: "Share".to_owned(),
+            symbol: "SHARE".to_owned(),
+            uri: String::new(),
+            uri_hash: String::new(),
+        };
+        let encoded_metadata = v1beta1::Metadata {
+            description: "Account shares".to_owned(),
+            denom_units: vec![
+                v1beta1::DenomUnit {
+                    denom: "factory/account/share".to_owned(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                v1beta1::DenomUnit {
+                    denom: "share".to_owned(),
+                    exponent: 6,
+                    aliases: vec!["shares".to_owned()],
+                },
+            ],
+            base: "factory/account/share".to_owned(),
+            display: "share".to_owned(),
+            name: "Share".to_owned(),
+            symbol: "SHARE".to_owned(),
+            uri: String::new(),
+            uri_hash: String::new(),
+        }
+        .encode_to_vec();
+
+        for flavor in flavors() {
+            let token_factory = app.token_factory(deps.as_ref(), flavor.clone())?;
+            let res = token_factory.set_denom_metadata(metadata.clone());
+            if !flavor.supports_denom_metadata() {
+                assert!(res.is_err());
+                continue;
+            }
+
+            let expected = expected_msg(
+                &flavor,
+                "MsgSetDenomMetadata",
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_bytes(2, &encoded_metadata),
+            );
+            assert_eq!(res?.messages(), vec![expected]);
+        }
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn set_before_send_hook() -> AbstractSdkResult<()> {
+        let deps = mock_dependencies();
+        let account = test_account(deps.api);
+        let app = MockModule::new(deps.api, account.clone());
+        let hook = deps.api.addr_make("hook");
+
+        for flavor in flavors() {
+            let token_factory = app.token_factory(deps.as_ref(), flavor.clone())?;
+            if !flavor.supports_before_send_hook() {
+                assert!(token_factory
+                    .set_before_send_hook("factory/account/share", Some(&hook))
+                    .is_err());
+                continue;
+            }
+
+            let action =
+                token_factory.set_before_send_hook("factory/account/share", Some(&hook))?;
+            let expected = expected_msg(
+                &flavor,
+                "MsgSetBeforeSendHook",
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_string(2, "factory/account/share")
+                    .append_string(3, &hook),
+            );
+            assert_eq!(action.messages(), vec![expected]);
+
+            // Removing the hook sends an empty contract address
+            let action = token_factory.set_before_send_hook("factory/account/share", None)?;
+            let expected = expected_msg(
+                &flavor,
+                "MsgSetBeforeSendHook",
+                Anybuf::new()
+                    .append_string(1, account.addr())
+                    .append_string(2, "factory/account/share")
+                    .append_string(3, ""),
+            );
+            assert_eq!(action.messages(), vec![expected]);
+        }
+        Ok(())
+    }
+}
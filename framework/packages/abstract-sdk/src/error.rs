@@ -2,7 +2,7 @@
 use std::fmt::{Display, Formatter};
 
 use crate::std::AbstractError;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
 use cw_asset::AssetError;
 use thiserror::Error;
 
@@ -84,6 +84,40 @@ pub enum AbstractSdkError {
         err: String,
     },
 
+    // Oracle price is older than allowed
+    #[error("Price of {base}/{quote} is stale: published {age}s ago, max age is {max_age}s")]
+    StalePrice {
+        base: String,
+        quote: String,
+        age: u64,
+        max_age: u64,
+    },
+
+    // Oracle is not confident enough about the price
+    #[error("Price of {base}/{quote} is uncertain: confidence interval is {confidence} of the price, max is {max_confidence}")]
+    UncertainPrice {
+        base: String,
+        quote: String,
+        confidence: Decimal,
+        max_confidence: Decimal,
+    },
+
+    // Oracle returned a price that can't be used
+    #[error("Invalid price for {base}/{quote}: {reason}")]
+    InvalidPrice {
+        base: String,
+        quote: String,
+        reason: String,
+    },
+
+    // Oracle source can't be resolved by the sdk
+    #[error("Oracle source for {base}/{quote} is not supported: {reason}")]
+    UnsupportedOracleSource {
+        base: String,
+        quote: String,
+        reason: String,
+    },
+
     // This call needs to be an admin call
     #[error(
         "Only the admin can execute this action. An admin is either the owner of an account of an account called by its owner"
@@ -24,11 +24,11 @@ pub use account_action::AccountAction;
 pub use error::{AbstractSdkError, EndpointError};
 
 #[cfg(feature = "stargate")]
-pub use crate::apis::{authz::*, distribution::*, feegrant::*};
+pub use crate::apis::{authz::*, distribution::*, feegrant::*, gov::*, token_factory::*};
 pub use crate::{
     apis::{
-        adapter::*, app::*, bank::*, execution::*, ibc::*, ibc_memo::*, modules::*, respond::*,
-        verify::*, version_registry::*,
+        adapter::*, app::*, bank::*, execution::*, ibc::*, ibc_memo::*, modules::*, nft::*,
+        oracle::*, respond::*, splitter::*, verify::*, version_registry::*,
     },
     features::AbstractNameServiceClient,
 };
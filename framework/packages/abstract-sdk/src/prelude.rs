@@ -9,11 +9,11 @@
 //! ```
 
 #[cfg(feature = "stargate")]
-pub use crate::apis::{distribution::*, stargate::feegrant::*};
+pub use crate::apis::{distribution::*, gov::*, stargate::feegrant::*, token_factory::*};
 pub use crate::{
     ans_resolve::Resolve,
     apis::{
-        adapter::*, app::*, bank::*, execution::*, ibc::*, modules::*, respond::*, verify::*,
-        version_registry::*,
+        adapter::*, app::*, bank::*, execution::*, ibc::*, modules::*, nft::*, oracle::*,
+        respond::*, splitter::*, verify::*, version_registry::*,
     },
 };
@@ -8,7 +8,8 @@
 //! The adapter structure is well-suited for implementing standard interfaces to external services like dexes, lending platforms, etc.
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_std::{Addr, Binary, Empty};
+use cw_asset::AssetInfoUnchecked;
 use serde::Serialize;
 
 use crate::{
@@ -16,7 +17,7 @@ use crate::{
         ExecuteMsg as MiddlewareExecMsg, InstantiateMsg as MiddlewareInstantiateMsg,
         QueryMsg as MiddlewareQueryMsg,
     },
-    objects::module_version::ModuleDataResponse,
+    objects::{module_version::ModuleDataResponse, AssetEntry},
 };
 
 pub type ExecuteMsg<Request = Empty> =
@@ -28,7 +29,13 @@ pub type InstantiateMsg<ModuleMsg = Empty> =
 /// Trait indicates that the type is used as an app message
 /// in the [`ExecuteMsg`] enum.
 /// Enables [`Into<ExecuteMsg>`] for BOOT fn-generation support.
-pub trait AdapterExecuteMsg: Serialize {}
+pub trait AdapterExecuteMsg: Serialize {
+    /// Describes the request so it can be checked against the [`AuthorizedScope`] of an authorized address.
+    /// Requests that aren't described have no action, so only scopes that don't restrict actions allow them.
+    fn request_scope(&self) -> RequestScope {
+        RequestScope::default()
+    }
+}
 impl<T: AdapterExecuteMsg> From<T> for ExecuteMsg<T> {
     fn from(request: T) -> Self {
         Self::Module(AdapterRequestMsg {
@@ -111,7 +118,95 @@ pub enum AdapterBaseMsg {
     UpdateAuthorizedAddresses {
         to_add: Vec<String>,
         to_remove: Vec<String>,
+        /// Restrict authorized addresses (or module ids) to a scope.
+        /// Authorized addresses without a scope can execute any request.
+        #[serde(default)]
+        scopes: Vec<(String, AuthorizedScope)>,
     },
+    /// Set the configuration of the Account on this adapter.
+    /// The config must deserialize into the adapter's `AccountConfig` type.
+    /// If None, the config is reset to its default.
+    /// Can also be called by the authorized addresses of the Account that aren't limited by a scope.
+    UpdateAccountConfig { config: Option<Binary> },
+}
+
+/// Restricts the requests an authorized address can execute on behalf of an Account.
+/// An empty list doesn't restrict that part of the request.
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct AuthorizedScope {
+    /// Actions the address can execute, in snake_case (e.g. `swap`)
+    pub actions: Vec<String>,
+    /// Providers the actions can be executed on (e.g. `osmosis`)
+    pub providers: Vec<String>,
+    /// Assets the actions can use, by their ANS name (e.g. `osmosis>osmo`).
+    /// Raw assets of a request are resolved to their ANS name, unregistered raw assets aren't allowed.
+    pub assets: Vec<AssetEntry>,
+}
+
+impl AuthorizedScope {
+    /// Whether the scope allows the request.
+    /// A request that doesn't report its provider or assets is only allowed if those aren't restricted.
+    pub fn allows(&self, request: &RequestScope) -> bool {
+        let action_allowed = self.actions.is_empty() || self.actions.contains(&request.action);
+        let provider_allowed = self.providers.is_empty()
+            || request
+                .provider
+                .as_ref()
+                .is_some_and(|provider| self.providers.contains(provider));
+        let assets_allowed = self.assets.is_empty()
+            || (request.raw_assets.is_empty()
+                && !request.assets.is_empty()
+                && request
+                    .assets
+                    .iter()
+                    .all(|asset| self.assets.contains(asset)));
+        action_allowed && provider_allowed && assets_allowed
+    }
+}
+
+/// Action, provider and assets of an adapter request. See [`AdapterExecuteMsg::request_scope`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RequestScope {
+    /// Name of the action, in snake_case
+    pub action: String,
+    /// Provider the action is executed on
+    pub provider: Option<String>,
+    /// ANS names of the assets used by the action
+    pub assets: Vec<AssetEntry>,
+    /// Raw assets used by the action that aren't resolved to their ANS name yet
+    pub raw_assets: Vec<AssetInfoUnchecked>,
+}
+
+impl RequestScope {
+    /// Scope of a request executing `action`
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the provider of the request
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Add ANS assets used by the request
+    pub fn with_assets<'a>(mut self, assets: impl IntoIterator<Item = &'a AssetEntry>) -> Self {
+        self.assets.extend(assets.into_iter().cloned());
+        self
+    }
+
+    /// Add raw assets used by the request
+    pub fn with_raw_assets<'a>(
+        mut self,
+        assets: impl IntoIterator<Item = &'a AssetInfoUnchecked>,
+    ) -> Self {
+        self.raw_assets.extend(assets.into_iter().cloned());
+        self
+    }
 }
 
 /// Query adapter message
@@ -124,6 +219,10 @@ pub enum BaseQueryMsg {
     /// Returns [`AuthorizedAddressesResponse`].
     #[returns(AuthorizedAddressesResponse)]
     AuthorizedAddresses { account_address: String },
+    /// Returns the config of the Account on this adapter.
+    /// Returns [`AccountConfigResponse`].
+    #[returns(AccountConfigResponse)]
+    AccountConfig { account_address: String },
     /// Returns module data
     /// Returns [`ModuleDataResponse`].
     #[returns(ModuleDataResponse)]
@@ -147,6 +246,15 @@ pub struct AdapterConfigResponse {
 pub struct AuthorizedAddressesResponse {
     /// Contains all authorized addresses
     pub addresses: Vec<Addr>,
+    /// Scopes of the authorized addresses that are restricted
+    #[serde(default)]
+    pub scopes: Vec<(Addr, AuthorizedScope)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct AccountConfigResponse {
+    /// Serialized `AccountConfig` of the adapter, None if the Account didn't set one
+    pub config: Option<Binary>,
 }
 
 /// The BaseState contains the main addresses needed for sending and verifying messages
@@ -156,3 +264,85 @@ pub struct AdapterState {
     /// Code id of abstract contract
     pub code_id: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cosmwasm_schema::cw_serde]
+    enum MockRequest {
+        Swap { offer: AssetEntry },
+        Claim,
+    }
+
+    impl AdapterExecuteMsg for MockRequest {
+        fn request_scope(&self) -> RequestScope {
+            match self {
+                MockRequest::Swap { offer } => RequestScope::new("swap").with_assets([offer]),
+                MockRequest::Claim => RequestScope::new("claim"),
+            }
+        }
+    }
+
+    #[coverage_helper::test]
+    fn request_scope_of_request() {
+        let swap = MockRequest::Swap {
+            offer: AssetEntry::new("juno"),
+        };
+        assert_eq!(
+            swap.request_scope(),
+            RequestScope {
+                action: "swap".to_owned(),
+                assets: vec![AssetEntry::new("juno")],
+                ..Default::default()
+            }
+        );
+        assert_eq!(MockRequest::Claim.request_scope().action, "claim");
+        assert_eq!(Empty {}.request_scope(), RequestScope::default());
+    }
+
+    #[coverage_helper::test]
+    fn scope_allows_request() {
+        let request = RequestScope::new("swap")
+            .with_provider("osmosis")
+            .with_assets(&[AssetEntry::new("osmo"), AssetEntry::new("atom")]);
+        assert!(AuthorizedScope::default().allows(&request));
+
+        let scope = AuthorizedScope {
+            actions: vec!["swap".to_owned()],
+            providers: vec!["osmosis".to_owned()],
+            assets: vec![
+                AssetEntry::new("osmo"),
+                AssetEntry::new("atom"),
+                AssetEntry::new("usdc"),
+            ],
+        };
+        assert!(scope.allows(&request));
+
+        let borrow = RequestScope {
+            action: "borrow".to_owned(),
+            ..request.clone()
+        };
+        assert!(!scope.allows(&borrow));
+
+        let other_asset = RequestScope {
+            assets: vec![AssetEntry::new("osmo"), AssetEntry::new("juno")],
+            ..request.clone()
+        };
+        assert!(!scope.allows(&other_asset));
+
+        // Raw assets that aren't resolved to an ANS name aren't allowed
+        let unresolved = request
+            .clone()
+            .with_raw_assets(&[AssetInfoUnchecked::native("uosmo")]);
+        assert!(!scope.allows(&unresolved));
+
+        // Restricted parts of the scope have to be reported by the request
+        let unknown = MockRequest::Claim.request_scope();
+        let scope = AuthorizedScope {
+            providers: vec!["osmosis".to_owned()],
+            ..Default::default()
+        };
+        assert!(!scope.allows(&unknown));
+    }
+}
@@ -17,6 +17,8 @@ pub const ABSTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// IBC protocols
 pub const ICS20: &str = "ics-20";
+/// ICS20 transfers of cw20 tokens through the ICS20-cw20 contract
+pub const ICS20_CW20: &str = "ics-20-cw20";
 
 // chain-id prefixes based on `https://cosmos.directory/`
 pub mod juno {
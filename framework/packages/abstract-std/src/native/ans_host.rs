@@ -6,13 +6,14 @@
 //! Contract and asset addresses are stored on the ans_host contract and are retrievable trough smart or raw queries.
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp};
 use cw_asset::{AssetInfo, AssetInfoUnchecked};
 
 use crate::objects::{
-    pool_id::UncheckedPoolAddress, pool_reference::PoolReference, AssetEntry, ChannelEntry,
-    ContractEntry, DexAssetPairing, PoolMetadata, PoolType, UncheckedChannelEntry,
-    UncheckedContractEntry, UniquePoolId,
+    pool_id::{PoolAddress, UncheckedPoolAddress},
+    pool_reference::PoolReference,
+    AssetEntry, ChannelEntry, ContractEntry, DexAssetPairing, OracleSource, PoolMetadata, PoolType,
+    UncheckedChannelEntry, UncheckedContractEntry, UniquePoolId,
 };
 
 pub type AssetPair = (AssetEntry, AssetEntry);
@@ -26,22 +27,30 @@ pub type AssetMapEntry = (AssetEntry, AssetInfo);
 pub type AssetInfoMapEntry = (AssetInfo, AssetEntry);
 /// Map entry for channels
 pub type ChannelMapEntry = (ChannelEntry, String);
+/// Map entry for channels (channel_id -> channel)
+pub type ChannelIdMapEntry = (String, ChannelEntry);
 /// Map entry for contracts (contract -> address)
 pub type ContractMapEntry = (ContractEntry, Addr);
+/// Map entry for contracts (address -> contract)
+pub type ContractAddressMapEntry = (Addr, ContractEntry);
 /// A map entry of (unique_pool_id -> pool_metadata)
 pub type PoolMetadataMapEntry = (UniquePoolId, PoolMetadata);
+/// A map entry of ((base, quote) -> oracle_source)
+pub type OracleSourceMapEntry = (AssetPair, OracleSource);
+/// A map entry of (change_set_id -> change_set)
+pub type ChangeSetMapEntry = (u64, ChangeSet);
 
 /// AnsHost state details
 pub mod state {
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{Addr, Empty};
     use cw_asset::AssetInfo;
     use cw_storage_plus::{Item, Map};
 
     use crate::{
-        ans_host::{DexAssetPairing, DexName, UniquePoolId},
+        ans_host::{ChangeSet, DexAssetPairing, DexName, UniquePoolId},
         objects::{
             pool_metadata::PoolMetadata, pool_reference::PoolReference, storage_namespaces,
-            AssetEntry, ChannelEntry, ContractEntry,
+            AssetEntry, ChannelEntry, ContractEntry, OracleSource,
         },
     };
 
@@ -63,10 +72,14 @@ pub mod state {
     /// Stores contract addresses
     pub const CONTRACT_ADDRESSES: Map<&ContractEntry, Addr> =
         Map::new(storage_namespaces::ans_host::CONTRACT_ADDRESSES);
+    pub const REV_CONTRACT_ADDRESSES: Map<&Addr, ContractEntry> =
+        Map::new(storage_namespaces::ans_host::REV_CONTRACT_ADDRESSES);
 
     /// stores channel-ids
     pub const CHANNELS: Map<&ChannelEntry, String> =
         Map::new(storage_namespaces::ans_host::CHANNELS);
+    pub const REV_CHANNELS: Map<&str, ChannelEntry> =
+        Map::new(storage_namespaces::ans_host::REV_CHANNELS);
 
     /// Stores the registered dex names
     pub const REGISTERED_DEXES: Item<Vec<DexName>> =
@@ -80,7 +93,25 @@ pub mod state {
     /// Stores the metadata for the pools using the unique pool id as the key
     pub const POOL_METADATA: Map<UniquePoolId, PoolMetadata> =
         Map::new(storage_namespaces::ans_host::POOL_METADATA);
+
+    /// Stores the oracle source of an asset pair
+    /// (base, quote) -> OracleSource
+    pub const ORACLE_SOURCES: Map<(&AssetEntry, &AssetEntry), OracleSource> =
+        Map::new(storage_namespaces::ans_host::ORACLE_SOURCES);
     // ANCHOR_END: ans_state
+
+    /// Id of the last recorded change set
+    pub const CHANGE_SET_SEQUENCE: Item<u64> =
+        Item::new(storage_namespaces::ans_host::CHANGE_SET_SEQUENCE);
+
+    /// Stores every change set applied to the name service
+    pub const CHANGE_SETS: Map<u64, ChangeSet> =
+        Map::new(storage_namespaces::ans_host::CHANGE_SETS);
+
+    /// Index of the change sets that changed an entry
+    /// (entry_key, change_set_id) -> ()
+    pub const ENTRY_CHANGE_SETS: Map<(&str, u64), Empty> =
+        Map::new(storage_namespaces::ans_host::ENTRY_CHANGE_SETS);
 }
 
 /// AnsHost Instantiate msg
@@ -129,6 +160,16 @@ pub enum ExecuteMsg {
         // Pools to remove
         to_remove: Vec<UniquePoolId>,
     },
+    /// Update the oracle sources of asset pairs
+    UpdateOracleSources {
+        // (base, quote) pairs and their price source to update or add
+        to_add: Vec<(AssetPair, OracleSource)>,
+        // (base, quote) pairs to remove
+        to_remove: Vec<AssetPair>,
+    },
+    /// Revert all the changes of a change set, recording the revert as a new change set.
+    /// Fails if any of the reverted entries was changed since.
+    RevertChangeSet { id: u64 },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -219,6 +260,13 @@ pub enum QueryMsg {
         start_after: Option<ContractEntry>,
         limit: Option<u8>,
     },
+    /// Queries contracts based on address
+    /// returns [`ContractAddressesResponse`]
+    #[returns(ContractAddressesResponse)]
+    ContractAddresses {
+        // Addresses of contracts to query
+        addresses: Vec<String>,
+    },
     /// Queries contracts based on name
     /// returns [`ChannelsResponse`]
     #[returns(ChannelsResponse)]
@@ -234,6 +282,13 @@ pub enum QueryMsg {
         start_after: Option<ChannelEntry>,
         limit: Option<u8>,
     },
+    /// Queries channels based on channel id
+    /// returns [`ChannelIdsResponse`]
+    #[returns(ChannelIdsResponse)]
+    ChannelIds {
+        // Channel ids to query
+        ids: Vec<String>,
+    },
     /// Retrieve the registered dexes
     /// returns [`RegisteredDexesResponse`]
     #[returns(RegisteredDexesResponse)]
@@ -262,6 +317,142 @@ pub enum QueryMsg {
         start_after: Option<UniquePoolId>,
         limit: Option<u8>,
     },
+    /// Retrieve the oracle sources of the given (base, quote) pairs
+    /// returns [`OracleSourcesResponse`]
+    #[returns(OracleSourcesResponse)]
+    OracleSources { pairs: Vec<AssetPair> },
+    /// Page over the registered oracle sources
+    /// returns [`OracleSourceListResponse`]
+    #[returns(OracleSourceListResponse)]
+    OracleSourceList {
+        start_after: Option<AssetPair>,
+        limit: Option<u8>,
+    },
+    /// Retrieve the change sets with the given ids
+    /// returns [`ChangeSetsResponse`]
+    #[returns(ChangeSetsResponse)]
+    ChangeSets { ids: Vec<u64> },
+    /// Page over the change sets
+    /// returns [`ChangeSetListResponse`]
+    #[returns(ChangeSetListResponse)]
+    ChangeSetList {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    /// Page over the change sets that changed an entry, only containing the changes of that entry
+    /// returns [`ChangeSetListResponse`]
+    #[returns(ChangeSetListResponse)]
+    EntryHistory {
+        entry: AnsHistoryEntry,
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+}
+
+/// Entry of the name service that can be changed
+#[cosmwasm_schema::cw_serde]
+pub enum AnsHistoryEntry {
+    Contract(ContractEntry),
+    Asset(AssetEntry),
+    Channel(ChannelEntry),
+    /// The list of registered dexes
+    Dexes,
+    Pool(UniquePoolId),
+    OracleSource(AssetPair),
+}
+
+impl AnsHistoryEntry {
+    /// Key of the entry in the change history index
+    pub fn key(&self) -> String {
+        match self {
+            AnsHistoryEntry::Contract(entry) => format!("contract:{entry}"),
+            AnsHistoryEntry::Asset(entry) => format!("asset:{entry}"),
+            AnsHistoryEntry::Channel(entry) => format!("channel:{entry}"),
+            AnsHistoryEntry::Dexes => "dexes".to_owned(),
+            AnsHistoryEntry::Pool(id) => format!("pool:{id}"),
+            AnsHistoryEntry::OracleSource((base, quote)) => format!("oracle:{base}/{quote}"),
+        }
+    }
+}
+
+/// Registered pool, as stored in the change history
+#[cosmwasm_schema::cw_serde]
+pub struct PoolRecord {
+    pub address: PoolAddress,
+    pub metadata: PoolMetadata,
+}
+
+/// A single change of a name service entry. `None` means the entry did not exist.
+#[cosmwasm_schema::cw_serde]
+pub enum AnsChange {
+    Contract {
+        entry: ContractEntry,
+        old: Option<Addr>,
+        new: Option<Addr>,
+    },
+    Asset {
+        entry: AssetEntry,
+        old: Option<AssetInfo>,
+        new: Option<AssetInfo>,
+    },
+    Channel {
+        entry: ChannelEntry,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    Dexes {
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    Pool {
+        id: UniquePoolId,
+        old: Option<PoolRecord>,
+        new: Option<PoolRecord>,
+    },
+    OracleSource {
+        pair: AssetPair,
+        old: Option<OracleSource>,
+        new: Option<OracleSource>,
+    },
+}
+
+impl AnsChange {
+    /// The entry that was changed
+    pub fn entry(&self) -> AnsHistoryEntry {
+        match self {
+            AnsChange::Contract { entry, .. } => AnsHistoryEntry::Contract(entry.clone()),
+            AnsChange::Asset { entry, .. } => AnsHistoryEntry::Asset(entry.clone()),
+            AnsChange::Channel { entry, .. } => AnsHistoryEntry::Channel(entry.clone()),
+            AnsChange::Dexes { .. } => AnsHistoryEntry::Dexes,
+            AnsChange::Pool { id, .. } => AnsHistoryEntry::Pool(*id),
+            AnsChange::OracleSource { pair, .. } => AnsHistoryEntry::OracleSource(pair.clone()),
+        }
+    }
+
+    /// Whether the change left the entry untouched
+    pub fn is_noop(&self) -> bool {
+        match self {
+            AnsChange::Contract { old, new, .. } => old == new,
+            AnsChange::Asset { old, new, .. } => old == new,
+            AnsChange::Channel { old, new, .. } => old == new,
+            AnsChange::Dexes { old, new } => old == new,
+            AnsChange::Pool { old, new, .. } => old == new,
+            AnsChange::OracleSource { old, new, .. } => old == new,
+        }
+    }
+}
+
+/// All changes applied by a single ans-host execution
+#[cosmwasm_schema::cw_serde]
+pub struct ChangeSet {
+    pub sender: Addr,
+    pub block_height: u64,
+    pub block_time: Timestamp,
+    pub changes: Vec<AnsChange>,
+    /// Id of the change set reverted by this change set
+    pub reverts: Option<u64>,
+    /// Id of the change set that reverted this change set
+    pub reverted_by: Option<u64>,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -306,6 +497,12 @@ pub struct ContractListResponse {
     pub contracts: Vec<ContractMapEntry>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ContractAddressesResponse {
+    /// Contracts (address, name)
+    pub contracts: Vec<ContractAddressMapEntry>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ChannelsResponse {
     pub channels: Vec<ChannelMapEntry>,
@@ -316,6 +513,12 @@ pub struct ChannelListResponse {
     pub channels: Vec<ChannelMapEntry>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ChannelIdsResponse {
+    /// Channels (channel_id, name)
+    pub channels: Vec<ChannelIdMapEntry>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct RegisteredDexesResponse {
     pub dexes: Vec<String>,
@@ -340,3 +543,23 @@ pub struct PoolMetadatasResponse {
 pub struct PoolMetadataListResponse {
     pub metadatas: Vec<PoolMetadataMapEntry>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct OracleSourcesResponse {
+    pub sources: Vec<OracleSourceMapEntry>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OracleSourceListResponse {
+    pub sources: Vec<OracleSourceMapEntry>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ChangeSetsResponse {
+    pub change_sets: Vec<ChangeSetMapEntry>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ChangeSetListResponse {
+    pub change_sets: Vec<ChangeSetMapEntry>,
+}
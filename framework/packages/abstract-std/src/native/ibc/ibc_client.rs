@@ -1,5 +1,8 @@
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Binary, CosmosMsg, Deps, Empty, QueryRequest, StdError, Uint64};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, Deps, Empty, QueryRequest, StdError, StdResult,
+    Uint64,
+};
 
 use self::state::IbcInfrastructure;
 use crate::{
@@ -10,7 +13,7 @@ use crate::{
         account::AccountId, module::ModuleInfo, module_reference::ModuleReference,
         registry::RegistryContract, TruncatedChainId,
     },
-    AbstractError,
+    AbstractError, IBC_CLIENT,
 };
 
 use super::{polytone_callbacks, IBCLifecycleComplete};
@@ -21,6 +24,7 @@ pub mod state {
     use cw_storage_plus::{Item, Map};
 
     use crate::{
+        constants::CHAIN_DELIMITER,
         ibc::ICS20PacketIdentifier,
         objects::{
             account::{AccountSequence, AccountTrace},
@@ -62,6 +66,20 @@ pub mod state {
     pub const ACCOUNTS: Map<(&AccountTrace, AccountSequence, &TruncatedChainId), String> =
         Map::new(storage_namespaces::ibc_client::ACCOUNTS);
 
+    /// (account_sequence, route) -> address of a remote account that's controlled through the remote accounts on the route.
+    /// The route holds the chains from the first hop up to the chain of the account, see [`route_key`].
+    pub const ROUTED_ACCOUNTS: Map<(AccountSequence, &str), String> =
+        Map::new(storage_namespaces::ibc_client::ROUTED_ACCOUNTS);
+
+    /// Storage key of a route of chains
+    pub fn route_key(route: &[TruncatedChainId]) -> String {
+        route
+            .iter()
+            .map(TruncatedChainId::as_str)
+            .collect::<Vec<_>>()
+            .join(CHAIN_DELIMITER)
+    }
+
     // For callbacks tests
     pub const ACKS: Item<Vec<String>> = Item::new(storage_namespaces::ibc_client::ACKS);
     pub const ICS20_ACCOUNT_CALLBACKS: Map<ICS20PacketIdentifier, (Addr, Coin, Vec<Binary>)> =
@@ -165,6 +183,43 @@ pub enum ExecuteMsg {
     /// Callback from the Polytone implementation
     /// This is triggered regardless of the execution result
     Callback(polytone_callbacks::CallbackMessage),
+    /// Only callable by the IBC host
+    /// Callback of a remote account, forwarded back towards the chain the account originates from
+    ForwardCallback {
+        /// Account that initiated the action, relative to this chain
+        account_id: AccountId,
+        /// Chains from the first hop up to the chain the action was executed on
+        route: Vec<TruncatedChainId>,
+        callback: ForwardedCallback,
+    },
+}
+
+impl ExecuteMsg {
+    /// Routes this message through the remote accounts on `hops`, so it's executed by the ibc client of the account on the last hop.
+    /// Each hop is the chain of a remote account of the previous hop, with the ibc client installed.
+    /// `funds` are taken from the balance of the account on the last hop.
+    ///
+    /// Returns the message to execute on the local ibc client and the funds to attach to it.
+    pub fn route_through(
+        self,
+        hops: &[TruncatedChainId],
+        mut funds: Vec<Coin>,
+    ) -> StdResult<(Self, Vec<Coin>)> {
+        let mut msg = self;
+        for host_chain in hops.iter().rev() {
+            msg = ExecuteMsg::RemoteAction {
+                host_chain: host_chain.clone(),
+                action: HostAction::Dispatch {
+                    account_msgs: vec![account::ExecuteMsg::ExecuteOnModule {
+                        module_id: IBC_CLIENT.to_owned(),
+                        exec_msg: to_json_binary(&msg)?,
+                        funds: std::mem::take(&mut funds),
+                    }],
+                },
+            };
+        }
+        Ok((msg, funds))
+    }
 }
 
 /// Copy of [polytone_note::msg::ExecuteMsg](https://docs.rs/polytone-note/1.0.0/polytone_note/msg/enum.ExecuteMsg.html)
@@ -215,6 +270,14 @@ pub enum IbcClientCallback {
     WhoAmI {},
 }
 
+/// Callback of a remote account that's forwarded to the chain the account originates from.
+/// This lets the origin follow accounts that are multiple hops away.
+#[cosmwasm_schema::cw_serde]
+pub enum ForwardedCallback {
+    /// Remote account got created on the last chain of the route
+    AccountCreated { account_address: String },
+}
+
 /// This is used for identifying calling modules
 /// For adapters, we don't need the account id because it's independent of an account
 /// For apps and standalone, the account id is used to identify the calling module
@@ -323,6 +386,16 @@ pub enum QueryMsg {
         account_id: AccountId,
     },
 
+    /// Get the address of a remote account that's controlled through the remote accounts on `route`.
+    /// The route holds the chains from the first hop up to the chain of the account.
+    /// Returns [`AccountResponse`]
+    #[returns(AccountResponse)]
+    #[cw_orch(fn_name("routed_remote_account"))]
+    RoutedAccount {
+        route: Vec<TruncatedChainId>,
+        account_id: AccountId,
+    },
+
     /// Get the hosts
     /// Returns [`ListRemoteHostsResponse`]
     #[returns(ListRemoteHostsResponse)]
@@ -424,4 +497,46 @@ mod tests {
             })
         )
     }
+
+    #[coverage_helper::test]
+    fn test_route_through_hops() {
+        use cosmwasm_std::coins;
+
+        use super::ExecuteMsg as IbcClientMsg;
+        use crate::{account, ibc_host::HostAction, objects::TruncatedChainId, IBC_CLIENT};
+
+        let register = IbcClientMsg::Register {
+            host_chain: TruncatedChainId::from_string("stargaze".to_owned()).unwrap(),
+            namespace: None,
+            install_modules: vec![],
+        };
+
+        // No hops keeps the message and funds local
+        let (msg, funds) = register
+            .clone()
+            .route_through(&[], coins(10, "ujuno"))
+            .unwrap();
+        assert_eq!(msg, register);
+        assert_eq!(funds, coins(10, "ujuno"));
+
+        let osmosis = TruncatedChainId::from_string("osmosis".to_owned()).unwrap();
+        let (msg, funds) = register
+            .clone()
+            .route_through(&[osmosis.clone()], coins(10, "uosmo"))
+            .unwrap();
+        assert!(funds.is_empty());
+        assert_eq!(
+            msg,
+            IbcClientMsg::RemoteAction {
+                host_chain: osmosis,
+                action: HostAction::Dispatch {
+                    account_msgs: vec![account::ExecuteMsg::ExecuteOnModule {
+                        module_id: IBC_CLIENT.to_owned(),
+                        exec_msg: to_json_binary(&register).unwrap(),
+                        funds: coins(10, "uosmo"),
+                    }],
+                },
+            }
+        );
+    }
 }
@@ -1,13 +1,14 @@
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp, Uint128};
 
 use crate::{
     account::{self, ModuleInstallConfig},
-    ibc_client::InstalledModuleIdentification,
-    objects::{account::AccountId, module::ModuleInfo, TruncatedChainId},
+    ibc_client::{ForwardedCallback, InstalledModuleIdentification},
+    objects::{account::AccountId, module::ModuleInfo, AssetEntry, TruncatedChainId},
 };
 
 pub mod state {
+    use cosmwasm_std::Empty;
     use cw_storage_plus::{Item, Map};
 
     use super::*;
@@ -19,6 +20,30 @@ pub mod state {
     pub const REVERSE_CHAIN_PROXIES: Map<&Addr, TruncatedChainId> =
         Map::new(storage_namespaces::ibc_host::REVERSE_CHAIN_PROXIES);
 
+    /// Rate limits on the packets of a client chain
+    pub const RATE_LIMITS: Map<&TruncatedChainId, RateLimit> =
+        Map::new(storage_namespaces::ibc_host::RATE_LIMITS);
+    /// Client chains whose packets are rejected
+    pub const PAUSED_CHAINS: Map<&TruncatedChainId, Empty> =
+        Map::new(storage_namespaces::ibc_host::PAUSED_CHAINS);
+    /// Packets received from a client chain in the current window
+    pub const PACKET_USAGE: Map<&TruncatedChainId, PacketUsage> =
+        Map::new(storage_namespaces::ibc_host::PACKET_USAGE);
+    /// Funds that left the remote accounts of a client chain in the current period, per denom
+    pub const OUTFLOW_USAGE: Map<(&TruncatedChainId, &str), OutflowUsage> =
+        Map::new(storage_namespaces::ibc_host::OUTFLOW_USAGE);
+
+    // Temporary structure to hold the account balances before an action is executed
+    pub const TEMP_OUTFLOW_CHECK: Item<OutflowCheckCache> =
+        Item::new(storage_namespaces::ibc_host::TEMP_OUTFLOW_CHECK);
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct OutflowCheckCache {
+        pub chain: TruncatedChainId,
+        pub account: Addr,
+        pub balances: Vec<Coin>,
+    }
+
     // Temporary structure to hold actions to be executed after account creation
     pub const TEMP_ACTION_AFTER_CREATION: Item<ActionAfterCreationCache> =
         Item::new(storage_namespaces::ibc_host::TEMP_ACTION_AFTER_CREATION);
@@ -51,12 +76,33 @@ pub enum InternalAction {
         namespace: Option<String>,
         install_modules: Vec<ModuleInstallConfig>,
     },
+    /// Forwards the callback of a remote account to the ibc client of the chain the account originates from
+    ForwardCallback {
+        /// Chains after the receiving chain up to the chain the action was executed on
+        route: Vec<TruncatedChainId>,
+        callback: ForwardedCallback,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
 #[non_exhaustive]
 pub enum HelperAction {
+    /// Send all the native coins and ANS-registered cw20 tokens of the remote account back to
+    /// the source chain. At most 50 registered cw20 tokens are checked, any of them with a balance
+    /// is sent through the ICS20-cw20 contract registered in ANS and fails the action without one.
+    /// ICS20 tokens that are native to the source chain but came in over another path are
+    /// unwound along their denom trace with packet forwarding.
     SendAllBack,
+    /// Send the selected assets of the remote account back to the source chain, unwinding
+    /// ICS20 tokens like [`HelperAction::SendAllBack`].
+    /// Cw20 tokens are sent through the ICS20-cw20 contract registered in ANS.
+    /// Assets without an ICS20 route are rejected, swap them before sending them back.
+    SendBack {
+        /// Assets to send back
+        assets: Vec<AssetEntry>,
+        /// Receiver on the source chain, defaults to the account.
+        destination: Option<String>,
+    },
 }
 
 /// Callable actions on a remote host
@@ -74,6 +120,44 @@ pub enum HostAction {
 }
 // ANCHOR_END: ibc-host-action
 
+/// Limits on the packets a client chain can send to the host
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct RateLimit {
+    /// Maximum number of packets per window of blocks
+    pub packets: Option<PacketLimit>,
+    /// Maximum amount of funds that can leave the remote accounts of the chain per period
+    pub outflow: Option<OutflowLimit>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PacketLimit {
+    pub max_packets: u32,
+    /// Length of the window in blocks
+    pub window_blocks: u64,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OutflowLimit {
+    /// Maximum net outflow for each limited denom, other denoms are not limited
+    pub max_amounts: Vec<Coin>,
+    /// Length of the period in seconds
+    pub period_seconds: u64,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PacketUsage {
+    /// Height of the block the window started at
+    pub window_start: u64,
+    pub packets: u32,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OutflowUsage {
+    pub period_start: Timestamp,
+    pub amount: Uint128,
+}
+
 /// Interface to the Host.
 #[cosmwasm_schema::cw_serde]
 #[derive(cw_orch::ExecuteFns)]
@@ -89,6 +173,17 @@ pub enum ExecuteMsg {
     RemoveChainProxy {
         chain: TruncatedChainId,
     },
+    /// Set the rate limit of a client chain, removes it when `None`.
+    SetRateLimit {
+        chain: TruncatedChainId,
+        rate_limit: Option<RateLimit>,
+    },
+    /// Pause or resume the packets of a client chain.
+    /// A paused chain can't act on its remote accounts.
+    SetChainPaused {
+        chain: TruncatedChainId,
+        paused: bool,
+    },
     // ANCHOR: ibc-host-execute
     /// Allows for remote execution from the Polytone implementation
     #[cw_orch(fn_name("ibc_execute"))]
@@ -135,6 +230,10 @@ pub enum QueryMsg {
     /// Returns [`ClientProxyResponse`].
     #[returns(ClientProxyResponse)]
     ClientProxy { chain: String },
+    /// Returns the rate limit of a client chain and its current usage.
+    /// Returns [`RateLimitResponse`].
+    #[returns(RateLimitResponse)]
+    RateLimit { chain: TruncatedChainId },
     /// Performs an query on a local module
     #[returns(Binary)]
     ModuleQuery {
@@ -159,3 +258,37 @@ pub struct ClientProxiesResponse {
 pub struct ClientProxyResponse {
     pub proxy: Addr,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct RateLimitResponse {
+    pub rate_limit: Option<RateLimit>,
+    pub paused: bool,
+    /// Packets received in the current window
+    pub packets: u32,
+    /// Net outflow of the limited denoms in the current period
+    pub outflows: Vec<Coin>,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+
+    use super::*;
+
+    #[coverage_helper::test]
+    fn send_all_back_keeps_unit_wire_format() {
+        let action: HostAction = from_json(r#"{"helpers":"send_all_back"}"#).unwrap();
+        assert_eq!(action, HostAction::Helpers(HelperAction::SendAllBack));
+
+        let action: HostAction =
+            from_json(r#"{"helpers":{"send_back":{"assets":["juno>juno"],"destination":null}}}"#)
+                .unwrap();
+        assert_eq!(
+            action,
+            HostAction::Helpers(HelperAction::SendBack {
+                assets: vec!["juno>juno".into()],
+                destination: None,
+            })
+        );
+    }
+}
@@ -6,13 +6,14 @@ use super::{AssetEntry, ChannelEntry, ContractEntry};
 use crate::{
     ans_host::{
         state::{
-            ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONTRACT_ADDRESSES, POOL_METADATA,
-            REGISTERED_DEXES, REV_ASSET_ADDRESSES,
+            ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONTRACT_ADDRESSES, ORACLE_SOURCES,
+            POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES, REV_CHANNELS,
+            REV_CONTRACT_ADDRESSES,
         },
         RegisteredDexesResponse,
     },
     native_addrs,
-    objects::{DexAssetPairing, PoolMetadata, PoolReference, UniquePoolId},
+    objects::{DexAssetPairing, OracleSource, PoolMetadata, PoolReference, UniquePoolId},
     AbstractResult,
 };
 
@@ -25,6 +26,10 @@ pub enum AnsHostError {
         ans_host: Addr,
     },
 
+    // contract address not found
+    #[error("Contract with address {address} not found in ans_host {ans_host}.")]
+    ContractAddressNotFound { address: Addr, ans_host: Addr },
+
     // asset not found
     #[error("Asset {asset} not found in ans_host {ans_host}.")]
     AssetNotFound { asset: AssetEntry, ans_host: Addr },
@@ -40,6 +45,10 @@ pub enum AnsHostError {
         ans_host: Addr,
     },
 
+    // channel id not found
+    #[error("Channel with id {channel_id} not found in ans_host {ans_host}.")]
+    ChannelIdNotFound { channel_id: String, ans_host: Addr },
+
     // dex asset Pairing not found
     #[error("Asset pairing {pairing} not found in ans_host {ans_host}.")]
     DexPairingNotFound {
@@ -51,6 +60,14 @@ pub enum AnsHostError {
     #[error("Pool metadata for pool {pool} not found in ans_host {ans_host}.")]
     PoolMetadataNotFound { pool: UniquePoolId, ans_host: Addr },
 
+    // oracle source not found
+    #[error("Oracle source for {base}/{quote} not found in ans_host {ans_host}.")]
+    OracleSourceNotFound {
+        base: AssetEntry,
+        quote: AssetEntry,
+        ans_host: Addr,
+    },
+
     #[error("Object {object} should be formatted {expected} but is {actual}")]
     FormattingError {
         object: String,
@@ -211,6 +228,46 @@ impl AnsHost {
         Ok(result)
     }
 
+    /// Raw query of the ContractEntry registered for an address
+    #[function_name::named]
+    pub fn query_contract_reverse(
+        &self,
+        querier: &QuerierWrapper,
+        address: &Addr,
+    ) -> AnsHostResult<ContractEntry> {
+        let result = REV_CONTRACT_ADDRESSES
+            .query(querier, self.address.clone(), address)
+            .map_err(|error| AnsHostError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?
+            .ok_or_else(|| AnsHostError::ContractAddressNotFound {
+                address: address.clone(),
+                ans_host: self.address.clone(),
+            })?;
+        Ok(result)
+    }
+
+    /// Raw query of the ChannelEntry registered for a channel id
+    #[function_name::named]
+    pub fn query_channel_reverse(
+        &self,
+        querier: &QuerierWrapper,
+        channel_id: &str,
+    ) -> AnsHostResult<ChannelEntry> {
+        let result = REV_CHANNELS
+            .query(querier, self.address.clone(), channel_id)
+            .map_err(|error| AnsHostError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?
+            .ok_or_else(|| AnsHostError::ChannelIdNotFound {
+                channel_id: channel_id.to_owned(),
+                ans_host: self.address.clone(),
+            })?;
+        Ok(result)
+    }
+
     /// Raw query of a single asset pairing
     #[function_name::named]
     pub fn query_asset_pairing(
@@ -250,6 +307,28 @@ impl AnsHost {
         Ok(result)
     }
 
+    /// Raw query of the oracle source of a (base, quote) asset pair
+    #[function_name::named]
+    pub fn query_oracle_source(
+        &self,
+        querier: &QuerierWrapper,
+        base: &AssetEntry,
+        quote: &AssetEntry,
+    ) -> AnsHostResult<OracleSource> {
+        let result: OracleSource = ORACLE_SOURCES
+            .query(querier, self.address.clone(), (base, quote))
+            .map_err(|error| AnsHostError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?
+            .ok_or_else(|| AnsHostError::OracleSourceNotFound {
+                base: base.clone(),
+                quote: quote.clone(),
+                ans_host: self.address.clone(),
+            })?;
+        Ok(result)
+    }
+
     #[function_name::named]
     pub fn query_registered_dexes(
         &self,
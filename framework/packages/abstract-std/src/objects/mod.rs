@@ -21,6 +21,7 @@ pub mod module;
 pub mod module_reference;
 pub mod module_version;
 pub mod namespace;
+pub mod oracle;
 pub mod ownership;
 pub mod time_weighted_average;
 pub(crate) mod truncated_chain_id;
@@ -37,6 +38,7 @@ pub use entry::{
     dex_asset_pairing::DexAssetPairing,
     lp_token::{DexName, LpToken},
 };
+pub use oracle::OracleSource;
 pub use truncated_chain_id::TruncatedChainId;
 
 pub mod chain_name {
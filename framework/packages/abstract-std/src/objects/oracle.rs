@@ -0,0 +1,36 @@
+//! # Oracle
+//! Price sources that can be registered on the ans-host for an asset pair.
+//!
+//! A price is always expressed as the amount of base-units of the quote asset that one base-unit of the base asset is worth.
+
+use cosmwasm_std::Decimal;
+
+use super::{ContractEntry, UniquePoolId};
+
+/// Source of a price for an (base, quote) asset pair.
+#[cosmwasm_schema::cw_serde]
+pub enum OracleSource {
+    /// Pyth price feed. The pyth contract is resolved through the ANS contract registry.
+    Pyth {
+        /// ANS entry of the pyth contract
+        contract: ContractEntry,
+        /// Hex-encoded pyth price feed identifier
+        price_feed_id: String,
+        /// Decimals of the base asset, used to convert the feed price to base-units
+        base_decimals: u8,
+        /// Decimals of the quote asset, used to convert the feed price to base-units
+        quote_decimals: u8,
+        /// Widest confidence interval accepted, as a ratio of the price (e.g. `0.01` for 1%)
+        max_confidence: Decimal,
+    },
+    /// Time weighted average price of a pool registered in the ANS pool registry.
+    /// Only available on Osmosis.
+    DexTwap {
+        /// Unique id of the pool
+        pool_id: UniquePoolId,
+        /// Length of the averaging window, ending at the current block.
+        window_seconds: u64,
+    },
+    /// Fixed price, never stale.
+    Fixed { price: Decimal },
+}
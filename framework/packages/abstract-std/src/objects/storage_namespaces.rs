@@ -34,6 +34,12 @@ pub mod ans_host {
     pub const REGISTERED_DEXES: &str = "be";
     pub const ASSET_PAIRINGS: &str = "bf";
     pub const POOL_METADATA: &str = "bg";
+    pub const ORACLE_SOURCES: &str = "bh";
+    pub const CHANGE_SET_SEQUENCE: &str = "bi";
+    pub const CHANGE_SETS: &str = "bj";
+    pub const ENTRY_CHANGE_SETS: &str = "bk";
+    pub const REV_CONTRACT_ADDRESSES: &str = "bl";
+    pub const REV_CHANNELS: &str = "bm";
 }
 
 pub mod registry {
@@ -60,12 +66,18 @@ pub mod ibc_client {
     pub const ACKS: &str = "ed";
     pub const ICS20_ACCOUNT_CALLBACKS: &str = "ee";
     pub const ICS20_ACCOUNT_CALLBACK_PAYLOAD: &str = "ef";
+    pub const ROUTED_ACCOUNTS: &str = "eg";
 }
 
 pub mod ibc_host {
     pub const CHAIN_PROXIES: &str = "fa";
     pub const REVERSE_CHAIN_PROXIES: &str = "fb";
     pub const TEMP_ACTION_AFTER_CREATION: &str = "fc";
+    pub const RATE_LIMITS: &str = "fd";
+    pub const PAUSED_CHAINS: &str = "fe";
+    pub const PACKET_USAGE: &str = "ff";
+    pub const OUTFLOW_USAGE: &str = "fg";
+    pub const TEMP_OUTFLOW_CHECK: &str = "fh";
 }
 
 pub mod ica_client {
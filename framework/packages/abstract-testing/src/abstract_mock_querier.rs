@@ -1,6 +1,8 @@
 use abstract_std::{
     account::state::{ACCOUNT_ID, CALLING_TO_AS_ADMIN},
-    ans_host::state::{ASSET_ADDRESSES, CHANNELS, CONTRACT_ADDRESSES},
+    ans_host::state::{
+        ASSET_ADDRESSES, CHANNELS, CONTRACT_ADDRESSES, REV_CHANNELS, REV_CONTRACT_ADDRESSES,
+    },
     objects::{
         gov_type::GovernanceDetails, ownership::Ownership,
         storage_namespaces::OWNERSHIP_STORAGE_KEY, AccountId, AssetEntry, ChannelEntry,
@@ -66,14 +68,24 @@ impl AbstractMockQuerier for MockQuerierBuilder {
 
     fn contracts(self, contracts: Vec<(&ContractEntry, Addr)>) -> Self {
         let abstract_addrs = self.addrs();
+        let reverse = contracts
+            .iter()
+            .map(|(entry, address)| (address, (*entry).clone()))
+            .collect();
 
-        self.with_contract_map_entries(&abstract_addrs.ans_host, CONTRACT_ADDRESSES, contracts)
+        self.with_contract_map_entries(&abstract_addrs.ans_host, REV_CONTRACT_ADDRESSES, reverse)
+            .with_contract_map_entries(&abstract_addrs.ans_host, CONTRACT_ADDRESSES, contracts)
     }
 
     fn channels(self, channels: Vec<(&ChannelEntry, String)>) -> Self {
         let abstract_addrs = self.addrs();
+        let reverse = channels
+            .iter()
+            .map(|(entry, channel_id)| (channel_id.as_str(), (*entry).clone()))
+            .collect();
 
-        self.with_contract_map_entries(&abstract_addrs.ans_host, CHANNELS, channels)
+        self.with_contract_map_entries(&abstract_addrs.ans_host, REV_CHANNELS, reverse)
+            .with_contract_map_entries(&abstract_addrs.ans_host, CHANNELS, channels)
     }
 
     fn addrs(&self) -> AbstractMockAddrs {
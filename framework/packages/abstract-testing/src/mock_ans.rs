@@ -2,7 +2,7 @@ use abstract_std::{
     ans_host::{
         state::{
             ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONTRACT_ADDRESSES, POOL_METADATA,
-            REGISTERED_DEXES,
+            REGISTERED_DEXES, REV_CHANNELS, REV_CONTRACT_ADDRESSES,
         },
         AssetPair,
     },
@@ -62,10 +62,23 @@ impl MockAnsHost {
                 CONTRACT_ADDRESSES,
                 self.contracts.iter().map(|(a, b)| (a, b.clone())).collect(),
             )
+            .with_contract_map_entries(
+                &abstract_addrs.ans_host,
+                REV_CONTRACT_ADDRESSES,
+                self.contracts.iter().map(|(a, b)| (b, a.clone())).collect(),
+            )
             .with_contract_map_entries(
                 &abstract_addrs.ans_host,
                 CHANNELS,
                 self.channels.iter().map(|(a, b)| (a, b.clone())).collect(),
+            )
+            .with_contract_map_entries(
+                &abstract_addrs.ans_host,
+                REV_CHANNELS,
+                self.channels
+                    .iter()
+                    .map(|(a, b)| (b.as_str(), a.clone()))
+                    .collect(),
             );
 
         let mut unique_id = UniquePoolId::new(0);
@@ -51,4 +51,29 @@ pub enum DexAction {
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
     },
+    /// Place a limit order on the dex order book
+    PlaceLimitOrder {
+        /// Pool to place the order on
+        pool: UncheckedPoolAddress,
+        /// The asset to offer
+        offer_asset: AssetBase<String>,
+        /// The asset to receive
+        ask_asset: AssetInfoBase<String>,
+        /// Amount of ask asset received per unit of offer asset when the order is filled
+        price: Decimal,
+    },
+    /// Cancel an open limit order, the unfilled offer asset is returned
+    CancelLimitOrder {
+        /// Pool the order was placed on
+        pool: UncheckedPoolAddress,
+        /// Identifier of the order on the dex
+        order_id: String,
+    },
+    /// Withdraw the filled part of limit orders
+    WithdrawFilledOrders {
+        /// Pool the orders were placed on
+        pool: UncheckedPoolAddress,
+        /// Identifiers of the orders on the dex
+        order_ids: Vec<String>,
+    },
 }
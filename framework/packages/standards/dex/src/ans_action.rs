@@ -41,6 +41,29 @@ pub enum DexAnsAction {
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
     },
+    /// Place a limit order on the dex order book
+    PlaceLimitOrder {
+        /// The asset to offer
+        offer_asset: AnsAsset,
+        /// The asset to receive
+        ask_asset: AssetEntry,
+        /// Amount of ask asset received per unit of offer asset when the order is filled
+        price: Decimal,
+    },
+    /// Cancel an open limit order, the unfilled offer asset is returned
+    CancelLimitOrder {
+        /// Assets of the pool the order was placed on
+        pair: (AssetEntry, AssetEntry),
+        /// Identifier of the order on the dex
+        order_id: String,
+    },
+    /// Withdraw the filled part of limit orders
+    WithdrawFilledOrders {
+        /// Assets of the pool the orders were placed on
+        pair: (AssetEntry, AssetEntry),
+        /// Identifiers of the orders on the dex
+        order_ids: Vec<String>,
+    },
 }
 /// Structure created to be able to resolve an action using ANS
 pub struct WholeDexAction(pub DexName, pub DexAnsAction);
@@ -141,6 +164,44 @@ impl Resolve for WholeDexAction {
                     },
                 })
             }
+            DexAnsAction::PlaceLimitOrder {
+                offer_asset,
+                ask_asset,
+                price,
+            } => {
+                let ask_asset_info = ask_asset.resolve(querier, ans_host)?;
+                let pool_address = pool_address(
+                    &self.0,
+                    (offer_asset.name.clone(), ask_asset),
+                    querier,
+                    ans_host,
+                )?;
+                let offer_asset = offer_asset.resolve(querier, ans_host)?;
+
+                Ok(DexExecuteMsg::Action {
+                    dex: self.0.clone(),
+                    action: DexAction::PlaceLimitOrder {
+                        pool: pool_address.into(),
+                        offer_asset: offer_asset.into(),
+                        ask_asset: ask_asset_info.into(),
+                        price,
+                    },
+                })
+            }
+            DexAnsAction::CancelLimitOrder { pair, order_id } => Ok(DexExecuteMsg::Action {
+                dex: self.0.clone(),
+                action: DexAction::CancelLimitOrder {
+                    pool: pool_address(&self.0, pair, querier, ans_host)?.into(),
+                    order_id,
+                },
+            }),
+            DexAnsAction::WithdrawFilledOrders { pair, order_ids } => Ok(DexExecuteMsg::Action {
+                dex: self.0.clone(),
+                action: DexAction::WithdrawFilledOrders {
+                    pool: pool_address(&self.0, pair, querier, ans_host)?.into(),
+                    order_ids,
+                },
+            }),
         }
     }
 }
@@ -264,6 +325,42 @@ mod ans_resolve_interface {
                         },
                     })
                 }
+                DexAnsAction::PlaceLimitOrder {
+                    offer_asset,
+                    ask_asset,
+                    price,
+                } => {
+                    let ask_asset_info = ask_asset.resolve(ans_host)?;
+                    let pool_address =
+                        pool_address(&self.0, (offer_asset.name.clone(), ask_asset), ans_host)?;
+                    let offer_asset = offer_asset.resolve(ans_host)?;
+
+                    Ok(DexExecuteMsg::Action {
+                        dex: self.0.clone(),
+                        action: DexAction::PlaceLimitOrder {
+                            pool: pool_address.into(),
+                            offer_asset: offer_asset.into(),
+                            ask_asset: ask_asset_info.into(),
+                            price,
+                        },
+                    })
+                }
+                DexAnsAction::CancelLimitOrder { pair, order_id } => Ok(DexExecuteMsg::Action {
+                    dex: self.0.clone(),
+                    action: DexAction::CancelLimitOrder {
+                        pool: pool_address(&self.0, pair, ans_host)?.into(),
+                        order_id,
+                    },
+                }),
+                DexAnsAction::WithdrawFilledOrders { pair, order_ids } => {
+                    Ok(DexExecuteMsg::Action {
+                        dex: self.0.clone(),
+                        action: DexAction::WithdrawFilledOrders {
+                            pool: pool_address(&self.0, pair, ans_host)?.into(),
+                            order_ids,
+                        },
+                    })
+                }
             }
         }
     }
@@ -4,7 +4,10 @@ use abstract_std::objects::{AssetEntry, DexAssetPairing, PoolAddress, PoolRefere
 use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, Uint128};
 use cw_asset::{Asset, AssetInfo};
 
-use crate::{error::DexError, msg::SwapNode};
+use crate::{
+    error::DexError,
+    msg::{LimitOrder, SwapNode},
+};
 
 pub type Return = Uint128;
 pub type Spread = Uint128;
@@ -94,6 +97,48 @@ pub trait DexCommand: Identify {
         ask_asset: AssetInfo,
     ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError>;
 
+    /// Place a limit order, filled at `price` ask asset per offer asset
+    fn place_limit_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _offer_asset: Asset,
+        _ask_asset: AssetInfo,
+        _price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Cancel an open limit order
+    fn cancel_limit_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Withdraw the filled part of limit orders
+    fn withdraw_filled_orders(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _order_ids: Vec<String>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Open limit orders of the owner
+    fn open_orders(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _owner: Addr,
+    ) -> Result<Vec<LimitOrder>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
     /// Fetch data for execute methods
     fn fetch_data(
         &mut self,
@@ -73,6 +73,9 @@ pub enum DexError {
     #[error("Pool address not specified. You need to specify it when using raw asset addresses or denom")]
     PoolAddressEmpty,
 
+    #[error("Limit order price can't be zero")]
+    ZeroLimitOrderPrice,
+
     #[error("Only account of abstract namespace can update configuration")]
     Unauthorized {},
 }
@@ -11,6 +11,6 @@ pub mod tests;
 pub use abstract_adapter_utils::{coins_in_assets, cw_approve_msgs, Identify};
 pub use command::{DexCommand, Fee, FeeOnInput, Return, Spread};
 pub use error::DexError;
-pub use msg::SwapNode;
+pub use msg::{LimitOrder, SwapNode};
 
 pub const DEX_ADAPTER_ID: &str = "abstract:dex";
@@ -5,8 +5,9 @@ use abstract_std::{
     adapter,
     objects::{
         fee::{Fee, UsageFee},
+        namespace::Namespace,
         pool_id::{PoolAddressBase, UncheckedPoolAddress},
-        AnsAsset, AssetEntry, DexAssetPairing,
+        AccountId, AnsAsset, AssetEntry, DexAssetPairing,
     },
     AbstractError, AbstractResult,
 };
@@ -32,7 +33,44 @@ pub type InstantiateMsg = adapter::InstantiateMsg<DexInstantiateMsg>;
 /// Top-level Abstract Adapter query message. This is the message that is passed to the `query` entrypoint of the smart-contract.
 pub type QueryMsg = adapter::QueryMsg<DexQueryMsg>;
 
-impl adapter::AdapterExecuteMsg for DexExecuteMsg {}
+impl adapter::AdapterExecuteMsg for DexExecuteMsg {
+    fn request_scope(&self) -> adapter::RequestScope {
+        let (dex, action) = match self {
+            DexExecuteMsg::UpdateFee { .. } => return adapter::RequestScope::new("update_fee"),
+            DexExecuteMsg::Action { dex, action } => (dex, action),
+        };
+        let scope = match action {
+            DexAction::ProvideLiquidity { assets, .. } => {
+                adapter::RequestScope::new("provide_liquidity")
+                    .with_raw_assets(assets.iter().map(|a| &a.info))
+            }
+            DexAction::WithdrawLiquidity { lp_token, .. } => {
+                adapter::RequestScope::new("withdraw_liquidity").with_raw_assets([&lp_token.info])
+            }
+            DexAction::Swap {
+                offer_asset,
+                ask_asset,
+                ..
+            } => adapter::RequestScope::new("swap").with_raw_assets([&offer_asset.info, ask_asset]),
+            DexAction::RouteSwap {
+                route, offer_asset, ..
+            } => adapter::RequestScope::new("route_swap")
+                .with_raw_assets([&offer_asset.info])
+                .with_raw_assets(route.iter().map(|node| &node.ask_asset)),
+            DexAction::PlaceLimitOrder {
+                offer_asset,
+                ask_asset,
+                ..
+            } => adapter::RequestScope::new("place_limit_order")
+                .with_raw_assets([&offer_asset.info, ask_asset]),
+            DexAction::CancelLimitOrder { .. } => adapter::RequestScope::new("cancel_limit_order"),
+            DexAction::WithdrawFilledOrders { .. } => {
+                adapter::RequestScope::new("withdraw_filled_orders")
+            }
+        };
+        scope.with_provider(dex)
+    }
+}
 impl adapter::AdapterQueryMsg for DexQueryMsg {}
 
 /// Response for simulating a swap.
@@ -47,8 +85,10 @@ pub struct SimulateSwapResponse<A = AssetEntry> {
     // LP/protocol fees could be withheld from either input or output so commission asset must be included.
     /// Commission charged for the swap
     pub commission: (A, Uint128),
-    /// Adapter fee charged for the swap (paid in offer asset)
+    /// Adapter fee charged for the swap (paid in offer asset, or in ask asset if `usage_fee_on_output`)
     pub usage_fee: Uint128,
+    /// Whether the adapter fee is taken from the swap output, `return_amount` is then net of it
+    pub usage_fee_on_output: bool,
 }
 
 /// Response from GenerateMsgs
@@ -58,6 +98,26 @@ pub struct GenerateMessagesResponse {
     pub messages: Vec<CosmosMsg>,
 }
 
+/// Limit order placed on a dex
+#[cosmwasm_schema::cw_serde]
+pub struct LimitOrder {
+    /// Identifier of the order on the dex
+    pub order_id: String,
+    /// Offered asset, with the amount that is not filled yet
+    pub offer_asset: AssetBase<String>,
+    /// Asset received when the order is filled
+    pub ask_asset: AssetInfoBase<String>,
+    /// Amount of ask asset that is filled and can be withdrawn, if reported by the dex
+    pub filled_amount: Option<Uint128>,
+}
+
+/// Response for the open orders of an owner
+#[cosmwasm_schema::cw_serde]
+pub struct OpenOrdersResponse {
+    /// Open limit orders
+    pub orders: Vec<LimitOrder>,
+}
+
 /// Response for Dex Fees
 #[cosmwasm_schema::cw_serde]
 pub struct DexFeesResponse {
@@ -65,6 +125,19 @@ pub struct DexFeesResponse {
     pub swap_fee: Fee,
     /// Address where all fees will go
     pub recipient: Addr,
+    /// Whether swap fees are taken from the ask asset
+    pub fee_on_output: bool,
+    /// Swap fees for specific namespaces or accounts
+    pub fee_tiers: Vec<(FeeTierKey, Fee)>,
+}
+
+/// Namespace or account a fee tier applies to
+#[cosmwasm_schema::cw_serde]
+pub enum FeeTierKey {
+    /// All accounts that own the namespace
+    Namespace(Namespace),
+    /// A single account, takes precedence over its namespace tier
+    Account(AccountId),
 }
 
 /// Instantiation message for dex adapter
@@ -85,6 +158,10 @@ pub enum DexExecuteMsg {
         swap_fee: Option<Decimal>,
         /// New recipient account for fees
         recipient_account: Option<u32>,
+        /// Fee tiers to set, a `None` fee removes the tier
+        fee_tiers: Option<Vec<(FeeTierKey, Option<Decimal>)>>,
+        /// Take swap fees from the ask asset instead of the offer asset
+        fee_on_output: Option<bool>,
     },
     /// Action to perform on the DEX with raw asset denominations
     Action {
@@ -144,6 +221,9 @@ pub enum DexQueryMsg {
         ask_asset: AssetEntry,
         /// Name of the dex to simulate the swap on
         dex: DexName,
+        /// Account the swap is simulated for, its fee tier applies.
+        /// The default swap fee is used if not set.
+        account: Option<String>,
     },
     /// Simulate a swap between two assets
     /// Returns [`SimulateSwapResponse`]
@@ -157,6 +237,9 @@ pub enum DexQueryMsg {
         pool: UncheckedPoolAddress,
         /// Name of the dex to simulate the swap on
         dex: DexName,
+        /// Account the swap is simulated for, its fee tier applies.
+        /// The default swap fee is used if not set.
+        account: Option<String>,
     },
     /// Endpoint can be used by front-end to easily interact with contracts.
     /// Returns [`GenerateMessagesResponse`]
@@ -170,6 +253,17 @@ pub enum DexQueryMsg {
     /// Fee info for using the different dex actions
     #[returns(DexFeesResponse)]
     Fees {},
+    /// Open limit orders of an owner on a pool
+    /// Returns [`OpenOrdersResponse`]
+    #[returns(OpenOrdersResponse)]
+    OpenOrders {
+        /// Name of the dex
+        dex: DexName,
+        /// Pool the orders were placed on
+        pool: UncheckedPoolAddress,
+        /// Owner of the orders
+        owner: String,
+    },
 }
 
 /// Fees for using the dex adapter
@@ -179,6 +273,14 @@ pub struct DexFees {
     swap_fee: Fee,
     /// Address where all fees will go
     pub recipient: Addr,
+    /// Whether swap fees are taken from the ask asset, charged on the amount received once the swap is executed.
+    /// Route swaps and limit orders are always charged on the offer asset,
+    /// messages from `GenerateMessages` can't act on the swap output and charge the fee on the offer asset.
+    #[serde(default)]
+    pub fee_on_output: bool,
+    /// Swap fees for specific namespaces or accounts
+    #[serde(default)]
+    fee_tiers: Vec<(FeeTierKey, Fee)>,
 }
 
 impl DexFees {
@@ -188,9 +290,58 @@ impl DexFees {
         Ok(Self {
             swap_fee: Fee::new(swap_fee_share)?,
             recipient,
+            fee_on_output: false,
+            fee_tiers: vec![],
         })
     }
 
+    /// Set the swap fee share of a tier, or remove the tier if `None`
+    pub fn set_fee_tier(&mut self, key: FeeTierKey, share: Option<Decimal>) -> AbstractResult<()> {
+        if let FeeTierKey::Namespace(namespace) = &key {
+            namespace.validate()?;
+        }
+        self.fee_tiers.retain(|(k, _)| k != &key);
+        if let Some(share) = share {
+            Self::check_fee_share(share)?;
+            self.fee_tiers.push((key, Fee::new(share)?));
+        }
+        Ok(())
+    }
+
+    /// Get fee tiers
+    pub fn fee_tiers(&self) -> &[(FeeTierKey, Fee)] {
+        &self.fee_tiers
+    }
+
+    /// Whether one of the tiers is keyed by namespace
+    pub fn has_namespace_tiers(&self) -> bool {
+        self.fee_tiers
+            .iter()
+            .any(|(k, _)| matches!(k, FeeTierKey::Namespace(_)))
+    }
+
+    /// Usage fee for swap of an account, falls back to the default swap fee if no tier applies
+    pub fn tiered_swap_usage_fee(
+        &self,
+        account_id: &AccountId,
+        namespaces: &[Namespace],
+    ) -> AbstractResult<UsageFee> {
+        let tier_fee = |key: &FeeTierKey| {
+            self.fee_tiers
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, fee)| *fee)
+        };
+        let fee = tier_fee(&FeeTierKey::Account(account_id.clone()))
+            .or_else(|| {
+                namespaces
+                    .iter()
+                    .find_map(|ns| tier_fee(&FeeTierKey::Namespace(ns.clone())))
+            })
+            .unwrap_or(self.swap_fee);
+        UsageFee::new(fee.share(), self.recipient.clone())
+    }
+
     /// Update swap share
     pub fn set_swap_fee_share(&mut self, new_swap_fee_share: Decimal) -> AbstractResult<()> {
         Self::check_fee_share(new_swap_fee_share)?;
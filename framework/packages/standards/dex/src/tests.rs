@@ -1,12 +1,12 @@
 use std::fmt::Debug;
 
 use abstract_std::objects::PoolAddress;
-use cosmwasm_std::{CosmosMsg, Decimal, StdError};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, StdError};
 use cw_asset::{Asset, AssetInfo};
 use cw_orch::daemon::live_mock::mock_dependencies;
 use cw_orch::prelude::*;
 
-use crate::{DexCommand, DexError, Fee, FeeOnInput, Return, Spread};
+use crate::{msg::LimitOrder, DexCommand, DexError, Fee, FeeOnInput, Return, Spread};
 
 pub struct DexCommandTester {
     chain: ChainInfoOwned,
@@ -76,6 +76,58 @@ impl DexCommandTester {
         Ok(msgs)
     }
 
+    pub fn test_place_limit_order(
+        &self,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self.adapter.place_limit_order(
+            deps.as_ref(),
+            pool_id,
+            offer_asset,
+            ask_asset,
+            price,
+        )?;
+        Ok(msgs)
+    }
+
+    pub fn test_cancel_limit_order(
+        &self,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self
+            .adapter
+            .cancel_limit_order(deps.as_ref(), pool_id, order_id)?;
+        Ok(msgs)
+    }
+
+    pub fn test_withdraw_filled_orders(
+        &self,
+        pool_id: PoolAddress,
+        order_ids: Vec<String>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self
+            .adapter
+            .withdraw_filled_orders(deps.as_ref(), pool_id, order_ids)?;
+        Ok(msgs)
+    }
+
+    pub fn test_open_orders(
+        &self,
+        pool_id: PoolAddress,
+        owner: Addr,
+    ) -> Result<Vec<LimitOrder>, DexError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let orders = self.adapter.open_orders(deps.as_ref(), pool_id, owner)?;
+        Ok(orders)
+    }
+
     pub fn test_simulate_swap(
         &self,
         pool_id: PoolAddress,
@@ -0,0 +1,68 @@
+use abstract_adapter_utils::identity::Identify;
+use abstract_sdk::feature_objects::AnsHost;
+use abstract_std::objects::{ans_host::AnsHostError, AssetEntry, ContractEntry};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, QuerierWrapper};
+use cw_asset::{Asset, AssetInfo};
+
+use crate::error::LiquidStakingError;
+
+/// Prefix of the ANS contract entries of the liquid staking contracts, see [`LiquidStakingCommand::hub_address`].
+pub const LIQUID_STAKING_CONTRACT_PREFIX: &str = "liquid_staking/";
+
+/// # LiquidStakingCommand
+/// ensures liquid staking adapters support the expected functionality.
+///
+/// Implements the usual liquid staking operations.
+pub trait LiquidStakingCommand: Identify {
+    /// Fetch data for execute methods
+    fn fetch_data(
+        &mut self,
+        _deps: Deps,
+        _addr_as_sender: Addr,
+        _ans_host: &AnsHost,
+    ) -> Result<(), LiquidStakingError> {
+        // Dummy implementation, since most of providers does not require this method
+        Ok(())
+    }
+
+    /// Address of the contract that liquid stakes the given asset.
+    /// Registered in the ANS as `liquid_staking/<asset>` under the provider name by default.
+    fn hub_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        let hub = ContractEntry {
+            protocol: self.name().to_string(),
+            contract: format!("{LIQUID_STAKING_CONTRACT_PREFIX}{asset}"),
+        };
+        ans_host.query_contract(querier, &hub)
+    }
+
+    /// Liquid staking token minted by the hub
+    fn liquid_token(&self, deps: Deps, hub: Addr) -> Result<AssetInfo, LiquidStakingError>;
+
+    /// Liquid stake the asset, the liquid staking tokens are sent back to the sender
+    fn liquid_stake(
+        &self,
+        deps: Deps,
+        hub: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError>;
+
+    /// Request the redemption of liquid staking tokens.
+    /// The redeemed tokens can be claimed once the unbonding period of the protocol has passed.
+    fn request_redeem(
+        &self,
+        deps: Deps,
+        hub: Addr,
+        liquid_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError>;
+
+    /// Claim the tokens of matured redemption requests
+    fn claim_redeemed(&self, deps: Deps, hub: Addr) -> Result<Vec<CosmosMsg>, LiquidStakingError>;
+
+    /// Amount of the staked asset that one liquid staking token can be redeemed for
+    fn exchange_rate(&self, deps: Deps, hub: Addr) -> Result<Decimal, LiquidStakingError>;
+}
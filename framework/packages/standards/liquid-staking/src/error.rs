@@ -0,0 +1,63 @@
+use abstract_adapter::AdapterError;
+use abstract_sdk::AbstractSdkError;
+use abstract_std::{objects::ans_host::AnsHostError, AbstractError};
+use cosmwasm_std::{CheckedFromRatioError, OverflowError, StdError};
+use cw_asset::AssetError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LiquidStakingError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    FromRatio(#[from] CheckedFromRatioError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    AbstractOs(#[from] AbstractError),
+
+    #[error(transparent)]
+    AbstractSdk(#[from] AbstractSdkError),
+
+    #[error(transparent)]
+    Asset(#[from] AssetError),
+
+    #[error(transparent)]
+    AdapterError(#[from] AdapterError),
+
+    #[error(transparent)]
+    AnsHostError(#[from] AnsHostError),
+
+    #[error(
+        "Liquid staking provider {provider} is not a known provider on this network ({:?}).",
+        chain
+    )]
+    UnknownProviderOnThisPlatform {
+        provider: String,
+        chain: Option<String>,
+    },
+
+    #[error("Liquid staking provider {0} is not a known provider by Abstract")]
+    UnknownProvider(String),
+
+    #[error("Liquid staking provider {0} is not local to this network.")]
+    ForeignProvider(String),
+
+    #[error("Asset type: {0} is unsupported.")]
+    UnsupportedAssetType(String),
+
+    #[error("No matured redemption to claim on liquid staking provider {0}")]
+    NothingToClaim(String),
+
+    #[error("Not implemented for liquid staking provider {0}")]
+    NotImplemented(String),
+
+    #[error("Liquid staking actions over IBC are not supported.")]
+    IbcNotSupported,
+
+    #[error("Message generation for IBC queries not supported.")]
+    IbcMsgQuery,
+}
@@ -0,0 +1,11 @@
+mod command;
+mod error;
+
+pub mod msg;
+
+// Export interface for use in SDK modules
+pub use abstract_adapter_utils::Identify;
+pub use command::{LiquidStakingCommand, LIQUID_STAKING_CONTRACT_PREFIX};
+pub use error::LiquidStakingError;
+
+pub const LIQUID_STAKING_ADAPTER_ID: &str = "abstract:liquid-staking";
@@ -0,0 +1,111 @@
+#![warn(missing_docs)]
+//! # Liquid Staking Adapter
+//!
+//! `abstract::liquid-staking`
+use abstract_std::{
+    adapter,
+    objects::{AnsAsset, AssetEntry},
+};
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{CosmosMsg, Decimal, Empty, Uint128};
+use cw_asset::AssetInfoBase;
+
+/// Name of the liquid staking provider, used by the ANS.
+pub type ProviderName = String;
+
+/// Top-level Abstract Adapter execute message. This is the message that is passed to the `execute` entrypoint of the smart-contract.
+pub type ExecuteMsg = adapter::ExecuteMsg<LiquidStakingExecuteMsg>;
+/// Top-level Abstract Adapter instantiate message. This is the message that is passed to the `instantiate` entrypoint of the smart-contract.
+pub type InstantiateMsg = adapter::InstantiateMsg<Empty>;
+/// Top-level Abstract Adapter query message. This is the message that is passed to the `query` entrypoint of the smart-contract.
+pub type QueryMsg = adapter::QueryMsg<LiquidStakingQueryMsg>;
+
+impl adapter::AdapterExecuteMsg for LiquidStakingExecuteMsg {
+    fn request_scope(&self) -> adapter::RequestScope {
+        let scope = match &self.action {
+            LiquidStakingAction::LiquidStake { asset } => {
+                adapter::RequestScope::new("liquid_stake").with_assets([&asset.name])
+            }
+            LiquidStakingAction::RequestRedeem { asset, .. } => {
+                adapter::RequestScope::new("request_redeem").with_assets([asset])
+            }
+            LiquidStakingAction::ClaimRedeemed { asset } => {
+                adapter::RequestScope::new("claim_redeemed").with_assets([asset])
+            }
+        };
+        scope.with_provider(&self.provider)
+    }
+}
+impl adapter::AdapterQueryMsg for LiquidStakingQueryMsg {}
+
+/// A request message that's sent to this liquid staking adapter
+#[cosmwasm_schema::cw_serde]
+pub struct LiquidStakingExecuteMsg {
+    /// The name of the liquid staking provider
+    pub provider: ProviderName,
+    /// the action to execute, see [LiquidStakingAction]
+    pub action: LiquidStakingAction,
+}
+
+/// Possible actions to perform on the liquid staking protocol.
+/// The protocol contract is resolved in the ANS from the staked asset.
+#[cosmwasm_schema::cw_serde]
+pub enum LiquidStakingAction {
+    /// Liquid stake the asset, receiving liquid staking tokens in return
+    LiquidStake {
+        /// The ANS-resolvable asset to liquid stake.
+        asset: AnsAsset,
+    },
+    /// Request the redemption of liquid staking tokens
+    RequestRedeem {
+        /// The staked asset the liquid staking tokens represent.
+        asset: AssetEntry,
+        /// Amount of liquid staking tokens to redeem.
+        amount: Uint128,
+    },
+    /// Claim the tokens of matured redemption requests
+    ClaimRedeemed {
+        /// The staked asset to claim.
+        asset: AssetEntry,
+    },
+}
+
+/// Query messages for the liquid staking adapter
+#[cosmwasm_schema::cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum LiquidStakingQueryMsg {
+    /// Exchange rate between the liquid staking token and the staked asset
+    /// Returns [`ExchangeRateResponse`]
+    #[returns(ExchangeRateResponse)]
+    ExchangeRate {
+        /// Name of the liquid staking provider
+        provider: ProviderName,
+        /// The staked asset
+        asset: AssetEntry,
+    },
+    /// Endpoint can be used by front-end to easily interact with contracts.
+    /// Returns [`GenerateMessagesResponse`]
+    #[returns(GenerateMessagesResponse)]
+    GenerateMessages {
+        /// Execute message to generate messages for
+        message: LiquidStakingExecuteMsg,
+        /// Sender Addr generate messages for
+        addr_as_sender: String,
+    },
+}
+
+/// Response for the exchange rate query
+#[cosmwasm_schema::cw_serde]
+pub struct ExchangeRateResponse {
+    /// Liquid staking token of the staked asset
+    pub liquid_token: AssetInfoBase<String>,
+    /// Amount of the staked asset one liquid staking token can be redeemed for
+    pub exchange_rate: Decimal,
+}
+
+/// Response from GenerateMessages
+#[cosmwasm_schema::cw_serde]
+pub struct GenerateMessagesResponse {
+    /// Messages generated for the liquid staking action
+    pub messages: Vec<CosmosMsg>,
+}
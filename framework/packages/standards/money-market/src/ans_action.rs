@@ -3,6 +3,7 @@
 //!
 use abstract_sdk::Resolve;
 use abstract_std::objects::{AnsAsset, AssetEntry};
+use cosmwasm_std::CosmosMsg;
 
 use crate::{
     raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
@@ -51,6 +52,24 @@ pub enum MoneyMarketAnsAction {
         /// Asset that identifies the market you want to borrow from
         collateral_asset: AssetEntry,
     },
+    /// Liquidate the position of a user
+    Liquidate {
+        /// User whose position is liquidated
+        user: String,
+        /// Debt of the user to repay
+        debt_asset: AnsAsset,
+        /// Collateral asset to seize in return
+        seize_asset: AssetEntry,
+    },
+    /// Borrow funds, execute the actions on the account and repay the funds in the same transaction
+    FlashLoan {
+        /// Asset to borrow
+        asset: AnsAsset,
+        /// Asset that identifies the market you want to borrow from
+        collateral_asset: AssetEntry,
+        /// Messages executed by the account between the borrow and the repayment
+        actions: Vec<CosmosMsg>,
+    },
 }
 
 /// Structure created to be able to resolve an action using ANS
@@ -173,6 +192,50 @@ impl Resolve for MoneyMarketActionResolveWrapper {
                     contract_addr: contract_addr.to_string(),
                 }
             }
+            MoneyMarketAnsAction::Liquidate {
+                user,
+                debt_asset,
+                seize_asset,
+            } => {
+                let contract_addr = self.0.borrow_address(
+                    querier,
+                    ans_host,
+                    debt_asset.name.clone(),
+                    seize_asset.clone(),
+                )?;
+                let debt_asset = debt_asset.resolve(querier, ans_host)?;
+                let seize_asset = seize_asset.resolve(querier, ans_host)?;
+                MoneyMarketRawAction {
+                    request: MoneyMarketRawRequest::Liquidate {
+                        user,
+                        debt_asset: debt_asset.into(),
+                        seize_asset: seize_asset.into(),
+                    },
+                    contract_addr: contract_addr.to_string(),
+                }
+            }
+            MoneyMarketAnsAction::FlashLoan {
+                asset,
+                collateral_asset,
+                actions,
+            } => {
+                let contract_addr = self.0.borrow_address(
+                    querier,
+                    ans_host,
+                    asset.name.clone(),
+                    collateral_asset.clone(),
+                )?;
+                let asset = asset.resolve(querier, ans_host)?;
+                let collateral_asset = collateral_asset.resolve(querier, ans_host)?;
+                MoneyMarketRawAction {
+                    request: MoneyMarketRawRequest::FlashLoan {
+                        asset: asset.into(),
+                        collateral_asset: collateral_asset.into(),
+                        actions,
+                    },
+                    contract_addr: contract_addr.to_string(),
+                }
+            }
         };
 
         Ok(raw_action)
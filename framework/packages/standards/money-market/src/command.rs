@@ -1,6 +1,9 @@
 use abstract_adapter_utils::identity::Identify;
 
-use crate::error::MoneyMarketError;
+use crate::{
+    error::MoneyMarketError,
+    msg::{PositionKind, UserHealthResponse},
+};
 use abstract_sdk::feature_objects::AnsHost;
 use abstract_std::objects::{ans_host::AnsHostError, AssetEntry};
 use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, QuerierWrapper, Uint128};
@@ -11,6 +14,38 @@ pub type Spread = Uint128;
 pub type Fee = Uint128;
 pub type FeeOnInput = bool;
 
+/// Change to a borrow position, used to simulate its LTV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionChange {
+    ProvideCollateral(Uint128),
+    WithdrawCollateral(Uint128),
+    Borrow(Uint128),
+    Repay(Uint128),
+}
+
+impl PositionChange {
+    /// Collateral and borrowed amounts of the position after the change
+    fn apply(
+        &self,
+        collateral: Uint128,
+        borrow: Uint128,
+    ) -> Result<(Uint128, Uint128), MoneyMarketError> {
+        Ok(match *self {
+            PositionChange::ProvideCollateral(amount) => (collateral.checked_add(amount)?, borrow),
+            PositionChange::WithdrawCollateral(amount) => (collateral.checked_sub(amount)?, borrow),
+            PositionChange::Borrow(amount) => (collateral, borrow.checked_add(amount)?),
+            PositionChange::Repay(amount) => (collateral, borrow.saturating_sub(amount)),
+        })
+    }
+}
+
+/// Position of a user on a money market, see [`MoneyMarketCommand::user_positions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserPosition {
+    pub kind: PositionKind,
+    pub asset: Asset,
+}
+
 /// # MoneyMarketCommand
 /// ensures Money Market adapters support the expected functionality.
 ///
@@ -96,6 +131,47 @@ pub trait MoneyMarketCommand: Identify {
         asset: Asset,
     ) -> Result<Vec<CosmosMsg>, MoneyMarketError>;
 
+    /// Liquidate the position of `user` by repaying (part of) its debt.
+    /// The `seize_asset` collateral is received in return.
+    fn liquidate(
+        &self,
+        _deps: Deps,
+        _contract_addr: Addr,
+        _user: Addr,
+        _debt_asset: Asset,
+        _seize_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        Err(MoneyMarketError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Borrow `asset`, execute the `actions` on the account and repay `asset` in the same transaction.
+    /// Emulated with a borrow and a repay by default, which requires the account to have enough collateral.
+    /// The adapter checks that the lender got `asset` and the [`MoneyMarketCommand::flash_loan_fee`] back
+    /// and that the debt of the account didn't increase once the messages are executed.
+    fn flash_loan(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+        actions: Vec<CosmosMsg>,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let mut msgs = self.borrow(deps, contract_addr.clone(), asset.clone())?;
+        msgs.extend(actions);
+        msgs.extend(self.repay(deps, contract_addr, asset)?);
+        Ok(msgs)
+    }
+
+    /// Fee the lender charges on top of the borrowed `asset` for a flash loan.
+    /// The emulated flash loan doesn't charge any fee.
+    fn flash_loan_fee(
+        &self,
+        _deps: Deps,
+        _contract_addr: Addr,
+        _asset: &Asset,
+    ) -> Result<Uint128, MoneyMarketError> {
+        Ok(Uint128::zero())
+    }
+
     //*****************   Queries   ****************/
     // This represents how much 1 unit of the base is worth in terms of the quote
     fn price(
@@ -105,6 +181,17 @@ pub trait MoneyMarketCommand: Identify {
         quote: AssetInfo,
     ) -> Result<Decimal, MoneyMarketError>;
 
+    /// All deposits, collateral and borrows of the user on the money market.
+    /// Called after [`MoneyMarketCommand::fetch_data`].
+    fn user_positions(
+        &self,
+        _deps: Deps,
+        _ans_host: &AnsHost,
+        _user: Addr,
+    ) -> Result<Vec<UserPosition>, MoneyMarketError> {
+        Err(MoneyMarketError::NotImplemented(self.name().to_owned()))
+    }
+
     fn user_deposit(
         &self,
         deps: Deps,
@@ -164,4 +251,211 @@ pub trait MoneyMarketCommand: Identify {
         lending_asset: AssetEntry,
         collateral_asset: AssetEntry,
     ) -> Result<Addr, AnsHostError>;
+
+    /// LTV above which the position of the user can be liquidated.
+    /// The address is the one returned by [`MoneyMarketCommand::current_ltv_address`].
+    fn liquidation_ltv(
+        &self,
+        _deps: Deps,
+        _current_ltv_addr: Addr,
+        _user: Addr,
+        _borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        Err(MoneyMarketError::NotImplemented(self.name().to_owned()))
+    }
+
+    /// Health of the position of the user.
+    /// The liquidation price assumes the collateral asset is the only collateral of the position.
+    fn user_health(
+        &self,
+        deps: Deps,
+        current_ltv_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<UserHealthResponse, MoneyMarketError> {
+        let current_ltv = self.current_ltv(
+            deps,
+            current_ltv_addr.clone(),
+            user.clone(),
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let liquidation_ltv = self.liquidation_ltv(
+            deps,
+            current_ltv_addr,
+            user,
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        // Nothing borrowed, the position can't be liquidated
+        if current_ltv.is_zero() {
+            return position_health(current_ltv, liquidation_ltv, Decimal::zero());
+        }
+
+        let collateral_price = self.price(deps, collateral_asset, borrowed_asset)?;
+        position_health(current_ltv, liquidation_ltv, collateral_price)
+    }
+
+    /// LTV of the position of the user after applying the change.
+    /// The address is the one returned by [`MoneyMarketCommand::current_ltv_address`].
+    fn simulate_ltv(
+        &self,
+        deps: Deps,
+        current_ltv_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+        change: PositionChange,
+    ) -> Result<Decimal, MoneyMarketError> {
+        let collateral = self.user_collateral(
+            deps,
+            current_ltv_addr.clone(),
+            user.clone(),
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let borrow = self.user_borrow(
+            deps,
+            current_ltv_addr,
+            user,
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let (collateral, borrow) = change.apply(collateral, borrow)?;
+
+        // This represents how much 1 unit of the collateral_asset is worth in terms of the borrowed_asset
+        let collateral_price = self.price(deps, collateral_asset, borrowed_asset)?;
+        position_ltv(collateral, borrow, collateral_price)
+    }
+}
+
+/// LTV of a position, the collateral price is the value of 1 unit of collateral in the borrowed asset
+fn position_ltv(
+    collateral: Uint128,
+    borrow: Uint128,
+    collateral_price: Decimal,
+) -> Result<Decimal, MoneyMarketError> {
+    let collateral_value = Decimal::from_ratio(collateral, 1u128).checked_mul(collateral_price)?;
+    if collateral_value.is_zero() {
+        return Ok(Decimal::zero());
+    }
+    Ok(Decimal::from_ratio(borrow, 1u128).checked_div(collateral_value)?)
+}
+
+/// Health of a position, the LTV scales inversely with the collateral price
+fn position_health(
+    current_ltv: Decimal,
+    liquidation_ltv: Decimal,
+    collateral_price: Decimal,
+) -> Result<UserHealthResponse, MoneyMarketError> {
+    if current_ltv.is_zero() {
+        return Ok(UserHealthResponse {
+            current_ltv,
+            liquidation_ltv,
+            health_factor: None,
+            liquidation_price: None,
+        });
+    }
+    Ok(UserHealthResponse {
+        current_ltv,
+        liquidation_ltv,
+        health_factor: Some(liquidation_ltv.checked_div(current_ltv)?),
+        liquidation_price: Some(
+            collateral_price
+                .checked_mul(current_ltv)?
+                .checked_div(liquidation_ltv)?,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::OverflowError;
+
+    use super::*;
+
+    #[coverage_helper::test]
+    fn ltv_of_position() {
+        // 1000 collateral worth 2 each, 500 borrowed
+        let ltv = position_ltv(
+            Uint128::new(1_000),
+            Uint128::new(500),
+            Decimal::percent(200),
+        );
+        assert_eq!(ltv, Ok(Decimal::percent(25)));
+
+        // Without collateral value the LTV is zero
+        let ltv = position_ltv(Uint128::zero(), Uint128::new(500), Decimal::percent(200));
+        assert_eq!(ltv, Ok(Decimal::zero()));
+        let ltv = position_ltv(Uint128::new(1_000), Uint128::new(500), Decimal::zero());
+        assert_eq!(ltv, Ok(Decimal::zero()));
+    }
+
+    #[coverage_helper::test]
+    fn simulated_position_changes() {
+        let collateral = Uint128::new(1_000);
+        let borrow = Uint128::new(500);
+        let price = Decimal::percent(200);
+
+        let simulate = |change: PositionChange| -> Result<Decimal, MoneyMarketError> {
+            let (collateral, borrow) = change.apply(collateral, borrow)?;
+            position_ltv(collateral, borrow, price)
+        };
+        assert_eq!(
+            simulate(PositionChange::ProvideCollateral(Uint128::new(250))),
+            Ok(Decimal::percent(20))
+        );
+        assert_eq!(
+            simulate(PositionChange::WithdrawCollateral(Uint128::new(500))),
+            Ok(Decimal::percent(50))
+        );
+        assert_eq!(
+            simulate(PositionChange::Borrow(Uint128::new(300))),
+            Ok(Decimal::percent(40))
+        );
+        assert_eq!(
+            simulate(PositionChange::Repay(Uint128::new(100))),
+            Ok(Decimal::percent(20))
+        );
+        // Repaying more than the debt closes the position
+        assert_eq!(
+            simulate(PositionChange::Repay(Uint128::new(1_000))),
+            Ok(Decimal::zero())
+        );
+        // Can't withdraw more collateral than provided
+        assert_eq!(
+            simulate(PositionChange::WithdrawCollateral(Uint128::new(1_001))),
+            Err(MoneyMarketError::Overflow(OverflowError::new(
+                cosmwasm_std::OverflowOperation::Sub
+            )))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn health_of_position() {
+        let health = position_health(
+            Decimal::percent(40),
+            Decimal::percent(80),
+            Decimal::percent(200),
+        )
+        .unwrap();
+        assert_eq!(
+            health,
+            UserHealthResponse {
+                current_ltv: Decimal::percent(40),
+                liquidation_ltv: Decimal::percent(80),
+                health_factor: Some(Decimal::percent(200)),
+                // The position is liquidated once the collateral lost half its value
+                liquidation_price: Some(Decimal::percent(100)),
+            }
+        );
+
+        // Nothing borrowed
+        let health =
+            position_health(Decimal::zero(), Decimal::percent(80), Decimal::percent(200)).unwrap();
+        assert_eq!(health.health_factor, None);
+        assert_eq!(health.liquidation_price, None);
+    }
 }
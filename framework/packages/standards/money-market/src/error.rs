@@ -2,7 +2,8 @@ use abstract_adapter::AdapterError;
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{objects::ans_host::AnsHostError, AbstractError};
 use cosmwasm_std::{
-    CheckedFromRatioError, ConversionOverflowError, DecimalRangeExceeded, StdError,
+    CheckedFromRatioError, CheckedMultiplyFractionError, ConversionOverflowError,
+    DecimalRangeExceeded, OverflowError, StdError, Uint128,
 };
 use cw_asset::AssetError;
 use thiserror::Error;
@@ -15,6 +16,12 @@ pub enum MoneyMarketError {
     #[error(transparent)]
     FromRatio(#[from] CheckedFromRatioError),
 
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    CheckedMultiplyFraction(#[from] CheckedMultiplyFractionError),
+
     #[error(transparent)]
     AbstractOs(#[from] AbstractError),
 
@@ -65,4 +72,21 @@ pub enum MoneyMarketError {
 
     #[error("Expected native asset")]
     ExpectedNative {},
+
+    #[error("Only actions that change a borrow position can be simulated")]
+    InvalidSimulation {},
+
+    #[error(
+        "Flash loan not repaid, expected {expected} to be returned to the lender but got {repaid}"
+    )]
+    FlashLoanNotRepaid { expected: Uint128, repaid: Uint128 },
+
+    #[error("Flash loan increased the debt of the account from {before} to {after}")]
+    FlashLoanDebtIncreased { before: Uint128, after: Uint128 },
+
+    #[error("A flash loan is already in progress")]
+    FlashLoanInProgress {},
+
+    #[error("Flash loans can only be requested by the account")]
+    FlashLoanUnauthorized {},
 }
@@ -1,8 +1,11 @@
 #![warn(missing_docs)]
 //! # MoneyMarket Adapter API
 // re-export response types
-use crate::{ans_action::MoneyMarketAnsAction, raw_action::MoneyMarketRawAction};
-use abstract_std::objects::AssetEntry;
+use crate::{
+    ans_action::MoneyMarketAnsAction,
+    raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
+};
+use abstract_std::objects::{AnsAsset, AssetEntry};
 use abstract_std::{adapter, objects::fee::UsageFee};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Decimal, StdError, StdResult, Uint128};
@@ -24,7 +27,107 @@ pub type InstantiateMsg = adapter::InstantiateMsg<MoneyMarketInstantiateMsg>;
 /// Top-level Abstract Adapter query message. This is the message that is passed to the `query` entrypoint of the smart-contract.
 pub type QueryMsg = adapter::QueryMsg<MoneyMarketQueryMsg>;
 
-impl adapter::AdapterExecuteMsg for MoneyMarketExecuteMsg {}
+impl adapter::AdapterExecuteMsg for MoneyMarketExecuteMsg {
+    fn request_scope(&self) -> adapter::RequestScope {
+        use adapter::RequestScope;
+        match self {
+            MoneyMarketExecuteMsg::UpdateFee { .. } => RequestScope::new("update_fee"),
+            MoneyMarketExecuteMsg::AnsAction {
+                money_market,
+                action,
+            } => {
+                let scope =
+                    match action {
+                        MoneyMarketAnsAction::Deposit { lending_asset } => {
+                            RequestScope::new("deposit").with_assets([&lending_asset.name])
+                        }
+                        MoneyMarketAnsAction::Withdraw { lent_asset } => {
+                            RequestScope::new("withdraw").with_assets([&lent_asset.name])
+                        }
+                        MoneyMarketAnsAction::ProvideCollateral {
+                            borrowable_asset,
+                            collateral_asset,
+                        } => RequestScope::new("provide_collateral")
+                            .with_assets([borrowable_asset, &collateral_asset.name]),
+                        MoneyMarketAnsAction::WithdrawCollateral {
+                            borrowable_asset,
+                            collateral_asset,
+                        } => RequestScope::new("withdraw_collateral")
+                            .with_assets([borrowable_asset, &collateral_asset.name]),
+                        MoneyMarketAnsAction::Borrow {
+                            borrow_asset,
+                            collateral_asset,
+                        } => RequestScope::new("borrow")
+                            .with_assets([&borrow_asset.name, collateral_asset]),
+                        MoneyMarketAnsAction::Repay {
+                            borrowed_asset,
+                            collateral_asset,
+                        } => RequestScope::new("repay")
+                            .with_assets([&borrowed_asset.name, collateral_asset]),
+                        MoneyMarketAnsAction::Liquidate {
+                            debt_asset,
+                            seize_asset,
+                            ..
+                        } => RequestScope::new("liquidate")
+                            .with_assets([&debt_asset.name, seize_asset]),
+                        MoneyMarketAnsAction::FlashLoan {
+                            asset,
+                            collateral_asset,
+                            ..
+                        } => RequestScope::new("flash_loan")
+                            .with_assets([&asset.name, collateral_asset]),
+                    };
+                scope.with_provider(money_market)
+            }
+            MoneyMarketExecuteMsg::RawAction {
+                money_market,
+                action,
+            } => {
+                let scope = match &action.request {
+                    MoneyMarketRawRequest::Deposit { lending_asset } => {
+                        RequestScope::new("deposit").with_raw_assets([&lending_asset.info])
+                    }
+                    MoneyMarketRawRequest::Withdraw { lent_asset } => {
+                        RequestScope::new("withdraw").with_raw_assets([&lent_asset.info])
+                    }
+                    MoneyMarketRawRequest::ProvideCollateral {
+                        borrowable_asset,
+                        collateral_asset,
+                    } => RequestScope::new("provide_collateral")
+                        .with_raw_assets([borrowable_asset, &collateral_asset.info]),
+                    MoneyMarketRawRequest::WithdrawCollateral {
+                        borrowable_asset,
+                        collateral_asset,
+                    } => RequestScope::new("withdraw_collateral")
+                        .with_raw_assets([borrowable_asset, &collateral_asset.info]),
+                    MoneyMarketRawRequest::Borrow {
+                        borrow_asset,
+                        collateral_asset,
+                    } => RequestScope::new("borrow")
+                        .with_raw_assets([&borrow_asset.info, collateral_asset]),
+                    MoneyMarketRawRequest::Repay {
+                        borrowed_asset,
+                        collateral_asset,
+                    } => RequestScope::new("repay")
+                        .with_raw_assets([&borrowed_asset.info, collateral_asset]),
+                    MoneyMarketRawRequest::Liquidate {
+                        debt_asset,
+                        seize_asset,
+                        ..
+                    } => RequestScope::new("liquidate")
+                        .with_raw_assets([&debt_asset.info, seize_asset]),
+                    MoneyMarketRawRequest::FlashLoan {
+                        asset,
+                        collateral_asset,
+                        ..
+                    } => RequestScope::new("flash_loan")
+                        .with_raw_assets([&asset.info, collateral_asset]),
+                };
+                scope.with_provider(money_market)
+            }
+        }
+    }
+}
 impl adapter::AdapterQueryMsg for MoneyMarketQueryMsg {}
 
 /// Response from GenerateMsgs
@@ -158,6 +261,32 @@ pub enum MoneyMarketQueryMsg {
         /// Name of the MoneyMarket to interact with
         money_market: MoneyMarketName,
     },
+    #[returns(UserHealthResponse)]
+    /// Health of a borrow position
+    /// Allows to know when the position can be liquidated
+    RawUserHealth {
+        /// User that has borrowed some funds
+        user: String,
+        /// Collateral asset to query
+        collateral_asset: AssetInfoBase<String>,
+        /// Borrowed asset to query
+        borrowed_asset: AssetInfoBase<String>,
+        /// Contract Address on which you execute the query
+        contract_addr: String,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
+    #[returns(SimulateLTVResponse)]
+    /// Loan-to-Value ratio of a borrow position after executing an action
+    /// Only collateral, borrow and repay actions can be simulated
+    RawSimulateLTV {
+        /// User that executes the action
+        user: String,
+        /// Action to simulate
+        action: MoneyMarketRawAction,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
     #[returns(PriceResponse)]
     /// Price of an asset compared to another asset
     /// The returned decimal corresponds to
@@ -233,6 +362,30 @@ pub enum MoneyMarketQueryMsg {
         /// Name of the MoneyMarket to interact with
         money_market: MoneyMarketName,
     },
+    #[returns(UserHealthResponse)]
+    /// Health of a borrow position
+    /// Allows to know when the position can be liquidated
+    AnsUserHealth {
+        /// User that has borrowed some funds
+        user: String,
+        /// Collateral asset to query
+        collateral_asset: AssetEntry,
+        /// Borrowed asset to query
+        borrowed_asset: AssetEntry,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
+    #[returns(SimulateLTVResponse)]
+    /// Loan-to-Value ratio of a borrow position after executing an action
+    /// Only collateral, borrow and repay actions can be simulated
+    AnsSimulateLTV {
+        /// User that executes the action
+        user: String,
+        /// Action to simulate
+        action: MoneyMarketAnsAction,
+        /// Name of the MoneyMarket to interact with
+        money_market: MoneyMarketName,
+    },
     #[returns(PriceResponse)]
     /// Price of an asset compared to another asset
     /// The returned decimal corresponds to
@@ -246,6 +399,18 @@ pub enum MoneyMarketQueryMsg {
         money_market: MoneyMarketName,
     },
 
+    #[returns(UserPositionsResponse)]
+    /// Deposits, collateral and borrows of a user across multiple money markets
+    /// Positions are valued in the quote asset using the price of the money market holding them
+    UserPositions {
+        /// User that holds the positions
+        user: String,
+        /// Names of the MoneyMarkets to aggregate
+        money_markets: Vec<MoneyMarketName>,
+        /// Asset in which the positions are valued
+        quote: AssetEntry,
+    },
+
     /// Fee info for using the different dex actions
     #[returns(MoneyMarketFeesResponse)]
     Fees {},
@@ -260,18 +425,25 @@ impl MoneyMarketQueryMsg {
             MoneyMarketQueryMsg::RawUserBorrow { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawCurrentLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawMaxLTV { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::RawUserHealth { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::RawSimulateLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::RawPrice { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserDeposit { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserCollateral { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsUserBorrow { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsCurrentLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsMaxLTV { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::AnsUserHealth { money_market, .. } => Ok(money_market),
+            MoneyMarketQueryMsg::AnsSimulateLTV { money_market, .. } => Ok(money_market),
             MoneyMarketQueryMsg::AnsPrice { money_market, .. } => Ok(money_market),
 
             MoneyMarketQueryMsg::GenerateMessages { .. } => {
                 Err(StdError::generic_err("Wrong query type"))
             }
             MoneyMarketQueryMsg::Fees {} => Err(StdError::generic_err("Wrong query type")),
+            MoneyMarketQueryMsg::UserPositions { .. } => {
+                Err(StdError::generic_err("Wrong query type"))
+            }
         }
     }
 }
@@ -307,6 +479,67 @@ pub struct UserMaxLTVResponse {
     pub max_ltv: Decimal,
 }
 
+/// Response wrapper for user health query
+#[cw_serde]
+pub struct UserHealthResponse {
+    /// Current LTV
+    pub current_ltv: Decimal,
+    /// LTV above which the position can be liquidated
+    pub liquidation_ltv: Decimal,
+    /// Liquidation LTV divided by the current LTV, the position can be liquidated below 1.
+    /// None if nothing is borrowed.
+    pub health_factor: Option<Decimal>,
+    /// Price of the collateral asset in terms of the borrowed asset under which the position can be liquidated.
+    /// None if nothing is borrowed.
+    pub liquidation_price: Option<Decimal>,
+}
+
+/// Response wrapper for simulate ltv query
+#[cw_serde]
+pub struct SimulateLTVResponse {
+    /// Current LTV
+    pub current_ltv: Decimal,
+    /// LTV after executing the action
+    pub simulated_ltv: Decimal,
+    /// Maximum LTV, the action fails if the simulated LTV is above it
+    pub max_ltv: Decimal,
+}
+
+/// Kind of a user position on a money market
+#[cw_serde]
+pub enum PositionKind {
+    /// Funds lent out
+    Deposit,
+    /// Funds provided as collateral
+    Collateral,
+    /// Borrowed funds
+    Borrow,
+}
+
+/// Position of a user on a money market
+#[cw_serde]
+pub struct MarketPosition {
+    /// Name of the MoneyMarket holding the position
+    pub money_market: MoneyMarketName,
+    /// Kind of the position
+    pub kind: PositionKind,
+    /// Asset and amount of the position
+    pub asset: AnsAsset,
+    /// Value of the position in the quote asset
+    pub value: Uint128,
+}
+
+/// Response wrapper for user positions query
+#[cw_serde]
+pub struct UserPositionsResponse {
+    /// Positions of the user, assets that are not registered in the ANS are omitted
+    pub positions: Vec<MarketPosition>,
+    /// Total value of the deposits and collateral in the quote asset
+    pub total_supplied_value: Uint128,
+    /// Total value of the borrows in the quote asset
+    pub total_borrowed_value: Uint128,
+}
+
 /// Response wrapper for price query
 #[cw_serde]
 pub struct PriceResponse {
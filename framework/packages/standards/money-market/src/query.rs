@@ -1,4 +1,7 @@
-use crate::{msg::MoneyMarketQueryMsg, MoneyMarketCommand, MoneyMarketError};
+use crate::{
+    ans_action::MoneyMarketActionResolveWrapper, msg::MoneyMarketQueryMsg, MoneyMarketCommand,
+    MoneyMarketError,
+};
 use abstract_sdk::Resolve;
 use abstract_std::objects::ans_host::AnsHostError;
 
@@ -135,6 +138,44 @@ impl Resolve for MoneyMarketQueryResolveWrapper {
                     money_market,
                 }
             }
+            MoneyMarketQueryMsg::AnsUserHealth {
+                user,
+                collateral_asset,
+                borrowed_asset,
+                money_market,
+            } => {
+                let platform = self.0(&money_market).map_err(err)?;
+                let contract_addr = platform.current_ltv_address(
+                    querier,
+                    ans_host,
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                )?;
+                let collateral_asset = collateral_asset.resolve(querier, ans_host)?;
+                let borrowed_asset = borrowed_asset.resolve(querier, ans_host)?;
+                MoneyMarketQueryMsg::RawUserHealth {
+                    user,
+                    collateral_asset: collateral_asset.into(),
+                    borrowed_asset: borrowed_asset.into(),
+
+                    contract_addr: contract_addr.to_string(),
+                    money_market,
+                }
+            }
+            MoneyMarketQueryMsg::AnsSimulateLTV {
+                user,
+                action,
+                money_market,
+            } => {
+                let platform = self.0(&money_market).map_err(err)?;
+                let action =
+                    MoneyMarketActionResolveWrapper(platform, action).resolve(querier, ans_host)?;
+                MoneyMarketQueryMsg::RawSimulateLTV {
+                    user,
+                    action,
+                    money_market,
+                }
+            }
             MoneyMarketQueryMsg::AnsPrice {
                 quote,
                 base,
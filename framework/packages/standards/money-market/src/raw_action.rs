@@ -1,8 +1,11 @@
 #![warn(missing_docs)]
 //! # Dex Adapter Raw Action Definition
 
+use cosmwasm_std::CosmosMsg;
 use cw_asset::{AssetBase, AssetInfoBase};
 
+use crate::PositionChange;
+
 /// Possible actions to perform on a Money Market
 /// This is an example using raw assets
 #[cosmwasm_schema::cw_serde]
@@ -45,6 +48,71 @@ pub enum MoneyMarketRawRequest {
         /// Asset that identifies the market you want to borrow from
         collateral_asset: AssetInfoBase<String>,
     },
+    /// Liquidate the position of a user
+    Liquidate {
+        /// User whose position is liquidated
+        user: String,
+        /// Debt of the user to repay
+        debt_asset: AssetBase<String>,
+        /// Collateral asset to seize in return
+        seize_asset: AssetInfoBase<String>,
+    },
+    /// Borrow funds, execute the actions on the account and repay the funds in the same transaction
+    FlashLoan {
+        /// Asset to borrow
+        asset: AssetBase<String>,
+        /// Asset that identifies the market you want to borrow from
+        collateral_asset: AssetInfoBase<String>,
+        /// Messages executed by the account between the borrow and the repayment
+        actions: Vec<CosmosMsg>,
+    },
+}
+
+impl MoneyMarketRawRequest {
+    /// Returns the (borrowed asset, collateral asset, change) of the borrow position changed by this request.
+    /// Returns None if the request doesn't change a borrow position of the sender.
+    pub fn position_change(
+        &self,
+    ) -> Option<(AssetInfoBase<String>, AssetInfoBase<String>, PositionChange)> {
+        match self {
+            MoneyMarketRawRequest::ProvideCollateral {
+                borrowable_asset,
+                collateral_asset,
+            } => Some((
+                borrowable_asset.clone(),
+                collateral_asset.info.clone(),
+                PositionChange::ProvideCollateral(collateral_asset.amount),
+            )),
+            MoneyMarketRawRequest::WithdrawCollateral {
+                borrowable_asset,
+                collateral_asset,
+            } => Some((
+                borrowable_asset.clone(),
+                collateral_asset.info.clone(),
+                PositionChange::WithdrawCollateral(collateral_asset.amount),
+            )),
+            MoneyMarketRawRequest::Borrow {
+                borrow_asset,
+                collateral_asset,
+            } => Some((
+                borrow_asset.info.clone(),
+                collateral_asset.clone(),
+                PositionChange::Borrow(borrow_asset.amount),
+            )),
+            MoneyMarketRawRequest::Repay {
+                borrowed_asset,
+                collateral_asset,
+            } => Some((
+                borrowed_asset.info.clone(),
+                collateral_asset.clone(),
+                PositionChange::Repay(borrowed_asset.amount),
+            )),
+            MoneyMarketRawRequest::Deposit { .. }
+            | MoneyMarketRawRequest::Withdraw { .. }
+            | MoneyMarketRawRequest::Liquidate { .. }
+            | MoneyMarketRawRequest::FlashLoan { .. } => None,
+        }
+    }
 }
 
 /// Action to execute on a money_market
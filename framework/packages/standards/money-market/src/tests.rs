@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 
 use cosmwasm_std::{Addr, CosmosMsg, StdError};
-use cw_asset::Asset;
+use cw_asset::{Asset, AssetInfo};
 use cw_orch::daemon::live_mock::mock_dependencies;
 use cw_orch::prelude::*;
 
@@ -93,4 +93,31 @@ impl MoneyMarketCommandTester {
         let msgs = self.adapter.repay(deps.as_ref(), contract_addr, asset)?;
         Ok(msgs)
     }
+
+    pub fn test_flash_loan(
+        &self,
+        contract_addr: Addr,
+        asset: Asset,
+        actions: Vec<CosmosMsg>,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs = self
+            .adapter
+            .flash_loan(deps.as_ref(), contract_addr, asset, actions)?;
+        Ok(msgs)
+    }
+
+    pub fn test_liquidate(
+        &self,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        seize_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let deps = mock_dependencies(self.chain.clone());
+        let msgs =
+            self.adapter
+                .liquidate(deps.as_ref(), contract_addr, user, debt_asset, seize_asset)?;
+        Ok(msgs)
+    }
 }
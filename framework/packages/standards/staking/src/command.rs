@@ -1,6 +1,6 @@
 use abstract_sdk::feature_objects::{AnsHost, RegistryContract};
 use abstract_std::objects::{AnsAsset, AssetEntry, ContractEntry};
-use cosmwasm_std::{Addr, CosmosMsg, Deps, Env, QuerierWrapper};
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Deps, Env, QuerierWrapper};
 
 use crate::{
     msg::{RewardTokensResponse, StakeResponse, StakingInfoResponse, UnbondingResponse},
@@ -85,6 +85,20 @@ pub trait CwStakingCommand: Identify {
         staker: Addr,
     ) -> Result<UnbondingResponse, CwStakingError>;
 
+    /// Query the unbonding positions of a given staker that can be claimed at the given block.
+    fn query_claimable(
+        &self,
+        querier: &QuerierWrapper,
+        block: &BlockInfo,
+        staker: Addr,
+    ) -> Result<UnbondingResponse, CwStakingError> {
+        let mut unbonding = self.query_unbonding(querier, staker)?;
+        for claims in unbonding.claims.iter_mut() {
+            claims.retain(|claim| claim.claimable_at.is_expired(block));
+        }
+        Ok(unbonding)
+    }
+
     /// Query the information of the reward tokens
     fn query_rewards(
         &self,
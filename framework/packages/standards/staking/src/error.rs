@@ -76,4 +76,10 @@ pub enum CwStakingError {
 
     #[error("Pool type {0} not supported for dex {1}")]
     NotSupportedPoolType(String, String),
+
+    #[error("Compounding requires the dex adapter to be installed on the account")]
+    DexAdapterNotInstalled {},
+
+    #[error("A compound is already in progress")]
+    CompoundInProgress {},
 }
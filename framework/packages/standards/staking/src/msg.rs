@@ -4,10 +4,10 @@
 //! `abstract::cw-staking`
 use abstract_std::{
     adapter,
-    objects::{AnsAsset, AssetEntry},
+    objects::{AnsAsset, AssetEntry, ContractEntry},
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Empty;
+use cosmwasm_std::{Decimal, Empty};
 use cw_utils::Duration;
 
 /// Name of the staking provider, used by the ANS.
@@ -23,7 +23,29 @@ pub type InstantiateMsg = adapter::InstantiateMsg<Empty>;
 /// Top-level Abstract Adapter query message. This is the message that is passed to the `query` entrypoint of the smart-contract.
 pub type QueryMsg = adapter::QueryMsg<StakingQueryMsg>;
 
-impl adapter::AdapterExecuteMsg for StakingExecuteMsg {}
+impl adapter::AdapterExecuteMsg for StakingExecuteMsg {
+    fn request_scope(&self) -> adapter::RequestScope {
+        let scope = match &self.action {
+            StakingAction::Stake { assets, .. } => {
+                adapter::RequestScope::new("stake").with_assets(assets.iter().map(|a| &a.name))
+            }
+            StakingAction::Unstake { assets, .. } => {
+                adapter::RequestScope::new("unstake").with_assets(assets.iter().map(|a| &a.name))
+            }
+            StakingAction::ClaimRewards { assets } => {
+                adapter::RequestScope::new("claim_rewards").with_assets(assets)
+            }
+            StakingAction::Claim { assets } => {
+                adapter::RequestScope::new("claim").with_assets(assets)
+            }
+            StakingAction::Compound { asset, .. } => {
+                adapter::RequestScope::new("compound").with_assets([asset])
+            }
+            StakingAction::ClaimAll { .. } => adapter::RequestScope::new("claim_all"),
+        };
+        scope.with_provider(&self.provider)
+    }
+}
 impl adapter::AdapterQueryMsg for StakingQueryMsg {}
 
 /// A request message that's sent to this staking adapter
@@ -63,6 +85,28 @@ pub enum StakingAction {
         /// Unbonded staking assets to claim.
         assets: Vec<AssetEntry>,
     },
+    /// Claim all matured unbonding tokens on the staking contracts of a page of ANS contract entries.
+    /// The provider of the request only selects the chain the claims are made on.
+    /// Providers whose claims can't be queried are skipped.
+    ClaimAll {
+        /// ANS contract entry to start scanning after, see [`ClaimableResponse::last_entry`]
+        start_after: Option<ContractEntry>,
+        /// Number of ANS contract entries to scan
+        limit: Option<u8>,
+    },
+    /// Claim rewards, swap them into the staked asset and restake it.
+    /// When the staked asset is an LP token of the `dex`, the rewards are swapped into the pool assets and provided as liquidity.
+    /// Requires the dex adapter to be installed on the account. Returns [`CompoundResponse`] as data.
+    Compound {
+        /// Staked asset to compound.
+        asset: AssetEntry,
+        /// Name of the dex to swap the rewards on.
+        dex: String,
+        /// Max spread to accept on the swaps and the liquidity provision.
+        max_spread: Option<Decimal>,
+        /// The unbonding period of the staked position.
+        unbonding_period: Option<Duration>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -102,6 +146,18 @@ pub enum StakingQueryMsg {
         /// The address of the staker (contract or user)
         staker_address: String,
     },
+    /// Get the unbonding entries that can be claimed now by the staker address,
+    /// on the staking contracts of a page of ANS contract entries
+    /// Returns [`ClaimableResponse`]
+    #[returns(ClaimableResponse)]
+    Claimable {
+        /// The address of the staker (contract or user)
+        staker_address: String,
+        /// ANS contract entry to start scanning after, see [`ClaimableResponse::last_entry`]
+        start_after: Option<ContractEntry>,
+        /// Number of ANS contract entries to scan
+        limit: Option<u8>,
+    },
     /// Get the reward tokens for a given provider and staking token
     /// Returns [`RewardTokensResponse`]
     #[returns(RewardTokensResponse)]
@@ -114,7 +170,7 @@ pub enum StakingQueryMsg {
 }
 
 use cosmwasm_std::{Addr, Uint128};
-use cw_asset::AssetInfo;
+use cw_asset::{Asset, AssetInfo};
 use cw_utils::Expiration;
 
 /// Possible staking targets to support staking on cosmwasm contract or cosmos Lockup module
@@ -195,6 +251,17 @@ pub struct RewardTokensResponse {
     pub tokens: Vec<Vec<AssetInfo>>,
 }
 
+/// Data returned by the [`StakingAction::Compound`] action
+#[cosmwasm_schema::cw_serde]
+pub struct CompoundResponse {
+    /// Claimed rewards
+    pub rewards: Vec<Asset>,
+    /// Amount of the staked asset that was restaked
+    pub staked: AnsAsset,
+    /// Max spread used for the swaps and the liquidity provision
+    pub max_spread: Option<Decimal>,
+}
+
 /// Response for the unbonding query
 #[cosmwasm_schema::cw_serde]
 pub struct UnbondingResponse {
@@ -202,6 +269,40 @@ pub struct UnbondingResponse {
     pub claims: Vec<Vec<Claim>>,
 }
 
+/// Response for the claimable query
+#[cosmwasm_schema::cw_serde]
+pub struct ClaimableResponse {
+    /// Claimable entries, per provider and staking token that has any
+    pub claims: Vec<ProviderClaims>,
+    /// Staking contracts whose claims couldn't be queried
+    pub failed: Vec<ProviderFailure>,
+    /// Last scanned ANS contract entry, to pass as `start_after` for the next page.
+    /// `None` once all the entries are scanned.
+    pub last_entry: Option<ContractEntry>,
+}
+
+/// Failure to query the claims of a staker on a provider
+#[cosmwasm_schema::cw_serde]
+pub struct ProviderFailure {
+    /// Name of the provider
+    pub provider: ProviderName,
+    /// Staking token of the claims
+    pub staking_token: AssetEntry,
+    /// Reason of the failure
+    pub error: String,
+}
+
+/// Claims of a staker on a provider for a staking token
+#[cosmwasm_schema::cw_serde]
+pub struct ProviderClaims {
+    /// Name of the provider
+    pub provider: ProviderName,
+    /// Staking token of the claims
+    pub staking_token: AssetEntry,
+    /// Claims of the staker
+    pub claims: Vec<Claim>,
+}
+
 /// A claim for a given amount of tokens that are unbonding.
 #[cosmwasm_schema::cw_serde]
 pub struct Claim {
@@ -0,0 +1,10 @@
+pub const DROP: &str = "drop";
+#[cfg(feature = "local")]
+pub const AVAILABLE_CHAINS: &[&str] = abstract_sdk::std::constants::LOCAL_CHAIN;
+#[cfg(not(feature = "local"))]
+pub const AVAILABLE_CHAINS: &[&str] = abstract_sdk::std::constants::NEUTRON;
+
+pub mod liquid_staking;
+
+#[cfg(feature = "full_integration")]
+pub mod mini_drop;
@@ -0,0 +1,373 @@
+use abstract_liquid_staking_standard::Identify;
+use cosmwasm_std::Addr;
+
+use crate::{AVAILABLE_CHAINS, DROP};
+
+// Source https://github.com/hadronlabs-org/drop-contracts
+#[derive(Default)]
+pub struct DropProtocol {
+    pub addr_as_sender: Option<Addr>,
+}
+
+impl Identify for DropProtocol {
+    fn name(&self) -> &'static str {
+        DROP
+    }
+    fn is_available_on(&self, chain_name: &str) -> bool {
+        AVAILABLE_CHAINS.contains(&chain_name)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+use {
+    crate::mini_drop::{core, token, voucher, withdrawal_manager},
+    abstract_liquid_staking_standard::{LiquidStakingCommand, LiquidStakingError},
+    abstract_sdk::feature_objects::AnsHost,
+    cosmwasm_std::{to_json_binary, wasm_execute, Coin, CosmosMsg, Decimal, Deps, StdError},
+    cw_asset::{Asset, AssetInfo},
+};
+
+/// Most withdrawal vouchers of the account checked when claiming redemptions
+#[cfg(feature = "full_integration")]
+pub const MAX_CLAIMED_VOUCHERS: u32 = 30;
+
+#[cfg(feature = "full_integration")]
+impl LiquidStakingCommand for DropProtocol {
+    fn fetch_data(
+        &mut self,
+        _deps: Deps,
+        addr_as_sender: Addr,
+        _ans_host: &AnsHost,
+    ) -> Result<(), LiquidStakingError> {
+        self.addr_as_sender = Some(addr_as_sender);
+        Ok(())
+    }
+
+    fn liquid_token(&self, deps: Deps, hub: Addr) -> Result<AssetInfo, LiquidStakingError> {
+        let config = core_config(deps, &hub)?;
+        let token: token::ConfigResponse = deps
+            .querier
+            .query_wasm_smart(config.token_contract, &token::QueryMsg::Config {})?;
+        Ok(AssetInfo::native(token.denom))
+    }
+
+    fn liquid_stake(
+        &self,
+        _deps: Deps,
+        hub: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let coin: Coin = asset.try_into()?;
+        Ok(vec![wasm_execute(
+            hub,
+            &core::ExecuteMsg::Bond {
+                receiver: None,
+                r#ref: None,
+            },
+            vec![coin],
+        )?
+        .into()])
+    }
+
+    fn request_redeem(
+        &self,
+        _deps: Deps,
+        hub: Addr,
+        liquid_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let coin: Coin = liquid_asset.try_into()?;
+        Ok(vec![wasm_execute(
+            hub,
+            &core::ExecuteMsg::Unbond {},
+            vec![coin],
+        )?
+        .into()])
+    }
+
+    fn claim_redeemed(&self, deps: Deps, hub: Addr) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let owner = self.addr_as_sender.as_ref().expect("no local account");
+        let config = core_config(deps, &hub)?;
+
+        // Each redemption request is a voucher NFT, it can be exchanged once its batch is withdrawn
+        let vouchers: voucher::TokensResponse = deps.querier.query_wasm_smart(
+            &config.withdrawal_voucher_contract,
+            &voucher::QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: None,
+                limit: Some(MAX_CLAIMED_VOUCHERS),
+            },
+        )?;
+        let withdraw_msg =
+            to_json_binary(&withdrawal_manager::ReceiveNftMsg::Withdraw { receiver: None })?;
+        let mut msgs = vec![];
+        for token_id in vouchers.tokens {
+            let info: voucher::NftInfoResponse = deps.querier.query_wasm_smart(
+                &config.withdrawal_voucher_contract,
+                &voucher::QueryMsg::NftInfo {
+                    token_id: token_id.clone(),
+                },
+            )?;
+            let Some(metadata) = info.extension else {
+                continue;
+            };
+            let batch_id = metadata
+                .batch_id
+                .parse()
+                .map_err(|_| StdError::generic_err("Invalid Drop batch id"))?;
+            let batch: core::UnbondBatch = deps
+                .querier
+                .query_wasm_smart(&hub, &core::QueryMsg::UnbondBatch { batch_id })?;
+            if !batch.status.is_withdrawn() {
+                continue;
+            }
+            msgs.push(
+                wasm_execute(
+                    &config.withdrawal_voucher_contract,
+                    &voucher::ExecuteMsg::SendNft {
+                        contract: config.withdrawal_manager_contract.clone(),
+                        token_id,
+                        msg: withdraw_msg.clone(),
+                    },
+                    vec![],
+                )?
+                .into(),
+            );
+        }
+        if msgs.is_empty() {
+            return Err(LiquidStakingError::NothingToClaim(DROP.to_owned()));
+        }
+        Ok(msgs)
+    }
+
+    fn exchange_rate(&self, deps: Deps, hub: Addr) -> Result<Decimal, LiquidStakingError> {
+        deps.querier
+            .query_wasm_smart(hub, &core::QueryMsg::ExchangeRate {})
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn core_config(deps: Deps, hub: &Addr) -> Result<core::Config, LiquidStakingError> {
+    deps.querier
+        .query_wasm_smart(hub, &core::QueryMsg::Config {})
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use abstract_liquid_staking_standard::{LiquidStakingCommand, LiquidStakingError};
+    use cosmwasm_std::{
+        coins, from_json,
+        testing::{mock_dependencies, MockApi, MockQuerier, MockStorage},
+        to_json_binary, wasm_execute, Addr, ContractResult, CosmosMsg, Decimal, OwnedDeps,
+        SystemResult, WasmQuery,
+    };
+    use cw_asset::{Asset, AssetInfo};
+
+    use super::DropProtocol;
+    use crate::{
+        mini_drop::{core, token, voucher, withdrawal_manager},
+        DROP,
+    };
+
+    const BASE_DENOM: &str = "ibc/atom";
+    const LIQUID_DENOM: &str = "factory/drop/udatom";
+
+    struct Contracts {
+        hub: Addr,
+        token_contract: Addr,
+        voucher_contract: Addr,
+        manager_contract: Addr,
+        owner: Addr,
+    }
+
+    fn contracts() -> Contracts {
+        let api = MockApi::default().with_prefix("neutron");
+        Contracts {
+            hub: api.addr_make("core"),
+            token_contract: api.addr_make("token"),
+            voucher_contract: api.addr_make("voucher"),
+            manager_contract: api.addr_make("manager"),
+            owner: api.addr_make("owner"),
+        }
+    }
+
+    /// Drop contracts with vouchers `1` of withdrawn batch `0` and `2` of unbonding batch `1`
+    fn mock_drop() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let Contracts {
+            hub,
+            token_contract,
+            voucher_contract,
+            manager_contract,
+            ..
+        } = contracts();
+        deps.querier.update_wasm(move |query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("unexpected query");
+            };
+            let response = if contract_addr == hub.as_str() {
+                match from_json(msg).unwrap() {
+                    core::QueryMsg::Config {} => to_json_binary(&core::Config {
+                        token_contract: token_contract.to_string(),
+                        withdrawal_voucher_contract: voucher_contract.to_string(),
+                        withdrawal_manager_contract: manager_contract.to_string(),
+                    }),
+                    core::QueryMsg::ExchangeRate {} => to_json_binary(&Decimal::percent(120)),
+                    core::QueryMsg::UnbondBatch { batch_id } => {
+                        to_json_binary(&core::UnbondBatch {
+                            status: if batch_id.is_zero() {
+                                core::UnbondBatchStatus::Withdrawn
+                            } else {
+                                core::UnbondBatchStatus::Unbonding
+                            },
+                        })
+                    }
+                }
+            } else if contract_addr == token_contract.as_str() {
+                to_json_binary(&token::ConfigResponse {
+                    denom: LIQUID_DENOM.to_owned(),
+                })
+            } else if contract_addr == voucher_contract.as_str() {
+                match from_json(msg).unwrap() {
+                    voucher::QueryMsg::Tokens { .. } => to_json_binary(&voucher::TokensResponse {
+                        tokens: vec!["1".to_owned(), "2".to_owned()],
+                    }),
+                    voucher::QueryMsg::NftInfo { token_id } => {
+                        let batch_id = if token_id == "1" { "0" } else { "1" };
+                        to_json_binary(&voucher::NftInfoResponse {
+                            extension: Some(voucher::Metadata {
+                                batch_id: batch_id.to_owned(),
+                            }),
+                        })
+                    }
+                }
+            } else {
+                panic!("unexpected contract {contract_addr}");
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        });
+        deps
+    }
+
+    fn drop_provider() -> DropProtocol {
+        DropProtocol {
+            addr_as_sender: Some(contracts().owner),
+        }
+    }
+
+    #[test]
+    fn liquid_stake_and_redeem() {
+        let deps = mock_dependencies();
+        let hub = contracts().hub;
+
+        let msgs = drop_provider()
+            .liquid_stake(
+                deps.as_ref(),
+                hub.clone(),
+                Asset::native(BASE_DENOM, 1_000u128),
+            )
+            .unwrap();
+        let expected: CosmosMsg = wasm_execute(
+            &hub,
+            &core::ExecuteMsg::Bond {
+                receiver: None,
+                r#ref: None,
+            },
+            coins(1_000, BASE_DENOM),
+        )
+        .unwrap()
+        .into();
+        assert_eq!(msgs, vec![expected]);
+
+        let msgs = drop_provider()
+            .request_redeem(
+                deps.as_ref(),
+                hub.clone(),
+                Asset::native(LIQUID_DENOM, 500u128),
+            )
+            .unwrap();
+        let expected: CosmosMsg =
+            wasm_execute(&hub, &core::ExecuteMsg::Unbond {}, coins(500, LIQUID_DENOM))
+                .unwrap()
+                .into();
+        assert_eq!(msgs, vec![expected]);
+    }
+
+    #[test]
+    fn queries() {
+        let deps = mock_drop();
+        let hub = contracts().hub;
+
+        let liquid_token = drop_provider()
+            .liquid_token(deps.as_ref(), hub.clone())
+            .unwrap();
+        assert_eq!(liquid_token, AssetInfo::native(LIQUID_DENOM));
+
+        let exchange_rate = drop_provider().exchange_rate(deps.as_ref(), hub).unwrap();
+        assert_eq!(exchange_rate, Decimal::percent(120));
+    }
+
+    #[test]
+    fn claim_withdrawn_vouchers() {
+        let deps = mock_drop();
+        let Contracts {
+            hub,
+            voucher_contract,
+            manager_contract,
+            ..
+        } = contracts();
+
+        // Only the voucher of the withdrawn batch is sent to the withdrawal manager
+        let msgs = drop_provider().claim_redeemed(deps.as_ref(), hub).unwrap();
+        let expected: CosmosMsg = wasm_execute(
+            &voucher_contract,
+            &voucher::ExecuteMsg::SendNft {
+                contract: manager_contract.to_string(),
+                token_id: "1".to_owned(),
+                msg: to_json_binary(&withdrawal_manager::ReceiveNftMsg::Withdraw {
+                    receiver: None,
+                })
+                .unwrap(),
+            },
+            vec![],
+        )
+        .unwrap()
+        .into();
+        assert_eq!(msgs, vec![expected]);
+    }
+
+    #[test]
+    fn claim_without_withdrawn_vouchers() {
+        let mut deps = mock_dependencies();
+        let Contracts {
+            hub,
+            token_contract,
+            voucher_contract,
+            manager_contract,
+            ..
+        } = contracts();
+        let hub_contract = hub.clone();
+        // The account holds no voucher
+        deps.querier.update_wasm(move |query| {
+            let WasmQuery::Smart { contract_addr, .. } = query else {
+                panic!("unexpected query");
+            };
+            let response = if contract_addr == hub_contract.as_str() {
+                to_json_binary(&core::Config {
+                    token_contract: token_contract.to_string(),
+                    withdrawal_voucher_contract: voucher_contract.to_string(),
+                    withdrawal_manager_contract: manager_contract.to_string(),
+                })
+            } else {
+                to_json_binary(&voucher::TokensResponse { tokens: vec![] })
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        });
+
+        let err = drop_provider()
+            .claim_redeemed(deps.as_ref(), hub)
+            .unwrap_err();
+        assert_eq!(err, LiquidStakingError::NothingToClaim(DROP.to_owned()));
+    }
+}
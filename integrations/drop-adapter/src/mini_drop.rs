@@ -0,0 +1,127 @@
+//! Minimalistic versions of the Drop protocol types that was created to reduce wasm size
+//! Responses ignore the fields the adapter doesn't use
+
+use cosmwasm_std::{Binary, Uint128};
+
+/// Messages of the Drop core contract, that liquid stakes an asset
+pub mod core {
+    use super::*;
+
+    #[cosmwasm_schema::cw_serde]
+    pub enum ExecuteMsg {
+        Bond {
+            receiver: Option<String>,
+            r#ref: Option<String>,
+        },
+        Unbond {},
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    pub enum QueryMsg {
+        Config {},
+        ExchangeRate {},
+        UnbondBatch { batch_id: Uint128 },
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct Config {
+        pub token_contract: String,
+        pub withdrawal_voucher_contract: String,
+        pub withdrawal_manager_contract: String,
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct UnbondBatch {
+        pub status: UnbondBatchStatus,
+    }
+
+    #[derive(
+        cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize, PartialEq, Eq,
+    )]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub enum UnbondBatchStatus {
+        New,
+        UnbondRequested,
+        UnbondFailed,
+        Unbonding,
+        Withdrawing,
+        Withdrawn,
+        WithdrawingEmergency,
+        WithdrawnEmergency,
+    }
+
+    impl UnbondBatchStatus {
+        /// Whether the vouchers of the batch can be exchanged at the withdrawal manager
+        pub fn is_withdrawn(&self) -> bool {
+            matches!(self, Self::Withdrawn | Self::WithdrawnEmergency)
+        }
+    }
+}
+
+/// Messages of the Drop token contract, that mints the liquid staking token
+pub mod token {
+    #[cosmwasm_schema::cw_serde]
+    pub enum QueryMsg {
+        Config {},
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct ConfigResponse {
+        pub denom: String,
+    }
+}
+
+/// Messages of the cw721 withdrawal voucher contract, minted for each redemption request
+pub mod voucher {
+    use super::*;
+
+    #[cosmwasm_schema::cw_serde]
+    pub enum ExecuteMsg {
+        SendNft {
+            contract: String,
+            token_id: String,
+            msg: Binary,
+        },
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    pub enum QueryMsg {
+        Tokens {
+            owner: String,
+            start_after: Option<String>,
+            limit: Option<u32>,
+        },
+        NftInfo {
+            token_id: String,
+        },
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct TokensResponse {
+        pub tokens: Vec<String>,
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct NftInfoResponse {
+        pub extension: Option<Metadata>,
+    }
+
+    #[derive(cosmwasm_schema::serde::Serialize, cosmwasm_schema::serde::Deserialize)]
+    #[serde(rename_all = "snake_case", crate = "::cosmwasm_schema::serde")]
+    pub struct Metadata {
+        pub batch_id: String,
+    }
+}
+
+/// Messages of the Drop withdrawal manager, that pays out the vouchers of withdrawn batches
+pub mod withdrawal_manager {
+    #[cosmwasm_schema::cw_serde]
+    pub enum ReceiveNftMsg {
+        Withdraw { receiver: Option<String> },
+    }
+}
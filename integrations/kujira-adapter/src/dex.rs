@@ -20,7 +20,7 @@ impl Identify for Fin {
 #[cfg(feature = "full_integration")]
 use ::{
     abstract_dex_standard::{
-        coins_in_assets, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
+        coins_in_assets, msg::LimitOrder, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
     },
     abstract_sdk::std::objects::PoolAddress,
     cosmwasm_std::{
@@ -29,6 +29,7 @@ use ::{
     },
     cw_asset::{Asset, AssetInfo, AssetInfoBase},
     kujira::{bow, fin},
+    std::str::FromStr,
 };
 
 #[cfg(feature = "full_integration")]
@@ -200,6 +201,127 @@ impl DexCommand for Fin {
             false,
         ))
     }
+
+    fn place_limit_order(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        offer_asset: Asset,
+        _ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let fin_pair_address = fin_pair_address(pool_id);
+        let [base_denom, _] = fin_denoms(deps, &fin_pair_address)?;
+
+        // FIN prices are expressed in quote per base, bids offer the quote asset
+        let price = decimal2decimal256(price)?;
+        let fin_price = if offer_asset.info == AssetInfo::native(base_denom) {
+            price
+        } else {
+            price.inv().ok_or(DexError::ZeroLimitOrderPrice)?
+        };
+
+        let msg = fin::ExecuteMsg::SubmitOrder {
+            price: fin_price,
+            callback: None,
+        };
+        let order_msg = wasm_execute(fin_pair_address, &msg, vec![offer_asset.try_into()?])?;
+        Ok(vec![order_msg.into()])
+    }
+
+    fn cancel_limit_order(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let msg = fin::ExecuteMsg::RetractOrder {
+            order_idx: Uint128::from_str(&order_id)?,
+            amount: None,
+            callback: None,
+        };
+        let cancel_msg = wasm_execute(fin_pair_address(pool_id), &msg, vec![])?;
+        Ok(vec![cancel_msg.into()])
+    }
+
+    fn withdraw_filled_orders(
+        &self,
+        _deps: Deps,
+        pool_id: PoolAddress,
+        order_ids: Vec<String>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let order_idxs = order_ids
+            .iter()
+            .map(|id| Uint128::from_str(id))
+            .collect::<StdResult<Vec<_>>>()?;
+        let msg = fin::ExecuteMsg::WithdrawOrders {
+            order_idxs: Some(order_idxs),
+            callback: None,
+        };
+        let withdraw_msg = wasm_execute(fin_pair_address(pool_id), &msg, vec![])?;
+        Ok(vec![withdraw_msg.into()])
+    }
+
+    fn open_orders(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        owner: Addr,
+    ) -> Result<Vec<LimitOrder>, DexError> {
+        let fin_pair_address = fin_pair_address(pool_id);
+        let denoms = fin_denoms(deps, &fin_pair_address)?;
+
+        let fin::OrdersResponse { orders } = deps.querier.query_wasm_smart(
+            fin_pair_address.to_string(),
+            &fin::QueryMsg::OrdersByUser {
+                address: owner,
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        orders
+            .into_iter()
+            .map(|order| {
+                let offer_denom = order.offer_denom.to_string();
+                let ask_denom = if offer_denom == denoms[0] {
+                    denoms[1].clone()
+                } else {
+                    denoms[0].clone()
+                };
+                Ok(LimitOrder {
+                    order_id: order.idx.to_string(),
+                    offer_asset: Asset::native(
+                        offer_denom,
+                        Uint128::try_from(order.offer_amount).map_err(StdError::from)?,
+                    )
+                    .into(),
+                    ask_asset: AssetInfo::native(ask_denom).into(),
+                    filled_amount: Some(
+                        Uint128::try_from(order.filled_amount).map_err(StdError::from)?,
+                    ),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn fin_pair_address(pool_id: PoolAddress) -> Addr {
+    match pool_id {
+        PoolAddress::SeparateAddresses { swap, liquidity: _ } => swap,
+        PoolAddress::Contract(swap) => swap,
+        _ => panic!("invalid address"),
+    }
+}
+
+#[cfg(feature = "full_integration")]
+/// Base and quote denoms of a FIN pair
+fn fin_denoms(deps: Deps, fin_pair_address: &Addr) -> Result<[String; 2], DexError> {
+    let fin::ConfigResponse { denoms, .. } = deps
+        .querier
+        .query_wasm_smart(fin_pair_address.to_string(), &fin::QueryMsg::Config {})?;
+    Ok([denoms[0].to_string(), denoms[1].to_string()])
 }
 
 #[cfg(feature = "full_integration")]
@@ -234,10 +356,10 @@ mod tests {
     use abstract_sdk::std::objects::PoolAddress;
     use cosmwasm_std::{coin, coins, wasm_execute, Addr, Decimal, Decimal256};
     use cw_asset::{Asset, AssetInfo};
-    use cw_orch::daemon::networks::HARPOON_4;
+    use cw_orch::daemon::{live_mock::mock_dependencies, networks::HARPOON_4};
     use kujira::{bow, fin};
 
-    use super::{decimal2decimal256, Fin};
+    use super::{decimal2decimal256, fin_denoms, Fin};
 
     fn create_setup() -> DexCommandTester {
         DexCommandTester::new(HARPOON_4.into(), Fin {})
@@ -382,6 +504,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cancel_limit_order() {
+        let msgs = create_setup()
+            .test_cancel_limit_order(pool_addr(), "42".to_owned())
+            .unwrap();
+
+        expect_eq(
+            vec![wasm_execute(
+                SWAP_CONTRACT,
+                &fin::ExecuteMsg::RetractOrder {
+                    order_idx: 42u128.into(),
+                    amount: None,
+                    callback: None,
+                },
+                vec![],
+            )
+            .unwrap()
+            .into()],
+            msgs,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn withdraw_filled_orders() {
+        let msgs = create_setup()
+            .test_withdraw_filled_orders(pool_addr(), vec!["4".to_owned(), "2".to_owned()])
+            .unwrap();
+
+        expect_eq(
+            vec![wasm_execute(
+                SWAP_CONTRACT,
+                &fin::ExecuteMsg::WithdrawOrders {
+                    order_idxs: Some(vec![4u128.into(), 2u128.into()]),
+                    callback: None,
+                },
+                vec![],
+            )
+            .unwrap()
+            .into()],
+            msgs,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn simulate_swap() {
         let amount = 100_000u128;
@@ -394,4 +561,52 @@ mod tests {
             )
             .unwrap();
     }
+
+    #[test]
+    fn place_limit_order() {
+        let deps = mock_dependencies(HARPOON_4.into());
+        let [base_denom, _] = fin_denoms(deps.as_ref(), &Addr::unchecked(SWAP_CONTRACT)).unwrap();
+        // FIN quotes the price in quote per base
+        let fin_price = if base_denom == DEMO {
+            Decimal256::from_str("0.2").unwrap()
+        } else {
+            Decimal256::from_str("5").unwrap()
+        };
+
+        // Selling DEMO at 0.2 KUJI or KUJI at 5 DEMO is the same FIN price
+        let amount = 100_000u128;
+        for (offer, ask, price) in [(DEMO, KUJI, "0.2"), (KUJI, DEMO, "5")] {
+            let msgs = create_setup()
+                .test_place_limit_order(
+                    pool_addr(),
+                    Asset::new(AssetInfo::native(offer), amount),
+                    AssetInfo::native(ask),
+                    Decimal::from_str(price).unwrap(),
+                )
+                .unwrap();
+
+            expect_eq(
+                vec![wasm_execute(
+                    SWAP_CONTRACT,
+                    &fin::ExecuteMsg::SubmitOrder {
+                        price: fin_price,
+                        callback: None,
+                    },
+                    coins(amount, offer),
+                )
+                .unwrap()
+                .into()],
+                msgs,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn open_orders() {
+        // The liquidity contract never places orders on FIN
+        let owner = Addr::unchecked(POOL_CONTRACT);
+        let orders = create_setup().test_open_orders(pool_addr(), owner).unwrap();
+        assert_eq!(orders, vec![]);
+    }
 }
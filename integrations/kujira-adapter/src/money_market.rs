@@ -18,10 +18,15 @@ impl Identify for Ghost {
 
 #[cfg(feature = "full_integration")]
 use ::{
-    abstract_money_market_standard::{MoneyMarketCommand, MoneyMarketError},
+    abstract_money_market_standard::{
+        msg::PositionKind, MoneyMarketCommand, MoneyMarketError, UserPosition,
+    },
     abstract_sdk::{
         feature_objects::AnsHost,
-        std::objects::{ans_host::AnsHostError, AssetEntry, ContractEntry},
+        std::{
+            ans_host::{self, ContractListResponse, ContractMapEntry},
+            objects::{ans_host::AnsHostError, AssetEntry, ContractEntry},
+        },
     },
     cosmwasm_std::{
         coins, wasm_execute, Addr, CosmosMsg, Decimal, Deps, GrpcQuery, QuerierWrapper, StdError,
@@ -146,6 +151,46 @@ impl MoneyMarketCommand for Ghost {
         Ok(vec![msg.into()])
     }
 
+    fn liquidate(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        seize_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        // The market is specific to its debt and collateral assets,
+        // the liquidated collateral is sold on Orca to repay the debt
+        let config: market::ConfigResponse = deps
+            .querier
+            .query_wasm_smart(&contract_addr, &market::QueryMsg::Config {})?;
+        let vault_config: receipt_vault::query::ConfigResponse = deps.querier.query_wasm_smart(
+            &config.vault_addr,
+            &receipt_vault::query::QueryMsg::Config {},
+        )?;
+        let debt_denom = vault_config.denom.to_string();
+        let collateral_denom = config.collateral_denom.to_string();
+        for (asset, denom) in [
+            (debt_asset.info, &debt_denom),
+            (seize_asset, &collateral_denom),
+        ] {
+            if unwrap_native(asset.clone())? != *denom {
+                return Err(MoneyMarketError::ArgumentMismatch(
+                    asset.to_string(),
+                    vec![debt_denom.clone(), collateral_denom.clone()],
+                ));
+            }
+        }
+
+        let vault_msg = market::ExecuteMsg::Liquidate(market::LiquidateMsg {
+            positions: Some(vec![user]),
+        });
+
+        let msg = wasm_execute(contract_addr, &vault_msg, vec![])?;
+
+        Ok(vec![msg.into()])
+    }
+
     fn price(
         &self,
         deps: Deps,
@@ -227,6 +272,66 @@ impl MoneyMarketCommand for Ghost {
             .unwrap_or_default())
     }
 
+    fn user_positions(
+        &self,
+        deps: Deps,
+        ans_host: &AnsHost,
+        user: Addr,
+    ) -> Result<Vec<UserPosition>, MoneyMarketError> {
+        let mut positions = vec![];
+        for (entry, contract_addr) in self.contracts(&deps.querier, ans_host)? {
+            if let Some(lending_asset) = entry.contract.strip_prefix("vault/") {
+                let asset = ans_host.query_asset(&deps.querier, &AssetEntry::new(lending_asset))?;
+                let receipt_amount =
+                    self.user_deposit(deps, contract_addr.clone(), user.clone(), asset.clone())?;
+                // Receipt tokens are converted back to the lent asset
+                let status: receipt_vault::query::StatusResponse = deps
+                    .querier
+                    .query_wasm_smart(&contract_addr, &receipt_vault::query::QueryMsg::Status {})?;
+                positions.push(UserPosition {
+                    kind: PositionKind::Deposit,
+                    asset: Asset::new(
+                        asset,
+                        receipt_amount.mul_floor(status.deposit_redemption_ratio),
+                    ),
+                });
+            } else if let Some((borrowed_asset, collateral_asset)) = entry
+                .contract
+                .strip_prefix("market/")
+                .and_then(|assets| assets.split_once('/'))
+            {
+                let borrowed_asset =
+                    ans_host.query_asset(&deps.querier, &AssetEntry::new(borrowed_asset))?;
+                let collateral_asset =
+                    ans_host.query_asset(&deps.querier, &AssetEntry::new(collateral_asset))?;
+                let collateral = self.user_collateral(
+                    deps,
+                    contract_addr.clone(),
+                    user.clone(),
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                )?;
+                let borrow = self.user_borrow(
+                    deps,
+                    contract_addr,
+                    user.clone(),
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                )?;
+                positions.push(UserPosition {
+                    kind: PositionKind::Collateral,
+                    asset: Asset::new(collateral_asset, collateral),
+                });
+                positions.push(UserPosition {
+                    kind: PositionKind::Borrow,
+                    asset: Asset::new(borrowed_asset, borrow),
+                });
+            }
+        }
+
+        Ok(positions)
+    }
+
     fn current_ltv(
         &self,
         deps: Deps,
@@ -278,6 +383,18 @@ impl MoneyMarketCommand for Ghost {
         Ok(query_response.max_ltv)
     }
 
+    fn liquidation_ltv(
+        &self,
+        deps: Deps,
+        market_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        // Positions are liquidated as soon as they exceed the max LTV of the market
+        self.max_ltv(deps, market_addr, user, borrowed_asset, collateral_asset)
+    }
+
     fn lending_address(
         &self,
         querier: &QuerierWrapper,
@@ -359,6 +476,46 @@ impl Ghost {
         ans_host.query_contract(querier, &market_contract)
     }
 
+    /// All the ghost contracts registered in the ANS
+    fn contracts(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+    ) -> Result<Vec<ContractMapEntry>, MoneyMarketError> {
+        let mut contracts = vec![];
+        // Contracts are ordered by protocol, start right before the first ghost entry
+        let mut start_after = Some(ContractEntry {
+            protocol: self.name().to_string(),
+            contract: String::new(),
+        });
+        loop {
+            let response: ContractListResponse = querier.query_wasm_smart(
+                &ans_host.address,
+                &ans_host::QueryMsg::ContractList {
+                    filter: None,
+                    start_after: start_after.take(),
+                    limit: None,
+                },
+            )?;
+            let Some((last, _)) = response.contracts.last() else {
+                break;
+            };
+            let done = last.protocol != self.name();
+            start_after = Some(last.clone());
+            contracts.extend(
+                response
+                    .contracts
+                    .into_iter()
+                    .take_while(|(entry, _)| entry.protocol == self.name()),
+            );
+            if done {
+                break;
+            }
+        }
+
+        Ok(contracts)
+    }
+
     fn exchange_rate(
         &self,
         querier: &QuerierWrapper,
@@ -21,7 +21,9 @@ impl Identify for Mars {
 
 #[cfg(feature = "full_integration")]
 use {
-    abstract_money_market_standard::{MoneyMarketCommand, MoneyMarketError},
+    abstract_money_market_standard::{
+        msg::PositionKind, MoneyMarketCommand, MoneyMarketError, PositionChange, UserPosition,
+    },
     abstract_sdk::{
         feature_objects::AnsHost,
         std::objects::{ans_host::AnsHostError, AssetEntry, ContractEntry},
@@ -140,6 +142,25 @@ impl MoneyMarketCommand for Mars {
         Ok(vec![msg.into()])
     }
 
+    fn liquidate(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        debt_asset: Asset,
+        seize_asset: AssetInfo,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let vault_msg = mars_red_bank_types::red_bank::ExecuteMsg::Liquidate {
+            user: user.to_string(),
+            collateral_denom: unwrap_native(seize_asset)?,
+            recipient: None,
+        };
+
+        let msg = wasm_execute(contract_addr, &vault_msg, vec![debt_asset.try_into()?])?;
+
+        Ok(vec![msg.into()])
+    }
+
     fn price(
         &self,
         deps: Deps,
@@ -221,6 +242,65 @@ impl MoneyMarketCommand for Mars {
         Ok(query_response.amount)
     }
 
+    fn user_positions(
+        &self,
+        deps: Deps,
+        ans_host: &AnsHost,
+        user: Addr,
+    ) -> Result<Vec<UserPosition>, MoneyMarketError> {
+        let red_bank = self.red_bank(&deps.querier, ans_host)?;
+        let mut positions = vec![];
+
+        // Deposits on mars are collateral, they only count towards borrowing power when enabled
+        let mut start_after = None;
+        loop {
+            let collaterals: Vec<mars_red_bank_types::red_bank::UserCollateralResponse> =
+                deps.querier.query_wasm_smart(
+                    &red_bank,
+                    &mars_red_bank_types::red_bank::QueryMsg::UserCollaterals {
+                        user: user.to_string(),
+                        start_after: start_after.take(),
+                        limit: None,
+                    },
+                )?;
+            let Some(last) = collaterals.last() else {
+                break;
+            };
+            start_after = Some(last.denom.clone());
+            positions.extend(collaterals.into_iter().map(|collateral| UserPosition {
+                kind: if collateral.enabled {
+                    PositionKind::Collateral
+                } else {
+                    PositionKind::Deposit
+                },
+                asset: Asset::native(collateral.denom, collateral.amount),
+            }));
+        }
+
+        let mut start_after = None;
+        loop {
+            let debts: Vec<mars_red_bank_types::red_bank::UserDebtResponse> =
+                deps.querier.query_wasm_smart(
+                    &red_bank,
+                    &mars_red_bank_types::red_bank::QueryMsg::UserDebts {
+                        user: user.to_string(),
+                        start_after: start_after.take(),
+                        limit: None,
+                    },
+                )?;
+            let Some(last) = debts.last() else {
+                break;
+            };
+            start_after = Some(last.denom.clone());
+            positions.extend(debts.into_iter().map(|debt| UserPosition {
+                kind: PositionKind::Borrow,
+                asset: Asset::native(debt.denom, debt.amount),
+            }));
+        }
+
+        Ok(positions)
+    }
+
     fn current_ltv(
         &self,
         deps: Deps,
@@ -271,6 +351,64 @@ impl MoneyMarketCommand for Mars {
         ))
     }
 
+    fn liquidation_ltv(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo, // LTV is global on Mars and doesn't depend on borrowing asset
+        _collateral_asset: AssetInfo, // LTV is global on Mars and doesn't depend on collateral asset
+    ) -> Result<Decimal, MoneyMarketError> {
+        let query_response = self.user_position(deps, contract_addr, user)?;
+
+        if query_response.total_enabled_collateral.is_zero() {
+            return Ok(Decimal::zero());
+        }
+
+        Ok(Decimal::from_ratio(
+            query_response.weighted_liquidation_threshold_collateral,
+            query_response.total_enabled_collateral,
+        ))
+    }
+
+    fn simulate_ltv(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+        change: PositionChange,
+    ) -> Result<Decimal, MoneyMarketError> {
+        // LTV is global on Mars, so the change is applied to the value of the whole position
+        let query_response = self.user_position(deps, contract_addr, user)?;
+        let mut collateral = query_response.total_enabled_collateral;
+        let mut debt = query_response.total_collateralized_debt;
+
+        match change {
+            PositionChange::ProvideCollateral(amount) => {
+                collateral =
+                    collateral.checked_add(self.value(deps, collateral_asset, amount)?)?
+            }
+            PositionChange::WithdrawCollateral(amount) => {
+                collateral =
+                    collateral.saturating_sub(self.value(deps, collateral_asset, amount)?)
+            }
+            PositionChange::Borrow(amount) => {
+                debt = debt.checked_add(self.value(deps, borrowed_asset, amount)?)?
+            }
+            PositionChange::Repay(amount) => {
+                debt = debt.saturating_sub(self.value(deps, borrowed_asset, amount)?)
+            }
+        }
+
+        if collateral.is_zero() {
+            return Ok(Decimal::zero());
+        }
+
+        Ok(Decimal::from_ratio(debt, collateral))
+    }
+
     fn lending_address(
         &self,
         querier: &QuerierWrapper,
@@ -333,6 +471,37 @@ impl Mars {
             .query_contract(querier, &contract_entry)
             .map_err(Into::into)
     }
+
+    fn user_position(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+    ) -> Result<mars_red_bank_types::red_bank::UserPositionResponse, MoneyMarketError> {
+        let market_msg = mars_red_bank_types::red_bank::QueryMsg::UserPosition {
+            user: user.to_string(),
+        };
+
+        Ok(deps.querier.query_wasm_smart(contract_addr, &market_msg)?)
+    }
+
+    /// Value of an amount of asset in the base denom of the oracle, which is used for user positions
+    fn value(
+        &self,
+        deps: Deps,
+        asset: AssetInfo,
+        amount: Uint128,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let oracle_contract = &self.oracle_contract.clone().unwrap();
+        let price: mars_red_bank_types::oracle::PriceResponse = deps.querier.query_wasm_smart(
+            oracle_contract,
+            &mars_red_bank_types::oracle::QueryMsg::Price {
+                denom: unwrap_native(asset)?,
+            },
+        )?;
+
+        Ok(amount.mul_floor(price.price))
+    }
 }
 
 #[cfg(feature = "full_integration")]
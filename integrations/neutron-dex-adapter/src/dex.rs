@@ -19,14 +19,27 @@ impl Identify for Neutron {
 
 #[cfg(feature = "full_integration")]
 use ::{
-    abstract_dex_standard::{DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode},
+    abstract_dex_standard::{
+        msg::LimitOrder, DexCommand, DexError, Fee, FeeOnInput, Return, Spread, SwapNode,
+    },
     abstract_sdk::feature_objects::{AnsHost, RegistryContract},
     abstract_sdk::std::objects::PoolAddress,
-    cosmwasm_std::{CosmosMsg, Decimal, Deps},
+    cosmwasm_std::{CosmosMsg, Decimal, Decimal256, Deps, StdError, StdResult, Uint128},
     cw_asset::{Asset, AssetInfo},
-    neutron_std::types::neutron::dex::{MsgMultiHopSwap, MultiHopRoute},
+    neutron_std::types::{
+        cosmos::base::query::v1beta1::PageRequest,
+        neutron::dex::{
+            DexQuerier, LimitOrderTrancheUser, LimitOrderType, MsgCancelLimitOrder,
+            MsgMultiHopSwap, MsgPlaceLimitOrder, MsgWithdrawFilledLimitOrder, MultiHopRoute,
+        },
+    },
+    std::str::FromStr,
 };
 
+/// Highest tick index of the Neutron dex, 1.0001^559680 is above the range of [`Decimal`] prices
+#[cfg(feature = "full_integration")]
+const MAX_TICK_INDEX: u32 = 559_680;
+
 #[cfg(feature = "full_integration")]
 impl DexCommand for Neutron {
     fn fetch_data(
@@ -136,4 +149,305 @@ impl DexCommand for Neutron {
     ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError> {
         unimplemented!();
     }
+
+    fn place_limit_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let sender = self
+            .addr_as_sender
+            .as_ref()
+            .expect("no local account")
+            .to_string();
+        let order_msg = MsgPlaceLimitOrder {
+            creator: sender.clone(),
+            receiver: sender,
+            token_in: offer_asset.info.inner(),
+            token_out: ask_asset.inner(),
+            tick_index_in_to_out: tick_index_in_to_out(price)?,
+            amount_in: offer_asset.amount.to_string(),
+            order_type: LimitOrderType::GoodTilCancelled as i32,
+            expiration_time: None,
+            max_amount_out: None,
+            limit_sell_price: None,
+            min_average_sell_price: None,
+        };
+        Ok(vec![order_msg.into()])
+    }
+
+    fn cancel_limit_order(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        order_id: String,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let cancel_msg = MsgCancelLimitOrder {
+            creator: self
+                .addr_as_sender
+                .as_ref()
+                .expect("no local account")
+                .to_string(),
+            tranche_key: order_id,
+        };
+        Ok(vec![cancel_msg.into()])
+    }
+
+    fn withdraw_filled_orders(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        order_ids: Vec<String>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        let sender = self
+            .addr_as_sender
+            .as_ref()
+            .expect("no local account")
+            .to_string();
+        // Tranches are withdrawn one by one
+        Ok(order_ids
+            .into_iter()
+            .map(|tranche_key| {
+                MsgWithdrawFilledLimitOrder {
+                    creator: sender.clone(),
+                    tranche_key,
+                }
+                .into()
+            })
+            .collect())
+    }
+
+    fn open_orders(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        owner: Addr,
+    ) -> Result<Vec<LimitOrder>, DexError> {
+        let querier = DexQuerier::new(&deps.querier);
+        let pool_denoms = pool_denoms(&querier, pool_id)?;
+
+        let mut orders = vec![];
+        let mut pagination = None;
+        loop {
+            let response =
+                querier.limit_order_tranche_user_all_by_address(owner.to_string(), pagination)?;
+            for order in response.limit_orders {
+                if let Some(order) = open_order(order, &pool_denoms)? {
+                    orders.push(order);
+                }
+            }
+            match response.pagination {
+                Some(page) if !page.next_key.is_empty() => {
+                    pagination = Some(PageRequest {
+                        key: page.next_key,
+                        ..Default::default()
+                    })
+                }
+                _ => break,
+            }
+        }
+        Ok(orders)
+    }
+}
+
+/// Denoms of the pair of the pool
+#[cfg(feature = "full_integration")]
+fn pool_denoms(
+    querier: &DexQuerier<'_, cosmwasm_std::Empty>,
+    pool_id: PoolAddress,
+) -> Result<[String; 2], DexError> {
+    let pool_id = pool_id.expect_id()?;
+    let pair = querier
+        .pool_by_id(pool_id)?
+        .pool
+        .and_then(|pool| pool.lower_tick0.or(pool.upper_tick1))
+        .and_then(|reserves| reserves.key)
+        .and_then(|key| key.trade_pair_id)
+        .ok_or_else(|| StdError::not_found(format!("neutron pool {pool_id}")))?;
+    Ok([pair.maker_denom, pair.taker_denom])
+}
+
+/// Limit order of the tranche, if it was placed on the pair of the pool
+#[cfg(feature = "full_integration")]
+fn open_order(
+    order: LimitOrderTrancheUser,
+    pool_denoms: &[String; 2],
+) -> StdResult<Option<LimitOrder>> {
+    let Some(pair) = order.trade_pair_id else {
+        return Ok(None);
+    };
+    if !pool_denoms.contains(&pair.maker_denom) || !pool_denoms.contains(&pair.taker_denom) {
+        return Ok(None);
+    }
+    // Neutron doesn't report the filled part of a tranche, so the offered amount
+    // includes the filled amount that is not withdrawn yet
+    let remaining = Uint128::from_str(&order.shares_owned)?
+        .checked_sub(Uint128::from_str(&order.shares_withdrawn)?)?
+        .checked_sub(Uint128::from_str(&order.shares_cancelled)?)?;
+    Ok(Some(LimitOrder {
+        order_id: order.tranche_key,
+        offer_asset: Asset::native(pair.maker_denom, remaining).into(),
+        ask_asset: AssetInfo::native(pair.taker_denom).into(),
+        filled_amount: None,
+    }))
+}
+
+/// Tick of a limit order that sells at `price` token out per token in.
+/// The price of a tick is 1.0001^-tick, the tick is rounded so the order never sells below `price`.
+#[cfg(feature = "full_integration")]
+fn tick_index_in_to_out(price: Decimal) -> Result<i64, DexError> {
+    if price.is_zero() {
+        return Err(DexError::ZeroLimitOrderPrice);
+    }
+    let price = Decimal256::from(price);
+    if price >= Decimal256::one() {
+        let ticks = first_tick(|factor| factor >= price)?;
+        Ok(-i64::from(ticks))
+    } else {
+        let inverse = Decimal256::one() / price;
+        let ticks = first_tick(|factor| factor > inverse)? - 1;
+        Ok(i64::from(ticks))
+    }
+}
+
+/// First number of ticks whose price factor 1.0001^ticks matches the predicate
+#[cfg(feature = "full_integration")]
+fn first_tick(predicate: impl Fn(Decimal256) -> bool) -> StdResult<u32> {
+    let base = Decimal256::from_str("1.0001")?;
+    let (mut low, mut high) = (0, MAX_TICK_INDEX);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if predicate(base.checked_pow(mid)?) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use abstract_dex_standard::tests::{expect_eq, DexCommandTester};
+    use abstract_sdk::std::objects::PoolAddress;
+    use cosmwasm_std::{Addr, Decimal};
+    use cw_asset::{Asset, AssetInfo};
+    use cw_orch::daemon::networks::PION_1;
+    use neutron_std::types::neutron::dex::{
+        LimitOrderTrancheUser, LimitOrderType, MsgPlaceLimitOrder, TradePairId,
+    };
+
+    use super::{open_order, tick_index_in_to_out, Neutron};
+    use abstract_dex_standard::{msg::LimitOrder, DexError};
+
+    const NTRN: &str = "untrn";
+    const USDC: &str = "uusdc";
+    const OWNER: &str = "neutron1owner";
+
+    fn create_setup() -> DexCommandTester {
+        DexCommandTester::new(
+            PION_1.into(),
+            Neutron {
+                addr_as_sender: Some(Addr::unchecked(OWNER)),
+            },
+        )
+    }
+
+    fn tranche(maker_denom: &str, taker_denom: &str) -> LimitOrderTrancheUser {
+        LimitOrderTrancheUser {
+            trade_pair_id: Some(TradePairId {
+                maker_denom: maker_denom.to_owned(),
+                taker_denom: taker_denom.to_owned(),
+            }),
+            tranche_key: "tranche".to_owned(),
+            address: OWNER.to_owned(),
+            shares_owned: "1000".to_owned(),
+            shares_withdrawn: "100".to_owned(),
+            shares_cancelled: "200".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ticks_of_prices() {
+        // 1.0001^-tick token out per token in, rounded to never sell below the price
+        assert_eq!(tick_index_in_to_out(Decimal::one()).unwrap(), 0);
+        assert_eq!(tick_index_in_to_out(Decimal::percent(200)).unwrap(), -6932);
+        assert_eq!(tick_index_in_to_out(Decimal::percent(50)).unwrap(), 6931);
+        assert_eq!(
+            tick_index_in_to_out(Decimal::percent(1000)).unwrap(),
+            -23028
+        );
+        assert_eq!(tick_index_in_to_out(Decimal::percent(10)).unwrap(), 23027);
+        assert_eq!(
+            tick_index_in_to_out(Decimal::zero()).unwrap_err(),
+            DexError::ZeroLimitOrderPrice
+        );
+    }
+
+    #[test]
+    fn place_limit_order() {
+        let msgs = create_setup()
+            .test_place_limit_order(
+                PoolAddress::Id(1),
+                Asset::native(NTRN, 100_000u128),
+                AssetInfo::native(USDC),
+                Decimal::from_str("0.5").unwrap(),
+            )
+            .unwrap();
+
+        expect_eq(
+            vec![MsgPlaceLimitOrder {
+                creator: OWNER.to_owned(),
+                receiver: OWNER.to_owned(),
+                token_in: NTRN.to_owned(),
+                token_out: USDC.to_owned(),
+                tick_index_in_to_out: 6931,
+                amount_in: "100000".to_owned(),
+                order_type: LimitOrderType::GoodTilCancelled as i32,
+                expiration_time: None,
+                max_amount_out: None,
+                limit_sell_price: None,
+                min_average_sell_price: None,
+            }
+            .into()],
+            msgs,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn open_orders_of_pool() {
+        let pool_denoms = [NTRN.to_owned(), USDC.to_owned()];
+
+        // Offered shares that are not withdrawn or cancelled
+        let order = open_order(tranche(USDC, NTRN), &pool_denoms).unwrap();
+        assert_eq!(
+            order,
+            Some(LimitOrder {
+                order_id: "tranche".to_owned(),
+                offer_asset: Asset::native(USDC, 700u128).into(),
+                ask_asset: AssetInfo::native(NTRN).into(),
+                filled_amount: None,
+            })
+        );
+
+        // Orders on other pairs are skipped
+        let order = open_order(tranche(USDC, "uatom"), &pool_denoms).unwrap();
+        assert_eq!(order, None);
+        let order = open_order(
+            LimitOrderTrancheUser {
+                trade_pair_id: None,
+                ..tranche(USDC, NTRN)
+            },
+            &pool_denoms,
+        )
+        .unwrap();
+        assert_eq!(order, None);
+    }
 }
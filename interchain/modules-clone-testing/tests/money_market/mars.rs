@@ -133,6 +133,20 @@ mod osmosis_tests {
         Ok(())
     }
 
+    #[test]
+    fn flash_loan() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_flash_loan()?;
+        Ok(())
+    }
+
+    #[test]
+    fn flash_loan_not_repaid() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_flash_loan_not_repaid()?;
+        Ok(())
+    }
+
     // Queries
     #[test]
     fn price() -> anyhow::Result<()> {
@@ -154,6 +168,27 @@ mod osmosis_tests {
         mm_tester.test_max_ltv()?;
         Ok(())
     }
+
+    #[test]
+    fn user_health() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_user_health()?;
+        Ok(())
+    }
+
+    #[test]
+    fn simulate_ltv() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_simulate_ltv()?;
+        Ok(())
+    }
+
+    #[test]
+    fn user_positions() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_user_positions()?;
+        Ok(())
+    }
 }
 mod neutron_tests {
     use super::*;
@@ -233,6 +268,20 @@ mod neutron_tests {
         Ok(())
     }
 
+    #[test]
+    fn flash_loan() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_flash_loan()?;
+        Ok(())
+    }
+
+    #[test]
+    fn flash_loan_not_repaid() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_flash_loan_not_repaid()?;
+        Ok(())
+    }
+
     // Queries
     #[test]
     fn price() -> anyhow::Result<()> {
@@ -254,4 +303,25 @@ mod neutron_tests {
         mm_tester.test_max_ltv()?;
         Ok(())
     }
+
+    #[test]
+    fn user_health() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_user_health()?;
+        Ok(())
+    }
+
+    #[test]
+    fn simulate_ltv() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_simulate_ltv()?;
+        Ok(())
+    }
+
+    #[test]
+    fn user_positions() -> anyhow::Result<()> {
+        let mm_tester = setup()?;
+        mm_tester.test_user_positions()?;
+        Ok(())
+    }
 }
@@ -1,19 +1,149 @@
-use abstract_adapter::std::registry::Account;
+use abstract_adapter::std::{
+    ans_host::{self, ContractListResponse},
+    registry::Account,
+};
 
 use abstract_adapter::sdk::{
     features::{AbstractNameService, AbstractRegistryAccess},
-    std::objects::AssetEntry,
-    Execution,
+    std::objects::{AnsEntryConvertor, AssetEntry, ContractEntry},
+    Execution, ModuleInterface, Resolve,
+};
+use abstract_dex_standard::DEX_ADAPTER_ID;
+use abstract_staking_standard::{
+    msg::{ProviderClaims, ProviderFailure, StakingAction},
+    CwStakingCommand, CwStakingError,
 };
-use abstract_staking_standard::{msg::StakingAction, CwStakingCommand, CwStakingError};
-use cosmwasm_std::{DepsMut, Env, SubMsg};
+use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Env, ReplyOn, StdResult, SubMsg};
+use cw_asset::{Asset, AssetInfo};
+
+use crate::{
+    resolver,
+    state::{CompoundState, COMPOUND},
+};
+
+pub const COMPOUND_CLAIM_REPLY_ID: u64 = 1;
+pub const COMPOUND_SWAP_REPLY_ID: u64 = 2;
+pub const COMPOUND_PROVIDE_REPLY_ID: u64 = 3;
 
-impl<T> CwStakingAdapter for T where T: AbstractNameService + AbstractRegistryAccess + Execution {}
+impl<T> CwStakingAdapter for T where
+    T: AbstractNameService + AbstractRegistryAccess + Execution + ModuleInterface
+{
+}
 
 /// Trait for dispatching *local* staking actions to the appropriate provider
 /// Resolves the required data for that provider
 /// Identifies an Adapter as a Staking Adapter
-pub trait CwStakingAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
+pub trait CwStakingAdapter:
+    AbstractNameService + AbstractRegistryAccess + Execution + ModuleInterface
+{
+    /// Matured unbonding claims of the staker on the staking contracts of a page of ANS contract entries.
+    /// The providers are returned with their data fetched for the staking token of the claims.
+    /// Staking contracts whose claims can't be queried are skipped and reported.
+    fn claimable(
+        &self,
+        deps: Deps,
+        env: &Env,
+        staker: &Addr,
+        start_after: Option<ContractEntry>,
+        limit: Option<u8>,
+    ) -> Result<ClaimablePage, CwStakingError> {
+        let ans_host = self.ans_host(deps)?;
+        let registry_contract = self.abstract_registry(deps)?;
+
+        let ContractListResponse { contracts } = deps.querier.query_wasm_smart(
+            &ans_host.address,
+            &ans_host::QueryMsg::ContractList {
+                filter: None,
+                start_after,
+                limit,
+            },
+        )?;
+        let mut page = ClaimablePage {
+            claimable: vec![],
+            failed: vec![],
+            last_entry: contracts.last().map(|(entry, _)| entry.clone()),
+        };
+        for (entry, _) in contracts {
+            let Some(staking_token) = entry.contract.strip_prefix("staking/") else {
+                continue;
+            };
+            let staking_token = AssetEntry::new(staking_token);
+            let claims =
+                resolver::resolve_local_provider(&entry.protocol).and_then(|mut provider| {
+                    provider.fetch_data(
+                        deps,
+                        env.clone(),
+                        Some(staker.clone()),
+                        &ans_host,
+                        registry_contract.clone(),
+                        vec![staking_token.clone()],
+                    )?;
+                    let claims = provider
+                        .query_claimable(&deps.querier, &env.block, staker.clone())?
+                        .claims
+                        .concat();
+                    Ok((provider, claims))
+                });
+            match claims {
+                Ok((_, claims)) if claims.is_empty() => {}
+                Ok((provider, claims)) => page.claimable.push((
+                    provider,
+                    ProviderClaims {
+                        provider: entry.protocol,
+                        staking_token,
+                        claims,
+                    },
+                )),
+                Err(error) => page.failed.push(ProviderFailure {
+                    provider: entry.protocol,
+                    staking_token,
+                    error: error.to_string(),
+                }),
+            }
+        }
+        Ok(page)
+    }
+
+    /// Claim the matured unbonding claims of the account on the staking contracts of a page of ANS contract entries
+    fn claim_all(
+        &self,
+        deps: Deps,
+        env: &Env,
+        target_account: &Account,
+        start_after: Option<ContractEntry>,
+        limit: Option<u8>,
+    ) -> Result<SubMsg, CwStakingError> {
+        let mut msgs = vec![];
+        let page = self.claimable(deps, env, target_account.addr(), start_after, limit)?;
+        for (provider, _) in page.claimable {
+            msgs.extend(provider.claim(deps)?);
+        }
+
+        self.executor(deps)
+            .execute(msgs)
+            .map(SubMsg::new)
+            .map_err(Into::into)
+    }
+
+    /// Fetch the data of the provider for the staking assets of the account
+    fn fetch_provider_data(
+        &self,
+        deps: Deps,
+        env: &Env,
+        target_account: &Account,
+        provider: &mut Box<dyn CwStakingCommand>,
+        staking_assets: Vec<AssetEntry>,
+    ) -> Result<(), CwStakingError> {
+        provider.fetch_data(
+            deps,
+            env.clone(),
+            Some(target_account.addr().clone()),
+            &self.ans_host(deps)?,
+            self.abstract_registry(deps)?,
+            staking_assets,
+        )
+    }
+
     /// resolve the provided staking action on a local provider
     fn resolve_staking_action(
         &self,
@@ -23,28 +153,115 @@ pub trait CwStakingAdapter: AbstractNameService + AbstractRegistryAccess + Execu
         action: StakingAction,
         mut provider: Box<dyn CwStakingCommand>,
     ) -> Result<SubMsg, CwStakingError> {
-        let staking_asset = staking_assets_from_action(&action);
-
-        provider.fetch_data(
-            deps.as_ref(),
-            env.clone(),
-            Some(target_account.into_addr()),
-            &self.ans_host(deps.as_ref())?,
-            self.abstract_registry(deps.as_ref())?,
-            staking_asset,
-        )?;
-
         let msgs = match action {
             StakingAction::Stake {
                 assets,
                 unbonding_period,
-            } => provider.stake(deps.as_ref(), assets, unbonding_period)?,
+            } => {
+                let staking_assets = assets.iter().map(|asset| asset.name.clone()).collect();
+                self.fetch_provider_data(
+                    deps.as_ref(),
+                    &env,
+                    &target_account,
+                    &mut provider,
+                    staking_assets,
+                )?;
+                provider.stake(deps.as_ref(), assets, unbonding_period)?
+            }
             StakingAction::Unstake {
                 assets,
                 unbonding_period,
-            } => provider.unstake(deps.as_ref(), assets, unbonding_period)?,
-            StakingAction::ClaimRewards { assets: _ } => provider.claim_rewards(deps.as_ref())?,
-            StakingAction::Claim { assets: _ } => provider.claim(deps.as_ref())?,
+            } => {
+                let staking_assets = assets.iter().map(|asset| asset.name.clone()).collect();
+                self.fetch_provider_data(
+                    deps.as_ref(),
+                    &env,
+                    &target_account,
+                    &mut provider,
+                    staking_assets,
+                )?;
+                provider.unstake(deps.as_ref(), assets, unbonding_period)?
+            }
+            StakingAction::ClaimRewards { assets } => {
+                self.fetch_provider_data(
+                    deps.as_ref(),
+                    &env,
+                    &target_account,
+                    &mut provider,
+                    assets,
+                )?;
+                provider.claim_rewards(deps.as_ref())?
+            }
+            StakingAction::Claim { assets } => {
+                self.fetch_provider_data(
+                    deps.as_ref(),
+                    &env,
+                    &target_account,
+                    &mut provider,
+                    assets,
+                )?;
+                provider.claim(deps.as_ref())?
+            }
+            // Not bound to the provider of the request
+            StakingAction::ClaimAll { start_after, limit } => {
+                return self.claim_all(deps.as_ref(), &env, &target_account, start_after, limit);
+            }
+            StakingAction::Compound {
+                asset,
+                dex,
+                max_spread,
+                unbonding_period,
+            } => {
+                self.fetch_provider_data(
+                    deps.as_ref(),
+                    &env,
+                    &target_account,
+                    &mut provider,
+                    vec![asset.clone()],
+                )?;
+                // Fail before claiming when the rewards can't be swapped
+                self.modules(deps.as_ref())
+                    .module_address(DEX_ADAPTER_ID)
+                    .map_err(|_| CwStakingError::DexAdapterNotInstalled {})?;
+                ensure!(
+                    !COMPOUND.exists(deps.storage),
+                    CwStakingError::CompoundInProgress {}
+                );
+                let ans_host = self.ans_host(deps.as_ref())?;
+
+                let targets = compound_targets(&asset, &dex);
+                let mut tracked: Vec<AssetInfo> = targets.resolve(&deps.querier, &ans_host)?;
+                tracked.push(asset.resolve(&deps.querier, &ans_host)?);
+                tracked.extend(provider.query_rewards(&deps.querier)?.tokens.concat());
+                let mut balances: Vec<Asset> = vec![];
+                for info in tracked {
+                    if balances.iter().any(|balance| balance.info == info) {
+                        continue;
+                    }
+                    let amount = info.query_balance(&deps.querier, target_account.addr())?;
+                    balances.push(Asset::new(info, amount));
+                }
+
+                COMPOUND.save(
+                    deps.storage,
+                    &CompoundState {
+                        provider: provider.name().to_string(),
+                        account: target_account,
+                        asset,
+                        dex,
+                        max_spread,
+                        unbonding_period,
+                        targets,
+                        balances,
+                        rewards: vec![],
+                    },
+                )?;
+                let msgs = provider.claim_rewards(deps.as_ref())?;
+                return self
+                    .executor(deps.as_ref())
+                    .execute_with_reply(msgs, ReplyOn::Success, COMPOUND_CLAIM_REPLY_ID)
+                    .map_err(Into::into);
+            }
         };
 
         self.executor(deps.as_ref())
@@ -54,22 +271,36 @@ pub trait CwStakingAdapter: AbstractNameService + AbstractRegistryAccess + Execu
     }
 }
 
-#[inline(always)]
-fn staking_assets_from_action(action: &StakingAction) -> Vec<AssetEntry> {
-    match action {
-        StakingAction::Stake {
-            assets: staking_tokens,
-            ..
-        } => staking_tokens.iter().map(|req| req.name.clone()).collect(),
-        StakingAction::Unstake {
-            assets: staking_tokens,
-            ..
-        } => staking_tokens.iter().map(|req| req.name.clone()).collect(),
-        StakingAction::ClaimRewards {
-            assets: staking_tokens,
-        } => staking_tokens.clone(),
-        StakingAction::Claim {
-            assets: staking_token,
-        } => staking_token.clone(),
+/// Matured unbonding claims on the staking contracts of a page of ANS contract entries
+pub struct ClaimablePage {
+    /// Providers with claims, with their data fetched for the staking token of the claims
+    pub claimable: Vec<(Box<dyn CwStakingCommand>, ProviderClaims)>,
+    /// Staking contracts whose claims couldn't be queried
+    pub failed: Vec<ProviderFailure>,
+    /// Last scanned ANS contract entry
+    pub last_entry: Option<ContractEntry>,
+}
+
+/// Assets the rewards are swapped into when compounding.
+/// The assets of the pool when the staked asset is an LP token of the dex, the staked asset otherwise.
+pub(crate) fn compound_targets(asset: &AssetEntry, dex: &str) -> Vec<AssetEntry> {
+    match AnsEntryConvertor::new(asset.clone()).lp_token() {
+        Ok(lp_token) if lp_token.dex == dex => lp_token.assets,
+        _ => vec![asset.clone()],
+    }
+}
+
+/// Amounts received by the account since the start of the compound.
+pub(crate) fn received(
+    querier: &cosmwasm_std::QuerierWrapper,
+    state: &CompoundState,
+) -> StdResult<Vec<Asset>> {
+    let mut received = vec![];
+    for before in &state.balances {
+        let balance = before.info.query_balance(querier, state.account.addr())?;
+        if balance > before.amount {
+            received.push(Asset::new(before.info.clone(), balance - before.amount));
+        }
     }
+    Ok(received)
 }
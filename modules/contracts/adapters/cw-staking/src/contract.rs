@@ -5,7 +5,10 @@ use abstract_staking_standard::{
 };
 use cosmwasm_std::{Empty, Response};
 
-use crate::{handlers, CW_STAKING_ADAPTER_ID};
+use crate::{
+    adapter::{COMPOUND_CLAIM_REPLY_ID, COMPOUND_PROVIDE_REPLY_ID, COMPOUND_SWAP_REPLY_ID},
+    handlers, CW_STAKING_ADAPTER_ID,
+};
 
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -20,7 +23,12 @@ pub type StakingResult<T = Response> = Result<T, CwStakingError>;
 pub const CW_STAKING_ADAPTER: CwStakingAdapter =
     CwStakingAdapter::new(CW_STAKING_ADAPTER_ID, CONTRACT_VERSION, None)
         .with_execute(handlers::execute_handler)
-        .with_query(handlers::query_handler);
+        .with_query(handlers::query_handler)
+        .with_replies(&[
+            (COMPOUND_CLAIM_REPLY_ID, handlers::compound_claim_reply),
+            (COMPOUND_SWAP_REPLY_ID, handlers::compound_swap_reply),
+            (COMPOUND_PROVIDE_REPLY_ID, handlers::compound_provide_reply),
+        ]);
 
 // Export the endpoints for this contract
 #[cfg(feature = "export")]
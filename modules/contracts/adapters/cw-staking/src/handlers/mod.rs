@@ -1,4 +1,9 @@
 mod execute;
 mod query;
+mod reply;
 
-pub use crate::handlers::{execute::execute_handler, query::query_handler};
+pub use crate::handlers::{
+    execute::execute_handler,
+    query::query_handler,
+    reply::{compound_claim_reply, compound_provide_reply, compound_swap_reply},
+};
@@ -1,8 +1,12 @@
 use abstract_adapter::sdk::features::{AbstractNameService, AbstractRegistryAccess};
-use abstract_staking_standard::{msg::StakingQueryMsg, CwStakingError};
+use abstract_staking_standard::{
+    msg::{ClaimableResponse, StakingQueryMsg},
+    CwStakingError,
+};
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
 
 use crate::{
+    adapter::CwStakingAdapter as _,
     contract::{CwStakingAdapter, StakingResult},
     resolver::{self, is_over_ibc},
 };
@@ -122,5 +126,22 @@ pub fn query_handler(
                 Ok(to_json_binary(&provider.query_rewards(&deps.querier)?)?)
             }
         }
+        StakingQueryMsg::Claimable {
+            staker_address,
+            start_after,
+            limit,
+        } => {
+            let staker = deps.api.addr_validate(&staker_address)?;
+            let page = module.claimable(deps, &env, &staker, start_after, limit)?;
+            Ok(to_json_binary(&ClaimableResponse {
+                claims: page
+                    .claimable
+                    .into_iter()
+                    .map(|(_, claims)| claims)
+                    .collect(),
+                failed: page.failed,
+                last_entry: page.last_entry,
+            })?)
+        }
     }
 }
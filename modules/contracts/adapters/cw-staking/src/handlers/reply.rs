@@ -0,0 +1,219 @@
+use abstract_adapter::sdk::{
+    feature_objects::AnsHost,
+    features::{AbstractNameService, AbstractRegistryAccess, AbstractResponse},
+    std::{
+        adapter::AdapterRequestMsg,
+        objects::{AnsAsset, AssetEntry},
+    },
+    Execution, ModuleInterface, Resolve,
+};
+use abstract_dex_standard::{
+    ans_action::{DexAnsAction, WholeDexAction},
+    DEX_ADAPTER_ID,
+};
+use abstract_staking_standard::{msg::CompoundResponse, CwStakingError};
+use cosmwasm_std::{
+    to_json_binary, wasm_execute, CosmosMsg, Deps, DepsMut, Env, Reply, ReplyOn, Uint128,
+};
+use cw_asset::{Asset, AssetInfo};
+
+use crate::{
+    adapter::{received, COMPOUND_PROVIDE_REPLY_ID, COMPOUND_SWAP_REPLY_ID},
+    contract::{CwStakingAdapter, StakingResult},
+    resolver,
+    state::{CompoundState, COMPOUND},
+};
+
+/// Swaps the claimed rewards into the compound targets
+pub fn compound_claim_reply(
+    deps: DepsMut,
+    env: Env,
+    mut module: CwStakingAdapter,
+    _reply: Reply,
+) -> StakingResult {
+    let mut state = COMPOUND.load(deps.storage)?;
+    module.target_account = Some(state.account.clone());
+    let ans_host = module.ans_host(deps.as_ref())?;
+
+    state.rewards = received(&deps.querier, &state)?;
+    let staked = state.asset.resolve(&deps.querier, &ans_host)?;
+    let targets: Vec<(AssetEntry, AssetInfo)> = state
+        .targets
+        .iter()
+        .map(|target| Ok((target.clone(), target.resolve(&deps.querier, &ans_host)?)))
+        .collect::<Result<_, CwStakingError>>()?;
+
+    let mut swaps = vec![];
+    for reward in &state.rewards {
+        // Rewards in the staked asset are restaked as is
+        if reward.info == staked {
+            continue;
+        }
+        // Rewards that are not registered in the ANS can't be swapped and stay on the account
+        let Ok(reward_entry) = ans_host.query_asset_reverse(&deps.querier, &reward.info) else {
+            continue;
+        };
+        // The reward is split evenly over the targets, the share in a target asset is kept
+        let share = reward.amount.multiply_ratio(1u128, targets.len() as u128);
+        if share.is_zero() {
+            continue;
+        }
+        for (target_entry, target_info) in &targets {
+            if reward.info == *target_info {
+                continue;
+            }
+            swaps.push(dex_msg(
+                deps.as_ref(),
+                &module,
+                &ans_host,
+                &state.dex,
+                DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(reward_entry.clone(), share),
+                    ask_asset: target_entry.clone(),
+                    max_spread: state.max_spread,
+                    belief_price: None,
+                },
+            )?);
+        }
+    }
+    COMPOUND.save(deps.storage, &state)?;
+
+    if swaps.is_empty() {
+        return provide_liquidity(deps, env, module, state);
+    }
+    let sub_msg = module.executor(deps.as_ref()).execute_with_reply(
+        swaps,
+        ReplyOn::Success,
+        COMPOUND_SWAP_REPLY_ID,
+    )?;
+    Ok(module
+        .custom_response("compound_claim", vec![("provider", state.provider)])
+        .add_submessage(sub_msg))
+}
+
+/// Provides the swapped rewards as liquidity
+pub fn compound_swap_reply(
+    deps: DepsMut,
+    env: Env,
+    mut module: CwStakingAdapter,
+    _reply: Reply,
+) -> StakingResult {
+    let state = COMPOUND.load(deps.storage)?;
+    module.target_account = Some(state.account.clone());
+    provide_liquidity(deps, env, module, state)
+}
+
+/// Stakes the provided liquidity
+pub fn compound_provide_reply(
+    deps: DepsMut,
+    env: Env,
+    mut module: CwStakingAdapter,
+    _reply: Reply,
+) -> StakingResult {
+    let state = COMPOUND.load(deps.storage)?;
+    module.target_account = Some(state.account.clone());
+    stake(deps, env, module, state)
+}
+
+fn provide_liquidity(
+    deps: DepsMut,
+    env: Env,
+    module: CwStakingAdapter,
+    state: CompoundState,
+) -> StakingResult {
+    // A single target is the staked asset itself
+    if state.targets.len() < 2 {
+        return stake(deps, env, module, state);
+    }
+    let ans_host = module.ans_host(deps.as_ref())?;
+
+    let received = received(&deps.querier, &state)?;
+    let assets = state
+        .targets
+        .iter()
+        .map(|target| {
+            let info = target.resolve(&deps.querier, &ans_host)?;
+            Ok(AnsAsset::new(
+                target.clone(),
+                received_amount(&received, &info),
+            ))
+        })
+        .collect::<Result<Vec<_>, CwStakingError>>()?;
+    if assets.iter().all(|asset| asset.amount.is_zero()) {
+        return stake(deps, env, module, state);
+    }
+
+    let msg = dex_msg(
+        deps.as_ref(),
+        &module,
+        &ans_host,
+        &state.dex,
+        DexAnsAction::ProvideLiquidity {
+            assets,
+            max_spread: state.max_spread,
+        },
+    )?;
+    let sub_msg = module.executor(deps.as_ref()).execute_with_reply(
+        vec![msg],
+        ReplyOn::Success,
+        COMPOUND_PROVIDE_REPLY_ID,
+    )?;
+    Ok(module
+        .custom_response("compound_swap", vec![("provider", state.provider)])
+        .add_submessage(sub_msg))
+}
+
+fn stake(deps: DepsMut, env: Env, module: CwStakingAdapter, state: CompoundState) -> StakingResult {
+    COMPOUND.remove(deps.storage);
+    let ans_host = module.ans_host(deps.as_ref())?;
+
+    let staked_info = state.asset.resolve(&deps.querier, &ans_host)?;
+    let amount = received_amount(&received(&deps.querier, &state)?, &staked_info);
+    let staked = AnsAsset::new(state.asset.clone(), amount);
+
+    let mut response = module.custom_response("compound", vec![("provider", &state.provider)]);
+    if !amount.is_zero() {
+        let mut provider = resolver::resolve_local_provider(&state.provider)?;
+        provider.fetch_data(
+            deps.as_ref(),
+            env,
+            Some(state.account.addr().clone()),
+            &ans_host,
+            module.abstract_registry(deps.as_ref())?,
+            vec![state.asset.clone()],
+        )?;
+        let msgs = provider.stake(deps.as_ref(), vec![staked.clone()], state.unbonding_period)?;
+        response = response.add_message(module.executor(deps.as_ref()).execute(msgs)?);
+    }
+
+    Ok(response.set_data(to_json_binary(&CompoundResponse {
+        rewards: state.rewards,
+        staked,
+        max_spread: state.max_spread,
+    })?))
+}
+
+fn received_amount(received: &[Asset], info: &AssetInfo) -> Uint128 {
+    received
+        .iter()
+        .find(|asset| asset.info == *info)
+        .map(|asset| asset.amount)
+        .unwrap_or_default()
+}
+
+/// Message for the account to execute an action on its dex adapter
+fn dex_msg(
+    deps: Deps,
+    module: &CwStakingAdapter,
+    ans_host: &AnsHost,
+    dex: &str,
+    action: DexAnsAction,
+) -> StakingResult<CosmosMsg> {
+    let dex_address = module
+        .modules(deps)
+        .module_address(DEX_ADAPTER_ID)
+        .map_err(|_| CwStakingError::DexAdapterNotInstalled {})?;
+    let request = WholeDexAction(dex.to_owned(), action).resolve(&deps.querier, ans_host)?;
+    let msg = abstract_dex_standard::msg::ExecuteMsg::Module(AdapterRequestMsg::new(None, request));
+    Ok(wasm_execute(dex_address, &msg, vec![])?.into())
+}
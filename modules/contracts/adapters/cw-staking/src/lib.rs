@@ -3,6 +3,7 @@ pub mod contract;
 mod handlers;
 
 mod resolver;
+pub mod state;
 
 pub mod msg {
     pub use abstract_staking_standard::msg::*;
@@ -31,7 +32,7 @@ pub mod interface {
     use abstract_adapter::sdk::features::ModuleIdentification as _;
     use abstract_adapter::std::{
         adapter,
-        objects::{AnsAsset, AssetEntry},
+        objects::{AnsAsset, AssetEntry, ContractEntry},
     };
     use abstract_adapter::traits::Dependencies;
     use cw_orch::{build::BuildPostfix, contract::Contract, interface, prelude::*};
@@ -49,11 +50,14 @@ pub mod interface {
 
     impl<Chain: CwEnv> Uploadable for CwStakingAdapter<Chain> {
         fn wrapper() -> <Mock as TxHandler>::ContractSource {
-            Box::new(ContractWrapper::new_with_empty(
-                crate::contract::execute,
-                crate::contract::instantiate,
-                crate::contract::query,
-            ))
+            Box::new(
+                ContractWrapper::new_with_empty(
+                    crate::contract::execute,
+                    crate::contract::instantiate,
+                    crate::contract::query,
+                )
+                .with_reply(crate::contract::reply),
+            )
         }
         fn wasm(chain: &ChainInfoOwned) -> WasmPath {
             artifacts_dir_from_workspace!()
@@ -150,6 +154,37 @@ pub mod interface {
             Ok(())
         }
 
+        pub fn claim_all(
+            &self,
+            provider: String,
+            start_after: Option<ContractEntry>,
+            limit: Option<u8>,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = StakingAction::ClaimAll { start_after, limit };
+            self.staking_action(provider, action, account)?;
+            Ok(())
+        }
+
+        pub fn compound(
+            &self,
+            stake_asset: AssetEntry,
+            provider: String,
+            dex: String,
+            max_spread: Option<cosmwasm_std::Decimal>,
+            duration: Option<cw_utils::Duration>,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = StakingAction::Compound {
+                asset: stake_asset,
+                dex,
+                max_spread,
+                unbonding_period: duration,
+            };
+            self.staking_action(provider, action, account)?;
+            Ok(())
+        }
+
         pub fn claim_rewards(
             &self,
             stake_asset: AssetEntry,
@@ -0,0 +1,24 @@
+use abstract_adapter::std::{objects::AssetEntry, registry::Account};
+use cosmwasm_std::Decimal;
+use cw_asset::Asset;
+use cw_storage_plus::Item;
+use cw_utils::Duration;
+
+/// State of a compound, kept between the steps of the compound.
+#[cosmwasm_schema::cw_serde]
+pub struct CompoundState {
+    pub provider: String,
+    pub account: Account,
+    pub asset: AssetEntry,
+    pub dex: String,
+    pub max_spread: Option<Decimal>,
+    pub unbonding_period: Option<Duration>,
+    /// Assets the rewards are swapped into, the staked asset or the assets of its pool.
+    pub targets: Vec<AssetEntry>,
+    /// Balances of the account before claiming the rewards.
+    pub balances: Vec<Asset>,
+    /// Claimed rewards, set once the rewards are claimed.
+    pub rewards: Vec<Asset>,
+}
+
+pub const COMPOUND: Item<CompoundState> = Item::new("compound");
@@ -1,19 +1,27 @@
 use abstract_adapter::abstract_interface::{Abstract, AccountI, AdapterDeployer, DeployStrategy};
 use abstract_adapter::std::{
     adapter::BaseQueryMsgFns,
-    objects::{module_version::ModuleDataResponse, AnsAsset, AssetEntry},
+    ans_host::ExecuteMsgFns,
+    objects::{
+        module_version::ModuleDataResponse, pool_id::PoolAddressBase, AnsAsset, AssetEntry,
+        PoolMetadata, UncheckedContractEntry,
+    },
 };
 use abstract_client::builder::cw20_builder::{ExecuteMsgInterfaceFns, QueryMsgInterfaceFns};
 use abstract_cw_staking::{
     contract::CONTRACT_VERSION, interface::CwStakingAdapter, msg::StakingQueryMsgFns,
 };
 use abstract_staking_standard::msg::{
-    Claim, RewardTokensResponse, StakingInfo, StakingInfoResponse, UnbondingResponse,
+    Claim, ClaimableResponse, CompoundResponse, RewardTokensResponse, StakingAction, StakingInfo,
+    StakingInfoResponse, UnbondingResponse,
 };
-use cosmwasm_std::{coin, Uint128};
-use cw_asset::AssetInfoBase;
+use cosmwasm_std::{coin, from_json, Decimal, Uint128};
+use cw_asset::{Asset, AssetInfoBase};
 use cw_orch::prelude::*;
-use mockdex_bundle::{EUR_USD_LP, WYNDEX as WYNDEX_WITHOUT_CHAIN, WYNDEX_OWNER, WYND_TOKEN};
+use mockdex_bundle::{
+    EUR, EUR_USD_LP, USD, WYNDEX as WYNDEX_WITHOUT_CHAIN, WYNDEX_OWNER, WYND_TOKEN,
+};
+use wyndex::asset::{AssetInfo, AssetInfoExt};
 
 const WYNDEX: &str = "cosmos-testnet>wyndex";
 
@@ -52,6 +60,28 @@ fn setup_mock() -> anyhow::Result<(
     Ok((chain, wyndex, staking, account))
 }
 
+/// Claimable entries of the staker on all the pages of ANS contract entries
+fn all_claimable(
+    staking: &CwStakingAdapter<MockBech32>,
+    staker: &Addr,
+) -> anyhow::Result<ClaimableResponse> {
+    let mut all = ClaimableResponse {
+        claims: vec![],
+        failed: vec![],
+        last_entry: None,
+    };
+    let mut start_after = None;
+    loop {
+        let page = staking.claimable(Some(2), staker.to_string(), start_after)?;
+        all.claims.extend(page.claims);
+        all.failed.extend(page.failed);
+        let Some(last_entry) = page.last_entry else {
+            return Ok(all);
+        };
+        start_after = Some(last_entry);
+    }
+}
+
 #[test]
 fn staking_inited() -> anyhow::Result<()> {
     let (_, wyndex, staking, _) = setup_mock()?;
@@ -246,6 +276,104 @@ fn claim_unbonded_lp() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn claim_all_unbonded_lp() -> anyhow::Result<()> {
+    let (chain, wyndex, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    let dur = cw_utils::Duration::Time(2);
+
+    // stake 100 EUR
+    staking.stake(
+        AnsAsset::new(EUR_USD_LP, 100u128),
+        WYNDEX.into(),
+        Some(dur),
+        &account,
+    )?;
+
+    // now unbond 50
+    staking.unstake(
+        AnsAsset::new(EUR_USD_LP, 50u128),
+        WYNDEX.into(),
+        Some(dur),
+        &account,
+    )?;
+
+    // nothing matured yet
+    let claimable = all_claimable(&staking, &account_addr)?;
+    assert!(claimable.claims.is_empty());
+
+    // forward 5 seconds
+    chain.next_block()?;
+
+    let claimable = all_claimable(&staking, &account_addr)?;
+    assert!(claimable.failed.is_empty());
+    assert_eq!(claimable.claims.len(), 1);
+    assert_eq!(claimable.claims[0].provider, WYNDEX);
+    assert_eq!(
+        claimable.claims[0].staking_token,
+        AssetEntry::new(EUR_USD_LP)
+    );
+    assert_eq!(claimable.claims[0].claims[0].amount, Uint128::from(50u128));
+
+    // claim everything on the first page of ANS contract entries
+    staking.claim_all(WYNDEX.into(), None, Some(25), &account)?;
+
+    // query balance
+    let balance = wyndex.eur_usd_lp.balance(account_addr.to_string())?;
+    assert_eq!(balance.balance.u128(), 950u128);
+
+    let claimable = all_claimable(&staking, &account_addr)?;
+    assert!(claimable.claims.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn claimable_reports_failing_provider() -> anyhow::Result<()> {
+    let (chain, _, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+    let dur = cw_utils::Duration::Time(2);
+
+    staking.stake(
+        AnsAsset::new(EUR_USD_LP, 100u128),
+        WYNDEX.into(),
+        Some(dur),
+        &account,
+    )?;
+    staking.unstake(
+        AnsAsset::new(EUR_USD_LP, 50u128),
+        WYNDEX.into(),
+        Some(dur),
+        &account,
+    )?;
+    chain.next_block()?;
+
+    // staking contract of a provider the adapter doesn't support
+    Abstract::load_from(chain.clone())?
+        .ans_host
+        .update_contract_addresses(
+            vec![(
+                UncheckedContractEntry::new("unknown", format!("staking/{EUR_USD_LP}")),
+                chain.addr_make("unknown_staking").to_string(),
+            )],
+            vec![],
+        )?;
+
+    // the failing provider is reported without hiding the claims on the other providers
+    let claimable = all_claimable(&staking, &account_addr)?;
+    assert_eq!(claimable.claims.len(), 1);
+    assert_eq!(claimable.claims[0].provider, WYNDEX);
+    assert_eq!(claimable.failed.len(), 1);
+    assert_eq!(claimable.failed[0].provider, "unknown");
+    assert_eq!(
+        claimable.failed[0].staking_token,
+        AssetEntry::new(EUR_USD_LP)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn claim_rewards() -> anyhow::Result<()> {
     let (chain, mut wyndex, staking, account) = setup_mock()?;
@@ -279,3 +407,164 @@ fn claim_rewards() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn compound_requires_dex_adapter() -> anyhow::Result<()> {
+    let (chain, mut wyndex, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    let dur = Some(cw_utils::Duration::Time(2));
+
+    staking.stake(
+        AnsAsset::new(EUR_USD_LP, 100u128),
+        WYNDEX.into(),
+        dur,
+        &account,
+    )?;
+
+    chain.wait_blocks(100)?;
+
+    chain.set_balance(&wyndex.eur_usd_staking, vec![coin(10_000, WYND_TOKEN)])?;
+    wyndex
+        .suite
+        .distribute_funds(wyndex.eur_usd_staking, &chain.addr_make(WYNDEX_OWNER), &[])
+        .unwrap();
+
+    // The dex adapter is not installed on the account
+    let res = staking.compound(
+        AssetEntry::new(EUR_USD_LP),
+        WYNDEX.into(),
+        WYNDEX_WITHOUT_CHAIN.into(),
+        None,
+        dur,
+        &account,
+    );
+    assert!(res.is_err());
+
+    // rewards are not claimed
+    let balance = chain.query_balance(&account_addr, WYND_TOKEN)?;
+    assert_eq!(balance.u128(), 0u128);
+
+    Ok(())
+}
+
+#[test]
+fn compound_rewards() -> anyhow::Result<()> {
+    let (chain, mut wyndex, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+    let owner = chain.addr_make(WYNDEX_OWNER);
+
+    let dex_adapter = abstract_dex_adapter::interface::DexAdapter::new(
+        abstract_dex_adapter::DEX_ADAPTER_ID,
+        chain.clone(),
+    );
+    dex_adapter.deploy(
+        abstract_dex_adapter::contract::CONTRACT_VERSION.parse()?,
+        abstract_dex_adapter::msg::DexInstantiateMsg {
+            recipient_account: 0,
+            swap_fee: Decimal::percent(1),
+        },
+        DeployStrategy::Try,
+    )?;
+    account.install_adapter(&dex_adapter, &[])?;
+
+    // The rewards are swapped into both pool assets, so the wynd/usd pool mirrors the wynd/eur pool
+    let wynd_info = AssetInfo::Native(WYND_TOKEN.to_owned());
+    let usd_info = AssetInfo::Native(USD.to_owned());
+    chain.add_balance(&owner, vec![coin(10_000, WYND_TOKEN), coin(10_000, USD)])?;
+    let wynd_usd_pair = wyndex
+        .suite
+        .create_pair(
+            &owner,
+            wyndex::factory::PairType::Xyk {},
+            [wynd_info.clone(), usd_info.clone()],
+            None,
+            None,
+        )
+        .unwrap();
+    wyndex
+        .suite
+        .provide_liquidity(
+            &owner,
+            &wynd_usd_pair,
+            [
+                wynd_info.with_balance(10_000u128),
+                usd_info.with_balance(10_000u128),
+            ],
+            &[coin(10_000, WYND_TOKEN), coin(10_000, USD)],
+        )
+        .unwrap();
+    Abstract::load_from(chain.clone())?.ans_host.update_pools(
+        vec![(
+            PoolAddressBase::contract(wynd_usd_pair.to_string()),
+            PoolMetadata::constant_product(
+                WYNDEX_WITHOUT_CHAIN,
+                vec![AssetEntry::new(WYND_TOKEN), AssetEntry::new(USD)],
+            ),
+        )],
+        vec![],
+    )?;
+
+    let dur = Some(cw_utils::Duration::Time(2));
+    staking.stake(
+        AnsAsset::new(EUR_USD_LP, 100u128),
+        WYNDEX.into(),
+        dur,
+        &account,
+    )?;
+
+    chain.wait_blocks(100)?;
+
+    chain.set_balance(&wyndex.eur_usd_staking, vec![coin(10_000, WYND_TOKEN)])?;
+    wyndex
+        .suite
+        .distribute_funds(wyndex.eur_usd_staking.clone(), &owner, &[])
+        .unwrap();
+
+    let res = staking.staking_action(
+        WYNDEX.into(),
+        StakingAction::Compound {
+            asset: AssetEntry::new(EUR_USD_LP),
+            dex: WYNDEX_WITHOUT_CHAIN.into(),
+            max_spread: None,
+            unbonding_period: dur,
+        },
+        &account,
+    )?;
+
+    // claim -> swap -> provide -> stake
+    let actions: Vec<String> = res
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm-abstract")
+        .flat_map(|event| event.attributes.iter())
+        .filter(|attr| attr.key == "action" && attr.value.starts_with("compound"))
+        .map(|attr| attr.value.clone())
+        .collect();
+    assert_eq!(actions, vec!["compound_claim", "compound_swap", "compound"]);
+
+    let compound: CompoundResponse = from_json(res.data.unwrap())?;
+    assert_eq!(
+        compound.rewards,
+        vec![Asset::native(WYND_TOKEN, 10_000u128)]
+    );
+    assert_eq!(compound.staked.name, AssetEntry::new(EUR_USD_LP));
+    assert!(!compound.staked.amount.is_zero());
+    assert_eq!(compound.max_spread, None);
+
+    // All rewards are swapped and the provided liquidity is staked
+    let staked_balance = staking.staked(
+        WYNDEX.into(),
+        account_addr.to_string(),
+        vec![AssetEntry::new(EUR_USD_LP)],
+        dur,
+    )?;
+    assert_eq!(
+        staked_balance.amounts[0],
+        compound.staked.amount + Uint128::new(100)
+    );
+    assert!(chain.query_balance(&account_addr, WYND_TOKEN)?.is_zero());
+    assert!(chain.query_balance(&account_addr, EUR)?.is_zero());
+
+    Ok(())
+}
@@ -3,7 +3,10 @@ use abstract_adapter::sdk::{
     features::{AbstractNameService, AbstractRegistryAccess},
     Execution,
 };
-use abstract_adapter::std::objects::pool_id::PoolAddressBase;
+use abstract_adapter::std::{
+    objects::{fee::UsageFee, pool_id::PoolAddressBase},
+    AbstractError,
+};
 use abstract_dex_standard::{action::DexAction, msg::SwapNode, DexCommand, DexError};
 use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps};
 use cw_asset::{AssetBase, AssetInfoBase};
@@ -15,6 +18,9 @@ pub const PROVIDE_LIQUIDITY_SYM: u64 = 7543;
 pub const WITHDRAW_LIQUIDITY: u64 = 7546;
 pub const SWAP: u64 = 7544;
 pub const SWAP_ROUTE: u64 = 7545;
+pub const PLACE_LIMIT_ORDER: u64 = 7547;
+pub const CANCEL_LIMIT_ORDER: u64 = 7548;
+pub const WITHDRAW_FILLED_ORDERS: u64 = 7549;
 
 impl<T> DexAdapter for T where T: AbstractNameService + Execution + AbstractRegistryAccess {}
 
@@ -22,12 +28,14 @@ pub(crate) type ReplyId = u64;
 
 pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
     /// resolve the provided dex action on a local dex
+    /// `output_fee` leaves the swap fee to the caller, which charges it on the received ask asset
     fn resolve_dex_action(
         &self,
         deps: Deps,
         sender: Addr,
         action: DexAction,
         mut exchange: Box<dyn DexCommand>,
+        output_fee: bool,
     ) -> Result<(Vec<CosmosMsg>, ReplyId), DexError> {
         Ok(match action {
             DexAction::ProvideLiquidity {
@@ -70,6 +78,7 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                     exchange.as_mut(),
                     max_spread,
                     belief_price,
+                    output_fee,
                 )?,
                 SWAP,
             ),
@@ -90,9 +99,77 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                 )?,
                 SWAP_ROUTE,
             ),
+            DexAction::PlaceLimitOrder {
+                pool,
+                offer_asset,
+                ask_asset,
+                price,
+            } => (
+                self.resolve_place_limit_order(
+                    deps,
+                    sender,
+                    offer_asset,
+                    ask_asset,
+                    pool,
+                    exchange.as_mut(),
+                    price,
+                )?,
+                PLACE_LIMIT_ORDER,
+            ),
+            DexAction::CancelLimitOrder { pool, order_id } => {
+                let pool_address = pool.check(deps.api)?;
+                exchange.fetch_data(
+                    deps,
+                    sender,
+                    self.abstract_registry(deps)?,
+                    self.ans_host(deps)?,
+                )?;
+                (
+                    exchange.cancel_limit_order(deps, pool_address, order_id)?,
+                    CANCEL_LIMIT_ORDER,
+                )
+            }
+            DexAction::WithdrawFilledOrders { pool, order_ids } => {
+                let pool_address = pool.check(deps.api)?;
+                exchange.fetch_data(
+                    deps,
+                    sender,
+                    self.abstract_registry(deps)?,
+                    self.ans_host(deps)?,
+                )?;
+                (
+                    exchange.withdraw_filled_orders(deps, pool_address, order_ids)?,
+                    WITHDRAW_FILLED_ORDERS,
+                )
+            }
         })
     }
 
+    /// Swap usage fee of the sender, using its account or namespace fee tier if any
+    fn swap_usage_fee(&self, deps: Deps, sender: &Addr) -> Result<UsageFee, DexError> {
+        let dex_fees = DEX_FEES.load(deps.storage)?;
+        if dex_fees.fee_tiers().is_empty() {
+            return Ok(dex_fees.swap_usage_fee()?);
+        }
+        let registry = self.abstract_registry(deps)?;
+        // Senders that are not accounts (e.g. when generating messages) pay the default fee
+        let Ok(account_id) = registry.account_id(sender, &deps.querier) else {
+            return Ok(dex_fees.swap_usage_fee()?);
+        };
+        let namespaces = if dex_fees.has_namespace_tiers() {
+            registry
+                .query_namespaces(vec![account_id.clone()], &deps.querier)
+                .map_err(AbstractError::from)?
+                .namespaces
+                .into_iter()
+                .map(|(namespace, _)| namespace)
+                .collect()
+        } else {
+            vec![]
+        };
+        Ok(dex_fees.tiered_swap_usage_fee(&account_id, &namespaces)?)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn resolve_swap(
         &self,
@@ -104,15 +181,19 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
         exchange: &mut dyn DexCommand,
         max_spread: Option<Decimal>,
         belief_price: Option<Decimal>,
+        output_fee: bool,
     ) -> Result<Vec<CosmosMsg>, DexError> {
         let pool_address = pool.check(deps.api)?;
         let mut offer_asset = offer_asset.check(deps.api, None)?;
         let ask_asset = ask_asset.check(deps.api, None)?;
 
-        // account for fee
-        let dex_fees = DEX_FEES.load(deps.storage)?;
-        let usage_fee = dex_fees.swap_usage_fee()?;
-        let fee_msg = offer_asset.charge_usage_fee(usage_fee)?;
+        // account for fee, an output fee is charged on the received ask asset once the swap is executed
+        let fee_msg = if output_fee {
+            None
+        } else {
+            let usage_fee = self.swap_usage_fee(deps, &sender)?;
+            offer_asset.charge_usage_fee(usage_fee)?
+        };
 
         exchange.fetch_data(
             deps,
@@ -154,8 +235,7 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
             .collect::<abstract_adapter::std::AbstractResult<_>>()?;
 
         // account for fee
-        let dex_fees = DEX_FEES.load(deps.storage)?;
-        let usage_fee = dex_fees.swap_usage_fee()?;
+        let usage_fee = self.swap_usage_fee(deps, &sender)?;
         let fee_msg = offer_asset.charge_usage_fee(usage_fee)?;
 
         exchange.fetch_data(
@@ -174,6 +254,44 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
         Ok(swap_msgs)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_place_limit_order(
+        &self,
+        deps: Deps,
+        sender: Addr,
+        offer_asset: AssetBase<String>,
+        ask_asset: AssetInfoBase<String>,
+        pool: PoolAddressBase<String>,
+        exchange: &mut dyn DexCommand,
+        price: Decimal,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        if price.is_zero() {
+            return Err(DexError::ZeroLimitOrderPrice);
+        }
+        let pool_address = pool.check(deps.api)?;
+        let mut offer_asset = offer_asset.check(deps.api, None)?;
+        let ask_asset = ask_asset.check(deps.api, None)?;
+
+        // account for fee, same as for a swap
+        let usage_fee = self.swap_usage_fee(deps, &sender)?;
+        let fee_msg = offer_asset.charge_usage_fee(usage_fee)?;
+
+        exchange.fetch_data(
+            deps,
+            sender,
+            self.abstract_registry(deps)?,
+            self.ans_host(deps)?,
+        )?;
+        let mut order_msgs =
+            exchange.place_limit_order(deps, pool_address, offer_asset, ask_asset, price)?;
+        // insert fee msg
+        if let Some(f) = fee_msg {
+            order_msgs.push(f)
+        }
+
+        Ok(order_msgs)
+    }
+
     fn resolve_provide_liquidity(
         &self,
         deps: Deps,
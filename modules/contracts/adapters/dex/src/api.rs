@@ -10,7 +10,7 @@ use abstract_adapter::traits::AbstractNameService;
 use abstract_dex_standard::msg::GenerateMessagesResponse;
 use abstract_dex_standard::{
     action::DexAction,
-    msg::{DexExecuteMsg, DexName, DexQueryMsg, SimulateSwapResponse},
+    msg::{DexExecuteMsg, DexName, DexQueryMsg, OpenOrdersResponse, SimulateSwapResponse},
 };
 use cosmwasm_schema::serde::de::DeserializeOwned;
 use cosmwasm_std::{CosmosMsg, Decimal, Deps, Env};
@@ -145,6 +145,46 @@ pub mod raw {
                 pool: pool.into(),
             })
         }
+
+        /// Place a limit order in the DEX
+        pub fn place_limit_order(
+            &self,
+            offer_asset: Asset,
+            ask_asset: AssetInfo,
+            price: Decimal,
+            pool: PoolAddress,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(DexAction::PlaceLimitOrder {
+                offer_asset: offer_asset.into(),
+                ask_asset: ask_asset.into(),
+                price,
+                pool: pool.into(),
+            })
+        }
+
+        /// Cancel a limit order in the DEX
+        pub fn cancel_limit_order(
+            &self,
+            order_id: impl Into<String>,
+            pool: PoolAddress,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(DexAction::CancelLimitOrder {
+                order_id: order_id.into(),
+                pool: pool.into(),
+            })
+        }
+
+        /// Withdraw the filled part of limit orders from the DEX
+        pub fn withdraw_filled_orders(
+            &self,
+            order_ids: Vec<String>,
+            pool: PoolAddress,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(DexAction::WithdrawFilledOrders {
+                order_ids,
+                pool: pool.into(),
+            })
+        }
     }
 
     impl<T: DexInterface> Dex<'_, T> {
@@ -167,10 +207,25 @@ pub mod raw {
                     ask_asset: ask_asset.into(),
                     pool: pool.into(),
                     dex: self.dex_name(),
+                    account: Some(self.base.account(self.deps)?.addr().to_string()),
                 })?;
             Ok(response)
         }
 
+        /// Open limit orders of `owner` in the DEX
+        pub fn open_orders(
+            &self,
+            pool: PoolAddress,
+            owner: impl Into<String>,
+        ) -> AbstractSdkResult<OpenOrdersResponse> {
+            let response: OpenOrdersResponse = self.query(DexQueryMsg::OpenOrders {
+                dex: self.dex_name(),
+                pool: pool.into(),
+                owner: owner.into(),
+            })?;
+            Ok(response)
+        }
+
         /// Generate the raw messages that are need to run a swap
         pub fn generate_swap_messages(
             &self,
@@ -281,6 +336,20 @@ pub mod ans {
         pub fn withdraw_liquidity(&self, lp_token: AnsAsset) -> AbstractSdkResult<CosmosMsg> {
             self.execute(DexAnsAction::WithdrawLiquidity { lp_token })
         }
+
+        /// Place a limit order in the DEX
+        pub fn place_limit_order(
+            &self,
+            offer_asset: AnsAsset,
+            ask_asset: AssetEntry,
+            price: Decimal,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(DexAnsAction::PlaceLimitOrder {
+                offer_asset,
+                ask_asset,
+                price,
+            })
+        }
     }
 
     impl<T: DexInterface> AnsDex<'_, T> {
@@ -300,6 +369,7 @@ pub mod ans {
                 dex: self.dex_name(),
                 offer_asset,
                 ask_asset,
+                account: Some(self.base.account(self.deps)?.addr().to_string()),
             })?;
             Ok(response)
         }
@@ -501,4 +571,51 @@ mod test {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn place_limit_order_msg() {
+        let mut deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        let account = test_account(deps.api);
+        deps.querier = abstract_adapter::abstract_testing::abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let dex_name = "junoswap".to_string();
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let dex = stub
+            .dex(deps.as_ref(), &env, dex_name.clone())
+            .with_module_id(abstract_adapter::abstract_testing::prelude::TEST_MODULE_ID);
+
+        let offer_asset = Asset::native("ujuno", 100_000u128);
+        let ask_asset = AssetInfo::native("uusd");
+        let price = Decimal::percent(150);
+        let pool = PoolAddressBase::Id(POOL);
+
+        let expected = expected_request_with_test_account(
+            DexExecuteMsg::Action {
+                dex: dex_name,
+                action: DexAction::PlaceLimitOrder {
+                    offer_asset: offer_asset.clone().into(),
+                    ask_asset: ask_asset.clone().into(),
+                    price,
+                    pool: pool.clone().into(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = dex.place_limit_order(offer_asset, ask_asset, price, pool);
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
 }
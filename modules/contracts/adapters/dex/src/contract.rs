@@ -5,7 +5,7 @@ use abstract_dex_standard::{
 };
 use cosmwasm_std::Response;
 
-use crate::{handlers, DEX_ADAPTER_ID};
+use crate::{adapter::SWAP, handlers, DEX_ADAPTER_ID};
 
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -15,7 +15,8 @@ pub type DexResult<T = Response> = Result<T, DexError>;
 pub const DEX_ADAPTER: DexAdapter = DexAdapter::new(DEX_ADAPTER_ID, CONTRACT_VERSION, None)
     .with_instantiate(handlers::instantiate_handler)
     .with_execute(handlers::execute_handler)
-    .with_query(handlers::query_handler);
+    .with_query(handlers::query_handler)
+    .with_replies(&[(SWAP, handlers::swap_output_fee_reply)]);
 
 #[cfg(feature = "export")]
 use abstract_adapter::export_endpoints;
@@ -568,6 +568,7 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                     offer_asset: offer_asset.clone(),
                     ask_asset: ask_asset.clone(),
                     dex: self.dex.name(),
+                    account: Some(account_addr.to_string()),
                 }))?;
         // Generate swap 1_000_000_000 asset_a to asset_b
         let generate_messages: GenerateMessagesResponse = self.dex_adapter.query(
@@ -12,15 +12,18 @@ use abstract_adapter::std::{
     },
 };
 use abstract_dex_standard::{action::DexAction, msg::ExecuteMsg, DexError, DEX_ADAPTER_ID};
-use cosmwasm_std::{ensure_eq, to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    ensure_eq, to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo, ReplyOn, Response,
+};
 use cw_asset::AssetBase;
 
 use crate::{
+    adapter::SWAP,
     contract::{DexAdapter, DexResult},
     exchanges::exchange_resolver,
     handlers::execute::exchange_resolver::is_over_ibc,
     msg::{DexExecuteMsg, DexName},
-    state::DEX_FEES,
+    state::{SwapOutputFee, DEX_FEES, SWAP_OUTPUT_FEE},
 };
 
 use abstract_adapter::sdk::features::AccountIdentification;
@@ -50,6 +53,8 @@ pub fn execute_handler(
         DexExecuteMsg::UpdateFee {
             swap_fee,
             recipient_account: recipient_account_id,
+            fee_tiers,
+            fee_on_output,
         } => {
             // Only namespace owner (abstract) can change recipient address
             let namespace = module
@@ -78,6 +83,15 @@ pub fn execute_handler(
                 fee.recipient = recipient.into_addr();
             }
 
+            // Update fee tiers
+            for (key, share) in fee_tiers.unwrap_or_default() {
+                fee.set_fee_tier(key, share)?;
+            }
+
+            if let Some(fee_on_output) = fee_on_output {
+                fee.fee_on_output = fee_on_output;
+            }
+
             DEX_FEES.save(deps.storage, &fee)?;
             Ok(Response::default())
         }
@@ -94,15 +108,43 @@ fn handle_local_request(
 ) -> DexResult {
     let exchange = exchange_resolver::resolve_exchange(&exchange)?;
     let target_account = module.account(deps.as_ref())?;
+    // With `fee_on_output` the swap fee is charged on the received ask asset in the reply
+    let output_fee_asset = match &action {
+        DexAction::Swap { ask_asset, .. } if DEX_FEES.load(deps.storage)?.fee_on_output => {
+            Some(ask_asset.check(deps.api, None)?)
+        }
+        _ => None,
+    };
     let (msgs, _) = crate::adapter::DexAdapter::resolve_dex_action(
         module,
         deps.as_ref(),
-        target_account.into_addr(),
+        target_account.addr().clone(),
         action,
         exchange,
+        output_fee_asset.is_some(),
+    )?;
+
+    let Some(ask_asset) = output_fee_asset else {
+        let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
+        return Ok(Response::new().add_message(account_msg));
+    };
+    let usage_fee =
+        crate::adapter::DexAdapter::swap_usage_fee(module, deps.as_ref(), target_account.addr())?;
+    let balance_before = ask_asset.query_balance(&deps.querier, target_account.addr())?;
+    SWAP_OUTPUT_FEE.save(
+        deps.storage,
+        &SwapOutputFee {
+            account: target_account,
+            ask_asset,
+            balance_before,
+            usage_fee,
+        },
     )?;
-    let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
-    Ok(Response::new().add_message(account_msg))
+    let account_msg =
+        module
+            .executor(deps.as_ref())
+            .execute_with_reply(msgs, ReplyOn::Success, SWAP)?;
+    Ok(Response::new().add_submessage(account_msg))
 }
 
 /// Handle an adapter request that can be executed on an IBC chain
@@ -177,6 +219,8 @@ pub(crate) fn resolve_assets_to_transfer(
             coins
         }
         DexAction::WithdrawLiquidity { lp_token, .. } => Ok(vec![offer_to_coin(lp_token)?]),
+        DexAction::PlaceLimitOrder { offer_asset, .. } => Ok(vec![offer_to_coin(offer_asset)?]),
+        DexAction::CancelLimitOrder { .. } | DexAction::WithdrawFilledOrders { .. } => Ok(vec![]),
     }
     .map_err(Into::into)
 }
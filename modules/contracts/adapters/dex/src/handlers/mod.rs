@@ -1,7 +1,9 @@
 mod execute;
 mod instantiate;
 mod query;
+mod reply;
 
 pub use execute::execute_handler;
 pub use instantiate::instantiate_handler;
 pub use query::query_handler;
+pub use reply::swap_output_fee_reply;
@@ -3,11 +3,12 @@ use abstract_adapter::std::objects::{AssetEntry, DexAssetPairing, PoolAddress};
 use abstract_dex_standard::{
     ans_action::pool_address,
     msg::{
-        DexExecuteMsg, DexFeesResponse, DexQueryMsg, GenerateMessagesResponse, SimulateSwapResponse,
+        DexExecuteMsg, DexFeesResponse, DexQueryMsg, GenerateMessagesResponse, OpenOrdersResponse,
+        SimulateSwapResponse,
     },
     DexError,
 };
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError, Uint128};
 
 use crate::{
     contract::{DexAdapter, DexResult},
@@ -29,10 +30,13 @@ pub fn query_handler(
             ask_asset,
             dex,
             pool,
+            account,
         } => {
             let simulate_response = simulate_swap(
                 deps,
                 env,
+                module,
+                account,
                 dex,
                 pool.check(deps.api)?,
                 offer_asset.check(deps.api, None)?,
@@ -54,12 +58,14 @@ pub fn query_handler(
                     }
                     let exchange = exchange_resolver::resolve_exchange(&local_dex_name)?;
                     let addr_as_sender = deps.api.addr_validate(&addr_as_sender)?;
+                    // Generated messages can't charge a fee on the swap output, the fee is always taken from the offer asset
                     let (messages, _) = crate::adapter::DexAdapter::resolve_dex_action(
                         module,
                         deps,
                         addr_as_sender,
                         action,
                         exchange,
+                        false,
                     )?;
                     to_json_binary(&GenerateMessagesResponse { messages }).map_err(Into::into)
                 }
@@ -67,10 +73,24 @@ pub fn query_handler(
             }
         }
         DexQueryMsg::Fees {} => fees(deps),
+        DexQueryMsg::OpenOrders { dex, pool, owner } => {
+            let (local_dex_name, is_over_ibc) = is_over_ibc(&env, &dex)?;
+            if is_over_ibc {
+                return Err(DexError::IbcMsgQuery);
+            }
+            let exchange = exchange_resolver::resolve_exchange(&local_dex_name)?;
+            let orders = exchange.open_orders(
+                deps,
+                pool.check(deps.api)?,
+                deps.api.addr_validate(&owner)?,
+            )?;
+            to_json_binary(&OpenOrdersResponse { orders }).map_err(Into::into)
+        }
         DexQueryMsg::SimulateSwap {
             offer_asset,
             ask_asset,
             dex,
+            account,
         } => {
             let ans = module.name_service(deps);
             let cw_offer_asset = ans.query(&offer_asset)?;
@@ -86,6 +106,8 @@ pub fn query_handler(
             let simulate_response = simulate_swap(
                 deps,
                 env,
+                module,
+                account,
                 dex.clone(),
                 pool_address,
                 cw_offer_asset,
@@ -103,6 +125,7 @@ pub fn query_handler(
                     (offer_asset.name, simulate_response.commission.1)
                 },
                 usage_fee: simulate_response.usage_fee,
+                usage_fee_on_output: simulate_response.usage_fee_on_output,
             };
             to_json_binary(&resp).map_err(Into::into)
         }
@@ -113,6 +136,8 @@ pub fn fees(deps: Deps) -> DexResult<Binary> {
     let dex_fees = DEX_FEES.load(deps.storage)?;
     let resp = DexFeesResponse {
         swap_fee: dex_fees.swap_fee(),
+        fee_on_output: dex_fees.fee_on_output,
+        fee_tiers: dex_fees.fee_tiers().to_vec(),
         recipient: dex_fees.recipient,
     };
     to_json_binary(&resp).map_err(Into::into)
@@ -121,6 +146,8 @@ pub fn fees(deps: Deps) -> DexResult<Binary> {
 pub fn simulate_swap(
     deps: Deps,
     _env: Env,
+    module: &DexAdapter,
+    account: Option<String>,
     dex: String,
     pool: PoolAddress,
     mut offer_asset: Asset,
@@ -134,14 +161,29 @@ pub fn simulate_swap(
         exchange.name(),
     );
 
-    // compute adapter fee
+    // compute adapter fee, with the fee tier of the account if provided
     let dex_fees = DEX_FEES.load(deps.storage)?;
-    let adapter_fee = dex_fees.swap_fee().compute(offer_asset.amount);
-    offer_asset.amount -= adapter_fee;
+    let usage_fee = match account {
+        Some(account) => crate::adapter::DexAdapter::swap_usage_fee(
+            module,
+            deps,
+            &deps.api.addr_validate(&account)?,
+        )?,
+        None => dex_fees.swap_usage_fee()?,
+    };
+    let mut adapter_fee = Uint128::zero();
+    if !dex_fees.fee_on_output {
+        adapter_fee = usage_fee.compute(offer_asset.amount);
+        offer_asset.amount -= adapter_fee;
+    }
 
-    let (return_amount, spread_amount, commission_amount, fee_on_input) = exchange
+    let (mut return_amount, spread_amount, commission_amount, fee_on_input) = exchange
         .simulate_swap(deps, pool, offer_asset.clone(), ask_asset.clone())
         .map_err(|e| StdError::generic_err(e.to_string()))?;
+    if dex_fees.fee_on_output {
+        adapter_fee = usage_fee.compute(return_amount);
+        return_amount -= adapter_fee;
+    }
     let commission_asset = if fee_on_input {
         ask_asset
     } else {
@@ -154,6 +196,7 @@ pub fn simulate_swap(
         spread_amount,
         commission: (commission_asset.into(), commission_amount),
         usage_fee: adapter_fee,
+        usage_fee_on_output: dex_fees.fee_on_output,
     };
     Ok(resp)
 }
@@ -0,0 +1,35 @@
+use abstract_adapter::sdk::{cw_helpers::Chargeable, Execution};
+use cosmwasm_std::{DepsMut, Env, Reply, Response};
+use cw_asset::Asset;
+
+use crate::{
+    contract::{DexAdapter, DexResult},
+    state::{SwapOutputFee, SWAP_OUTPUT_FEE},
+};
+
+/// Charges the swap fee on the ask asset the account received from the swap
+pub fn swap_output_fee_reply(
+    deps: DepsMut,
+    _env: Env,
+    mut module: DexAdapter,
+    _reply: Reply,
+) -> DexResult {
+    let SwapOutputFee {
+        account,
+        ask_asset,
+        balance_before,
+        usage_fee,
+    } = SWAP_OUTPUT_FEE.load(deps.storage)?;
+    SWAP_OUTPUT_FEE.remove(deps.storage);
+
+    let received = ask_asset
+        .query_balance(&deps.querier, account.addr())?
+        .saturating_sub(balance_before);
+    module.target_account = Some(account);
+
+    let Some(fee_msg) = Asset::new(ask_asset, received).charge_usage_fee(usage_fee)? else {
+        return Ok(Response::new());
+    };
+    let account_msg = module.executor(deps.as_ref()).execute(vec![fee_msg])?;
+    Ok(Response::new().add_message(account_msg))
+}
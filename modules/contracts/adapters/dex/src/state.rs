@@ -1,4 +1,18 @@
+use abstract_adapter::std::{objects::fee::UsageFee, registry::Account};
 use abstract_dex_standard::msg::DexFees;
+use cosmwasm_std::Uint128;
+use cw_asset::AssetInfo;
 use cw_storage_plus::Item;
 
 pub const DEX_FEES: Item<DexFees> = Item::new("dex_fees");
+
+/// Ask asset balance of the account before a swap that's charged the fee on its output.
+#[cosmwasm_schema::cw_serde]
+pub struct SwapOutputFee {
+    pub account: Account,
+    pub ask_asset: AssetInfo,
+    pub balance_before: Uint128,
+    pub usage_fee: UsageFee,
+}
+
+pub const SWAP_OUTPUT_FEE: Item<SwapOutputFee> = Item::new("swap_output_fee");
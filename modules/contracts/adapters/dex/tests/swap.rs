@@ -1,8 +1,25 @@
-use abstract_adapter::std::{ans_host::QueryMsgFns as _, objects::ABSTRACT_ACCOUNT_ID};
+use abstract_adapter::std::{
+    ans_host::QueryMsgFns as _,
+    objects::{namespace::Namespace, AnsAsset, ABSTRACT_ACCOUNT_ID},
+};
 use abstract_client::builder::cw20_builder::{ExecuteMsgInterfaceFns, QueryMsgInterfaceFns};
-use abstract_dex_adapter::{contract::CONTRACT_VERSION, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
-use abstract_dex_standard::{msg::DexFeesResponse, DexError};
-use abstract_interface::{AbstractInterfaceError, AccountI, AdapterDeployer, DeployStrategy};
+use abstract_dex_adapter::{
+    contract::CONTRACT_VERSION,
+    msg::{DexInstantiateMsg, QueryMsg},
+    DEX_ADAPTER_ID,
+};
+use abstract_dex_standard::{
+    ans_action::{DexAnsAction, WholeDexAction},
+    msg::{
+        DexExecuteMsg, DexFeesResponse, DexQueryMsg, FeeTierKey, GenerateMessagesResponse,
+        SimulateSwapResponse,
+    },
+    DexError,
+};
+use abstract_interface::{
+    AbstractInterfaceError, AccountExecFns, AccountI, AdapterDeployer, ClientResolve,
+    DeployStrategy, RegistryExecFns,
+};
 
 use abstract_dex_adapter::interface::DexAdapter;
 use abstract_integration_tests::create_default_account;
@@ -165,6 +182,8 @@ fn authorized_update_fee() -> anyhow::Result<()> {
             request: abstract_dex_standard::msg::DexExecuteMsg::UpdateFee {
                 swap_fee: Some(Decimal::percent(5)),
                 recipient_account: None,
+                fee_tiers: None,
+                fee_on_output: None,
             },
         },
     );
@@ -178,6 +197,229 @@ fn authorized_update_fee() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn update_fee_as_abstract(
+    dex_adapter: &DexAdapter<MockBech32>,
+    abstr: &Abstract<MockBech32>,
+    request: DexExecuteMsg,
+) -> anyhow::Result<()> {
+    let account0 = AccountI::load_from(abstr, ABSTRACT_ACCOUNT_ID)?;
+    let update_fee_msg = abstract_dex_standard::msg::ExecuteMsg::Module(
+        abstract_adapter::std::adapter::AdapterRequestMsg {
+            account_address: Some(account0.addr_str()?),
+            request,
+        },
+    );
+    dex_adapter.execute(&update_fee_msg, &[])?;
+    Ok(())
+}
+
+#[test]
+fn account_fee_tier() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, account, abstr) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    // waive the swap fee for the account
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: Some(vec![(
+                FeeTierKey::Account(account.id()?),
+                Some(Decimal::zero()),
+            )]),
+            fee_on_output: None,
+        },
+    )?;
+
+    use abstract_dex_adapter::msg::DexQueryMsgFns as _;
+    let fees: DexFeesResponse = dex_adapter.fees()?;
+    assert_eq!(fees.fee_tiers.len(), 1);
+    assert_eq!(fees.swap_fee.share(), Decimal::percent(1));
+
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &account, &abstr.ans_host)?;
+
+    let eur_balance = chain.query_balance(&account_addr, EUR)?;
+    assert_eq!(eur_balance.u128(), 9_900);
+
+    // no fee charged
+    let os0_account = AccountI::load_from(&abstr, ABSTRACT_ACCOUNT_ID)?.address()?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert!(os0_eur_balance.is_zero());
+
+    // removing the tier restores the default fee
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: Some(vec![(FeeTierKey::Account(account.id()?), None)]),
+            fee_on_output: None,
+        },
+    )?;
+    let fees: DexFeesResponse = dex_adapter.fees()?;
+    assert!(fees.fee_tiers.is_empty());
+
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &account, &abstr.ans_host)?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert_eq!(os0_eur_balance.u128(), 1);
+    Ok(())
+}
+
+#[test]
+fn fee_on_output() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, account, abstr) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: None,
+            fee_on_output: Some(true),
+        },
+    )?;
+
+    use abstract_dex_adapter::msg::DexQueryMsgFns as _;
+    let fees: DexFeesResponse = dex_adapter.fees()?;
+    assert!(fees.fee_on_output);
+
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &account, &abstr.ans_host)?;
+
+    // whole offer asset swapped
+    let eur_balance = chain.query_balance(&account_addr, EUR)?;
+    assert_eq!(eur_balance.u128(), 9_000);
+
+    // fee paid in the ask asset, on the amount the swap returned
+    let os0_account = AccountI::load_from(&abstr, ABSTRACT_ACCOUNT_ID)?.address()?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert!(os0_eur_balance.is_zero());
+    let os0_usd_balance = chain.query_balance(&os0_account, USD)?;
+    assert!(!os0_usd_balance.is_zero());
+    let usd_balance = chain.query_balance(&account_addr, USD)?;
+    assert_eq!(
+        os0_usd_balance,
+        (usd_balance + os0_usd_balance).mul_floor(Decimal::percent(1))
+    );
+    Ok(())
+}
+
+#[test]
+fn fee_on_output_generate_messages() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, account, abstr) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: None,
+            fee_on_output: Some(true),
+        },
+    )?;
+
+    let generated: GenerateMessagesResponse =
+        dex_adapter.query(&QueryMsg::Module(DexQueryMsg::GenerateMessages {
+            message: WholeDexAction(
+                WYNDEX.to_owned(),
+                DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, 1_000u128),
+                    ask_asset: USD.into(),
+                    max_spread: None,
+                    belief_price: None,
+                },
+            )
+            .resolve(&abstr.ans_host)?,
+            addr_as_sender: account_addr.to_string(),
+        }))?;
+    account.execute_msgs(generated.messages, &[])?;
+
+    // generated messages can't act on the output, the fee is charged on the offer asset
+    let eur_balance = chain.query_balance(&account_addr, EUR)?;
+    assert_eq!(eur_balance.u128(), 9_000);
+    let os0_account = AccountI::load_from(&abstr, ABSTRACT_ACCOUNT_ID)?.address()?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert_eq!(os0_eur_balance.u128(), 10);
+    let os0_usd_balance = chain.query_balance(&os0_account, USD)?;
+    assert!(os0_usd_balance.is_zero());
+    Ok(())
+}
+
+#[test]
+fn namespace_fee_tier() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, account, abstr) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    abstr
+        .registry
+        .claim_namespace(account.id()?, "tester".to_owned())?;
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: Some(vec![(
+                FeeTierKey::Namespace(Namespace::new("tester")?),
+                Some(Decimal::percent(10)),
+            )]),
+            fee_on_output: None,
+        },
+    )?;
+
+    // Simulation applies the tier of the account
+    let simulate = |account: Option<String>| -> anyhow::Result<SimulateSwapResponse> {
+        Ok(
+            dex_adapter.query(&QueryMsg::Module(DexQueryMsg::SimulateSwap {
+                offer_asset: AnsAsset::new(EUR, 1_000u128),
+                ask_asset: USD.into(),
+                dex: WYNDEX.into(),
+                account,
+            }))?,
+        )
+    };
+    assert_eq!(
+        simulate(Some(account_addr.to_string()))?.usage_fee.u128(),
+        100
+    );
+    assert_eq!(simulate(None)?.usage_fee.u128(), 10);
+
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &account, &abstr.ans_host)?;
+
+    let os0_account = AccountI::load_from(&abstr, ABSTRACT_ACCOUNT_ID)?.address()?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert_eq!(os0_eur_balance.u128(), 100);
+
+    // The tier of the account takes precedence over its namespace tier
+    update_fee_as_abstract(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: None,
+            recipient_account: None,
+            fee_tiers: Some(vec![(
+                FeeTierKey::Account(account.id()?),
+                Some(Decimal::zero()),
+            )]),
+            fee_on_output: None,
+        },
+    )?;
+    assert!(simulate(Some(account_addr.to_string()))?
+        .usage_fee
+        .is_zero());
+
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &account, &abstr.ans_host)?;
+    let os0_eur_balance = chain.query_balance(&os0_account, EUR)?;
+    assert_eq!(os0_eur_balance.u128(), 100);
+    Ok(())
+}
+
 #[test]
 fn unauthorized_update_fee() -> anyhow::Result<()> {
     let (_, _, _, account, _) = setup_mock()?;
@@ -188,6 +430,8 @@ fn unauthorized_update_fee() -> anyhow::Result<()> {
             request: abstract_dex_standard::msg::DexExecuteMsg::UpdateFee {
                 swap_fee: Some(Decimal::percent(5)),
                 recipient_account: None,
+                fee_tiers: None,
+                fee_on_output: None,
             },
         },
     );
@@ -0,0 +1,39 @@
+use abstract_adapter::sdk::{features::AbstractNameService, Execution, Resolve};
+use abstract_liquid_staking_standard::{
+    msg::LiquidStakingAction, LiquidStakingCommand, LiquidStakingError,
+};
+use cosmwasm_std::{Addr, CosmosMsg, Deps};
+use cw_asset::Asset;
+
+impl<T> LiquidStakingAdapter for T where T: AbstractNameService + Execution {}
+
+pub trait LiquidStakingAdapter: AbstractNameService + Execution {
+    /// resolve the provided liquid staking action on a local provider
+    fn resolve_liquid_staking_action(
+        &self,
+        deps: Deps,
+        sender: Addr,
+        action: LiquidStakingAction,
+        mut provider: Box<dyn LiquidStakingCommand>,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let ans_host = self.ans_host(deps)?;
+        provider.fetch_data(deps, sender, &ans_host)?;
+
+        match action {
+            LiquidStakingAction::LiquidStake { asset } => {
+                let hub = provider.hub_address(&deps.querier, &ans_host, asset.name.clone())?;
+                let asset = asset.resolve(&deps.querier, &ans_host)?;
+                provider.liquid_stake(deps, hub, asset)
+            }
+            LiquidStakingAction::RequestRedeem { asset, amount } => {
+                let hub = provider.hub_address(&deps.querier, &ans_host, asset)?;
+                let liquid_token = provider.liquid_token(deps, hub.clone())?;
+                provider.request_redeem(deps, hub, Asset::new(liquid_token, amount))
+            }
+            LiquidStakingAction::ClaimRedeemed { asset } => {
+                let hub = provider.hub_address(&deps.querier, &ans_host, asset)?;
+                provider.claim_redeemed(deps, hub)
+            }
+        }
+    }
+}
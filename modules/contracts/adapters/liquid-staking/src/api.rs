@@ -0,0 +1,159 @@
+use crate::LIQUID_STAKING_ADAPTER_ID;
+use abstract_adapter::sdk::{
+    features::{AccountIdentification, Dependencies, ModuleIdentification},
+    AbstractSdkResult, AdapterInterface,
+};
+use abstract_adapter::std::objects::{module::ModuleId, AnsAsset, AssetEntry};
+use abstract_liquid_staking_standard::msg::{
+    ExchangeRateResponse, LiquidStakingAction, LiquidStakingExecuteMsg, LiquidStakingQueryMsg,
+    ProviderName,
+};
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::{CosmosMsg, Deps, Uint128};
+
+// API for Abstract SDK users
+/// Interact with the liquid staking adapter in your module.
+pub trait LiquidStakingInterface:
+    AccountIdentification + Dependencies + ModuleIdentification
+{
+    /// Construct a new liquid staking interface.
+    fn liquid_staking<'a>(
+        &'a self,
+        deps: Deps<'a>,
+        provider: ProviderName,
+    ) -> LiquidStaking<'a, Self> {
+        LiquidStaking {
+            base: self,
+            deps,
+            provider,
+            module_id: LIQUID_STAKING_ADAPTER_ID,
+        }
+    }
+}
+
+impl<T: AccountIdentification + Dependencies + ModuleIdentification> LiquidStakingInterface for T {}
+
+#[derive(Clone)]
+pub struct LiquidStaking<'a, T: LiquidStakingInterface> {
+    base: &'a T,
+    provider: ProviderName,
+    module_id: ModuleId<'a>,
+    deps: Deps<'a>,
+}
+
+impl<'a, T: LiquidStakingInterface> LiquidStaking<'a, T> {
+    /// Set the module id for the liquid staking adapter
+    pub fn with_module_id(self, module_id: ModuleId<'a>) -> Self {
+        Self { module_id, ..self }
+    }
+
+    /// Executes a [LiquidStakingAction] on the provider
+    fn execute(&self, action: LiquidStakingAction) -> AbstractSdkResult<CosmosMsg> {
+        let adapters = self.base.adapters(self.deps);
+
+        adapters.execute(
+            self.module_id,
+            LiquidStakingExecuteMsg {
+                provider: self.provider.clone(),
+                action,
+            },
+        )
+    }
+
+    /// Liquid stake the asset
+    pub fn liquid_stake(&self, asset: AnsAsset) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(LiquidStakingAction::LiquidStake { asset })
+    }
+
+    /// Request the redemption of `amount` liquid staking tokens of the asset
+    pub fn request_redeem(
+        &self,
+        asset: AssetEntry,
+        amount: Uint128,
+    ) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(LiquidStakingAction::RequestRedeem { asset, amount })
+    }
+
+    /// Claim the matured redemptions of the asset
+    pub fn claim_redeemed(&self, asset: AssetEntry) -> AbstractSdkResult<CosmosMsg> {
+        self.execute(LiquidStakingAction::ClaimRedeemed { asset })
+    }
+}
+
+impl<T: LiquidStakingInterface> LiquidStaking<'_, T> {
+    /// Do a query on the liquid staking adapter
+    fn query<R: DeserializeOwned>(&self, query_msg: LiquidStakingQueryMsg) -> AbstractSdkResult<R> {
+        let adapters = self.base.adapters(self.deps);
+        adapters.query(self.module_id, query_msg)
+    }
+
+    /// Exchange rate between the liquid staking token of the asset and the asset
+    pub fn exchange_rate(&self, asset: AssetEntry) -> AbstractSdkResult<ExchangeRateResponse> {
+        self.query(LiquidStakingQueryMsg::ExchangeRate {
+            provider: self.provider.clone(),
+            asset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::msg::ExecuteMsg;
+    use abstract_adapter::abstract_testing::prelude::{
+        test_account, AbstractMockAddrs, AbstractMockQuerier, TEST_ACCOUNT_ID, TEST_MODULE_ID,
+    };
+    use abstract_adapter::sdk::mock_module::MockModule;
+    use abstract_adapter::std::adapter::AdapterRequestMsg;
+    use cosmwasm_std::{testing::mock_dependencies, wasm_execute, Addr};
+
+    fn expected_request_with_test_account(
+        request: LiquidStakingExecuteMsg,
+        account_address: &Addr,
+    ) -> ExecuteMsg {
+        AdapterRequestMsg {
+            account_address: Some(account_address.to_string()),
+            request,
+        }
+        .into()
+    }
+
+    #[test]
+    fn liquid_stake_msg() {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        deps.querier = abstract_adapter::abstract_testing::abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let liquid_staking = stub
+            .liquid_staking(deps.as_ref(), "stride".into())
+            .with_module_id(TEST_MODULE_ID);
+
+        let asset = AnsAsset::new("atom", 1000u128);
+
+        let expected = expected_request_with_test_account(
+            LiquidStakingExecuteMsg {
+                provider: "stride".into(),
+                action: LiquidStakingAction::LiquidStake {
+                    asset: asset.clone(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = liquid_staking.liquid_stake(asset);
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+}
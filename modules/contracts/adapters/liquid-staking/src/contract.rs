@@ -0,0 +1,24 @@
+use abstract_adapter::AdapterContract;
+use abstract_liquid_staking_standard::{
+    msg::{LiquidStakingExecuteMsg, LiquidStakingQueryMsg},
+    LiquidStakingError,
+};
+use cosmwasm_std::{Empty, Response};
+
+use crate::{handlers, LIQUID_STAKING_ADAPTER_ID};
+
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub type LiquidStakingAdapter =
+    AdapterContract<LiquidStakingError, Empty, LiquidStakingExecuteMsg, LiquidStakingQueryMsg>;
+pub type LiquidStakingResult<T = Response> = Result<T, LiquidStakingError>;
+
+pub const LIQUID_STAKING_ADAPTER: LiquidStakingAdapter =
+    LiquidStakingAdapter::new(LIQUID_STAKING_ADAPTER_ID, CONTRACT_VERSION, None)
+        .with_execute(handlers::execute_handler)
+        .with_query(handlers::query_handler);
+
+#[cfg(feature = "export")]
+use abstract_adapter::export_endpoints;
+#[cfg(feature = "export")]
+export_endpoints!(LIQUID_STAKING_ADAPTER, LiquidStakingAdapter);
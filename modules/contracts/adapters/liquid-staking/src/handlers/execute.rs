@@ -0,0 +1,51 @@
+use abstract_adapter::sdk::{features::AccountIdentification, Execution};
+use abstract_liquid_staking_standard::{msg::LiquidStakingAction, LiquidStakingError};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use crate::{
+    adapter::LiquidStakingAdapter as _,
+    contract::{LiquidStakingAdapter, LiquidStakingResult},
+    msg::LiquidStakingExecuteMsg,
+    providers::provider_resolver::{self, is_over_ibc},
+};
+
+pub fn execute_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: LiquidStakingAdapter,
+    msg: LiquidStakingExecuteMsg,
+) -> LiquidStakingResult {
+    let LiquidStakingExecuteMsg {
+        provider: provider_name,
+        action,
+    } = msg;
+    let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider_name)?;
+    if is_over_ibc {
+        return Err(LiquidStakingError::IbcNotSupported);
+    }
+    // the action can be executed on the local chain
+    handle_local_request(deps, info, &module, local_provider_name, action)
+}
+
+/// Handle an adapter request that can be executed on the local chain
+fn handle_local_request(
+    deps: DepsMut,
+    _info: MessageInfo,
+    module: &LiquidStakingAdapter,
+    provider_name: String,
+    action: LiquidStakingAction,
+) -> LiquidStakingResult {
+    let provider = provider_resolver::resolve_provider(&provider_name)?;
+    let target_account = module.account(deps.as_ref())?;
+    let msgs = module.resolve_liquid_staking_action(
+        deps.as_ref(),
+        target_account.into_addr(),
+        action,
+        provider,
+    )?;
+    let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
+    Ok(Response::new()
+        .add_attribute("provider", provider_name)
+        .add_message(account_msg))
+}
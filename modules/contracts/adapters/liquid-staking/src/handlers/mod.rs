@@ -0,0 +1,5 @@
+mod execute;
+mod query;
+
+pub use execute::execute_handler;
+pub use query::query_handler;
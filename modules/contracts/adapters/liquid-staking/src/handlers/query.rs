@@ -0,0 +1,53 @@
+use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_liquid_staking_standard::{
+    msg::{ExchangeRateResponse, GenerateMessagesResponse, LiquidStakingQueryMsg},
+    LiquidStakingError,
+};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+
+use crate::{
+    adapter::LiquidStakingAdapter as _,
+    contract::{LiquidStakingAdapter, LiquidStakingResult},
+    msg::LiquidStakingExecuteMsg,
+    providers::provider_resolver::{self, is_over_ibc},
+};
+
+pub fn query_handler(
+    deps: Deps,
+    env: Env,
+    module: &LiquidStakingAdapter,
+    msg: LiquidStakingQueryMsg,
+) -> LiquidStakingResult<Binary> {
+    match msg {
+        LiquidStakingQueryMsg::ExchangeRate { provider, asset } => {
+            let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider)?;
+            if is_over_ibc {
+                return Err(LiquidStakingError::IbcMsgQuery);
+            }
+            let provider = provider_resolver::resolve_provider(&local_provider_name)?;
+            let ans_host = module.ans_host(deps)?;
+            let hub = provider.hub_address(&deps.querier, &ans_host, asset)?;
+
+            let resp = ExchangeRateResponse {
+                liquid_token: provider.liquid_token(deps, hub.clone())?.into(),
+                exchange_rate: provider.exchange_rate(deps, hub)?,
+            };
+            to_json_binary(&resp).map_err(Into::into)
+        }
+        LiquidStakingQueryMsg::GenerateMessages {
+            message,
+            addr_as_sender,
+        } => {
+            let LiquidStakingExecuteMsg { provider, action } = message;
+            let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider)?;
+            if is_over_ibc {
+                return Err(LiquidStakingError::IbcMsgQuery);
+            }
+            let provider = provider_resolver::resolve_provider(&local_provider_name)?;
+            let addr_as_sender = deps.api.addr_validate(&addr_as_sender)?;
+            let messages =
+                module.resolve_liquid_staking_action(deps, addr_as_sender, action, provider)?;
+            to_json_binary(&GenerateMessagesResponse { messages }).map_err(Into::into)
+        }
+    }
+}
@@ -0,0 +1,152 @@
+pub mod adapter;
+pub mod api;
+pub mod contract;
+pub(crate) mod handlers;
+pub mod providers;
+pub mod msg {
+    pub use abstract_liquid_staking_standard::msg::*;
+}
+pub use abstract_liquid_staking_standard::LIQUID_STAKING_ADAPTER_ID;
+
+// Export interface for use in SDK modules
+pub use crate::api::LiquidStakingInterface;
+
+#[cfg(feature = "testing")]
+pub mod liquid_staking_tester;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod interface {
+    use crate::{contract::LIQUID_STAKING_ADAPTER, msg::*};
+    use abstract_adapter::abstract_interface::{
+        AbstractInterfaceError, AccountI, AdapterDeployer, RegisteredModule,
+    };
+    use abstract_adapter::objects::dependency::StaticDependency;
+    use abstract_adapter::sdk::features::ModuleIdentification;
+    use abstract_adapter::std::{
+        adapter,
+        objects::{AnsAsset, AssetEntry},
+    };
+    use abstract_adapter::traits::Dependencies;
+    use cosmwasm_std::Uint128;
+    use cw_orch::{build::BuildPostfix, interface};
+    use cw_orch::{contract::Contract, prelude::*};
+
+    #[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+    pub struct LiquidStakingAdapter<Chain>;
+
+    // Implement deployer trait
+    impl<Chain: CwEnv> AdapterDeployer<Chain, Empty> for LiquidStakingAdapter<Chain> {}
+
+    impl<Chain: CwEnv> Uploadable for LiquidStakingAdapter<Chain> {
+        #[cfg(feature = "export")]
+        fn wrapper() -> <Mock as TxHandler>::ContractSource {
+            Box::new(ContractWrapper::new_with_empty(
+                crate::contract::execute,
+                crate::contract::instantiate,
+                crate::contract::query,
+            ))
+        }
+        fn wasm(chain: &ChainInfoOwned) -> WasmPath {
+            artifacts_dir_from_workspace!()
+                .find_wasm_path_with_build_postfix(
+                    "abstract_liquid_staking_adapter",
+                    BuildPostfix::ChainName(chain),
+                )
+                .unwrap()
+        }
+    }
+
+    impl<Chain: CwEnv> LiquidStakingAdapter<Chain> {
+        /// Liquid staking action using Abstract Account
+        pub fn liquid_staking_action(
+            &self,
+            provider: String,
+            action: LiquidStakingAction,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<<Chain as TxHandler>::Response, AbstractInterfaceError> {
+            let account = account.as_ref();
+            let msg = ExecuteMsg::Module(adapter::AdapterRequestMsg {
+                account_address: Some(account.addr_str()?),
+                request: LiquidStakingExecuteMsg { provider, action },
+            });
+            self.execute(&msg, &[]).map_err(Into::into)
+        }
+
+        /// Liquid stake using ans resolved asset
+        pub fn liquid_stake(
+            &self,
+            asset: AnsAsset,
+            provider: String,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = LiquidStakingAction::LiquidStake { asset };
+            self.liquid_staking_action(provider, action, account)?;
+            Ok(())
+        }
+
+        /// Request the redemption of liquid staking tokens of the asset
+        pub fn request_redeem(
+            &self,
+            asset: AssetEntry,
+            amount: impl Into<Uint128>,
+            provider: String,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = LiquidStakingAction::RequestRedeem {
+                asset,
+                amount: amount.into(),
+            };
+            self.liquid_staking_action(provider, action, account)?;
+            Ok(())
+        }
+
+        /// Claim matured redemptions of the asset
+        pub fn claim_redeemed(
+            &self,
+            asset: AssetEntry,
+            provider: String,
+            account: impl AsRef<AccountI<Chain>>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let action = LiquidStakingAction::ClaimRedeemed { asset };
+            self.liquid_staking_action(provider, action, account)?;
+            Ok(())
+        }
+    }
+
+    impl<Chain: CwEnv> RegisteredModule for LiquidStakingAdapter<Chain> {
+        type InitMsg = Empty;
+
+        fn module_id<'a>() -> &'a str {
+            LIQUID_STAKING_ADAPTER.module_id()
+        }
+
+        fn module_version<'a>() -> &'a str {
+            LIQUID_STAKING_ADAPTER.version()
+        }
+
+        fn dependencies<'a>() -> &'a [StaticDependency] {
+            LIQUID_STAKING_ADAPTER.dependencies()
+        }
+    }
+
+    impl<Chain: CwEnv> From<Contract<Chain>> for LiquidStakingAdapter<Chain> {
+        fn from(contract: Contract<Chain>) -> Self {
+            Self(contract)
+        }
+    }
+
+    impl<Chain: cw_orch::environment::CwEnv>
+        abstract_adapter::abstract_interface::DependencyCreation for LiquidStakingAdapter<Chain>
+    {
+        type DependenciesConfig = cosmwasm_std::Empty;
+
+        fn dependency_install_configs(
+            _configuration: Self::DependenciesConfig,
+        ) -> Result<
+            Vec<abstract_adapter::std::account::ModuleInstallConfig>,
+            abstract_adapter::abstract_interface::AbstractInterfaceError,
+        > {
+            Ok(vec![])
+        }
+    }
+}
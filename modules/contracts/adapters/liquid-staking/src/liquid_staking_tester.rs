@@ -0,0 +1,231 @@
+use crate::{interface::LiquidStakingAdapter, LIQUID_STAKING_ADAPTER_ID};
+use abstract_adapter::abstract_interface::{
+    AdapterDeployer, DeployStrategy, ExecuteMsgFns, RegistryExecFns,
+};
+use abstract_adapter::std::objects::{
+    module::{ModuleInfo, ModuleVersion},
+    AnsAsset, AssetEntry, UncheckedContractEntry,
+};
+use abstract_client::{AbstractClient, Environment};
+use abstract_liquid_staking_standard::msg::{ExchangeRateResponse, LiquidStakingQueryMsgFns};
+use cosmwasm_std::{coins, Decimal, Uint128};
+use cw_asset::AssetInfoUnchecked;
+use cw_orch::{environment::MutCwEnv, prelude::*};
+
+use cw_orch::anyhow;
+
+pub const STAKE_VALUE: u128 = 1_000_000_000u128;
+
+pub trait MockLiquidStaking {
+    /// Name of the liquid staking provider
+    fn name(&self) -> String;
+
+    /// Staked asset
+    fn asset(&self) -> (String, AssetInfoUnchecked);
+
+    /// Liquid staking token of the staked asset
+    fn liquid_token(&self) -> AssetInfoUnchecked;
+
+    /// Exchange rate of the liquid staking token
+    fn exchange_rate(&self) -> Decimal;
+
+    /// Seconds before a redemption can be claimed
+    fn unbonding_period(&self) -> u64;
+
+    /// Specific liquid staking setup
+    /// Should return objects that will be registered inside abstract ANS
+    fn setup(&self) -> Vec<(UncheckedContractEntry, String)>;
+}
+
+pub struct LiquidStakingTester<Chain: MutCwEnv, Provider: MockLiquidStaking> {
+    pub abstr_deployment: AbstractClient<Chain>,
+    pub liquid_staking_adapter: LiquidStakingAdapter<Chain>,
+    pub provider: Provider,
+}
+
+impl<Chain: MutCwEnv, Provider: MockLiquidStaking> LiquidStakingTester<Chain, Provider> {
+    pub fn new(
+        abstr_deployment: AbstractClient<Chain>,
+        provider: Provider,
+    ) -> anyhow::Result<Self> {
+        // Re-register liquid staking adapter, to make sure it's latest
+        let _ = abstr_deployment
+            .registry()
+            .remove_module(ModuleInfo::from_id(
+                LIQUID_STAKING_ADAPTER_ID,
+                ModuleVersion::Version(crate::contract::CONTRACT_VERSION.to_owned()),
+            )?);
+        let liquid_staking_adapter =
+            LiquidStakingAdapter::new(LIQUID_STAKING_ADAPTER_ID, abstr_deployment.environment());
+        liquid_staking_adapter.deploy(
+            crate::contract::CONTRACT_VERSION.parse()?,
+            Empty {},
+            DeployStrategy::Force,
+        )?;
+
+        // Registering assets
+        abstr_deployment
+            .name_service()
+            .update_asset_addresses(vec![provider.asset()], vec![])?;
+
+        let new_contract_entries = provider.setup();
+        abstr_deployment
+            .name_service()
+            .update_contract_addresses(new_contract_entries, vec![])?;
+
+        Ok(Self {
+            abstr_deployment,
+            liquid_staking_adapter,
+            provider,
+        })
+    }
+
+    pub fn test_liquid_stake(&self) -> anyhow::Result<()> {
+        let (ans_asset, asset_info) = self.provider.asset();
+        let liquid_token = self.provider.liquid_token();
+
+        let new_account = self
+            .abstr_deployment
+            .account_builder()
+            .install_adapter::<LiquidStakingAdapter<Chain>>()
+            .build()?;
+        let account_addr = new_account.address()?;
+
+        self.add_account_balance(&account_addr, &asset_info, STAKE_VALUE)?;
+
+        self.liquid_staking_adapter.liquid_stake(
+            AnsAsset::new(ans_asset, STAKE_VALUE),
+            self.provider.name(),
+            &new_account,
+        )?;
+
+        // Assert balances
+        let balance = self.query_account_balance(&account_addr, &asset_info)?;
+        assert!(balance.is_zero());
+        let liquid_balance = self.query_account_balance(&account_addr, &liquid_token)?;
+        assert_eq!(
+            liquid_balance,
+            Uint128::new(STAKE_VALUE).mul_floor(Decimal::one() / self.provider.exchange_rate())
+        );
+
+        Ok(())
+    }
+
+    pub fn test_redeem(&self) -> anyhow::Result<()> {
+        let (ans_asset, asset_info) = self.provider.asset();
+        let liquid_token = self.provider.liquid_token();
+
+        let new_account = self
+            .abstr_deployment
+            .account_builder()
+            .install_adapter::<LiquidStakingAdapter<Chain>>()
+            .build()?;
+        let account_addr = new_account.address()?;
+
+        self.add_account_balance(&account_addr, &asset_info, STAKE_VALUE)?;
+
+        self.liquid_staking_adapter.liquid_stake(
+            AnsAsset::new(ans_asset.clone(), STAKE_VALUE),
+            self.provider.name(),
+            &new_account,
+        )?;
+        let liquid_balance = self.query_account_balance(&account_addr, &liquid_token)?;
+
+        // Redeem half
+        let redeem_amount = liquid_balance / Uint128::new(2);
+        self.liquid_staking_adapter.request_redeem(
+            AssetEntry::new(&ans_asset),
+            redeem_amount,
+            self.provider.name(),
+            &new_account,
+        )?;
+
+        let liquid_balance_after = self.query_account_balance(&account_addr, &liquid_token)?;
+        assert_eq!(liquid_balance_after, liquid_balance - redeem_amount);
+
+        // Redemption not claimable yet
+        let res = self.liquid_staking_adapter.claim_redeemed(
+            AssetEntry::new(&ans_asset),
+            self.provider.name(),
+            &new_account,
+        );
+        assert!(res.is_err());
+
+        self.abstr_deployment
+            .environment()
+            .wait_seconds(self.provider.unbonding_period())?;
+
+        self.liquid_staking_adapter.claim_redeemed(
+            AssetEntry::new(&ans_asset),
+            self.provider.name(),
+            &new_account,
+        )?;
+
+        // Ensure redeemed tokens were claimed
+        let balance = self.query_account_balance(&account_addr, &asset_info)?;
+        assert_eq!(
+            balance,
+            redeem_amount.mul_floor(self.provider.exchange_rate())
+        );
+
+        Ok(())
+    }
+
+    pub fn test_exchange_rate(&self) -> anyhow::Result<()> {
+        let (ans_asset, _) = self.provider.asset();
+
+        let response: ExchangeRateResponse = self
+            .liquid_staking_adapter
+            .exchange_rate(AssetEntry::new(&ans_asset), self.provider.name())?;
+
+        assert_eq!(
+            response,
+            ExchangeRateResponse {
+                liquid_token: self.provider.liquid_token(),
+                exchange_rate: self.provider.exchange_rate(),
+            }
+        );
+
+        Ok(())
+    }
+
+    fn add_account_balance(
+        &self,
+        account_addr: &Addr,
+        asset: &AssetInfoUnchecked,
+        amount: u128,
+    ) -> anyhow::Result<()> {
+        let mut chain = self.abstr_deployment.environment();
+
+        match asset {
+            cw_asset::AssetInfoBase::Native(denom) => {
+                chain.add_balance(account_addr, coins(amount, denom))?;
+            }
+            _ => unimplemented!(),
+        }
+        Ok(())
+    }
+
+    fn query_account_balance(
+        &self,
+        account_addr: &Addr,
+        asset: &AssetInfoUnchecked,
+    ) -> anyhow::Result<Uint128> {
+        let chain = self.abstr_deployment.environment();
+
+        let balance = match asset {
+            cw_asset::AssetInfoBase::Native(denom) => {
+                chain
+                    .bank_querier()
+                    .balance(account_addr, Some(denom.to_owned()))
+                    .unwrap()
+                    .pop()
+                    .unwrap()
+                    .amount
+            }
+            _ => unimplemented!(),
+        };
+
+        Ok(balance)
+    }
+}
@@ -0,0 +1,245 @@
+//! Mock liquid staking protocol, used to test the adapter.
+//!
+//! The [`hub`] contract liquid stakes a native denom by transferring its reserve of a liquid denom,
+//! at an exchange rate that can be updated by anyone.
+use abstract_liquid_staking_standard::{Identify, LiquidStakingCommand, LiquidStakingError};
+use cosmwasm_std::{wasm_execute, Addr, Coin, CosmosMsg, Decimal, Deps};
+use cw_asset::{Asset, AssetInfo};
+
+pub const MOCK: &str = "mock";
+
+#[derive(Default)]
+pub struct MockProvider {}
+
+impl Identify for MockProvider {
+    fn is_available_on(&self, _chain_name: &str) -> bool {
+        true
+    }
+    fn name(&self) -> &'static str {
+        MOCK
+    }
+}
+
+impl LiquidStakingCommand for MockProvider {
+    fn liquid_token(&self, deps: Deps, hub: Addr) -> Result<AssetInfo, LiquidStakingError> {
+        let config: hub::Config = deps
+            .querier
+            .query_wasm_smart(hub, &hub::QueryMsg::Config {})?;
+        Ok(AssetInfo::native(config.liquid_denom))
+    }
+
+    fn liquid_stake(
+        &self,
+        _deps: Deps,
+        hub: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let coin: Coin = asset.try_into()?;
+        Ok(vec![wasm_execute(
+            hub,
+            &hub::ExecuteMsg::Bond {},
+            vec![coin],
+        )?
+        .into()])
+    }
+
+    fn request_redeem(
+        &self,
+        _deps: Deps,
+        hub: Addr,
+        liquid_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        let coin: Coin = liquid_asset.try_into()?;
+        Ok(vec![wasm_execute(
+            hub,
+            &hub::ExecuteMsg::Unbond {},
+            vec![coin],
+        )?
+        .into()])
+    }
+
+    fn claim_redeemed(&self, _deps: Deps, hub: Addr) -> Result<Vec<CosmosMsg>, LiquidStakingError> {
+        Ok(vec![wasm_execute(
+            hub,
+            &hub::ExecuteMsg::Withdraw {},
+            vec![],
+        )?
+        .into()])
+    }
+
+    fn exchange_rate(&self, deps: Deps, hub: Addr) -> Result<Decimal, LiquidStakingError> {
+        let config: hub::Config = deps
+            .querier
+            .query_wasm_smart(hub, &hub::QueryMsg::Config {})?;
+        Ok(config.exchange_rate)
+    }
+}
+
+pub mod hub {
+    use cosmwasm_schema::QueryResponses;
+    use cosmwasm_std::{
+        coins, to_json_binary, Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Env, Fraction,
+        MessageInfo, Response, StdError, StdResult, Timestamp, Uint128,
+    };
+    use cw_storage_plus::{Item, Map};
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct InstantiateMsg {
+        /// Denom that is liquid staked
+        pub denom: String,
+        /// Denom of the liquid staking token, the hub must hold a reserve of it
+        pub liquid_denom: String,
+        /// Seconds before a redemption can be withdrawn
+        pub unbonding_period: u64,
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    pub enum ExecuteMsg {
+        /// Liquid stake the sent `denom`
+        Bond {},
+        /// Redeem the sent `liquid_denom`
+        Unbond {},
+        /// Withdraw matured redemptions
+        Withdraw {},
+        /// Update the exchange rate
+        SetExchangeRate { exchange_rate: Decimal },
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    #[derive(QueryResponses)]
+    pub enum QueryMsg {
+        #[returns(Config)]
+        Config {},
+        #[returns(Vec<Redemption>)]
+        Redemptions { address: String },
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct Config {
+        pub denom: String,
+        pub liquid_denom: String,
+        pub unbonding_period: u64,
+        /// Amount of `denom` one `liquid_denom` is redeemed for
+        pub exchange_rate: Decimal,
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    pub struct Redemption {
+        pub amount: Uint128,
+        pub release_at: Timestamp,
+    }
+
+    const CONFIG: Item<Config> = Item::new("config");
+    const REDEMPTIONS: Map<&Addr, Vec<Redemption>> = Map::new("redemptions");
+
+    pub fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> StdResult<Response> {
+        CONFIG.save(
+            deps.storage,
+            &Config {
+                denom: msg.denom,
+                liquid_denom: msg.liquid_denom,
+                unbonding_period: msg.unbonding_period,
+                exchange_rate: Decimal::one(),
+            },
+        )?;
+        Ok(Response::new())
+    }
+
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> StdResult<Response> {
+        let mut config = CONFIG.load(deps.storage)?;
+        match msg {
+            ExecuteMsg::Bond {} => {
+                let amount = cw_utils::must_pay(&info, &config.denom)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                let liquid_amount = amount.multiply_ratio(
+                    config.exchange_rate.denominator(),
+                    config.exchange_rate.numerator(),
+                );
+                Ok(Response::new().add_message(BankMsg::Send {
+                    to_address: info.sender.into_string(),
+                    amount: coins(liquid_amount.u128(), config.liquid_denom),
+                }))
+            }
+            ExecuteMsg::Unbond {} => {
+                let liquid_amount = cw_utils::must_pay(&info, &config.liquid_denom)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                let redemption = Redemption {
+                    amount: liquid_amount.mul_floor(config.exchange_rate),
+                    release_at: env.block.time.plus_seconds(config.unbonding_period),
+                };
+                REDEMPTIONS.update(deps.storage, &info.sender, |redemptions| {
+                    let mut redemptions = redemptions.unwrap_or_default();
+                    redemptions.push(redemption);
+                    StdResult::Ok(redemptions)
+                })?;
+                Ok(Response::new())
+            }
+            ExecuteMsg::Withdraw {} => {
+                let (matured, pending): (Vec<_>, Vec<_>) = REDEMPTIONS
+                    .may_load(deps.storage, &info.sender)?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .partition(|redemption| redemption.release_at <= env.block.time);
+                REDEMPTIONS.save(deps.storage, &info.sender, &pending)?;
+
+                let amount: Uint128 = matured.iter().map(|redemption| redemption.amount).sum();
+                if amount.is_zero() {
+                    return Err(StdError::generic_err("Nothing to withdraw"));
+                }
+                Ok(Response::new().add_message(BankMsg::Send {
+                    to_address: info.sender.into_string(),
+                    amount: coins(amount.u128(), config.denom),
+                }))
+            }
+            ExecuteMsg::SetExchangeRate { exchange_rate } => {
+                config.exchange_rate = exchange_rate;
+                CONFIG.save(deps.storage, &config)?;
+                Ok(Response::new())
+            }
+        }
+    }
+
+    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+            QueryMsg::Redemptions { address } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_json_binary(
+                    &REDEMPTIONS
+                        .may_load(deps.storage, &address)?
+                        .unwrap_or_default(),
+                )
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub mod interface {
+        use cw_orch::{interface, prelude::*};
+
+        use super::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+        #[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+        pub struct MockHub<Chain>;
+
+        impl<Chain: CwEnv> Uploadable for MockHub<Chain> {
+            fn wrapper() -> <Mock as TxHandler>::ContractSource {
+                Box::new(ContractWrapper::new_with_empty(
+                    super::execute,
+                    super::instantiate,
+                    super::query,
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+#[cfg(feature = "mock")]
+pub mod mock;
+pub(crate) mod provider_resolver;
@@ -0,0 +1,52 @@
+use abstract_adapter_utils::identity::{
+    decompose_platform_name, is_available_on, is_current_chain,
+};
+use abstract_liquid_staking_standard::{Identify, LiquidStakingCommand, LiquidStakingError};
+use cosmwasm_std::Env;
+
+/// Any liquid staking provider should be identified by the adapter
+/// This allows erroring the execution before sending any IBC message to another chain
+/// This provides superior UX in case of an IBC execution
+pub(crate) fn identify_provider(value: &str) -> Result<Box<dyn Identify>, LiquidStakingError> {
+    match value {
+        abstract_drop_adapter::DROP => {
+            Ok(Box::<abstract_drop_adapter::liquid_staking::DropProtocol>::default())
+        }
+        #[cfg(feature = "mock")]
+        super::mock::MOCK => Ok(Box::<super::mock::MockProvider>::default()),
+        _ => Err(LiquidStakingError::UnknownProvider(value.to_owned())),
+    }
+}
+
+/// Given the provider name, return the local provider implementation
+pub(crate) fn resolve_provider(
+    value: &str,
+) -> Result<Box<dyn LiquidStakingCommand>, LiquidStakingError> {
+    match value {
+        #[cfg(feature = "drop")]
+        abstract_drop_adapter::DROP => {
+            Ok(Box::<abstract_drop_adapter::liquid_staking::DropProtocol>::default())
+        }
+        #[cfg(feature = "mock")]
+        super::mock::MOCK => Ok(Box::<super::mock::MockProvider>::default()),
+        _ => Err(LiquidStakingError::ForeignProvider(value.to_owned())),
+    }
+}
+
+/// Given a FULL provider name (e.g. cosmoshub>stride), returns whether the request is local or over IBC
+pub fn is_over_ibc(env: &Env, platform_name: &str) -> Result<(String, bool), LiquidStakingError> {
+    let (chain_name, local_platform_name) = decompose_platform_name(platform_name);
+    if chain_name.is_some() && !is_current_chain(env, &chain_name.clone().unwrap()) {
+        Ok((local_platform_name, true))
+    } else {
+        let platform_id = identify_provider(&local_platform_name)?;
+        // We verify the adapter is available on the current chain
+        if !is_available_on(platform_id, env, chain_name.as_deref()) {
+            return Err(LiquidStakingError::UnknownProviderOnThisPlatform {
+                provider: platform_name.to_string(),
+                chain: chain_name,
+            });
+        }
+        Ok((local_platform_name, false))
+    }
+}
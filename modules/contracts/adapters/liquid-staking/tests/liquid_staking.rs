@@ -0,0 +1,105 @@
+use abstract_adapter::std::objects::UncheckedContractEntry;
+use abstract_client::AbstractClient;
+use abstract_liquid_staking_adapter::{
+    liquid_staking_tester::{LiquidStakingTester, MockLiquidStaking},
+    providers::mock::{
+        hub::{interface::MockHub, ExecuteMsg, InstantiateMsg},
+        MOCK,
+    },
+};
+use abstract_liquid_staking_standard::LIQUID_STAKING_CONTRACT_PREFIX;
+use cosmwasm_std::{coins, Decimal};
+use cw_asset::AssetInfoUnchecked;
+use cw_orch::prelude::*;
+
+const ATOM: &str = "atom";
+const DENOM: &str = "uatom";
+const LIQUID_DENOM: &str = "stuatom";
+const UNBONDING_PERIOD: u64 = 21 * 24 * 60 * 60;
+
+struct MockHubProtocol {
+    hub: MockHub<MockBech32>,
+    exchange_rate: Decimal,
+}
+
+impl MockLiquidStaking for MockHubProtocol {
+    fn name(&self) -> String {
+        MOCK.to_owned()
+    }
+
+    fn asset(&self) -> (String, AssetInfoUnchecked) {
+        (ATOM.to_owned(), AssetInfoUnchecked::native(DENOM))
+    }
+
+    fn liquid_token(&self) -> AssetInfoUnchecked {
+        AssetInfoUnchecked::native(LIQUID_DENOM)
+    }
+
+    fn exchange_rate(&self) -> Decimal {
+        self.exchange_rate
+    }
+
+    fn unbonding_period(&self) -> u64 {
+        UNBONDING_PERIOD
+    }
+
+    fn setup(&self) -> Vec<(UncheckedContractEntry, String)> {
+        vec![(
+            UncheckedContractEntry::new(MOCK, format!("{LIQUID_STAKING_CONTRACT_PREFIX}{ATOM}")),
+            self.hub.addr_str().unwrap(),
+        )]
+    }
+}
+
+fn setup(
+    exchange_rate: Decimal,
+) -> anyhow::Result<LiquidStakingTester<MockBech32, MockHubProtocol>> {
+    let chain = MockBech32::new("mock");
+    let abstr_deployment = AbstractClient::builder(chain.clone()).build()?;
+
+    let hub = MockHub::new("mock_hub", chain.clone());
+    hub.upload()?;
+    hub.instantiate(
+        &InstantiateMsg {
+            denom: DENOM.to_owned(),
+            liquid_denom: LIQUID_DENOM.to_owned(),
+            unbonding_period: UNBONDING_PERIOD,
+        },
+        None,
+        &[],
+    )?;
+    hub.execute(&ExecuteMsg::SetExchangeRate { exchange_rate }, &[])?;
+    // Reserves of the hub, paid out on liquid stake and redemption
+    chain.add_balance(&hub.address()?, coins(100_000_000_000, LIQUID_DENOM))?;
+    chain.add_balance(&hub.address()?, coins(100_000_000_000, DENOM))?;
+
+    LiquidStakingTester::new(abstr_deployment, MockHubProtocol { hub, exchange_rate })
+}
+
+#[test]
+fn liquid_stake() -> anyhow::Result<()> {
+    let liquid_staking_tester = setup(Decimal::one())?;
+    liquid_staking_tester.test_liquid_stake()?;
+    Ok(())
+}
+
+#[test]
+fn liquid_stake_with_exchange_rate() -> anyhow::Result<()> {
+    let liquid_staking_tester = setup(Decimal::percent(125))?;
+    liquid_staking_tester.test_liquid_stake()?;
+    Ok(())
+}
+
+#[test]
+fn redeem() -> anyhow::Result<()> {
+    let liquid_staking_tester = setup(Decimal::percent(125))?;
+    liquid_staking_tester.test_redeem()?;
+    Ok(())
+}
+
+#[test]
+fn exchange_rate() -> anyhow::Result<()> {
+    let liquid_staking_tester = setup(Decimal::percent(125))?;
+    liquid_staking_tester.test_exchange_rate()?;
+    Ok(())
+}
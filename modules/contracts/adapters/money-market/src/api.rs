@@ -6,7 +6,10 @@ use abstract_adapter::sdk::{
 use abstract_adapter::std::objects::{module::ModuleId, AnsAsset, AssetEntry};
 use abstract_money_market_standard::{
     ans_action::MoneyMarketAnsAction,
-    msg::{MoneyMarketExecuteMsg, MoneyMarketName, MoneyMarketQueryMsg},
+    msg::{
+        MoneyMarketExecuteMsg, MoneyMarketName, MoneyMarketQueryMsg, SimulateLTVResponse,
+        UserHealthResponse, UserPositionsResponse,
+    },
     raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
 };
 use cosmwasm_schema::serde::de::DeserializeOwned;
@@ -189,6 +192,42 @@ pub mod raw {
                 },
             })
         }
+
+        /// Liquidate the position of a user on MoneyMarket
+        pub fn liquidate(
+            &self,
+            contract_addr: Addr,
+            user: String,
+            debt_asset: Asset,
+            seize_asset: AssetInfo,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketRawAction {
+                contract_addr: contract_addr.to_string(),
+                request: MoneyMarketRawRequest::Liquidate {
+                    user,
+                    debt_asset: debt_asset.into(),
+                    seize_asset: seize_asset.into(),
+                },
+            })
+        }
+
+        /// Flash loan an asset on MoneyMarket, the actions are executed by the account before the repayment
+        pub fn flash_loan(
+            &self,
+            contract_addr: Addr,
+            asset: Asset,
+            collateral_asset: AssetInfo,
+            actions: Vec<CosmosMsg>,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketRawAction {
+                contract_addr: contract_addr.to_string(),
+                request: MoneyMarketRawRequest::FlashLoan {
+                    asset: asset.into(),
+                    collateral_asset: collateral_asset.into(),
+                    actions,
+                },
+            })
+        }
     }
 
     impl<T: MoneyMarketInterface> MoneyMarket<'_, T> {
@@ -266,6 +305,32 @@ pub mod raw {
                 money_market: self.money_market_name(),
             })
         }
+        pub fn user_health(
+            &self,
+            user: String,
+            collateral_asset: AssetInfo,
+            borrowed_asset: AssetInfo,
+            contract_addr: String,
+        ) -> AbstractSdkResult<UserHealthResponse> {
+            self.query(MoneyMarketQueryMsg::RawUserHealth {
+                user,
+                borrowed_asset: borrowed_asset.into(),
+                collateral_asset: collateral_asset.into(),
+                contract_addr,
+                money_market: self.money_market_name(),
+            })
+        }
+        pub fn simulate_ltv(
+            &self,
+            user: String,
+            action: MoneyMarketRawAction,
+        ) -> AbstractSdkResult<SimulateLTVResponse> {
+            self.query(MoneyMarketQueryMsg::RawSimulateLTV {
+                user,
+                action,
+                money_market: self.money_market_name(),
+            })
+        }
         pub fn price(&self, quote: AssetInfo, base: AssetInfo) -> AbstractSdkResult<Decimal> {
             self.query(MoneyMarketQueryMsg::RawPrice {
                 quote: quote.into(),
@@ -394,6 +459,34 @@ pub mod ans {
                 borrowed_asset,
             })
         }
+
+        /// Liquidate the position of a user on MoneyMarket
+        pub fn liquidate(
+            &self,
+            user: String,
+            debt_asset: AnsAsset,
+            seize_asset: AssetEntry,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketAnsAction::Liquidate {
+                user,
+                debt_asset,
+                seize_asset,
+            })
+        }
+
+        /// Flash loan an asset on MoneyMarket, the actions are executed by the account before the repayment
+        pub fn flash_loan(
+            &self,
+            asset: AnsAsset,
+            collateral_asset: AssetEntry,
+            actions: Vec<CosmosMsg>,
+        ) -> AbstractSdkResult<CosmosMsg> {
+            self.execute(MoneyMarketAnsAction::FlashLoan {
+                asset,
+                collateral_asset,
+                actions,
+            })
+        }
     }
 
     impl<T: MoneyMarketInterface> AnsMoneyMarket<'_, T> {
@@ -457,6 +550,45 @@ pub mod ans {
                 money_market: self.money_market_name(),
             })
         }
+        pub fn user_health(
+            &self,
+            user: String,
+            collateral_asset: AssetEntry,
+            borrowed_asset: AssetEntry,
+        ) -> AbstractSdkResult<UserHealthResponse> {
+            self.query(MoneyMarketQueryMsg::AnsUserHealth {
+                user,
+                borrowed_asset,
+                collateral_asset,
+                money_market: self.money_market_name(),
+            })
+        }
+        pub fn simulate_ltv(
+            &self,
+            user: String,
+            action: MoneyMarketAnsAction,
+        ) -> AbstractSdkResult<SimulateLTVResponse> {
+            self.query(MoneyMarketQueryMsg::AnsSimulateLTV {
+                user,
+                action,
+                money_market: self.money_market_name(),
+            })
+        }
+        /// Positions of the user on this and the `other_money_markets`, valued in `quote`
+        pub fn user_positions(
+            &self,
+            user: String,
+            other_money_markets: Vec<MoneyMarketName>,
+            quote: AssetEntry,
+        ) -> AbstractSdkResult<UserPositionsResponse> {
+            let mut money_markets = vec![self.money_market_name()];
+            money_markets.extend(other_money_markets);
+            self.query(MoneyMarketQueryMsg::UserPositions {
+                user,
+                money_markets,
+                quote,
+            })
+        }
         pub fn price(&self, quote: AssetEntry, base: AssetEntry) -> AbstractSdkResult<Decimal> {
             self.query(MoneyMarketQueryMsg::AnsPrice {
                 quote,
@@ -744,6 +876,95 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn liquidate_msg() {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let money_market = stub
+            .ans_money_market(deps.as_ref(), "mars".into())
+            .with_module_id(TEST_MODULE_ID);
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let money_market_name = "mars".to_string();
+        let user = deps.api.addr_make("liquidated").to_string();
+        let debt_asset = AnsAsset::new("usdc", 1000u128);
+        let seize_asset = AssetEntry::new("juno");
+
+        let expected = expected_request_with_test_account(
+            MoneyMarketExecuteMsg::AnsAction {
+                money_market: money_market_name,
+                action: MoneyMarketAnsAction::Liquidate {
+                    user: user.clone(),
+                    debt_asset: debt_asset.clone(),
+                    seize_asset: seize_asset.clone(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = money_market.liquidate(user, debt_asset, seize_asset);
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn flash_loan_msg() {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        let stub = MockModule::new(deps.api, account.clone());
+        let money_market = stub
+            .ans_money_market(deps.as_ref(), "mars".into())
+            .with_module_id(TEST_MODULE_ID);
+        let abstr = AbstractMockAddrs::new(deps.api);
+
+        let money_market_name = "mars".to_string();
+        let asset = AnsAsset::new("usdc", 1000u128);
+        let collateral_asset = AssetEntry::new("juno");
+        let actions: Vec<CosmosMsg> =
+            vec![wasm_execute(deps.api.addr_make("dex"), &"swap", vec![])
+                .unwrap()
+                .into()];
+
+        let expected = expected_request_with_test_account(
+            MoneyMarketExecuteMsg::AnsAction {
+                money_market: money_market_name,
+                action: MoneyMarketAnsAction::FlashLoan {
+                    asset: asset.clone(),
+                    collateral_asset: collateral_asset.clone(),
+                    actions: actions.clone(),
+                },
+            },
+            account.addr(),
+        );
+
+        let actual = money_market.flash_loan(asset, collateral_asset, actions);
+
+        assert!(actual.is_ok());
+
+        let actual = match actual.unwrap() {
+            CosmosMsg::Wasm(msg) => msg,
+            _ => panic!("expected wasm msg"),
+        };
+        let expected = wasm_execute(&abstr.module_address, &expected, vec![]).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
     mod raw {
         use super::*;
 
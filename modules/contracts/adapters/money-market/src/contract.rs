@@ -5,7 +5,7 @@ use abstract_money_market_standard::{
 };
 use cosmwasm_std::Response;
 
-use crate::{handlers, MONEY_MARKET_ADAPTER_ID};
+use crate::{adapter::FLASH_LOAN, handlers, MONEY_MARKET_ADAPTER_ID};
 
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -21,7 +21,8 @@ pub const MONEY_MARKET_ADAPTER: MoneyMarketAdapter =
     MoneyMarketAdapter::new(MONEY_MARKET_ADAPTER_ID, CONTRACT_VERSION, None)
         .with_instantiate(handlers::instantiate_handler)
         .with_execute(handlers::execute_handler)
-        .with_query(handlers::query_handler);
+        .with_query(handlers::query_handler)
+        .with_replies(&[(FLASH_LOAN, handlers::flash_loan_reply)]);
 
 #[cfg(feature = "export")]
 export_endpoints!(MONEY_MARKET_ADAPTER, MoneyMarketAdapter);
@@ -8,16 +8,19 @@ use abstract_adapter::std::objects::{
     AccountId,
 };
 use abstract_money_market_standard::{
-    ans_action::MoneyMarketActionResolveWrapper, raw_action::MoneyMarketRawAction, MoneyMarketError,
+    ans_action::MoneyMarketActionResolveWrapper,
+    raw_action::{MoneyMarketRawAction, MoneyMarketRawRequest},
+    MoneyMarketError,
 };
-use cosmwasm_std::{ensure_eq, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{ensure, ensure_eq, DepsMut, Env, MessageInfo, ReplyOn, Response};
 
 use crate::{
+    adapter::FLASH_LOAN as FLASH_LOAN_REPLY_ID,
     contract::{MoneyMarketAdapter, MoneyMarketResult},
     handlers::execute::platform_resolver::is_over_ibc,
     msg::MoneyMarketExecuteMsg,
     platform_resolver,
-    state::MONEY_MARKET_FEES,
+    state::{FlashLoanState, FLASH_LOAN, MONEY_MARKET_FEES},
 };
 
 pub fn execute_handler(
@@ -105,21 +108,78 @@ pub fn execute_handler(
 /// Handle an adapter request that can be executed on the local chain
 fn handle_local_request(
     deps: DepsMut,
-    _info: MessageInfo,
+    info: MessageInfo,
     module: &MoneyMarketAdapter,
-    money_market: String,
+    money_market_name: String,
     action: MoneyMarketRawAction,
 ) -> MoneyMarketResult {
-    let money_market = platform_resolver::resolve_money_market(&money_market)?;
-    let target_account = module.account(deps.as_ref())?;
+    let mut money_market = platform_resolver::resolve_money_market(&money_market_name)?;
+    let target_account = module.account(deps.as_ref())?.into_addr();
+
+    if let MoneyMarketRawRequest::FlashLoan {
+        asset,
+        collateral_asset,
+        ..
+    } = &action.request
+    {
+        // The actions are executed by the account, only the account itself can request them
+        ensure_eq!(
+            info.sender,
+            target_account,
+            MoneyMarketError::FlashLoanUnauthorized {}
+        );
+        ensure!(
+            !FLASH_LOAN.exists(deps.storage),
+            MoneyMarketError::FlashLoanInProgress {}
+        );
+        let contract_addr = deps.api.addr_validate(&action.contract_addr)?;
+        let asset = asset.check(deps.api, None)?;
+        let borrowed_asset = asset.info.clone();
+        let collateral_asset = collateral_asset.check(deps.api, None)?;
 
-    let (msgs, _) = crate::adapter::MoneyMarketAdapter::resolve_money_market_action(
+        money_market.fetch_data(
+            target_account.clone(),
+            &deps.querier,
+            &module.ans_host(deps.as_ref())?,
+        )?;
+        let debt_before = money_market.user_borrow(
+            deps.as_ref(),
+            contract_addr.clone(),
+            target_account.clone(),
+            borrowed_asset.clone(),
+            collateral_asset.clone(),
+        )?;
+        let fee = money_market.flash_loan_fee(deps.as_ref(), contract_addr.clone(), &asset)?;
+        let lender_balance_before = borrowed_asset.query_balance(&deps.querier, &contract_addr)?;
+        FLASH_LOAN.save(
+            deps.storage,
+            &FlashLoanState {
+                money_market: money_market_name,
+                account: target_account.clone(),
+                contract_addr,
+                borrowed_asset,
+                collateral_asset,
+                amount: asset.amount,
+                fee,
+                lender_balance_before,
+                debt_before,
+            },
+        )?;
+    }
+
+    let (msgs, reply_id) = crate::adapter::MoneyMarketAdapter::resolve_money_market_action(
         module,
         deps.as_ref(),
-        target_account.into_addr(),
+        target_account,
         action,
         money_market,
     )?;
-    let account_msg = module.executor(deps.as_ref()).execute(msgs)?;
+    let executor = module.executor(deps.as_ref());
+    if reply_id == FLASH_LOAN_REPLY_ID {
+        // The repayment is checked once the account executed the messages
+        let sub_msg = executor.execute_with_reply(msgs, ReplyOn::Success, FLASH_LOAN_REPLY_ID)?;
+        return Ok(Response::new().add_submessage(sub_msg));
+    }
+    let account_msg = executor.execute(msgs)?;
     Ok(Response::new().add_message(account_msg))
 }
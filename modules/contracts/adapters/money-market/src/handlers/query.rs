@@ -1,15 +1,17 @@
 use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_adapter::std::objects::{AnsAsset, AssetEntry};
 use abstract_money_market_standard::{
     ans_action::MoneyMarketActionResolveWrapper,
     msg::{
-        GenerateMessagesResponse, MoneyMarketExecuteMsg, MoneyMarketQueryMsg, PriceResponse,
-        UserBorrowResponse, UserCollateralResponse, UserCurrentLTVResponse, UserDepositResponse,
-        UserMaxLTVResponse,
+        GenerateMessagesResponse, MarketPosition, MoneyMarketExecuteMsg, MoneyMarketName,
+        MoneyMarketQueryMsg, PositionKind, PriceResponse, SimulateLTVResponse, UserBorrowResponse,
+        UserCollateralResponse, UserCurrentLTVResponse, UserDepositResponse, UserMaxLTVResponse,
+        UserPositionsResponse,
     },
     query::MoneyMarketQueryResolveWrapper,
-    MoneyMarketError,
+    MoneyMarketError, UserPosition,
 };
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
+use cosmwasm_std::{to_json_binary, Binary, Decimal, Deps, Env, StdError, Uint128};
 
 use crate::{
     contract::{MoneyMarketAdapter, MoneyMarketResult},
@@ -76,6 +78,11 @@ pub fn query_handler(
             }
         }
         MoneyMarketQueryMsg::Fees {} => fees(deps),
+        MoneyMarketQueryMsg::UserPositions {
+            user,
+            money_markets,
+            quote,
+        } => user_positions(deps, env, module, user, money_markets, quote),
         _ => {
             let money_market = msg.money_market()?;
 
@@ -98,6 +105,66 @@ pub fn fees(deps: Deps) -> MoneyMarketResult<Binary> {
     to_json_binary(&money_market_fees).map_err(Into::into)
 }
 
+/// Aggregate the positions of the user over the local money markets
+fn user_positions(
+    deps: Deps,
+    env: Env,
+    module: &MoneyMarketAdapter,
+    user: String,
+    money_markets: Vec<MoneyMarketName>,
+    quote: AssetEntry,
+) -> MoneyMarketResult<Binary> {
+    let user = deps.api.addr_validate(&user)?;
+    let ans_host = module.ans_host(deps)?;
+    let quote = ans_host.query_asset(&deps.querier, &quote)?;
+
+    let mut response = UserPositionsResponse {
+        positions: vec![],
+        total_supplied_value: Uint128::zero(),
+        total_borrowed_value: Uint128::zero(),
+    };
+    for name in money_markets {
+        let (local_money_market_name, is_over_ibc) = is_over_ibc(&env, &name)?;
+        if is_over_ibc {
+            return Err(MoneyMarketError::IbcMsgQuery);
+        }
+        let mut money_market = platform_resolver::resolve_money_market(&local_money_market_name)?;
+        money_market.fetch_data(user.clone(), &deps.querier, &ans_host)?;
+
+        for UserPosition { kind, asset } in
+            money_market.user_positions(deps, &ans_host, user.clone())?
+        {
+            if asset.amount.is_zero() {
+                continue;
+            }
+            // Positions in unregistered assets can't be named, skip them
+            let Ok(entry) = ans_host.query_asset_reverse(&deps.querier, &asset.info) else {
+                continue;
+            };
+            let price = if asset.info == quote {
+                Decimal::one()
+            } else {
+                money_market.price(deps, asset.info.clone(), quote.clone())?
+            };
+            let value = asset.amount.checked_mul_floor(price)?;
+            let total = match kind {
+                PositionKind::Borrow => &mut response.total_borrowed_value,
+                PositionKind::Deposit | PositionKind::Collateral => {
+                    &mut response.total_supplied_value
+                }
+            };
+            *total = total.checked_add(value)?;
+            response.positions.push(MarketPosition {
+                money_market: name.clone(),
+                kind,
+                asset: AnsAsset::new(entry, asset.amount),
+                value,
+            });
+        }
+    }
+    to_json_binary(&response).map_err(Into::into)
+}
+
 /// Handle an adapter request that can be executed on the local chain
 /// We only execute local queries here
 fn handle_local_query(
@@ -217,6 +284,67 @@ fn handle_local_query(
                 )?,
             })?
         }
+        MoneyMarketQueryMsg::RawUserHealth {
+            user,
+            collateral_asset,
+            borrowed_asset,
+            contract_addr,
+            money_market: _,
+        } => {
+            let user = deps.api.addr_validate(&user)?;
+            let contract_addr = deps.api.addr_validate(&contract_addr)?;
+            let collateral_asset = collateral_asset.check(deps.api, None)?;
+            let borrowed_asset = borrowed_asset.check(deps.api, None)?;
+
+            money_market.fetch_data(user.clone(), &deps.querier, &ans_host)?;
+            to_json_binary(&money_market.user_health(
+                deps,
+                contract_addr,
+                user,
+                borrowed_asset,
+                collateral_asset,
+            )?)?
+        }
+        MoneyMarketQueryMsg::RawSimulateLTV {
+            user,
+            action,
+            money_market: _,
+        } => {
+            let user = deps.api.addr_validate(&user)?;
+            let contract_addr = deps.api.addr_validate(&action.contract_addr)?;
+            let (borrowed_asset, collateral_asset, change) = action
+                .request
+                .position_change()
+                .ok_or(MoneyMarketError::InvalidSimulation {})?;
+            let collateral_asset = collateral_asset.check(deps.api, None)?;
+            let borrowed_asset = borrowed_asset.check(deps.api, None)?;
+
+            money_market.fetch_data(user.clone(), &deps.querier, &ans_host)?;
+            to_json_binary(&SimulateLTVResponse {
+                current_ltv: money_market.current_ltv(
+                    deps,
+                    contract_addr.clone(),
+                    user.clone(),
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                )?,
+                simulated_ltv: money_market.simulate_ltv(
+                    deps,
+                    contract_addr.clone(),
+                    user.clone(),
+                    borrowed_asset.clone(),
+                    collateral_asset.clone(),
+                    change,
+                )?,
+                max_ltv: money_market.max_ltv(
+                    deps,
+                    contract_addr,
+                    user,
+                    borrowed_asset,
+                    collateral_asset,
+                )?,
+            })?
+        }
         MoneyMarketQueryMsg::RawPrice {
             quote,
             base,
@@ -0,0 +1,131 @@
+use abstract_adapter::sdk::features::AbstractNameService;
+use abstract_money_market_standard::MoneyMarketError;
+use cosmwasm_std::{ensure, DepsMut, Env, Reply, Response, Uint128};
+
+use crate::{
+    contract::{MoneyMarketAdapter, MoneyMarketResult},
+    platform_resolver,
+    state::{FlashLoanState, FLASH_LOAN},
+};
+
+/// Checks that the flash loan was repaid to the lender and didn't leave extra debt on the account
+pub fn flash_loan_reply(
+    deps: DepsMut,
+    _env: Env,
+    module: MoneyMarketAdapter,
+    _reply: Reply,
+) -> MoneyMarketResult {
+    let state = FLASH_LOAN.load(deps.storage)?;
+    FLASH_LOAN.remove(deps.storage);
+
+    let lender_balance_after = state
+        .borrowed_asset
+        .query_balance(&deps.querier, &state.contract_addr)?;
+
+    let mut money_market = platform_resolver::resolve_money_market(&state.money_market)?;
+    money_market.fetch_data(
+        state.account.clone(),
+        &deps.querier,
+        &module.ans_host(deps.as_ref())?,
+    )?;
+    let debt_after = money_market.user_borrow(
+        deps.as_ref(),
+        state.contract_addr.clone(),
+        state.account.clone(),
+        state.borrowed_asset.clone(),
+        state.collateral_asset.clone(),
+    )?;
+    ensure_repaid(&state, lender_balance_after, debt_after)?;
+
+    Ok(Response::new())
+}
+
+/// The lender has to get the borrowed amount plus the fee back, without the debt of the account increasing.
+fn ensure_repaid(
+    state: &FlashLoanState,
+    lender_balance_after: Uint128,
+    debt_after: Uint128,
+) -> Result<(), MoneyMarketError> {
+    let expected = state.amount.checked_add(state.fee)?;
+    // Funds sent back to the lender, counted from the moment the borrowed amount left it
+    let repaid = lender_balance_after
+        .checked_add(state.amount)?
+        .saturating_sub(state.lender_balance_before);
+    ensure!(
+        repaid >= expected,
+        MoneyMarketError::FlashLoanNotRepaid { expected, repaid }
+    );
+    ensure!(
+        debt_after <= state.debt_before,
+        MoneyMarketError::FlashLoanDebtIncreased {
+            before: state.debt_before,
+            after: debt_after,
+        }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use cw_asset::AssetInfo;
+
+    use super::*;
+
+    fn flash_loan_state() -> FlashLoanState {
+        FlashLoanState {
+            money_market: "mars".to_owned(),
+            account: Addr::unchecked("account"),
+            contract_addr: Addr::unchecked("red_bank"),
+            borrowed_asset: AssetInfo::native("uusd"),
+            collateral_asset: AssetInfo::native("uatom"),
+            amount: Uint128::new(1_000),
+            fee: Uint128::new(9),
+            lender_balance_before: Uint128::new(10_000),
+            debt_before: Uint128::new(500),
+        }
+    }
+
+    #[test]
+    fn repaid_with_fee() {
+        let state = flash_loan_state();
+        ensure_repaid(&state, Uint128::new(10_009), Uint128::new(500)).unwrap();
+    }
+
+    #[test]
+    fn repayment_failed() {
+        let state = flash_loan_state();
+        // The borrowed amount never came back
+        let err = ensure_repaid(&state, Uint128::new(9_000), Uint128::new(500)).unwrap_err();
+        assert_eq!(
+            err,
+            MoneyMarketError::FlashLoanNotRepaid {
+                expected: Uint128::new(1_009),
+                repaid: Uint128::zero(),
+            }
+        );
+
+        // The principal came back without the fee
+        let err = ensure_repaid(&state, Uint128::new(10_000), Uint128::new(500)).unwrap_err();
+        assert_eq!(
+            err,
+            MoneyMarketError::FlashLoanNotRepaid {
+                expected: Uint128::new(1_009),
+                repaid: Uint128::new(1_000),
+            }
+        );
+    }
+
+    #[test]
+    fn debt_increased() {
+        let state = flash_loan_state();
+        let err = ensure_repaid(&state, Uint128::new(10_009), Uint128::new(1_500)).unwrap_err();
+        assert_eq!(
+            err,
+            MoneyMarketError::FlashLoanDebtIncreased {
+                before: Uint128::new(500),
+                after: Uint128::new(1_500),
+            }
+        );
+    }
+}
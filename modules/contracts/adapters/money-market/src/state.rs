@@ -1,4 +1,23 @@
 use abstract_adapter::std::objects::fee::UsageFee;
+use cosmwasm_std::{Addr, Uint128};
+use cw_asset::AssetInfo;
 use cw_storage_plus::Item;
 
+/// Lender balance and borrow position of the account before a flash loan, checked once the flash loan is executed.
+#[cosmwasm_schema::cw_serde]
+pub struct FlashLoanState {
+    pub money_market: String,
+    pub account: Addr,
+    pub contract_addr: Addr,
+    pub borrowed_asset: AssetInfo,
+    pub collateral_asset: AssetInfo,
+    /// Borrowed amount
+    pub amount: Uint128,
+    /// Fee owed to the lender on top of the borrowed amount
+    pub fee: Uint128,
+    pub lender_balance_before: Uint128,
+    pub debt_before: Uint128,
+}
+
 pub const MONEY_MARKET_FEES: Item<UsageFee> = Item::new("money_market_fees");
+pub const FLASH_LOAN: Item<FlashLoanState> = Item::new("flash_loan");
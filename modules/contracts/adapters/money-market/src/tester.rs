@@ -16,9 +16,10 @@ use abstract_adapter::std::{
 use abstract_client::{AbstractClient, Account, Environment};
 use abstract_money_market_standard::msg::MoneyMarketQueryMsgFns;
 use abstract_money_market_standard::{
-    ans_action::MoneyMarketAnsAction, msg::MoneyMarketExecuteMsg,
+    ans_action::MoneyMarketAnsAction,
+    msg::{MoneyMarketExecuteMsg, PositionKind},
 };
-use cosmwasm_std::{coins, Decimal, Uint128};
+use cosmwasm_std::{coins, wasm_execute, BankMsg, CosmosMsg, Decimal, Uint128};
 use cw_asset::AssetInfoUnchecked;
 use cw_orch::{environment::MutCwEnv, prelude::*};
 
@@ -326,6 +327,93 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
         Ok(account)
     }
 
+    pub fn test_flash_loan(&self) -> anyhow::Result<Account<Chain>> {
+        let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
+        let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
+        let account: Account<Chain> = self.test_provide_collateral()?;
+        let account_addr = account.address()?;
+
+        // Flash loans can only be requested by the account
+        account.as_ref().execute_on_module(
+            MONEY_MARKET_ADAPTER_ID,
+            crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
+                account_address: None,
+                request: MoneyMarketExecuteMsg::AnsAction {
+                    money_market: self.moneymarket.name(),
+                    action: MoneyMarketAnsAction::FlashLoan {
+                        asset: AnsAsset::new(&ans_lending_asset, BORROW_VALUE),
+                        collateral_asset: AssetEntry::new(&ans_collateral_asset),
+                        actions: vec![],
+                    },
+                },
+            }),
+            vec![],
+        )?;
+        let user_borrow = self.moneymarket_adapter.ans_user_borrow(
+            AssetEntry::new(&ans_lending_asset),
+            AssetEntry::new(&ans_collateral_asset),
+            self.moneymarket.name(),
+            account_addr.to_string(),
+        )?;
+
+        assert_eq!(user_borrow.amount.u128(), 0);
+
+        Ok(account)
+    }
+
+    pub fn test_flash_loan_not_repaid(&self) -> anyhow::Result<()> {
+        let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
+        let (ans_lending_asset, asset_info_lending) = self.moneymarket.lending_asset();
+        let account: Account<Chain> = self.test_provide_collateral()?;
+        let account_addr = account.address()?;
+        let recipient = self.abstr_deployment.environment().sender_addr();
+
+        // The account sends the borrowed funds away, so they can't be repaid
+        let send_away: CosmosMsg = match &asset_info_lending {
+            cw_asset::AssetInfoBase::Native(denom) => BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(BORROW_VALUE, denom),
+            }
+            .into(),
+            cw_asset::AssetInfoBase::Cw20(addr) => wasm_execute(
+                addr,
+                &cw20::Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: BORROW_VALUE.into(),
+                },
+                vec![],
+            )?
+            .into(),
+            _ => unreachable!(),
+        };
+        let res = account.as_ref().execute_on_module(
+            MONEY_MARKET_ADAPTER_ID,
+            crate::msg::ExecuteMsg::Module(adapter::AdapterRequestMsg {
+                account_address: None,
+                request: MoneyMarketExecuteMsg::AnsAction {
+                    money_market: self.moneymarket.name(),
+                    action: MoneyMarketAnsAction::FlashLoan {
+                        asset: AnsAsset::new(&ans_lending_asset, BORROW_VALUE),
+                        collateral_asset: AssetEntry::new(&ans_collateral_asset),
+                        actions: vec![send_away],
+                    },
+                },
+            }),
+            vec![],
+        );
+        assert!(res.is_err());
+
+        let user_borrow = self.moneymarket_adapter.ans_user_borrow(
+            AssetEntry::new(&ans_lending_asset),
+            AssetEntry::new(&ans_collateral_asset),
+            self.moneymarket.name(),
+            account_addr.to_string(),
+        )?;
+        assert_eq!(user_borrow.amount.u128(), 0);
+
+        Ok(())
+    }
+
     pub fn test_price(&self) -> anyhow::Result<()> {
         let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
         let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
@@ -356,6 +444,65 @@ impl<Chain: MutCwEnv, Moneymarket: MockMoneyMarket> MoneyMarketTester<Chain, Mon
         Ok(())
     }
 
+    pub fn test_user_health(&self) -> anyhow::Result<()> {
+        let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
+        let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
+
+        let account = self.test_borrow()?;
+
+        let health = self.moneymarket_adapter.ans_user_health(
+            AssetEntry::new(&ans_lending_asset),
+            AssetEntry::new(&ans_collateral_asset),
+            self.moneymarket.name(),
+            account.address()?.to_string(),
+        )?;
+
+        assert!(health.health_factor.unwrap() > Decimal::one());
+        assert!(health.liquidation_price.is_some());
+
+        Ok(())
+    }
+
+    pub fn test_simulate_ltv(&self) -> anyhow::Result<()> {
+        let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
+        let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
+
+        let account = self.test_borrow()?;
+
+        let simulation = self.moneymarket_adapter.ans_simulate_ltv(
+            MoneyMarketAnsAction::Borrow {
+                borrow_asset: AnsAsset::new(&ans_lending_asset, BORROW_VALUE),
+                collateral_asset: AssetEntry::new(&ans_collateral_asset),
+            },
+            self.moneymarket.name(),
+            account.address()?.to_string(),
+        )?;
+
+        assert!(simulation.simulated_ltv > simulation.current_ltv);
+
+        Ok(())
+    }
+
+    pub fn test_user_positions(&self) -> anyhow::Result<()> {
+        let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
+
+        let account = self.test_borrow()?;
+
+        let positions = self.moneymarket_adapter.user_positions(
+            vec![self.moneymarket.name()],
+            AssetEntry::new(&ans_lending_asset),
+            account.address()?.to_string(),
+        )?;
+
+        assert!(positions
+            .positions
+            .iter()
+            .any(|position| position.kind == PositionKind::Borrow));
+        assert!(positions.total_supplied_value > positions.total_borrowed_value);
+
+        Ok(())
+    }
+
     pub fn test_max_ltv(&self) -> anyhow::Result<()> {
         let (ans_collateral_asset, _asset_info_collateral) = self.moneymarket.collateral_asset();
         let (ans_lending_asset, _asset_info_lending) = self.moneymarket.lending_asset();
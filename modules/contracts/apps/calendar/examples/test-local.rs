@@ -70,6 +70,8 @@ fn main() -> anyhow::Result<()> {
                 hour: 17,
                 minute: 0,
             },
+            weekly_availability: vec![],
+            refund_policy: vec![],
         },
         &[],
     )?;
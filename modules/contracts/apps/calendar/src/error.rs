@@ -83,4 +83,25 @@ pub enum CalendarError {
 
     #[error("Minutes out of bounds")]
     MinutesOutOfBounds {},
+
+    #[error("Day is not available for meetings")]
+    DayNotAvailable {},
+
+    #[error("Refund share can't exceed 100%")]
+    InvalidRefundShare {},
+
+    #[error("A series needs between 1 and {max_occurrences} occurrences and a non-zero interval")]
+    InvalidRecurrence { max_occurrences: u32 },
+
+    #[error("Only the requester can cancel the meeting")]
+    NotMeetingRequester {},
+
+    #[error("Meeting already started")]
+    MeetingAlreadyStarted {},
+
+    #[error("Meeting already cancelled")]
+    MeetingAlreadyCancelled {},
+
+    #[error("Date range can't exceed {max_days} days")]
+    DateRangeTooLarge { max_days: u32 },
 }
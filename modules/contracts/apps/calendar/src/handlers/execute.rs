@@ -3,9 +3,10 @@ use abstract_app::sdk::{
     TransferInterface,
 };
 use abstract_app::std::objects::AssetEntry;
-use chrono::{DateTime, FixedOffset, LocalResult, NaiveTime, TimeZone};
+use chrono::{DateTime, FixedOffset, LocalResult, TimeZone};
 use cosmwasm_std::{
-    BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, Int64, MessageInfo, StdError, Uint128,
+    Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, Int64, MessageInfo, Response,
+    StdError, Storage, Uint128,
 };
 use cw_asset::AssetInfoBase;
 use cw_utils::must_pay;
@@ -13,10 +14,17 @@ use cw_utils::must_pay;
 use crate::{
     contract::{CalendarApp, CalendarAppResult},
     error::CalendarError,
-    msg::CalendarExecuteMsg,
-    state::{Meeting, CALENDAR, CONFIG},
+    msg::{CalendarExecuteMsg, RefundTier, WeekdayWindow},
+    state::{
+        day_datetime, Config, Meeting, MeetingSeries, BLOCKED_DAYS, CALENDAR, CONFIG,
+        NEXT_SERIES_ID, SERIES,
+    },
 };
 
+/// The maximum number of meetings in a series.
+pub const MAX_SERIES_OCCURRENCES: u32 = 52;
+pub(crate) const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
 enum StakeAction {
     Return,
     FullSlash,
@@ -35,6 +43,28 @@ pub fn execute_handler(
             start_time,
             end_time,
         } => request_meeting(deps, info, module, env, start_time, end_time),
+        CalendarExecuteMsg::RequestMeetingSeries {
+            start_time,
+            end_time,
+            interval_days,
+            occurrences,
+        } => request_meeting_series(
+            deps,
+            info,
+            module,
+            env,
+            start_time,
+            end_time,
+            interval_days,
+            occurrences,
+        ),
+        CalendarExecuteMsg::CancelMeeting {
+            day_datetime,
+            meeting_index,
+        } => cancel_meeting(deps, info, module, env, day_datetime, meeting_index),
+        CalendarExecuteMsg::CancelMeetingSeries { series_id } => {
+            cancel_meeting_series(deps, info, module, env, series_id)
+        }
         CalendarExecuteMsg::SlashFullStake {
             day_datetime,
             meeting_index,
@@ -75,7 +105,22 @@ pub fn execute_handler(
         CalendarExecuteMsg::UpdateConfig {
             price_per_minute,
             denom,
-        } => update_config(deps, env, info, module, price_per_minute, denom),
+            weekly_availability,
+            refund_policy,
+        } => update_config(
+            deps,
+            env,
+            info,
+            module,
+            price_per_minute,
+            denom,
+            weekly_availability,
+            refund_policy,
+        ),
+        CalendarExecuteMsg::UpdateBlockedDays {
+            to_block,
+            to_unblock,
+        } => update_blocked_days(deps, env, info, module, to_block, to_unblock),
     }
 }
 
@@ -90,70 +135,273 @@ fn request_meeting(
     let config = CONFIG.load(deps.storage)?;
     let amount_sent = must_pay(&info, &config.denom)?;
 
+    let (_, meeting) = book_meeting(
+        deps.storage,
+        &config,
+        &env,
+        meeting_start_time.i64(),
+        meeting_end_time.i64(),
+        info.sender,
+        None,
+    )?;
+
+    let expected_amount = meeting.amount_staked;
+    if amount_sent != expected_amount {
+        return Err(CalendarError::InvalidStakeAmountSent { expected_amount });
+    }
+
+    Ok(module
+        .response("request_meeting")
+        .add_attribute("meeting_start_time", meeting.start_time.to_string())
+        .add_attribute("meeting_end_time", meeting.end_time.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn request_meeting_series(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: CalendarApp,
+    env: Env,
+    meeting_start_time: Int64,
+    meeting_end_time: Int64,
+    interval_days: u32,
+    occurrences: u32,
+) -> CalendarAppResult {
+    if interval_days == 0 || occurrences == 0 || occurrences > MAX_SERIES_OCCURRENCES {
+        return Err(CalendarError::InvalidRecurrence {
+            max_occurrences: MAX_SERIES_OCCURRENCES,
+        });
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let amount_sent = must_pay(&info, &config.denom)?;
+
+    let series_id = NEXT_SERIES_ID.may_load(deps.storage)?.unwrap_or_default();
+    NEXT_SERIES_ID.save(deps.storage, &(series_id + 1))?;
+
+    let interval_seconds = interval_days as i64 * SECONDS_PER_DAY;
+    let mut expected_amount = Uint128::zero();
+    let mut day_datetimes = Vec::with_capacity(occurrences as usize);
+    for occurrence in 0..occurrences as i64 {
+        let offset = occurrence * interval_seconds;
+        let (day_datetime, meeting) = book_meeting(
+            deps.storage,
+            &config,
+            &env,
+            meeting_start_time.i64() + offset,
+            meeting_end_time.i64() + offset,
+            info.sender.clone(),
+            Some(series_id),
+        )?;
+        expected_amount += meeting.amount_staked;
+        day_datetimes.push(day_datetime);
+    }
+
+    if amount_sent != expected_amount {
+        return Err(CalendarError::InvalidStakeAmountSent { expected_amount });
+    }
+
+    SERIES.save(
+        deps.storage,
+        series_id,
+        &MeetingSeries {
+            requester: info.sender,
+            day_datetimes,
+        },
+    )?;
+
+    Ok(module
+        .response("request_meeting_series")
+        .add_attribute("series_id", series_id.to_string())
+        .add_attribute("occurrences", occurrences.to_string()))
+}
+
+/// Validates the meeting and adds it to the calendar.
+fn book_meeting(
+    storage: &mut dyn Storage,
+    config: &Config,
+    env: &Env,
+    meeting_start_time: i64,
+    meeting_end_time: i64,
+    requester: Addr,
+    series_id: Option<u64>,
+) -> CalendarAppResult<(i64, Meeting)> {
     let timezone: FixedOffset =
         FixedOffset::east_opt(config.utc_offset).ok_or(CalendarError::InvalidUtcOffset {})?;
 
     let meeting_start_datetime: DateTime<FixedOffset> =
-        get_date_time(timezone, meeting_start_time)?;
-    let meeting_start_time: NaiveTime = meeting_start_datetime.time();
-
-    let meeting_end_datetime = get_date_time(timezone, meeting_end_time)?;
-    let meeting_end_time: NaiveTime = meeting_end_datetime.time();
+        get_date_time(timezone, Int64::new(meeting_start_time))?;
+    let meeting_end_datetime = get_date_time(timezone, Int64::new(meeting_end_time))?;
 
-    let meeting: Meeting = Meeting::new(
-        &config,
+    let mut meeting: Meeting = Meeting::new(
+        config,
         &env.block,
         meeting_start_datetime,
         meeting_end_datetime,
-        info.sender,
-        amount_sent,
+        requester,
+        Uint128::zero(),
     )?;
-
-    let meeting_start_timestamp = meeting.start_time;
-    let meeting_end_timestamp = meeting.end_time;
+    meeting.series_id = series_id;
 
     // This number will be positive enforced by previous checks.
     let duration_in_minutes: Uint128 =
-        Uint128::new((meeting_end_time - meeting_start_time).num_minutes() as u128);
-
-    let expected_amount = duration_in_minutes * config.price_per_minute;
-    if amount_sent != expected_amount {
-        return Err(CalendarError::InvalidStakeAmountSent { expected_amount });
-    }
+        Uint128::new(((meeting.end_time - meeting.start_time) / 60) as u128);
+    meeting.amount_staked = duration_in_minutes * config.price_per_minute;
 
     // Get unix start date of the current day
-    let start_of_day_timestamp: i64 = meeting_start_datetime
-        .date_naive()
-        .and_time(NaiveTime::default())
-        .and_utc()
-        .timestamp();
+    let start_of_day_timestamp: i64 = day_datetime(meeting_start_datetime.date_naive());
+
+    if BLOCKED_DAYS.has(storage, start_of_day_timestamp) {
+        return Err(CalendarError::DayNotAvailable {});
+    }
 
     let mut existing_meetings: Vec<Meeting> = CALENDAR
-        .may_load(deps.storage, start_of_day_timestamp)?
+        .may_load(storage, start_of_day_timestamp)?
         .unwrap_or_default();
 
-    if !existing_meetings.is_empty() {
-        //Validate that there are no colisions.
-        for meeting in existing_meetings.iter() {
-            let start_time_conflicts = meeting.start_time <= meeting_start_timestamp
-                && meeting_start_timestamp < meeting.end_time;
+    //Validate that there are no colisions.
+    for existing in existing_meetings.iter().filter(|m| !m.cancelled) {
+        let start_time_conflicts =
+            existing.start_time <= meeting.start_time && meeting.start_time < existing.end_time;
 
-            let end_time_conflicts = meeting.start_time < meeting_end_timestamp
-                && meeting_end_timestamp <= meeting.end_time;
+        let end_time_conflicts =
+            existing.start_time < meeting.end_time && meeting.end_time <= existing.end_time;
 
-            if start_time_conflicts || end_time_conflicts {
-                return Err(CalendarError::MeetingConflictExists {});
-            }
+        if start_time_conflicts || end_time_conflicts {
+            return Err(CalendarError::MeetingConflictExists {});
         }
     }
-    existing_meetings.push(meeting);
+    existing_meetings.push(meeting.clone());
 
-    CALENDAR.save(deps.storage, start_of_day_timestamp, &existing_meetings)?;
+    CALENDAR.save(storage, start_of_day_timestamp, &existing_meetings)?;
 
-    Ok(module
-        .response("request_meeting")
-        .add_attribute("meeting_start_time", meeting_start_timestamp.to_string())
-        .add_attribute("meeting_end_time", meeting_end_timestamp.to_string()))
+    Ok((start_of_day_timestamp, meeting))
+}
+
+fn cancel_meeting(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: CalendarApp,
+    env: Env,
+    day_datetime: Int64,
+    meeting_index: u32,
+) -> CalendarAppResult {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut meetings = CALENDAR
+        .may_load(deps.storage, day_datetime.i64())?
+        .ok_or(CalendarError::NoMeetingsAtGivenDayDateTime {})?;
+    let meeting: &mut Meeting = meetings
+        .get_mut(meeting_index as usize)
+        .ok_or(CalendarError::MeetingDoesNotExist {})?;
+
+    if meeting.requester != info.sender {
+        return Err(CalendarError::NotMeetingRequester {});
+    }
+    let (refund, slashed) = cancel(meeting, &config, &env)?;
+
+    CALENDAR.save(deps.storage, day_datetime.i64(), &meetings)?;
+
+    settle_cancellation(
+        deps.as_ref(),
+        &module,
+        module.response("cancel_meeting"),
+        &config,
+        info.sender,
+        refund,
+        slashed,
+    )
+}
+
+fn cancel_meeting_series(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: CalendarApp,
+    env: Env,
+    series_id: u64,
+) -> CalendarAppResult {
+    let config = CONFIG.load(deps.storage)?;
+    let series = SERIES.load(deps.storage, series_id)?;
+    if series.requester != info.sender {
+        return Err(CalendarError::NotMeetingRequester {});
+    }
+
+    let mut total_refund = Uint128::zero();
+    let mut total_slashed = Uint128::zero();
+    for day_datetime in series.day_datetimes {
+        let mut meetings = CALENDAR.load(deps.storage, day_datetime)?;
+        for meeting in meetings.iter_mut().filter(|meeting| {
+            meeting.series_id == Some(series_id)
+                && !meeting.cancelled
+                && (env.block.time.seconds() as i64) < meeting.start_time
+        }) {
+            let (refund, slashed) = cancel(meeting, &config, &env)?;
+            total_refund += refund;
+            total_slashed += slashed;
+        }
+        CALENDAR.save(deps.storage, day_datetime, &meetings)?;
+    }
+
+    settle_cancellation(
+        deps.as_ref(),
+        &module,
+        module
+            .response("cancel_meeting_series")
+            .add_attribute("series_id", series_id.to_string()),
+        &config,
+        info.sender,
+        total_refund,
+        total_slashed,
+    )
+}
+
+/// Cancels the meeting and splits its stake into the refund and the slashed amount.
+fn cancel(
+    meeting: &mut Meeting,
+    config: &Config,
+    env: &Env,
+) -> CalendarAppResult<(Uint128, Uint128)> {
+    if meeting.cancelled {
+        return Err(CalendarError::MeetingAlreadyCancelled {});
+    }
+    let now = env.block.time.seconds() as i64;
+    if now >= meeting.start_time {
+        return Err(CalendarError::MeetingAlreadyStarted {});
+    }
+
+    let notice = (meeting.start_time - now) as u64;
+    let refund = meeting.amount_staked.mul_floor(config.refund_share(notice));
+    let slashed = meeting.amount_staked - refund;
+
+    meeting.cancelled = true;
+    meeting.amount_staked = Uint128::zero();
+    Ok((refund, slashed))
+}
+
+/// Refunds the requester and deposits the slashed stake on the account.
+fn settle_cancellation(
+    deps: Deps,
+    module: &CalendarApp,
+    mut response: Response,
+    config: &Config,
+    requester: Addr,
+    refund: Uint128,
+    slashed: Uint128,
+) -> CalendarAppResult {
+    if !refund.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: requester.into_string(),
+            amount: vec![Coin::new(refund, config.denom.clone())],
+        });
+    }
+    if !slashed.is_zero() {
+        let account_deposit_msgs: Vec<CosmosMsg> = module
+            .bank(deps)
+            .deposit(vec![Coin::new(slashed, config.denom.clone())])?;
+        response = response.add_messages(account_deposit_msgs);
+    }
+    Ok(response
+        .add_attribute("refund", refund)
+        .add_attribute("slashed", slashed))
 }
 
 fn handle_stake(
@@ -234,6 +482,7 @@ fn handle_stake(
     Ok(response)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update_config(
     deps: DepsMut,
     env: Env,
@@ -241,6 +490,8 @@ fn update_config(
     module: CalendarApp,
     price_per_minute: Option<Uint128>,
     denom: Option<AssetEntry>,
+    weekly_availability: Option<Vec<WeekdayWindow>>,
+    refund_policy: Option<Vec<RefundTier>>,
 ) -> CalendarAppResult {
     module
         .admin
@@ -256,10 +507,44 @@ fn update_config(
         config.denom = denom;
         attrs.push(("denom", unresolved.to_string()));
     }
+    if let Some(weekly_availability) = weekly_availability {
+        for window in weekly_availability.iter() {
+            window.validate()?;
+        }
+        config.weekly_availability = weekly_availability;
+        attrs.push(("weekly_availability", "updated".to_owned()));
+    }
+    if let Some(refund_policy) = refund_policy {
+        for tier in refund_policy.iter() {
+            tier.validate()?;
+        }
+        config.refund_policy = refund_policy;
+        attrs.push(("refund_policy", "updated".to_owned()));
+    }
     CONFIG.save(deps.storage, &config)?;
     Ok(module.custom_response("update_config", attrs))
 }
 
+fn update_blocked_days(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: CalendarApp,
+    to_block: Vec<Int64>,
+    to_unblock: Vec<Int64>,
+) -> CalendarAppResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+    for day_datetime in to_block {
+        BLOCKED_DAYS.save(deps.storage, day_datetime.i64(), &Empty {})?;
+    }
+    for day_datetime in to_unblock {
+        BLOCKED_DAYS.remove(deps.storage, day_datetime.i64());
+    }
+    Ok(module.response("update_blocked_days"))
+}
+
 pub fn resolve_native_ans_denom(
     deps: Deps,
     module: &CalendarApp,
@@ -274,7 +559,7 @@ pub fn resolve_native_ans_denom(
     Ok(denom)
 }
 
-fn get_date_time(
+pub(crate) fn get_date_time(
     timezone: FixedOffset,
     timestamp: Int64,
 ) -> CalendarAppResult<DateTime<FixedOffset>> {
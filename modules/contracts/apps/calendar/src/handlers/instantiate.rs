@@ -24,12 +24,21 @@ pub fn instantiate_handler(
         return Err(CalendarError::EndTimeMustBeAfterStartTime {});
     }
 
+    for window in msg.weekly_availability.iter() {
+        window.validate()?;
+    }
+    for tier in msg.refund_policy.iter() {
+        tier.validate()?;
+    }
+
     let config: Config = Config {
         price_per_minute: msg.price_per_minute,
         denom,
         utc_offset: msg.utc_offset,
         start_time: msg.start_time,
         end_time: msg.end_time,
+        weekly_availability: msg.weekly_availability,
+        refund_policy: msg.refund_policy,
     };
 
     CONFIG.save(deps.storage, &config)?;
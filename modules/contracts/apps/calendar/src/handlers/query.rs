@@ -1,14 +1,20 @@
+use chrono::{Datelike, FixedOffset, NaiveTime, TimeZone};
 use cosmwasm_std::{to_json_binary, Binary, Deps, Env, Int64, StdResult};
 
+use super::execute::{get_date_time, SECONDS_PER_DAY};
 use crate::{
     contract::{CalendarApp, CalendarAppResult},
-    msg::{CalendarQueryMsg, ConfigResponse, MeetingsResponse},
-    state::{CALENDAR, CONFIG},
+    error::CalendarError,
+    msg::{CalendarQueryMsg, ConfigResponse, FreeSlotsResponse, MeetingsResponse, Slot},
+    state::{day_datetime, MeetingSeries, BLOCKED_DAYS, CALENDAR, CONFIG, SERIES},
 };
 
+/// The maximum number of days that can be queried for free slots at once.
+pub const MAX_FREE_SLOTS_DAYS: u32 = 31;
+
 pub fn query_handler(
     deps: Deps,
-    _env: Env,
+    env: Env,
     _module: &CalendarApp,
     msg: CalendarQueryMsg,
 ) -> CalendarAppResult<Binary> {
@@ -17,6 +23,13 @@ pub fn query_handler(
         CalendarQueryMsg::Meetings { day_datetime } => {
             to_json_binary(&query_meetings(deps, day_datetime)?)
         }
+        CalendarQueryMsg::MeetingSeries { series_id } => {
+            to_json_binary(&query_meeting_series(deps, series_id)?)
+        }
+        CalendarQueryMsg::FreeSlots {
+            start_time,
+            end_time,
+        } => to_json_binary(&query_free_slots(deps, env, start_time, end_time)?),
     }
     .map_err(Into::into)
 }
@@ -28,6 +41,8 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         utc_offset: config.utc_offset,
         start_time: config.start_time,
         end_time: config.end_time,
+        weekly_availability: config.weekly_availability,
+        refund_policy: config.refund_policy,
     })
 }
 
@@ -37,3 +52,82 @@ fn query_meetings(deps: Deps, day_datetime: Int64) -> StdResult<MeetingsResponse
         .unwrap_or_default();
     Ok(MeetingsResponse { meetings })
 }
+
+fn query_meeting_series(deps: Deps, series_id: u64) -> StdResult<MeetingSeries> {
+    SERIES.load(deps.storage, series_id)
+}
+
+fn query_free_slots(
+    deps: Deps,
+    env: Env,
+    start_time: Int64,
+    end_time: Int64,
+) -> CalendarAppResult<FreeSlotsResponse> {
+    if start_time >= end_time {
+        return Err(CalendarError::EndTimeMustBeAfterStartTime {});
+    }
+    if end_time.i64() - start_time.i64() > MAX_FREE_SLOTS_DAYS as i64 * SECONDS_PER_DAY {
+        return Err(CalendarError::DateRangeTooLarge {
+            max_days: MAX_FREE_SLOTS_DAYS,
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let timezone: FixedOffset =
+        FixedOffset::east_opt(config.utc_offset).ok_or(CalendarError::InvalidUtcOffset {})?;
+
+    // Slots in the past can't be booked, meetings start on a whole minute.
+    let now = env.block.time.seconds() as i64;
+    let range_start = start_time.i64().max((now + 59) / 60 * 60);
+    let range_end = end_time.i64();
+
+    let mut slots: Vec<Slot> = vec![];
+    let mut next_date = Some(get_date_time(timezone, start_time)?.date_naive());
+    let last_date = get_date_time(timezone, end_time)?.date_naive();
+    while let Some(date) = next_date.filter(|date| *date <= last_date) {
+        next_date = date.succ_opt();
+        let day = day_datetime(date);
+        if BLOCKED_DAYS.has(deps.storage, day) {
+            continue;
+        }
+
+        let mut meetings = CALENDAR.may_load(deps.storage, day)?.unwrap_or_default();
+        meetings.retain(|meeting| !meeting.cancelled);
+        meetings.sort_by_key(|meeting| meeting.start_time);
+
+        for (window_start, window_end) in config.windows_on(date.weekday().into()) {
+            let to_timestamp = |time: NaiveTime| {
+                timezone
+                    .from_local_datetime(&date.and_time(time))
+                    .single()
+                    .map(|datetime| datetime.timestamp())
+                    .ok_or(CalendarError::InvalidTime {})
+            };
+            let window_start = to_timestamp(window_start.into())?.max(range_start);
+            let window_end = to_timestamp(window_end.into())?.min(range_end);
+
+            // Subtract the booked meetings from the window.
+            let mut cursor = window_start;
+            for meeting in meetings.iter() {
+                if meeting.end_time <= cursor || meeting.start_time >= window_end {
+                    continue;
+                }
+                if meeting.start_time > cursor {
+                    slots.push(Slot {
+                        start_time: cursor,
+                        end_time: meeting.start_time,
+                    });
+                }
+                cursor = cursor.max(meeting.end_time);
+            }
+            if cursor < window_end {
+                slots.push(Slot {
+                    start_time: cursor,
+                    end_time: window_end,
+                });
+            }
+        }
+    }
+
+    Ok(FreeSlotsResponse { slots })
+}
@@ -1,9 +1,13 @@
 use abstract_app::std::objects::AssetEntry;
 use chrono::NaiveTime;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Int64, Uint128};
+use cosmwasm_std::{Decimal, Int64, Uint128};
 
-use crate::{contract::CalendarApp, error::CalendarError, state::Meeting};
+use crate::{
+    contract::CalendarApp,
+    error::CalendarError,
+    state::{Meeting, MeetingSeries},
+};
 
 // This is used for type safety and re-exporting the contract endpoint structs.
 abstract_app::app_msg_types!(CalendarApp, CalendarExecuteMsg, CalendarQueryMsg);
@@ -33,6 +37,75 @@ impl From<Time> for NaiveTime {
     }
 }
 
+#[cosmwasm_schema::cw_serde]
+#[derive(Copy, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(value: chrono::Weekday) -> Self {
+        match value {
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+        }
+    }
+}
+
+/// Window on a weekday during which meetings can be scheduled.
+#[cosmwasm_schema::cw_serde]
+pub struct WeekdayWindow {
+    pub weekday: Weekday,
+    pub start_time: Time,
+    pub end_time: Time,
+}
+
+impl WeekdayWindow {
+    pub fn validate(&self) -> Result<(), CalendarError> {
+        self.start_time.validate()?;
+        self.end_time.validate()?;
+        if self.start_time >= self.end_time {
+            return Err(CalendarError::EndTimeMustBeAfterStartTime {});
+        }
+        Ok(())
+    }
+}
+
+/// Share of the stake that's refunded when a meeting is cancelled at least `min_notice` seconds
+/// before it starts.
+#[cosmwasm_schema::cw_serde]
+pub struct RefundTier {
+    pub min_notice: u64,
+    pub refund: Decimal,
+}
+
+impl RefundTier {
+    pub fn validate(&self) -> Result<(), CalendarError> {
+        if self.refund > Decimal::one() {
+            return Err(CalendarError::InvalidRefundShare {});
+        }
+        Ok(())
+    }
+}
+
+/// A free time slot, as unix timestamps.
+#[cosmwasm_schema::cw_serde]
+pub struct Slot {
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct CalendarInstantiateMsg {
     /// The price per minute charged to determine the amount of stake necessary to request a
@@ -46,6 +119,12 @@ pub struct CalendarInstantiateMsg {
     pub start_time: Time,
     /// The end time for each day that meetings can be scheduled.
     pub end_time: Time,
+    /// Per-weekday windows that meetings can be scheduled in. Overrides the daily `start_time`
+    /// and `end_time` when not empty, weekdays without a window are unavailable.
+    pub weekly_availability: Vec<WeekdayWindow>,
+    /// The refund policy for meetings cancelled by the requester. The highest refund whose notice
+    /// is met applies, the rest of the stake is slashed.
+    pub refund_policy: Vec<RefundTier>,
 }
 
 /// App execute messages
@@ -60,6 +139,32 @@ pub enum CalendarExecuteMsg {
         /// The unix timestamp of the end datetime of the meeting.
         end_time: Int64,
     },
+    #[cw_orch(payable)]
+    /// Request a series of recurring meetings. The stake for every occurrence is paid up-front.
+    RequestMeetingSeries {
+        /// The unix timestamp of the start datetime of the first meeting.
+        start_time: Int64,
+        /// The unix timestamp of the end datetime of the first meeting.
+        end_time: Int64,
+        /// The number of days between two meetings.
+        interval_days: u32,
+        /// The number of meetings in the series.
+        occurrences: u32,
+    },
+    /// Cancels a meeting that hasn't started yet. Requester only.
+    /// Part of the stake is refunded according to the refund policy.
+    CancelMeeting {
+        /// The unix timestamp denoting the start of the day the meeting is on. This is equivalent
+        /// to the "time" portion being all zero with respect to the `config.utc_offset`.
+        day_datetime: Int64,
+        /// The index of the meeting to be cancelled.
+        meeting_index: u32,
+    },
+    /// Cancels all meetings of a series that haven't started yet. Requester only.
+    CancelMeetingSeries {
+        /// The id of the series.
+        series_id: u64,
+    },
     /// Fully slashes the stake for a completed meeting. Admin only.
     SlashFullStake {
         /// The unix timestamp denoting the start of the day the meeting is on. This is equivalent
@@ -93,6 +198,17 @@ pub enum CalendarExecuteMsg {
         price_per_minute: Option<Uint128>,
         /// The updated denom.
         denom: Option<AssetEntry>,
+        /// The updated per-weekday windows.
+        weekly_availability: Option<Vec<WeekdayWindow>>,
+        /// The updated refund policy.
+        refund_policy: Option<Vec<RefundTier>>,
+    },
+    /// Block out or re-open days for new meetings. Admin only.
+    UpdateBlockedDays {
+        /// The unix timestamps denoting the start of the days to block.
+        to_block: Vec<Int64>,
+        /// The unix timestamps denoting the start of the days to unblock.
+        to_unblock: Vec<Int64>,
     },
 }
 
@@ -108,6 +224,14 @@ pub enum CalendarQueryMsg {
     /// Returns [`MeetingsResponse`]
     #[returns(MeetingsResponse)]
     Meetings { day_datetime: Int64 },
+    /// Returns a meeting series.
+    /// Returns [`MeetingSeries`]
+    #[returns(MeetingSeries)]
+    MeetingSeries { series_id: u64 },
+    /// Returns the free slots between two unix timestamps, at most 31 days apart.
+    /// Returns [`FreeSlotsResponse`]
+    #[returns(FreeSlotsResponse)]
+    FreeSlots { start_time: Int64, end_time: Int64 },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -119,9 +243,16 @@ pub struct ConfigResponse {
     pub utc_offset: i32,
     pub start_time: Time,
     pub end_time: Time,
+    pub weekly_availability: Vec<WeekdayWindow>,
+    pub refund_policy: Vec<RefundTier>,
 }
 
 #[cosmwasm_schema::cw_serde]
 pub struct MeetingsResponse {
     pub meetings: Vec<Meeting>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct FreeSlotsResponse {
+    pub slots: Vec<Slot>,
+}
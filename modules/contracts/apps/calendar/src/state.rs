@@ -1,8 +1,11 @@
-use chrono::{DateTime, FixedOffset, NaiveTime, Timelike};
-use cosmwasm_std::{Addr, BlockInfo, Uint128};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Timelike};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
 
-use crate::{error::CalendarError, msg::Time};
+use crate::{
+    error::CalendarError,
+    msg::{RefundTier, Time, Weekday, WeekdayWindow},
+};
 
 #[cosmwasm_schema::cw_serde]
 pub struct Config {
@@ -11,6 +14,34 @@ pub struct Config {
     pub utc_offset: i32,
     pub start_time: Time,
     pub end_time: Time,
+    #[serde(default)]
+    pub weekly_availability: Vec<WeekdayWindow>,
+    #[serde(default)]
+    pub refund_policy: Vec<RefundTier>,
+}
+
+impl Config {
+    /// The windows meetings can be scheduled in on the given weekday.
+    pub fn windows_on(&self, weekday: Weekday) -> Vec<(Time, Time)> {
+        if self.weekly_availability.is_empty() {
+            return vec![(self.start_time, self.end_time)];
+        }
+        self.weekly_availability
+            .iter()
+            .filter(|window| window.weekday == weekday)
+            .map(|window| (window.start_time, window.end_time))
+            .collect()
+    }
+
+    /// The share of the stake refunded when cancelling with the given notice in seconds.
+    pub fn refund_share(&self, notice: u64) -> Decimal {
+        self.refund_policy
+            .iter()
+            .filter(|tier| notice >= tier.min_notice)
+            .map(|tier| tier.refund)
+            .max()
+            .unwrap_or_default()
+    }
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -19,6 +50,11 @@ pub struct Meeting {
     pub end_time: i64,
     pub requester: Addr,
     pub amount_staked: Uint128,
+    /// The series this meeting is part of.
+    #[serde(default)]
+    pub series_id: Option<u64>,
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 impl Meeting {
@@ -36,9 +72,6 @@ impl Meeting {
         let meeting_start_time: NaiveTime = meeting_start_datetime.time();
         let meeting_end_time: NaiveTime = meeting_end_datetime.time();
 
-        let calendar_start_time: NaiveTime = config.start_time.into();
-        let calendar_end_time: NaiveTime = config.end_time.into();
-
         if meeting_start_datetime.date_naive() != meeting_end_datetime.date_naive() {
             return Err(CalendarError::StartAndEndTimeNotOnSameDay {});
         }
@@ -61,10 +94,20 @@ impl Meeting {
             return Err(CalendarError::EndTimeMustBeAfterStartTime {});
         }
 
-        if meeting_start_time < calendar_start_time || meeting_start_time > calendar_end_time {
-            return Err(CalendarError::OutOfBoundsStartTime {});
+        let windows = config.windows_on(meeting_start_datetime.weekday().into());
+        if windows.is_empty() {
+            return Err(CalendarError::DayNotAvailable {});
         }
 
+        // The meeting has to fit in the window it starts in.
+        let Some((calendar_start_time, calendar_end_time)) = windows
+            .into_iter()
+            .map(|(start, end)| (NaiveTime::from(start), NaiveTime::from(end)))
+            .find(|(start, end)| meeting_start_time >= *start && meeting_start_time <= *end)
+        else {
+            return Err(CalendarError::OutOfBoundsStartTime {});
+        };
+
         if meeting_end_time < calendar_start_time || meeting_end_time > calendar_end_time {
             return Err(CalendarError::OutOfBoundsEndTime {});
         }
@@ -74,10 +117,29 @@ impl Meeting {
             end_time: meeting_end_timestamp,
             requester,
             amount_staked,
+            series_id: None,
+            cancelled: false,
         })
     }
 }
 
+/// Recurring meetings booked together.
+#[cosmwasm_schema::cw_serde]
+pub struct MeetingSeries {
+    pub requester: Addr,
+    /// The day datetimes of the meetings in the series.
+    pub day_datetimes: Vec<i64>,
+}
+
+/// Unix timestamp of the start of the day, used as the key of the calendar.
+pub fn day_datetime(date: NaiveDate) -> i64 {
+    date.and_time(NaiveTime::default()).and_utc().timestamp()
+}
+
 // unix start-time of the day -> vector of meetings in that day.
 pub const CALENDAR: Map<i64, Vec<Meeting>> = Map::new("calendar");
 pub const CONFIG: Item<Config> = Item::new("config");
+// unix start-time of the day -> blocked for new meetings.
+pub const BLOCKED_DAYS: Map<i64, Empty> = Map::new("blocked_days");
+pub const NEXT_SERIES_ID: Item<u64> = Item::new("next_series_id");
+pub const SERIES: Map<u64, MeetingSeries> = Map::new("series");
@@ -5,13 +5,13 @@ use calendar_app::{
     error::CalendarError,
     msg::{
         CalendarExecuteMsg, CalendarExecuteMsgFns, CalendarInstantiateMsg, CalendarQueryMsgFns,
-        ConfigResponse, Time,
+        ConfigResponse, RefundTier, Slot, Time, Weekday, WeekdayWindow,
     },
-    state::Meeting,
+    state::{Meeting, MeetingSeries},
     CalendarAppInterface,
 };
-use chrono::{DateTime, Days, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Timelike};
-use cosmwasm_std::{coins, BlockInfo, Uint128};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use cosmwasm_std::{coins, BlockInfo, Decimal, Uint128};
 use cw_asset::AssetInfoUnchecked;
 use cw_orch::{anyhow, prelude::*};
 
@@ -88,6 +88,20 @@ fn setup_with_time(
     Application<MockBech32, CalendarAppInterface<MockBech32>>,
     AbstractClient<MockBech32>,
     MockBech32,
+)> {
+    setup_with_policies(start_time, end_time, vec![], vec![])
+}
+
+#[allow(clippy::type_complexity)]
+fn setup_with_policies(
+    start_time: Time,
+    end_time: Time,
+    weekly_availability: Vec<WeekdayWindow>,
+    refund_policy: Vec<RefundTier>,
+) -> anyhow::Result<(
+    Application<MockBech32, CalendarAppInterface<MockBech32>>,
+    AbstractClient<MockBech32>,
+    MockBech32,
 )> {
     let chain = MockBech32::new("mock");
     let client: AbstractClient<MockBech32> = AbstractClient::builder(chain.clone())
@@ -124,6 +138,8 @@ fn setup_with_time(
                 utc_offset: 0,
                 start_time,
                 end_time,
+                weekly_availability,
+                refund_policy,
             },
             &[],
         )?;
@@ -278,6 +294,8 @@ fn successful_install() -> anyhow::Result<()> {
                 hour: 17,
                 minute: 0,
             },
+            weekly_availability: vec![],
+            refund_policy: vec![],
         }
     );
     Ok(())
@@ -320,6 +338,8 @@ fn request_meeting_at_start_of_day() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::from(60u128),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response.meetings
     );
@@ -364,6 +384,8 @@ fn request_meeting_at_end_of_day() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::from(60u128),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response.meetings
     );
@@ -425,12 +447,16 @@ fn request_multiple_meetings_on_same_day() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             }
         ],
         meetings_response.meetings
@@ -503,12 +529,16 @@ fn request_back_to_back_meetings_on_left() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             }
         ],
         meetings_response.meetings
@@ -581,12 +611,16 @@ fn request_back_to_back_meetings_on_right() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                series_id: None,
+                cancelled: false,
             }
         ],
         meetings_response.meetings
@@ -646,6 +680,8 @@ fn request_meetings_on_different_days() -> anyhow::Result<()> {
             end_time: meeting_end_datetime1.and_utc().timestamp(),
             requester: sender1,
             amount_staked: Uint128::from(60u128),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response1.meetings
     );
@@ -665,6 +701,8 @@ fn request_meetings_on_different_days() -> anyhow::Result<()> {
             end_time: meeting_end_datetime2.and_utc().timestamp(),
             requester: sender2,
             amount_staked: Uint128::from(60u128),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response2.meetings
     );
@@ -1189,6 +1227,8 @@ fn slash_full_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::zero(),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response.meetings
     );
@@ -1245,6 +1285,8 @@ fn return_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender.clone(),
             amount_staked: Uint128::zero(),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response.meetings
     );
@@ -1305,6 +1347,8 @@ fn slash_partial_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender.clone(),
             amount_staked: Uint128::zero(),
+            series_id: None,
+            cancelled: false,
         }],
         meetings_response.meetings
     );
@@ -1318,3 +1362,360 @@ fn slash_partial_stake() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Unix timestamps of the given time and of the start of the day after `days` days.
+fn timestamps_in_days(client: &AbstractClient<MockBech32>, days: u64, time: Time) -> (i64, i64) {
+    let block_time = client.block_info().unwrap().time.seconds() as i64;
+    let date = DateTime::from_timestamp(block_time, 0)
+        .unwrap()
+        .date_naive()
+        .checked_add_days(Days::new(days))
+        .unwrap();
+    (
+        date.and_time(time.into()).and_utc().timestamp(),
+        date.and_time(NaiveTime::default()).and_utc().timestamp(),
+    )
+}
+
+#[test]
+fn request_meeting_series() -> anyhow::Result<()> {
+    // Set up the environment and contract
+    let (mut app, client, chain) = setup_with_policies(
+        Time { hour: 9, minute: 0 },
+        Time {
+            hour: 17,
+            minute: 0,
+        },
+        vec![],
+        vec![RefundTier {
+            min_notice: 0,
+            refund: Decimal::one(),
+        }],
+    )?;
+
+    let sender = chain.addr_make("sender");
+    app.set_sender(&sender);
+
+    let (start_time, _) = timestamps_in_days(
+        &client,
+        1,
+        Time {
+            hour: 10,
+            minute: 0,
+        },
+    );
+    let end_time = start_time + 3600;
+
+    // Stake of every occurrence has to be sent
+    let error = app
+        .request_meeting_series(
+            end_time.into(),
+            7,
+            3,
+            start_time.into(),
+            &[Coin::new(60u128, DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        CalendarError::InvalidStakeAmountSent {
+            expected_amount: Uint128::from(180u128)
+        }
+        .to_string(),
+        error.root_cause().to_string()
+    );
+
+    app.request_meeting_series(
+        end_time.into(),
+        7,
+        3,
+        start_time.into(),
+        &[Coin::new(180u128, DENOM)],
+    )?;
+
+    let series: MeetingSeries = app.meeting_series(0)?;
+    assert_eq!(series.requester, sender);
+    assert_eq!(series.day_datetimes.len(), 3);
+
+    for (occurrence, day_datetime) in series.day_datetimes.into_iter().enumerate() {
+        let offset = occurrence as i64 * 7 * 24 * 3600;
+        assert_eq!(
+            vec![Meeting {
+                start_time: start_time + offset,
+                end_time: end_time + offset,
+                requester: sender.clone(),
+                amount_staked: Uint128::from(60u128),
+                series_id: Some(0),
+                cancelled: false,
+            }],
+            app.meetings(day_datetime.into())?.meetings
+        );
+    }
+
+    // Too many occurrences
+    let error = app
+        .request_meeting_series(
+            end_time.into(),
+            1,
+            53,
+            start_time.into(),
+            &[Coin::new(60u128, DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        CalendarError::InvalidRecurrence {
+            max_occurrences: 52
+        }
+        .to_string(),
+        error.root_cause().to_string()
+    );
+
+    // Cancelling refunds the upcoming meetings
+    app.cancel_meeting_series(0)?;
+    assert_eq!(
+        Uint128::from(INITIAL_BALANCE),
+        client.query_balance(&sender, DENOM)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_meeting_with_refund_policy() -> anyhow::Result<()> {
+    // Set up the environment and contract
+    let (mut app, client, chain) = setup_with_policies(
+        Time { hour: 9, minute: 0 },
+        Time {
+            hour: 17,
+            minute: 0,
+        },
+        vec![],
+        vec![
+            RefundTier {
+                min_notice: 7 * 24 * 3600,
+                refund: Decimal::one(),
+            },
+            RefundTier {
+                min_notice: 0,
+                refund: Decimal::percent(50),
+            },
+        ],
+    )?;
+
+    let sender = chain.addr_make("sender");
+    app.set_sender(&sender);
+
+    let (start_time, day_datetime) = timestamps_in_days(
+        &client,
+        1,
+        Time {
+            hour: 10,
+            minute: 0,
+        },
+    );
+    app.request_meeting(
+        (start_time + 3600).into(),
+        start_time.into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    // Only the requester can cancel
+    app.set_sender(&chain.addr_make("sender1"));
+    let error = app.cancel_meeting(day_datetime.into(), 0).unwrap_err();
+    assert_eq!(
+        CalendarError::NotMeetingRequester {}.to_string(),
+        error.root_cause().to_string()
+    );
+
+    // Less than a week of notice, half of the stake is refunded
+    app.set_sender(&sender);
+    app.cancel_meeting(day_datetime.into(), 0)?;
+    assert_eq!(
+        Uint128::from(INITIAL_BALANCE - 30),
+        client.query_balance(&sender, DENOM)?
+    );
+    assert_eq!(Uint128::from(30u128), app.account().query_balance(DENOM)?);
+
+    let meetings = app.meetings(day_datetime.into())?.meetings;
+    assert!(meetings[0].cancelled);
+    assert_eq!(meetings[0].amount_staked, Uint128::zero());
+
+    let error = app.cancel_meeting(day_datetime.into(), 0).unwrap_err();
+    assert_eq!(
+        CalendarError::MeetingAlreadyCancelled {}.to_string(),
+        error.root_cause().to_string()
+    );
+
+    // The slot is free again
+    app.set_sender(&chain.addr_make("sender2"));
+    app.request_meeting(
+        (start_time + 3600).into(),
+        start_time.into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn weekday_windows_and_blocked_days() -> anyhow::Result<()> {
+    // Set up the environment and contract
+    let (mut app, client, chain) = setup()?;
+    let admin = app.account().owner()?;
+
+    let (tomorrow, tomorrow_datetime) = timestamps_in_days(
+        &client,
+        1,
+        Time {
+            hour: 13,
+            minute: 0,
+        },
+    );
+    let weekday: Weekday = DateTime::from_timestamp(tomorrow, 0)
+        .unwrap()
+        .weekday()
+        .into();
+
+    // Only available on tomorrow's weekday in the afternoon
+    app.set_sender(&admin);
+    app.update_config(
+        None,
+        None,
+        None,
+        Some(vec![WeekdayWindow {
+            weekday,
+            start_time: Time {
+                hour: 13,
+                minute: 0,
+            },
+            end_time: Time {
+                hour: 15,
+                minute: 0,
+            },
+        }]),
+    )?;
+
+    let sender = chain.addr_make("sender");
+    app.set_sender(&sender);
+
+    let error = app
+        .request_meeting(
+            (tomorrow - 3 * 3600 + 3600).into(),
+            (tomorrow - 3 * 3600).into(),
+            &[Coin::new(60u128, DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        CalendarError::OutOfBoundsStartTime {}.to_string(),
+        error.root_cause().to_string()
+    );
+
+    let (day_after, _) = timestamps_in_days(
+        &client,
+        2,
+        Time {
+            hour: 13,
+            minute: 0,
+        },
+    );
+    let error = app
+        .request_meeting(
+            (day_after + 3600).into(),
+            day_after.into(),
+            &[Coin::new(60u128, DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        CalendarError::DayNotAvailable {}.to_string(),
+        error.root_cause().to_string()
+    );
+
+    app.request_meeting(
+        (tomorrow + 3600).into(),
+        tomorrow.into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    // Blocked days can't be booked
+    app.set_sender(&admin);
+    app.update_blocked_days(vec![tomorrow_datetime.into()], vec![])?;
+
+    app.set_sender(&sender);
+    let error = app
+        .request_meeting(
+            (tomorrow + 2 * 3600).into(),
+            (tomorrow + 3600).into(),
+            &[Coin::new(60u128, DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        CalendarError::DayNotAvailable {}.to_string(),
+        error.root_cause().to_string()
+    );
+
+    app.set_sender(&admin);
+    app.update_blocked_days(vec![], vec![tomorrow_datetime.into()])?;
+
+    app.set_sender(&sender);
+    app.request_meeting(
+        (tomorrow + 2 * 3600).into(),
+        (tomorrow + 3600).into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn free_slots() -> anyhow::Result<()> {
+    // Set up the environment and contract
+    let (mut app, client, chain) = setup()?;
+
+    let sender = chain.addr_make("sender");
+    app.set_sender(&sender);
+
+    let (start_time, day_datetime) = timestamps_in_days(
+        &client,
+        1,
+        Time {
+            hour: 10,
+            minute: 0,
+        },
+    );
+    app.request_meeting(
+        (start_time + 3600).into(),
+        start_time.into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    let day_end = day_datetime + 24 * 3600 - 1;
+    let slots = app.free_slots(day_end.into(), day_datetime.into())?.slots;
+    assert_eq!(
+        vec![
+            Slot {
+                start_time: start_time - 3600,
+                end_time: start_time,
+            },
+            Slot {
+                start_time: start_time + 3600,
+                end_time: start_time + 7 * 3600,
+            },
+        ],
+        slots
+    );
+
+    // Blocked days have no free slots
+    let admin = app.account().owner()?;
+    app.set_sender(&admin);
+    app.update_blocked_days(vec![day_datetime.into()], vec![])?;
+    let slots = app.free_slots(day_end.into(), day_datetime.into())?.slots;
+    assert!(slots.is_empty());
+
+    let error = app
+        .free_slots((day_datetime + 32 * 24 * 3600).into(), day_datetime.into())
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains(&CalendarError::DateRangeTooLarge { max_days: 31 }.to_string()));
+
+    Ok(())
+}
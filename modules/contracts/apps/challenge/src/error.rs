@@ -68,4 +68,10 @@ pub enum AppError {
 
     #[error("Challenge has no proposals yet")]
     ExpectedProposal {},
+
+    #[error("Escrow is locked until the challenge ended and its last proposal is counted")]
+    EscrowLocked {},
+
+    #[error("Escrow of challenge {challenge_id} can't cover the strike")]
+    InsufficientEscrow { challenge_id: u64 },
 }
@@ -2,25 +2,28 @@ use std::collections::HashSet;
 
 use abstract_app::{
     sdk::{
-        features::AbstractResponse, AbstractSdkResult, AccountVerification, Execution,
-        TransferInterface,
+        features::{AbstractNameService, AbstractResponse},
+        AbstractSdkResult, AccountVerification, Execution, TransferInterface,
     },
     std::objects::{
-        voting::{ProposalId, ProposalInfo, ProposalOutcome, Vote},
+        voting::{ProposalId, ProposalInfo, ProposalOutcome, ProposalStatus, Vote},
         AnsAsset,
     },
 };
 use cosmwasm_std::{
-    ensure, Addr, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult, Uint128,
+    ensure, Addr, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
+    Uint128,
 };
+use cw_asset::Asset;
 
 use crate::{
     contract::{AppResult, ChallengeApp},
     error::AppError,
     msg::{ChallengeExecuteMsg, ChallengeRequest, Friend},
     state::{
-        ChallengeEntry, ChallengeEntryUpdate, UpdateFriendsOpKind, CHALLENGES, CHALLENGE_FRIENDS,
-        CHALLENGE_PROPOSALS, MAX_AMOUNT_OF_FRIENDS, NEXT_ID, SIMPLE_VOTING,
+        ChallengeEntry, ChallengeEntryUpdate, Escrow, StrikeStrategy, UpdateFriendsOpKind,
+        CHALLENGES, CHALLENGE_FRIENDS, CHALLENGE_PROPOSALS, ESCROWS, MAX_AMOUNT_OF_FRIENDS,
+        NEXT_ID, SIMPLE_VOTING,
     },
 };
 
@@ -55,6 +58,9 @@ pub fn execute_handler(
             count_votes(deps, env, info, &module, challenge_id)
         }
         ChallengeExecuteMsg::Veto { challenge_id } => veto(deps, env, info, &module, challenge_id),
+        ChallengeExecuteMsg::ReleaseEscrow { challenge_id } => {
+            release_escrow(deps, env, &module, challenge_id)
+        }
         ChallengeExecuteMsg::UpdateConfig { new_vote_config } => {
             SIMPLE_VOTING.update_vote_config(deps.storage, &new_vote_config)?;
             Ok(Response::new())
@@ -106,9 +112,24 @@ fn create_challenge(
     let challenge = ChallengeEntry::new(challenge_req, end_timestamp)?;
     CHALLENGES.save(deps.storage, challenge_id, &challenge)?;
 
+    // Lock up the budget for all the strikes
+    let budget = challenge.strike_strategy.strike_amount(friend_addrs.len())
+        * Uint128::from(challenge.admin_strikes.remaining());
+    ESCROWS.save(
+        deps.storage,
+        challenge_id,
+        &Escrow {
+            balance: budget,
+            paid_out: Uint128::zero(),
+        },
+    )?;
+    let escrow_msgs = escrow_funds(deps.as_ref(), &env, &module, &challenge, budget)?;
+
     Ok(module
         .response("create_challenge")
-        .add_attribute("challenge_id", challenge_id.to_string()))
+        .add_messages(escrow_msgs)
+        .add_attribute("challenge_id", challenge_id.to_string())
+        .add_attribute("escrowed", budget))
 }
 
 fn update_challenge(
@@ -163,15 +184,21 @@ fn cancel_challenge(
     // If there is active proposal - cancel it
     let last_proposal_id = last_proposal(challenge_id, deps.as_ref())?;
     if let Some(proposal_id) = last_proposal_id {
-        SIMPLE_VOTING.cancel_proposal(deps.storage, &env.block, proposal_id)?;
+        let proposal = SIMPLE_VOTING.load_proposal(deps.storage, &env.block, proposal_id)?;
+        if !matches!(proposal.status, ProposalStatus::Finished(_)) {
+            SIMPLE_VOTING.cancel_proposal(deps.storage, &env.block, proposal_id)?;
+        }
     }
 
     // End it now
     challenge.end_timestamp = env.block.time;
     CHALLENGES.save(deps.storage, challenge_id, &challenge)?;
 
+    let release_msgs = release_leftover(deps, module, &challenge, challenge_id)?;
+
     Ok(module
         .response("cancel_challenge")
+        .add_messages(release_msgs)
         .add_attribute("challenge_id", challenge_id.to_string()))
 }
 
@@ -207,6 +234,11 @@ fn update_friends_for_challenge(
         }
     }
 
+    let num_friends_before = CHALLENGE_FRIENDS
+        .may_load(deps.storage, challenge_id)?
+        .map(|friends| friends.len())
+        .unwrap_or_default();
+
     match op_kind {
         UpdateFriendsOpKind::Add {} => {
             let mut current_friends = CHALLENGE_FRIENDS
@@ -242,8 +274,12 @@ fn update_friends_for_challenge(
             })?;
         }
     }
+
+    let escrow_msgs = rebalance_escrow(deps, &env, module, challenge_id, num_friends_before)?;
+
     Ok(module
         .response("update_friends")
+        .add_messages(escrow_msgs)
         .add_attribute("challenge_id", challenge_id.to_string()))
 }
 
@@ -324,6 +360,7 @@ fn count_votes(
 
     try_finish_challenge(
         deps,
+        &env,
         module,
         proposal_info,
         outcome,
@@ -352,8 +389,32 @@ fn veto(
         .add_attribute("proposal_info", format!("{proposal_info:?}")))
 }
 
+fn release_escrow(deps: DepsMut, env: Env, module: &ChallengeApp, challenge_id: u64) -> AppResult {
+    let challenge = CHALLENGES
+        .may_load(deps.storage, challenge_id)?
+        .ok_or(AppError::ChallengeNotFound {})?;
+    if env.block.time < challenge.end_timestamp {
+        return Err(AppError::EscrowLocked {});
+    }
+    // Friends have to be paid out for the last proposal first
+    if let Some(proposal_id) = last_proposal(challenge_id, deps.as_ref())? {
+        let proposal = SIMPLE_VOTING.load_proposal(deps.storage, &env.block, proposal_id)?;
+        if !matches!(proposal.status, ProposalStatus::Finished(_)) {
+            return Err(AppError::EscrowLocked {});
+        }
+    }
+
+    let release_msgs = release_leftover(deps, module, &challenge, challenge_id)?;
+
+    Ok(module
+        .response("release_escrow")
+        .add_messages(release_msgs)
+        .add_attribute("challenge_id", challenge_id.to_string()))
+}
+
 fn try_finish_challenge(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: &Env,
     module: &ChallengeApp,
     proposal_info: ProposalInfo,
     proposal_outcome: ProposalOutcome,
@@ -361,27 +422,38 @@ fn try_finish_challenge(
     challenge_id: u64,
 ) -> AppResult {
     let friends = CHALLENGE_FRIENDS.load(deps.storage, challenge_id)?;
-    let challenge_finished = if matches!(proposal_outcome, ProposalOutcome::Passed) {
-        challenge.admin_strikes.strike()
-    } else {
-        false
-    };
 
     // Return here if not required to charge penalty
-    let res = if !matches!(proposal_outcome, ProposalOutcome::Passed) {
-        module.response("finish_vote")
-    } else {
-        charge_penalty(deps, module, challenge, friends)?
-    };
+    if !matches!(proposal_outcome, ProposalOutcome::Passed) {
+        return Ok(module
+            .response("finish_vote")
+            .add_attribute("proposal_info", format!("{proposal_info:?}"))
+            .add_attribute("challenge_finished", false.to_string()));
+    }
+
+    let challenge_finished = challenge.admin_strikes.strike();
+    if challenge_finished {
+        // End it now
+        challenge.end_timestamp = env.block.time;
+    }
+    CHALLENGES.save(deps.storage, challenge_id, &challenge)?;
+
+    let mut res = charge_penalty(deps.branch(), module, &challenge, challenge_id, friends)?;
+    if challenge_finished {
+        let release_msgs = release_leftover(deps, module, &challenge, challenge_id)?;
+        res = res.add_messages(release_msgs);
+    }
     Ok(res
         .add_attribute("proposal_info", format!("{proposal_info:?}"))
         .add_attribute("challenge_finished", challenge_finished.to_string()))
 }
 
+/// Pays out the strike to the friends from the escrow
 fn charge_penalty(
     deps: DepsMut,
     module: &ChallengeApp,
-    challenge: ChallengeEntry,
+    challenge: &ChallengeEntry,
+    challenge_id: u64,
     friends: Vec<Friend<Addr>>,
 ) -> Result<Response, AppError> {
     let num_friends = friends.len() as u128;
@@ -389,44 +461,130 @@ fn charge_penalty(
         return Err(AppError::ZeroFriends {});
     }
     let (amount_per_friend, remainder) = match challenge.strike_strategy {
-        crate::state::StrikeStrategy::Split(amount) => (
+        StrikeStrategy::Split(amount) => (
             Uint128::new(amount.u128() / num_friends),
             amount.u128() % num_friends,
         ),
-        crate::state::StrikeStrategy::PerFriend(amount) => (amount, 0),
+        StrikeStrategy::PerFriend(amount) => (amount, 0),
     };
-
-    let asset_per_friend = AnsAsset {
-        name: challenge.strike_asset,
-        amount: amount_per_friend,
-    };
-
-    let bank = module.bank(deps.as_ref());
-    let executor = module.executor(deps.as_ref());
-
-    // Create a transfer action for each friend
-    let transfer_actions = friends
+    let total_payout = amount_per_friend * Uint128::new(num_friends);
+
+    // Funds are held by the app, so it pays out directly
+    let mut escrow = ESCROWS.load(deps.storage, challenge_id)?;
+    escrow.balance = escrow
+        .balance
+        .checked_sub(total_payout)
+        .map_err(|_| AppError::InsufficientEscrow { challenge_id })?;
+    escrow.paid_out += total_payout;
+    ESCROWS.save(deps.storage, challenge_id, &escrow)?;
+
+    let strike_asset = module
+        .name_service(deps.as_ref())
+        .query(&challenge.strike_asset)?;
+    let transfer_msgs = friends
         .into_iter()
-        .map(|friend| {
-            let recipent = match friend {
-                Friend::Addr(addr) => addr.address,
-                Friend::AbstractAccount(account_id) => module
-                    .account_registry(deps.as_ref())?
-                    .account(&account_id)?
-                    .into_addr(),
-            };
-            bank.transfer(vec![asset_per_friend.clone()], &recipent)
+        .map(|friend| -> AppResult<CosmosMsg> {
+            let recipient = friend.addr(deps.as_ref(), module)?;
+            Asset::new(strike_asset.clone(), amount_per_friend)
+                .transfer_msg(recipient)
+                .map_err(Into::into)
         })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let transfer_msg = executor.execute(transfer_actions)?;
+        .collect::<AppResult<Vec<_>>>()?;
 
     Ok(module
         .response("charge_penalty")
-        .add_message(transfer_msg)
+        .add_messages(transfer_msgs)
         .add_attribute("remainder", remainder.to_string()))
 }
 
+/// Withdraws the funds from the account into the escrow of the app
+fn escrow_funds(
+    deps: Deps,
+    env: &Env,
+    module: &ChallengeApp,
+    challenge: &ChallengeEntry,
+    amount: Uint128,
+) -> AppResult<Vec<CosmosMsg>> {
+    if amount.is_zero() {
+        return Ok(vec![]);
+    }
+    let withdraw_action = module.bank(deps).withdraw(
+        env,
+        vec![AnsAsset::new(challenge.strike_asset.clone(), amount)],
+    )?;
+    let withdraw_msg = module.executor(deps).execute(vec![withdraw_action])?;
+    Ok(vec![withdraw_msg.into()])
+}
+
+/// Returns what's left in the escrow to the account
+fn release_leftover(
+    deps: DepsMut,
+    module: &ChallengeApp,
+    challenge: &ChallengeEntry,
+    challenge_id: u64,
+) -> AppResult<Vec<CosmosMsg>> {
+    let mut escrow = ESCROWS
+        .may_load(deps.storage, challenge_id)?
+        .unwrap_or_default();
+    if escrow.balance.is_zero() {
+        return Ok(vec![]);
+    }
+    let leftover = AnsAsset::new(challenge.strike_asset.clone(), escrow.balance);
+    escrow.balance = Uint128::zero();
+    ESCROWS.save(deps.storage, challenge_id, &escrow)?;
+
+    module
+        .bank(deps.as_ref())
+        .deposit(vec![leftover])
+        .map_err(Into::into)
+}
+
+/// Keeps the escrow of a [`StrikeStrategy::PerFriend`] challenge in line with its friends
+fn rebalance_escrow(
+    deps: DepsMut,
+    env: &Env,
+    module: &ChallengeApp,
+    challenge_id: u64,
+    num_friends_before: usize,
+) -> AppResult<Vec<CosmosMsg>> {
+    let challenge = CHALLENGES.load(deps.storage, challenge_id)?;
+    let StrikeStrategy::PerFriend(amount) = challenge.strike_strategy else {
+        return Ok(vec![]);
+    };
+    // Escrow of ended challenges is released
+    if env.block.time >= challenge.end_timestamp {
+        return Ok(vec![]);
+    }
+    let num_friends = CHALLENGE_FRIENDS.load(deps.storage, challenge_id)?.len();
+    let remaining_strikes = Uint128::from(challenge.admin_strikes.remaining());
+
+    let mut escrow = ESCROWS
+        .may_load(deps.storage, challenge_id)?
+        .unwrap_or_default();
+    let msgs = if num_friends > num_friends_before {
+        let added = amount * Uint128::from((num_friends - num_friends_before) as u128);
+        let to_escrow = added * remaining_strikes;
+        escrow.balance += to_escrow;
+        escrow_funds(deps.as_ref(), env, module, &challenge, to_escrow)?
+    } else if num_friends < num_friends_before {
+        let removed = amount * Uint128::from((num_friends_before - num_friends) as u128);
+        let to_release = (removed * remaining_strikes).min(escrow.balance);
+        escrow.balance -= to_release;
+        if to_release.is_zero() {
+            vec![]
+        } else {
+            module.bank(deps.as_ref()).deposit(vec![AnsAsset::new(
+                challenge.strike_asset.clone(),
+                to_release,
+            )])?
+        }
+    } else {
+        return Ok(vec![]);
+    };
+    ESCROWS.save(deps.storage, challenge_id, &escrow)?;
+    Ok(msgs)
+}
+
 pub(crate) fn last_proposal(challenge_id: u64, deps: Deps) -> StdResult<Option<ProposalId>> {
     CHALLENGE_PROPOSALS
         .prefix(challenge_id)
@@ -5,11 +5,12 @@ use cw_storage_plus::Bound;
 use super::execute::last_proposal;
 use crate::{
     contract::{AppResult, ChallengeApp},
+    error::AppError,
     msg::{
         ChallengeEntryResponse, ChallengeQueryMsg, ChallengeResponse, ChallengesResponse,
-        FriendsResponse, ProposalsResponse, VoteResponse, VotesResponse,
+        EscrowResponse, FriendsResponse, ProposalsResponse, VoteResponse, VotesResponse,
     },
-    state::{CHALLENGES, CHALLENGE_FRIENDS, CHALLENGE_PROPOSALS, SIMPLE_VOTING},
+    state::{CHALLENGES, CHALLENGE_FRIENDS, CHALLENGE_PROPOSALS, ESCROWS, SIMPLE_VOTING},
 };
 
 pub fn query_handler(
@@ -64,6 +65,9 @@ pub fn query_handler(
             start_after,
             limit,
         )?),
+        ChallengeQueryMsg::Escrow { challenge_id } => {
+            to_json_binary(&query_escrow(deps, challenge_id)?)
+        }
     }
     .map_err(Into::into)
 }
@@ -86,7 +90,7 @@ fn get_proposal_if_active(
     challenge_id: u64,
     deps: Deps,
     block: &BlockInfo,
-) -> Result<Option<ProposalInfo>, crate::error::AppError> {
+) -> Result<Option<ProposalInfo>, AppError> {
     let maybe_id = last_proposal(challenge_id, deps)?;
     let proposal = maybe_id
         .map(|id| {
@@ -213,3 +217,17 @@ fn query_votes(
     };
     Ok(VotesResponse { votes })
 }
+
+fn query_escrow(deps: Deps, challenge_id: u64) -> AppResult<EscrowResponse> {
+    let challenge = CHALLENGES
+        .may_load(deps.storage, challenge_id)?
+        .ok_or(AppError::ChallengeNotFound {})?;
+    let escrow = ESCROWS
+        .may_load(deps.storage, challenge_id)?
+        .unwrap_or_default();
+    Ok(EscrowResponse {
+        strike_asset: challenge.strike_asset,
+        balance: escrow.balance,
+        paid_out: escrow.paid_out,
+    })
+}
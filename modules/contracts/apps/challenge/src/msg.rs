@@ -8,7 +8,7 @@ use abstract_app::{
     },
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Deps, StdResult, Timestamp, Uint64};
+use cosmwasm_std::{Addr, Deps, StdResult, Timestamp, Uint128, Uint64};
 use cw_address_like::AddressLike;
 
 use crate::{
@@ -37,6 +37,7 @@ pub enum ChallengeExecuteMsg {
         new_vote_config: VoteConfig,
     },
     /// Create new challenge
+    /// The strike budget for all strikes is escrowed from the account
     CreateChallenge {
         /// New challenge arguments
         challenge_req: ChallengeRequest,
@@ -79,6 +80,11 @@ pub enum ChallengeExecuteMsg {
         /// Challenge id to do the veto
         challenge_id: u64,
     },
+    /// Return the leftover escrow of an ended challenge to the account
+    ReleaseEscrow {
+        /// Challenge id to release the escrow of
+        challenge_id: u64,
+    },
 }
 
 /// Challenge query messages
@@ -145,6 +151,13 @@ pub enum ChallengeQueryMsg {
         /// Max amount of proposals in response
         limit: Option<u64>,
     },
+    /// Get escrowed strike funds of the challenge
+    /// Returns [`EscrowResponse`]
+    #[returns(EscrowResponse)]
+    Escrow {
+        /// Id of requested challenge
+        challenge_id: u64,
+    },
 }
 /// Response for previous_vote query
 #[cosmwasm_schema::cw_serde]
@@ -160,6 +173,17 @@ pub struct ProposalsResponse {
     pub proposals: Vec<(ProposalId, ProposalInfo)>,
 }
 
+/// Response for escrow query
+#[cosmwasm_schema::cw_serde]
+pub struct EscrowResponse {
+    /// Escrowed asset
+    pub strike_asset: AssetEntry,
+    /// Amount currently held in escrow
+    pub balance: Uint128,
+    /// Amount paid out to friends so far
+    pub paid_out: Uint128,
+}
+
 /// Response for challenge query
 #[cosmwasm_schema::cw_serde]
 pub struct ChallengeResponse {
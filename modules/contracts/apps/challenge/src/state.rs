@@ -35,6 +35,16 @@ pub enum StrikeStrategy {
     PerFriend(Uint128),
 }
 
+impl StrikeStrategy {
+    /// Amount paid out to the friends for a single strike
+    pub fn strike_amount(&self, num_friends: usize) -> Uint128 {
+        match self {
+            StrikeStrategy::Split(amount) => *amount,
+            StrikeStrategy::PerFriend(amount) => *amount * Uint128::from(num_friends as u128),
+        }
+    }
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct AdminStrikes {
     /// The number of strikes the admin has incurred.
@@ -57,6 +67,21 @@ impl AdminStrikes {
         // check if it's last strike
         self.num_strikes >= self.limit
     }
+
+    /// The number of strikes left before the limit is reached.
+    pub fn remaining(&self) -> u8 {
+        self.limit.saturating_sub(self.num_strikes)
+    }
+}
+
+/// Strike funds locked up for a challenge
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct Escrow {
+    /// Amount of the strike asset held by the app for this challenge
+    pub balance: Uint128,
+    /// Amount paid out to friends so far
+    pub paid_out: Uint128,
 }
 
 impl ChallengeEntry {
@@ -105,3 +130,5 @@ pub const CHALLENGES: Map<u64, ChallengeEntry> = Map::new("challenges");
 // Helpful during distributing penalty and re-creation voting
 pub const CHALLENGE_FRIENDS: Map<u64, Vec<Friend<Addr>>> = Map::new("friends");
 pub const CHALLENGE_PROPOSALS: Map<(u64, ProposalId), cosmwasm_std::Empty> = Map::new("proposals");
+/// Escrowed strike funds of the challenge
+pub const ESCROWS: Map<u64, Escrow> = Map::new("escrows");
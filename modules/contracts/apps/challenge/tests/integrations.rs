@@ -12,8 +12,8 @@ use challenge_app::{
     error::AppError,
     msg::{
         ChallengeEntryResponse, ChallengeInstantiateMsg, ChallengeQueryMsg, ChallengeRequest,
-        ChallengeResponse, ChallengesResponse, Friend, FriendByAddr, FriendsResponse,
-        ProposalsResponse, VoteResponse,
+        ChallengeResponse, ChallengesResponse, EscrowResponse, Friend, FriendByAddr,
+        FriendsResponse, ProposalsResponse, VoteResponse,
     },
     state::{AdminStrikes, ChallengeEntryUpdate, StrikeStrategy, UpdateFriendsOpKind},
     *,
@@ -511,7 +511,13 @@ fn test_not_charge_penalty_for_voting_false() -> anyhow::Result<()> {
     );
 
     let balance = mock.query_balance(&account.address()?, DENOM)?;
-    // if no one voted true, no penalty should be charged, so balance will be 50_000_000
+    // if no one voted true, no penalty should be charged, strike stays escrowed
+    assert_eq!(balance, Uint128::new(INITIAL_BALANCE - 30_000_000));
+
+    // Escrow returned after challenge ended
+    mock.wait_seconds(10_000)?;
+    apps.challenge_app.release_escrow(FIRST_CHALLENGE_ID)?;
+    let balance = mock.query_balance(&account.address()?, DENOM)?;
     assert_eq!(balance, Uint128::new(INITIAL_BALANCE));
     Ok(())
 }
@@ -550,12 +556,35 @@ fn test_charge_penalty_for_voting_true() -> anyhow::Result<()> {
     let balance = mock.query_balance(&account.address()?, DENOM)?;
     // Initial balance - strike
     assert_eq!(balance, Uint128::new(INITIAL_BALANCE - 30_000_000));
+
+    // Friends got paid out from the escrow
+    for friend in [
+        alice_address(&mock),
+        bob_address(&mock),
+        charlie_address(&mock),
+    ] {
+        assert_eq!(
+            mock.query_balance(&friend, DENOM)?,
+            Uint128::new(10_000_000)
+        );
+    }
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(
+        escrow,
+        EscrowResponse {
+            strike_asset: AssetEntry::new("denom"),
+            balance: Uint128::zero(),
+            paid_out: Uint128::new(30_000_000),
+        }
+    );
     Ok(())
 }
 
 #[test]
 fn test_query_challenges_within_range() -> anyhow::Result<()> {
-    let (mock, _account, _abstr, apps) = setup()?;
+    let (mock, account, _abstr, apps) = setup()?;
+    // Enough funds to escrow the strikes of all challenges
+    mock.set_balance(&account.address()?, vec![coin(10 * INITIAL_BALANCE, DENOM)])?;
     for _ in 0..10 {
         apps.challenge_app
             .create_challenge(challenge_req_func(&mock).clone())?;
@@ -574,7 +603,9 @@ fn test_query_challenges_within_range() -> anyhow::Result<()> {
 
 #[test]
 fn test_query_challenges_within_different_range() -> anyhow::Result<()> {
-    let (mock, _account, _abstr, apps) = setup()?;
+    let (mock, account, _abstr, apps) = setup()?;
+    // Enough funds to escrow the strikes of all challenges
+    mock.set_balance(&account.address()?, vec![coin(10 * INITIAL_BALANCE, DENOM)])?;
     for _ in 0..10 {
         apps.challenge_app
             .create_challenge(challenge_req_func(&mock).clone())?;
@@ -639,9 +670,9 @@ fn test_vetoed() -> anyhow::Result<()> {
     let status = prev_proposals.proposals[0].1.status.clone();
     assert_eq!(status, ProposalStatus::Finished(ProposalOutcome::Vetoed));
 
-    // balance unchanged
+    // balance unchanged, strike still escrowed
     let balance = mock.query_balance(&account.address()?, DENOM)?;
-    assert_eq!(balance, Uint128::new(INITIAL_BALANCE));
+    assert_eq!(balance, Uint128::new(INITIAL_BALANCE - 30_000_000));
     Ok(())
 }
 
@@ -737,6 +768,84 @@ fn test_duplicate_friends() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_escrow_strike_budget() -> anyhow::Result<()> {
+    let (mock, account, _abstr, apps) = setup()?;
+    apps.challenge_app.create_challenge(ChallengeRequest {
+        strike_strategy: StrikeStrategy::PerFriend(Uint128::new(1_000_000)),
+        strikes_limit: Some(2),
+        ..challenge_req_func(&mock).clone()
+    })?;
+
+    // 3 friends, 2 strikes
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(escrow.balance, Uint128::new(6_000_000));
+    let balance = mock.query_balance(&account.address()?, DENOM)?;
+    assert_eq!(balance, Uint128::new(INITIAL_BALANCE - 6_000_000));
+
+    // Removing a friend releases its part of the escrow
+    apps.challenge_app.update_friends_for_challenge(
+        FIRST_CHALLENGE_ID,
+        vec![alice_friend(&mock).clone()],
+        UpdateFriendsOpKind::Remove {},
+    )?;
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(escrow.balance, Uint128::new(4_000_000));
+
+    // Adding it back escrows it again
+    apps.challenge_app.update_friends_for_challenge(
+        FIRST_CHALLENGE_ID,
+        vec![alice_friend(&mock).clone()],
+        UpdateFriendsOpKind::Add {},
+    )?;
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(escrow.balance, Uint128::new(6_000_000));
+
+    let votes = vec![
+        (
+            alice_address(&mock).clone(),
+            Vote {
+                vote: true,
+                memo: None,
+            },
+        ),
+        (
+            bob_address(&mock).clone(),
+            Vote {
+                vote: true,
+                memo: None,
+            },
+        ),
+    ];
+    run_challenge_vote_sequence(&mock, &apps, votes)?;
+
+    // Paid out one strike
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(escrow.balance, Uint128::new(3_000_000));
+    assert_eq!(escrow.paid_out, Uint128::new(3_000_000));
+    assert_eq!(
+        mock.query_balance(&alice_address(&mock), DENOM)?,
+        Uint128::new(1_000_000)
+    );
+
+    // Can't release before the challenge ends
+    let err: AppError = apps
+        .challenge_app
+        .release_escrow(FIRST_CHALLENGE_ID)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, AppError::EscrowLocked {});
+
+    // Cancelling returns the leftover to the account
+    apps.challenge_app.cancel_challenge(FIRST_CHALLENGE_ID)?;
+    let escrow: EscrowResponse = apps.challenge_app.escrow(FIRST_CHALLENGE_ID)?;
+    assert_eq!(escrow.balance, Uint128::zero());
+    let balance = mock.query_balance(&account.address()?, DENOM)?;
+    assert_eq!(balance, Uint128::new(INITIAL_BALANCE - 3_000_000));
+    Ok(())
+}
+
 fn run_challenge_vote_sequence(
     mock: &MockBech32,
     apps: &DeployedApps,
@@ -13,6 +13,9 @@ pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// The id of the app
 pub const APP_ID: &str = "abstract:payment";
 
+/// Reply of the last message of an invoice payment, after the payment is forwarded and converted
+pub const INVOICE_PAYMENT_REPLY_ID: u64 = 1;
+
 /// The type of the result returned by your app's entry points.
 pub type AppResult<T = Response> = Result<T, AppError>;
 
@@ -31,6 +34,7 @@ const APP: PaymentApp = PaymentApp::new(APP_ID, APP_VERSION, None)
     .with_execute(handlers::execute_handler)
     .with_query(handlers::query_handler)
     .with_migrate(handlers::migrate_handler)
+    .with_replies(&[(INVOICE_PAYMENT_REPLY_ID, handlers::invoice_payment_reply)])
     // Specify dependencies
     .with_dependencies(&[DEX_DEPENDENCY]);
 // ANCHOR_END: dependencies
@@ -34,4 +34,34 @@ pub enum AppError {
 
     #[error("Dex {0} is not registered on Abstract Name Service")]
     DexNotRegistered(String),
+
+    #[error("Invoice {0} does not exist")]
+    InvoiceDoesNotExist(u64),
+
+    #[error("Invoice {0} is not open")]
+    InvoiceNotOpen(u64),
+
+    #[error("Payment schedule {0} does not exist")]
+    ScheduleDoesNotExist(u64),
+
+    #[error("Payment of schedule {schedule_id} is not due before {next_payment}")]
+    PaymentNotDue {
+        schedule_id: u64,
+        next_payment: cosmwasm_std::Timestamp,
+    },
+
+    #[error("Payment schedule {0} has no payments left")]
+    ScheduleFinished(u64),
+
+    #[error("Payment interval can't be zero")]
+    ZeroInterval {},
+
+    #[error("No funds sent")]
+    NoFunds {},
+
+    #[error("Asset {0} can't be converted to the requested asset")]
+    UnsupportedPaymentAsset(String),
+
+    #[error("Sender is not authorized")]
+    Unauthorized {},
 }
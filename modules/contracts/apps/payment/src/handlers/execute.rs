@@ -2,8 +2,8 @@ use std::collections::HashSet;
 
 use abstract_app::sdk::{
     cw_helpers::{AbstractAttributes, Clearable},
-    features::AbstractNameService,
-    AbstractResponse, TransferInterface,
+    features::{AbstractNameService, AccountIdentification},
+    AbstractResponse, AuthZInterface, Execution, TransferInterface,
 };
 use abstract_app::std::{
     ans_host::AssetPairingFilter,
@@ -12,18 +12,24 @@ use abstract_app::std::{
 };
 use abstract_dex_adapter::DexInterface;
 use cosmwasm_std::{
-    Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
+    coins, wasm_execute, Addr, BankMsg, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Response, StdResult, Storage, SubMsg, Timestamp, Uint128,
 };
-use cw_asset::{Asset, AssetList};
+use cw20::Cw20ExecuteMsg;
+use cw_asset::{Asset, AssetInfo, AssetList};
 
-use crate::contract::{AppResult, PaymentApp};
+use crate::contract::{AppResult, PaymentApp, INVOICE_PAYMENT_REPLY_ID};
 
 const MAX_SPREAD_PERCENT: u64 = 20;
 
 use crate::{
     error::AppError,
-    msg::AppExecuteMsg,
-    state::{CONFIG, TIPPERS, TIPPER_COUNT, TIP_COUNT},
+    msg::{AppExecuteMsg, Invoice, InvoiceStatus, PaymentSchedule},
+    state::{
+        InvoicePayment, CONFIG, INVOICES, INVOICE_PAYMENT, INVOICE_PAYMENTS, NEXT_INVOICE_ID,
+        NEXT_SCHEDULE_ID, PAYER_INVOICES, PAYER_SCHEDULES, SCHEDULES, TIPPERS, TIPPER_COUNT,
+        TIP_COUNT,
+    },
 };
 
 pub fn execute_handler(
@@ -48,6 +54,29 @@ pub fn execute_handler(
             exchanges,
         ),
         AppExecuteMsg::Tip {} => tip(deps, env, info, module, None),
+        AppExecuteMsg::CreateInvoice {
+            payer,
+            amount,
+            description,
+        } => create_invoice(deps, env, info, module, payer, amount, description),
+        AppExecuteMsg::CancelInvoice { invoice_id } => {
+            cancel_invoice(deps, env, info, module, invoice_id)
+        }
+        AppExecuteMsg::PayInvoice { invoice_id } => {
+            pay_invoice(deps, env, info, module, invoice_id, None)
+        }
+        AppExecuteMsg::CreateSchedule {
+            asset,
+            interval,
+            start,
+            payments,
+        } => create_schedule(deps, env, info, module, asset, interval, start, payments),
+        AppExecuteMsg::CancelSchedule { schedule_id } => {
+            cancel_schedule(deps, env, info, module, schedule_id)
+        }
+        AppExecuteMsg::TriggerPayment { schedule_id } => {
+            trigger_payment(deps, env, module, schedule_id)
+        }
     }
 }
 
@@ -91,7 +120,6 @@ pub fn tip(
 
     let mut swap_msgs: Vec<CosmosMsg> = Vec::new();
     let mut attrs: Vec<(&str, String)> = Vec::new();
-    let exchange_strs: HashSet<&str> = config.exchanges.iter().map(AsRef::as_ref).collect();
 
     // For tip history
     let mut desired_asset_amount = Uint128::zero();
@@ -105,34 +133,18 @@ pub fn tip(
             desired_asset_amount += pay_asset.amount;
             continue;
         }
-        // query the pools that contain the desired asset
-        let resp: Vec<AssetPairingMapEntry> = ans.pool_list(
-            Some(AssetPairingFilter {
-                asset_pair: Some((desired_asset.clone(), pay_asset.name.clone())),
-                dex: None,
-            }),
-            None,
-            None,
-        )?;
-
-        // use the first pair you find to swap on
-        if let Some((pair, _refs)) = resp
-            .into_iter()
-            .find(|(pair, refs)| !refs.is_empty() && exchange_strs.contains(&pair.dex()))
-        {
-            let dex = module.ans_dex(deps.as_ref(), &env, pair.dex().to_owned());
-            let trigger_swap_msg = dex.swap(
-                pay_asset.clone(),
-                desired_asset.clone(),
-                Some(Decimal::percent(MAX_SPREAD_PERCENT)),
-                None,
-            )?;
+        if let Some((trigger_swap_msg, return_amount)) = conversion_swap(
+            deps.as_ref(),
+            &env,
+            &module,
+            &config.exchanges,
+            &pay_asset,
+            &desired_asset,
+        )? {
             swap_msgs.push(trigger_swap_msg);
             attrs.push(("swap", format!("{} for {}", pay_asset.name, desired_asset)));
 
-            desired_asset_amount += dex
-                .simulate_swap(pay_asset.clone(), desired_asset.clone())?
-                .return_amount;
+            desired_asset_amount += return_amount;
         } else {
             // If swap not found just accept payment
             assets_to_add.push(pay_asset);
@@ -156,6 +168,362 @@ pub fn tip(
         .add_abstract_attributes(attrs))
 }
 
+/// Swap message and expected return to convert `pay_asset` into `target`,
+/// using the first pool found on the configured exchanges.
+fn conversion_swap(
+    deps: Deps,
+    env: &Env,
+    module: &PaymentApp,
+    exchanges: &[DexName],
+    pay_asset: &AnsAsset,
+    target: &AssetEntry,
+) -> AppResult<Option<(CosmosMsg, Uint128)>> {
+    let exchange_strs: HashSet<&str> = exchanges.iter().map(AsRef::as_ref).collect();
+
+    // query the pools that contain the target asset
+    let resp: Vec<AssetPairingMapEntry> = module.name_service(deps).pool_list(
+        Some(AssetPairingFilter {
+            asset_pair: Some((target.clone(), pay_asset.name.clone())),
+            dex: None,
+        }),
+        None,
+        None,
+    )?;
+
+    // use the first pair you find to swap on
+    let Some((pair, _refs)) = resp
+        .into_iter()
+        .find(|(pair, refs)| !refs.is_empty() && exchange_strs.contains(&pair.dex()))
+    else {
+        return Ok(None);
+    };
+    let dex = module.ans_dex(deps, env, pair.dex().to_owned());
+    let swap_msg = dex.swap(
+        pay_asset.clone(),
+        target.clone(),
+        Some(Decimal::percent(MAX_SPREAD_PERCENT)),
+        None,
+    )?;
+    let return_amount = dex
+        .simulate_swap(pay_asset.clone(), target.clone())?
+        .return_amount;
+    Ok(Some((swap_msg, return_amount)))
+}
+
+fn create_invoice(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: PaymentApp,
+    payer: Option<String>,
+    amount: AnsAsset,
+    description: Option<String>,
+) -> AppResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+    // invoice asset has to be resolvable
+    module.name_service(deps.as_ref()).query(&amount.name)?;
+    let payer = payer.map(|p| deps.api.addr_validate(&p)).transpose()?;
+
+    let id = NEXT_INVOICE_ID.may_load(deps.storage)?.unwrap_or_default();
+    NEXT_INVOICE_ID.save(deps.storage, &(id + 1))?;
+    if let Some(payer) = &payer {
+        PAYER_INVOICES.save(deps.storage, (payer, id), &Empty {})?;
+    }
+    INVOICES.save(
+        deps.storage,
+        id,
+        &Invoice {
+            id,
+            payer,
+            amount,
+            paid: Uint128::zero(),
+            description,
+            status: InvoiceStatus::Open,
+        },
+    )?;
+
+    Ok(module
+        .response("create_invoice")
+        .add_attribute("invoice_id", id.to_string()))
+}
+
+fn cancel_invoice(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: PaymentApp,
+    invoice_id: u64,
+) -> AppResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+    let mut invoice = load_open_invoice(deps.storage, invoice_id)?;
+    invoice.status = InvoiceStatus::Cancelled;
+    INVOICES.save(deps.storage, invoice_id, &invoice)?;
+
+    // Refund what every payer paid so far
+    let payments: Vec<(Addr, Uint128)> = INVOICE_PAYMENTS
+        .prefix(invoice_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (payer, _) in &payments {
+        INVOICE_PAYMENTS.remove(deps.storage, (invoice_id, payer));
+    }
+    let invoice_asset = module
+        .name_service(deps.as_ref())
+        .query(&invoice.amount.name)?;
+    let bank = module.bank(deps.as_ref());
+    let refund_actions = payments
+        .iter()
+        .map(|(payer, amount)| {
+            bank.transfer(vec![Asset::new(invoice_asset.clone(), *amount)], payer)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut response = module
+        .response("cancel_invoice")
+        .add_attribute("invoice_id", invoice_id.to_string());
+    if !refund_actions.is_empty() {
+        response = response.add_message(module.executor(deps.as_ref()).execute(refund_actions)?);
+    }
+    Ok(response)
+}
+
+// Called when an invoice is paid, payments in other assets are swapped to the invoice asset.
+// The invoice is credited in a reply, with the amount the account actually received.
+pub fn pay_invoice(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: PaymentApp,
+    invoice_id: u64,
+    cw20_receipt: Option<Asset>,
+) -> AppResult {
+    let invoice = load_open_invoice(deps.storage, invoice_id)?;
+    if invoice
+        .payer
+        .as_ref()
+        .is_some_and(|payer| *payer != info.sender)
+    {
+        return Err(AppError::Unauthorized {});
+    }
+
+    if info.funds.is_empty() && cw20_receipt.is_none() {
+        return Err(AppError::NoFunds {});
+    }
+    let mut deposited_assets = AssetList::from(info.funds);
+    if let Some(cw20_deposit) = &cw20_receipt {
+        deposited_assets.add(cw20_deposit)?;
+    }
+
+    // forward payment to the account contract
+    let forward_payment_msgs = module
+        .bank(deps.as_ref())
+        .deposit(deposited_assets.to_vec())?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let name_service = module.name_service(deps.as_ref());
+    let asset_entries = name_service.query(&deposited_assets.to_vec())?;
+
+    let mut msgs: Vec<CosmosMsg> = forward_payment_msgs;
+    for pay_asset in asset_entries {
+        if pay_asset.name == invoice.amount.name {
+            continue;
+        }
+        let (swap_msg, _) = conversion_swap(
+            deps.as_ref(),
+            &env,
+            &module,
+            &config.exchanges,
+            &pay_asset,
+            &invoice.amount.name,
+        )?
+        .ok_or_else(|| AppError::UnsupportedPaymentAsset(pay_asset.name.to_string()))?;
+        msgs.push(swap_msg);
+    }
+
+    // The received amount is measured on the account balance once the last message ran
+    let invoice_asset = name_service.query(&invoice.amount.name)?;
+    let account = module.account(deps.as_ref())?;
+    let balance_before = invoice_asset.query_balance(&deps.querier, account.addr())?;
+    INVOICE_PAYMENT.save(
+        deps.storage,
+        &InvoicePayment {
+            invoice_id,
+            payer: info.sender,
+            balance_before,
+        },
+    )?;
+    let Some(last_msg) = msgs.pop() else {
+        return Err(AppError::NoFunds {});
+    };
+
+    Ok(module
+        .response("pay_invoice")
+        .add_messages(msgs)
+        .add_submessage(SubMsg::reply_on_success(last_msg, INVOICE_PAYMENT_REPLY_ID))
+        .add_attribute("invoice_id", invoice_id.to_string()))
+}
+
+fn load_open_invoice(storage: &dyn Storage, invoice_id: u64) -> AppResult<Invoice> {
+    let invoice = INVOICES
+        .may_load(storage, invoice_id)?
+        .ok_or(AppError::InvoiceDoesNotExist(invoice_id))?;
+    if invoice.status != InvoiceStatus::Open {
+        return Err(AppError::InvoiceNotOpen(invoice_id));
+    }
+    Ok(invoice)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: PaymentApp,
+    asset: AnsAsset,
+    interval: u64,
+    start: Option<Timestamp>,
+    payments: Option<u32>,
+) -> AppResult {
+    if interval == 0 {
+        return Err(AppError::ZeroInterval {});
+    }
+    // schedule asset has to be resolvable
+    module.name_service(deps.as_ref()).query(&asset.name)?;
+
+    let id = NEXT_SCHEDULE_ID.may_load(deps.storage)?.unwrap_or_default();
+    NEXT_SCHEDULE_ID.save(deps.storage, &(id + 1))?;
+    PAYER_SCHEDULES.save(deps.storage, (&info.sender, id), &Empty {})?;
+    SCHEDULES.save(
+        deps.storage,
+        id,
+        &PaymentSchedule {
+            id,
+            payer: info.sender,
+            asset,
+            interval,
+            next_payment: start.unwrap_or(env.block.time),
+            payments_left: payments,
+            payments_made: 0,
+        },
+    )?;
+
+    Ok(module
+        .response("create_schedule")
+        .add_attribute("schedule_id", id.to_string()))
+}
+
+fn cancel_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: PaymentApp,
+    schedule_id: u64,
+) -> AppResult {
+    let schedule = SCHEDULES
+        .may_load(deps.storage, schedule_id)?
+        .ok_or(AppError::ScheduleDoesNotExist(schedule_id))?;
+    if schedule.payer != info.sender && !module.admin.is_admin(deps.as_ref(), &env, &info.sender)? {
+        return Err(AppError::Unauthorized {});
+    }
+    SCHEDULES.remove(deps.storage, schedule_id);
+    PAYER_SCHEDULES.remove(deps.storage, (&schedule.payer, schedule_id));
+
+    Ok(module
+        .response("cancel_schedule")
+        .add_attribute("schedule_id", schedule_id.to_string()))
+}
+
+// Pulls a due payment from the payer, swapped to the desired asset if set
+fn trigger_payment(deps: DepsMut, env: Env, module: PaymentApp, schedule_id: u64) -> AppResult {
+    let mut schedule = SCHEDULES
+        .may_load(deps.storage, schedule_id)?
+        .ok_or(AppError::ScheduleDoesNotExist(schedule_id))?;
+    if schedule.payments_left == Some(0) {
+        return Err(AppError::ScheduleFinished(schedule_id));
+    }
+    if env.block.time < schedule.next_payment {
+        return Err(AppError::PaymentNotDue {
+            schedule_id,
+            next_payment: schedule.next_payment,
+        });
+    }
+
+    let asset = module.name_service(deps.as_ref()).query(&schedule.asset)?;
+    let account = module.account(deps.as_ref())?;
+    let pull_msg = pull_payment_msg(
+        &module,
+        deps.as_ref(),
+        &env,
+        &schedule.payer,
+        account.addr(),
+        &asset,
+    )?
+    .ok_or_else(|| AppError::UnsupportedPaymentAsset(schedule.asset.name.to_string()))?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let swap_msg = match config.desired_asset {
+        Some(desired_asset) if desired_asset != schedule.asset.name => conversion_swap(
+            deps.as_ref(),
+            &env,
+            &module,
+            &config.exchanges,
+            &schedule.asset,
+            &desired_asset,
+        )?
+        .map(|(swap_msg, _)| swap_msg),
+        _ => None,
+    };
+
+    schedule.payments_made += 1;
+    schedule.payments_left = schedule.payments_left.map(|left| left - 1);
+    schedule.next_payment = schedule.next_payment.plus_seconds(schedule.interval);
+    SCHEDULES.save(deps.storage, schedule_id, &schedule)?;
+
+    Ok(module
+        .response("trigger_payment")
+        .add_message(pull_msg)
+        .add_messages(swap_msg)
+        .add_attribute("schedule_id", schedule_id.to_string()))
+}
+
+/// Message that pulls `asset` from the payer into the account, if the asset can be pulled
+fn pull_payment_msg(
+    module: &PaymentApp,
+    deps: Deps,
+    env: &Env,
+    payer: &Addr,
+    account: &Addr,
+    asset: &Asset,
+) -> AppResult<Option<CosmosMsg>> {
+    let pull_msg = match &asset.info {
+        // pulled with the authz send grant of the payer
+        AssetInfo::Native(denom) => module.auth_z(deps, Some(payer.clone()))?.execute(
+            &env.contract.address,
+            BankMsg::Send {
+                to_address: account.to_string(),
+                amount: coins(asset.amount.u128(), denom),
+            },
+        ),
+        // pulled with the cw20 allowance of the payer
+        AssetInfo::Cw20(token) => wasm_execute(
+            token,
+            &Cw20ExecuteMsg::TransferFrom {
+                owner: payer.to_string(),
+                recipient: account.to_string(),
+                amount: asset.amount,
+            },
+            vec![],
+        )?
+        .into(),
+        _ => return Ok(None),
+    };
+    Ok(Some(pull_msg))
+}
+
 fn update_tipper_history(
     storage: &mut dyn Storage,
     sender: &Addr,
@@ -223,3 +591,98 @@ fn update_config(
 
     Ok(module.response("update_config"))
 }
+
+#[cfg(test)]
+mod tests {
+    use abstract_app::{
+        sdk::namespaces::BASE_STATE,
+        std::{app::AppState, registry::Account},
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Item;
+
+    use super::*;
+    use crate::contract::{APP_ID, APP_VERSION};
+
+    /// App installed on `account`
+    fn mock_module(storage: &mut dyn Storage, account: &Addr) -> PaymentApp {
+        Item::new(BASE_STATE)
+            .save(
+                storage,
+                &AppState {
+                    account: Account::new(account.clone()),
+                },
+            )
+            .unwrap();
+        PaymentApp::new(APP_ID, APP_VERSION, None)
+    }
+
+    #[test]
+    fn native_payment_pulled_with_payer_grant() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let payer = deps.api.addr_make("payer");
+        let account = deps.api.addr_make("account");
+        let module = mock_module(deps.as_mut().storage, &account);
+        let asset = Asset::native("ujuno", 100u128);
+
+        let pull_msg = pull_payment_msg(&module, deps.as_ref(), &env, &payer, &account, &asset)
+            .unwrap()
+            .unwrap();
+
+        // The app executes a send from the payer to the account, granted by the payer
+        let expected = module
+            .auth_z(deps.as_ref(), Some(payer.clone()))
+            .unwrap()
+            .execute(
+                &env.contract.address,
+                BankMsg::Send {
+                    to_address: account.to_string(),
+                    amount: coins(100, "ujuno"),
+                },
+            );
+        assert_eq!(pull_msg, expected);
+        // Not a plain send out of the app or the account
+        assert!(!matches!(pull_msg, CosmosMsg::Bank(_)));
+
+        // A grant of another payer doesn't pull from this payer
+        let other = module
+            .auth_z(deps.as_ref(), Some(deps.api.addr_make("other")))
+            .unwrap()
+            .execute(
+                &env.contract.address,
+                BankMsg::Send {
+                    to_address: account.to_string(),
+                    amount: coins(100, "ujuno"),
+                },
+            );
+        assert_ne!(pull_msg, other);
+    }
+
+    #[test]
+    fn cw20_payment_pulled_with_allowance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let payer = deps.api.addr_make("payer");
+        let account = deps.api.addr_make("account");
+        let module = mock_module(deps.as_mut().storage, &account);
+        let token = deps.api.addr_make("token");
+        let asset = Asset::cw20(token.clone(), 100u128);
+
+        let pull_msg = pull_payment_msg(&module, deps.as_ref(), &env, &payer, &account, &asset)
+            .unwrap()
+            .unwrap();
+        let expected: CosmosMsg = wasm_execute(
+            token,
+            &Cw20ExecuteMsg::TransferFrom {
+                owner: payer.to_string(),
+                recipient: account.to_string(),
+                amount: Uint128::new(100),
+            },
+            vec![],
+        )
+        .unwrap()
+        .into();
+        assert_eq!(pull_msg, expected);
+    }
+}
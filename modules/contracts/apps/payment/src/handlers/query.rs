@@ -1,14 +1,19 @@
 use abstract_app::std::objects::{AnsAsset, AssetEntry};
-use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Order, StdResult};
-use cw_storage_plus::Bound;
+use cosmwasm_schema::serde::{de::DeserializeOwned, Serialize};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Empty, Env, Order, StdResult};
+use cw_storage_plus::{Bound, Map};
 
 use crate::{
     contract::{AppResult, PaymentApp},
+    error::AppError,
     msg::{
-        AppQueryMsg, ConfigResponse, TipCountResponse, TipperCountResponse, TipperResponse,
-        TippersCountResponse,
+        AppQueryMsg, ConfigResponse, InvoicesResponse, SchedulesResponse, TipCountResponse,
+        TipperCountResponse, TipperResponse, TippersCountResponse,
+    },
+    state::{
+        CONFIG, INVOICES, PAYER_INVOICES, PAYER_SCHEDULES, SCHEDULES, TIPPERS, TIPPER_COUNT,
+        TIP_COUNT,
     },
-    state::{CONFIG, TIPPERS, TIPPER_COUNT, TIP_COUNT},
 };
 
 const DEFAULT_LIMIT: u32 = 10;
@@ -31,6 +36,30 @@ pub fn query_handler(
             limit,
             at_height,
         } => to_json_binary(&query_tipper(deps, address, start_after, limit, at_height)?),
+        AppQueryMsg::Invoice { invoice_id } => to_json_binary(
+            &INVOICES
+                .may_load(deps.storage, invoice_id)?
+                .ok_or(AppError::InvoiceDoesNotExist(invoice_id))?,
+        ),
+        AppQueryMsg::Invoices {
+            payer,
+            start_after,
+            limit,
+        } => to_json_binary(&InvoicesResponse {
+            invoices: list_by_payer(deps, INVOICES, PAYER_INVOICES, payer, start_after, limit)?,
+        }),
+        AppQueryMsg::Schedule { schedule_id } => to_json_binary(
+            &SCHEDULES
+                .may_load(deps.storage, schedule_id)?
+                .ok_or(AppError::ScheduleDoesNotExist(schedule_id))?,
+        ),
+        AppQueryMsg::Schedules {
+            payer,
+            start_after,
+            limit,
+        } => to_json_binary(&SchedulesResponse {
+            schedules: list_by_payer(deps, SCHEDULES, PAYER_SCHEDULES, payer, start_after, limit)?,
+        }),
     }
     .map_err(Into::into)
 }
@@ -106,6 +135,35 @@ fn query_list_tippers_count(
     Ok(TippersCountResponse { tippers })
 }
 
+/// List the entries of `map`, or only the ones of `payer` using its `payer_index`
+fn list_by_payer<T: Serialize + DeserializeOwned>(
+    deps: Deps,
+    map: Map<u64, T>,
+    payer_index: Map<(&Addr, u64), Empty>,
+    payer: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> AppResult<Vec<T>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let Some(payer) = payer else {
+        return map
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, value)| value))
+            .collect::<StdResult<_>>()
+            .map_err(Into::into);
+    };
+
+    let payer = deps.api.addr_validate(&payer)?;
+    payer_index
+        .prefix(&payer)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|id| Ok(map.load(deps.storage, id?)?))
+        .collect()
+}
+
 fn tipper_at_height(
     deps: Deps,
     address: Addr,
@@ -1,8 +1,11 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use cosmwasm_std::{from_json, DepsMut, Env, MessageInfo};
 use cw20::Cw20ReceiveMsg;
 use cw_asset::Asset;
 
-use crate::contract::{AppResult, PaymentApp};
+use crate::{
+    contract::{AppResult, PaymentApp},
+    msg::Cw20HookMsg,
+};
 
 pub fn receive_handler(
     deps: DepsMut,
@@ -14,11 +17,22 @@ pub fn receive_handler(
     let Cw20ReceiveMsg {
         sender,
         amount,
-        msg: _,
+        msg,
     } = msg;
 
     let receipt = Asset::cw20(info.sender, amount);
 
     info.sender = deps.api.addr_validate(&sender)?;
-    crate::handlers::execute::tip(deps, env, info, module, Some(receipt))
+    // Any message other than an invoice payment is a tip
+    match from_json(&msg) {
+        Ok(Cw20HookMsg::PayInvoice { invoice_id }) => crate::handlers::execute::pay_invoice(
+            deps,
+            env,
+            info,
+            module,
+            invoice_id,
+            Some(receipt),
+        ),
+        Err(_) => crate::handlers::execute::tip(deps, env, info, module, Some(receipt)),
+    }
 }
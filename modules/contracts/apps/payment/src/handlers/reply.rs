@@ -0,0 +1,67 @@
+use abstract_app::sdk::{
+    features::{AbstractNameService, AccountIdentification},
+    AbstractResponse, Execution, TransferInterface,
+};
+use cosmwasm_std::{DepsMut, Env, Reply, StdResult, Uint128};
+use cw_asset::Asset;
+
+use crate::{
+    contract::{AppResult, PaymentApp},
+    msg::InvoiceStatus,
+    state::{InvoicePayment, INVOICES, INVOICE_PAYMENT, INVOICE_PAYMENTS},
+};
+
+/// Credits the invoice with the amount the account received, refunding what was paid on top of it
+pub fn invoice_payment_reply(
+    deps: DepsMut,
+    _env: Env,
+    module: PaymentApp,
+    _reply: Reply,
+) -> AppResult {
+    let InvoicePayment {
+        invoice_id,
+        payer,
+        balance_before,
+    } = INVOICE_PAYMENT.load(deps.storage)?;
+    INVOICE_PAYMENT.remove(deps.storage);
+
+    let mut invoice = INVOICES.load(deps.storage, invoice_id)?;
+    let invoice_asset = module
+        .name_service(deps.as_ref())
+        .query(&invoice.amount.name)?;
+    let account = module.account(deps.as_ref())?;
+    let balance_after = invoice_asset.query_balance(&deps.querier, account.addr())?;
+    let received = balance_after.saturating_sub(balance_before);
+
+    // Overpayment goes back to the payer
+    let refund = (invoice.paid + received).saturating_sub(invoice.amount.amount);
+    let credited = received - refund;
+    invoice.paid += credited;
+    if invoice.paid >= invoice.amount.amount {
+        invoice.status = InvoiceStatus::Paid;
+    }
+    INVOICES.save(deps.storage, invoice_id, &invoice)?;
+    INVOICE_PAYMENTS.update(
+        deps.storage,
+        (invoice_id, &payer),
+        |paid| -> StdResult<Uint128> { Ok(paid.unwrap_or_default() + credited) },
+    )?;
+
+    let mut response = module
+        .response("invoice_payment")
+        .add_attribute("invoice_id", invoice_id.to_string())
+        .add_attribute("paid", invoice.paid);
+    if !refund.is_zero() {
+        let refund_action = module
+            .bank(deps.as_ref())
+            .transfer(vec![Asset::new(invoice_asset, refund)], &payer)?;
+        response = response
+            .add_message(
+                module
+                    .executor(deps.as_ref())
+                    .execute(vec![refund_action])?,
+            )
+            .add_attribute("refunded", refund);
+    }
+    Ok(response)
+}
@@ -1,7 +1,7 @@
 use abstract_app::sdk::cw_helpers::Clearable;
 use abstract_app::std::objects::{AnsAsset, AssetEntry, DexName};
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 
 use crate::contract::PaymentApp;
 
@@ -36,6 +36,36 @@ pub enum AppExecuteMsg {
         denom_asset: Option<String>,
         exchanges: Option<Vec<DexName>>,
     },
+    /// Issue an invoice, admin only
+    CreateInvoice {
+        /// Only this address can pay the invoice if set
+        payer: Option<String>,
+        /// Amount to pay, payments in other assets are converted to it
+        amount: AnsAsset,
+        description: Option<String>,
+    },
+    /// Cancel an open invoice, admin only
+    CancelInvoice { invoice_id: u64 },
+    /// Pay an invoice with the attached funds
+    #[cw_orch(payable)]
+    PayInvoice { invoice_id: u64 },
+    /// Create a recurring payment schedule with the sender as payer.
+    /// The payer pre-authorizes the pulls with an authz send grant to this app for native assets,
+    /// or a cw20 allowance for cw20 assets.
+    CreateSchedule {
+        /// Asset pulled on every payment
+        asset: AnsAsset,
+        /// Seconds between two payments
+        interval: u64,
+        /// Time of the first payment, defaults to now
+        start: Option<Timestamp>,
+        /// Number of payments, unlimited if not set
+        payments: Option<u32>,
+    },
+    /// Cancel a payment schedule, payer or admin only
+    CancelSchedule { schedule_id: u64 },
+    /// Pull the due payment of a schedule, callable by anyone
+    TriggerPayment { schedule_id: u64 },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -81,6 +111,28 @@ pub enum AppQueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Returns [`Invoice`]
+    #[returns(Invoice)]
+    Invoice { invoice_id: u64 },
+    /// Invoices issued by the account, or only those of `payer`
+    /// Returns [`InvoicesResponse`]
+    #[returns(InvoicesResponse)]
+    Invoices {
+        payer: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns [`PaymentSchedule`]
+    #[returns(PaymentSchedule)]
+    Schedule { schedule_id: u64 },
+    /// Payment schedules paying the account, or only those of `payer`
+    /// Returns [`SchedulesResponse`]
+    #[returns(SchedulesResponse)]
+    Schedules {
+        payer: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -89,6 +141,43 @@ pub struct AppMigrateMsg {}
 #[cosmwasm_schema::cw_serde]
 pub struct Cw20TipMsg {}
 
+/// Hook message for cw20 payments, any other message is handled as a tip
+#[cosmwasm_schema::cw_serde]
+pub enum Cw20HookMsg {
+    PayInvoice { invoice_id: u64 },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub enum InvoiceStatus {
+    Open,
+    Paid,
+    Cancelled,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct Invoice {
+    pub id: u64,
+    pub payer: Option<Addr>,
+    pub amount: AnsAsset,
+    /// Amount paid so far, in the invoice asset
+    pub paid: Uint128,
+    pub description: Option<String>,
+    pub status: InvoiceStatus,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PaymentSchedule {
+    pub id: u64,
+    pub payer: Addr,
+    pub asset: AnsAsset,
+    /// Seconds between two payments
+    pub interval: u64,
+    pub next_payment: Timestamp,
+    /// Payments left, unlimited if not set
+    pub payments_left: Option<u32>,
+    pub payments_made: u32,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ConfigResponse {
     pub desired_asset: Option<AssetEntry>,
@@ -118,3 +207,13 @@ pub struct TippersCountResponse {
 pub struct TipCountResponse {
     pub count: u32,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct InvoicesResponse {
+    pub invoices: Vec<Invoice>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct SchedulesResponse {
+    pub schedules: Vec<PaymentSchedule>,
+}
@@ -1,6 +1,8 @@
 use abstract_app::std::objects::{AssetEntry, DexName};
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, SnapshotMap, Strategy};
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+
+use crate::msg::{Invoice, PaymentSchedule};
 
 pub const CONFIG: Item<Config> = Item::new("cfg");
 // The sender address is used here for querying by tipper
@@ -14,9 +16,31 @@ pub const TIPPER_COUNT: SnapshotMap<&Addr, u32> = SnapshotMap::new(
     Strategy::EveryBlock,
 );
 
+pub const NEXT_INVOICE_ID: Item<u64> = Item::new("next-invoice");
+pub const INVOICES: Map<u64, Invoice> = Map::new("invoices");
+// Secondary index for querying by payer
+pub const PAYER_INVOICES: Map<(&Addr, u64), Empty> = Map::new("payer-invoices");
+// Amount credited to each payer of an invoice, refunded when the invoice is cancelled
+pub const INVOICE_PAYMENTS: Map<(u64, &Addr), Uint128> = Map::new("invoice-payments");
+// Invoice payment in progress, credited once the received amount is known
+pub const INVOICE_PAYMENT: Item<InvoicePayment> = Item::new("invoice-payment");
+
+pub const NEXT_SCHEDULE_ID: Item<u64> = Item::new("next-schedule");
+pub const SCHEDULES: Map<u64, PaymentSchedule> = Map::new("schedules");
+// Secondary index for querying by payer
+pub const PAYER_SCHEDULES: Map<(&Addr, u64), Empty> = Map::new("payer-schedules");
+
 #[cosmwasm_schema::cw_serde]
 pub struct Config {
     pub desired_asset: Option<AssetEntry>,
     pub denom_asset: String,
     pub exchanges: Vec<DexName>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct InvoicePayment {
+    pub invoice_id: u64,
+    pub payer: Addr,
+    /// Balance of the account in the invoice asset before the payment
+    pub balance_before: Uint128,
+}
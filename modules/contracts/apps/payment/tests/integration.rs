@@ -21,8 +21,10 @@ use cw_plus_orch::cw20_base::{
 use mockdex_bundle::WynDex;
 use payment_app::{
     contract::{APP_ID, APP_VERSION},
+    error::AppError,
     msg::{
-        AppInstantiateMsg, ConfigResponse, TipCountResponse, TipperCountResponse, TipperResponse,
+        AppInstantiateMsg, ConfigResponse, Cw20HookMsg, Invoice, InvoiceStatus, InvoicesResponse,
+        PaymentSchedule, SchedulesResponse, TipCountResponse, TipperCountResponse, TipperResponse,
         TippersCountResponse,
     },
     *,
@@ -516,3 +518,274 @@ fn test_sent_desired_asset() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Creates a cw20 token registered in the ANS, with `holder` owning `amount`
+fn setup_cw20(
+    mock: &MockBech32,
+    abstr_deployment: &Abstract<MockBech32>,
+    holder: &Addr,
+    amount: u128,
+) -> anyhow::Result<AbstractCw20Base<MockBech32>> {
+    let cw20_token_name = "cw20_token";
+    let cw20_token = AbstractCw20Base::new(cw20_token_name, mock.clone());
+    cw20_token.upload()?;
+    cw20_token.instantiate(
+        &Cw20InstantiateMsg {
+            name: cw20_token_name.to_owned(),
+            symbol: "token".to_owned(),
+            decimals: 18,
+            initial_balances: vec![Cw20Coin {
+                address: holder.to_string(),
+                amount: Uint128::from(amount),
+            }],
+            mint: None,
+            marketing: None,
+        },
+        None,
+        &[],
+    )?;
+    abstr_deployment.ans_host.update_asset_addresses(
+        vec![(
+            cw20_token.address()?.to_string(),
+            cw_asset::AssetInfoBase::cw20(cw20_token.address()?.to_string()),
+        )],
+        vec![],
+    )?;
+    Ok(cw20_token)
+}
+
+#[test]
+fn test_invoice_paid_with_conversion() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) =
+        setup(mock.clone(), Some(AssetEntry::new(mockdex_bundle::USD)))?;
+    let WynDex {
+        eur_token,
+        usd_token,
+        ..
+    } = wyndex;
+
+    let payer = mock.addr_make("payer");
+    let pay_coins = coins(100, eur_token.to_string());
+    mock.set_balance(&payer, pay_coins.clone())?;
+
+    app.create_invoice(
+        AnsAsset::new(mockdex_bundle::USD, 50u128),
+        Some("order #1".to_owned()),
+        Some(payer.to_string()),
+    )?;
+
+    // only the payer can pay
+    let other = mock.addr_make("other");
+    mock.set_balance(&other, pay_coins.clone())?;
+    let err: AppError = app
+        .call_as(&other)
+        .pay_invoice(0, &pay_coins)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, AppError::Unauthorized {});
+
+    // EUR payment gets swapped to USD
+    app.call_as(&payer).pay_invoice(0, &pay_coins)?;
+
+    // the invoice amount stays on the account, the rest of the swapped USD is refunded
+    let balance = mock.query_balance(&account.address()?, &usd_token.to_string())?;
+    assert_eq!(balance.u128(), 50);
+    let balance = mock.query_balance(&account.address()?, &eur_token.to_string())?;
+    assert!(balance.is_zero());
+    let refund = mock.query_balance(&payer, &usd_token.to_string())?;
+    assert!(!refund.is_zero());
+
+    let invoice: Invoice = app.invoice(0)?;
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.paid.u128(), 50);
+
+    let invoices: InvoicesResponse = app.invoices(None, Some(payer.to_string()), None)?;
+    assert_eq!(invoices.invoices, vec![invoice]);
+    let invoices: InvoicesResponse = app.invoices(None, Some(other.to_string()), None)?;
+    assert!(invoices.invoices.is_empty());
+
+    // paid invoice can't be paid again
+    mock.set_balance(&payer, pay_coins.clone())?;
+    let err: AppError = app
+        .call_as(&payer)
+        .pay_invoice(0, &pay_coins)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, AppError::InvoiceNotOpen(0));
+
+    Ok(())
+}
+
+#[test]
+fn test_invoice_partial_cw20_payment_and_cancel() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, abstr_deployment, app, _wyndex) = setup(mock.clone(), None)?;
+    let payer = mock.addr_make("payer");
+    let cw20_token = setup_cw20(&mock, &abstr_deployment, &payer, 1_000)?;
+
+    app.create_invoice(AnsAsset::new(cw20_token.addr_str()?, 500u128), None, None)?;
+
+    cw20_token.call_as(&payer).send(
+        Uint128::new(200),
+        app.addr_str()?,
+        to_json_binary(&Cw20HookMsg::PayInvoice { invoice_id: 0 })?,
+    )?;
+
+    let account_balance = cw20_token.balance(account.addr_str()?)?.balance;
+    assert_eq!(account_balance.u128(), 200);
+
+    let invoice: Invoice = app.invoice(0)?;
+    assert_eq!(invoice.status, InvoiceStatus::Open);
+    assert_eq!(invoice.paid.u128(), 200);
+    // not counted as a tip
+    let tip_count_response: TipCountResponse = app.tip_count()?;
+    assert_eq!(0, tip_count_response.count);
+
+    // a second payer pays part of the rest
+    let other_payer = mock.addr_make("other_payer");
+    cw20_token
+        .call_as(&payer)
+        .transfer(Uint128::new(100), other_payer.to_string())?;
+    cw20_token.call_as(&other_payer).send(
+        Uint128::new(100),
+        app.addr_str()?,
+        to_json_binary(&Cw20HookMsg::PayInvoice { invoice_id: 0 })?,
+    )?;
+    let invoice: Invoice = app.invoice(0)?;
+    assert_eq!(invoice.paid.u128(), 300);
+
+    // cancelling refunds every payer
+    app.cancel_invoice(0)?;
+    let invoice: Invoice = app.invoice(0)?;
+    assert_eq!(invoice.status, InvoiceStatus::Cancelled);
+    assert_eq!(cw20_token.balance(payer.to_string())?.balance.u128(), 900);
+    assert_eq!(
+        cw20_token.balance(other_payer.to_string())?.balance.u128(),
+        100
+    );
+    assert!(cw20_token.balance(account.addr_str()?)?.balance.is_zero());
+
+    // only the admin can issue invoices
+    let err: AppError = app
+        .call_as(&payer)
+        .create_invoice(AnsAsset::new(cw20_token.addr_str()?, 500u128), None, None)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, AppError::Admin(_)));
+
+    Ok(())
+}
+
+#[test]
+fn test_invoice_overpayment_refunded() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, abstr_deployment, app, _wyndex) = setup(mock.clone(), None)?;
+    let payer = mock.addr_make("payer");
+    let cw20_token = setup_cw20(&mock, &abstr_deployment, &payer, 1_000)?;
+
+    app.create_invoice(
+        AnsAsset::new(cw20_token.addr_str()?, 300u128),
+        None,
+        Some(payer.to_string()),
+    )?;
+
+    cw20_token.call_as(&payer).send(
+        Uint128::new(200),
+        app.addr_str()?,
+        to_json_binary(&Cw20HookMsg::PayInvoice { invoice_id: 0 })?,
+    )?;
+    // only the missing 100 of the second payment is kept
+    cw20_token.call_as(&payer).send(
+        Uint128::new(250),
+        app.addr_str()?,
+        to_json_binary(&Cw20HookMsg::PayInvoice { invoice_id: 0 })?,
+    )?;
+
+    let invoice: Invoice = app.invoice(0)?;
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.paid.u128(), 300);
+    assert_eq!(cw20_token.balance(account.addr_str()?)?.balance.u128(), 300);
+    assert_eq!(cw20_token.balance(payer.to_string())?.balance.u128(), 700);
+
+    Ok(())
+}
+
+#[test]
+fn test_cw20_recurring_payment() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, abstr_deployment, app, _wyndex) = setup(mock.clone(), None)?;
+    let payer = mock.addr_make("payer");
+    let cw20_token = setup_cw20(&mock, &abstr_deployment, &payer, 1_000)?;
+    let interval = 3_600;
+
+    // pre-authorize the pulls
+    cw20_token.call_as(&payer).execute(
+        &cw20_base::msg::ExecuteMsg::IncreaseAllowance {
+            spender: app.addr_str()?,
+            amount: Uint128::new(200),
+            expires: None,
+        },
+        &[],
+    )?;
+    app.call_as(&payer).create_schedule(
+        AnsAsset::new(cw20_token.addr_str()?, 100u128),
+        interval,
+        Some(2),
+        None,
+    )?;
+
+    // anyone can trigger a due payment
+    let keeper = mock.addr_make("keeper");
+    app.call_as(&keeper).trigger_payment(0)?;
+    let account_balance = cw20_token.balance(account.addr_str()?)?.balance;
+    assert_eq!(account_balance.u128(), 100);
+
+    let err: AppError = app
+        .call_as(&keeper)
+        .trigger_payment(0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    let schedule: PaymentSchedule = app.schedule(0)?;
+    assert_eq!(
+        err,
+        AppError::PaymentNotDue {
+            schedule_id: 0,
+            next_payment: schedule.next_payment,
+        }
+    );
+
+    mock.wait_seconds(interval)?;
+    app.call_as(&keeper).trigger_payment(0)?;
+    let account_balance = cw20_token.balance(account.addr_str()?)?.balance;
+    assert_eq!(account_balance.u128(), 200);
+
+    let schedules: SchedulesResponse = app.schedules(None, Some(payer.to_string()), None)?;
+    assert_eq!(schedules.schedules.len(), 1);
+    assert_eq!(schedules.schedules[0].payments_made, 2);
+    assert_eq!(schedules.schedules[0].payments_left, Some(0));
+
+    mock.wait_seconds(interval)?;
+    let err: AppError = app
+        .call_as(&keeper)
+        .trigger_payment(0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, AppError::ScheduleFinished(0));
+
+    // payer can cancel
+    app.call_as(&payer).cancel_schedule(0)?;
+    let schedules: SchedulesResponse = app.schedules(None, None, None)?;
+    assert!(schedules.schedules.is_empty());
+
+    Ok(())
+}
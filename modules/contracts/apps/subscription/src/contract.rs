@@ -58,7 +58,7 @@ mod tests {
     use std::str::FromStr;
 
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env},
+        testing::{message_info, mock_dependencies, mock_env},
         to_json_binary, CosmosMsg, Decimal, SubMsg, WasmMsg,
     };
 
@@ -75,6 +75,7 @@ mod tests {
     fn unsubscribe_no_hook_msg() {
         let mut deps = mock_dependencies();
         let bob = deps.api.addr_make("bob");
+        let sender = deps.api.addr_make("sender");
         let depsmut = deps.as_mut();
         let env = mock_env();
         let app = SUBSCRIPTION_MODULE;
@@ -87,9 +88,12 @@ mod tests {
                 depsmut.storage,
                 &SubscriptionConfig {
                     payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
+                    accepted_payment_assets: vec![],
                     subscription_cost_per_second: Decimal::from_str("0.1").unwrap(),
                     subscription_per_second_emissions: crate::state::EmissionType::None,
                     unsubscribe_hook_addr: None,
+                    grace_period: 0,
+                    terms: vec![],
                 },
             )
             .unwrap();
@@ -100,6 +104,7 @@ mod tests {
                 &Subscriber {
                     expiration_timestamp: env.block.time,
                     last_emission_claim_timestamp: env.block.time,
+                    payments: vec![],
                 },
             )
             .unwrap();
@@ -107,7 +112,14 @@ mod tests {
             .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
             .unwrap();
 
-        let res = handlers::execute::unsubscribe(depsmut, env, app, vec![bob.to_string()]).unwrap();
+        let res = handlers::execute::unsubscribe(
+            depsmut,
+            env,
+            message_info(&sender, &[]),
+            app,
+            vec![bob.to_string()],
+        )
+        .unwrap();
 
         assert!(res.messages.is_empty());
     }
@@ -117,6 +129,7 @@ mod tests {
         let mut deps = mock_dependencies();
         let alice = deps.api.addr_make("alice");
         let bob = deps.api.addr_make("bob");
+        let sender = deps.api.addr_make("sender");
         let depsmut = deps.as_mut();
         let env = mock_env();
         let app = SUBSCRIPTION_MODULE;
@@ -129,9 +142,12 @@ mod tests {
                 depsmut.storage,
                 &SubscriptionConfig {
                     payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
+                    accepted_payment_assets: vec![],
                     subscription_cost_per_second: Decimal::from_str("0.1").unwrap(),
                     subscription_per_second_emissions: crate::state::EmissionType::None,
                     unsubscribe_hook_addr: Some(alice.clone()),
+                    grace_period: 0,
+                    terms: vec![],
                 },
             )
             .unwrap();
@@ -142,6 +158,7 @@ mod tests {
                 &Subscriber {
                     expiration_timestamp: env.block.time,
                     last_emission_claim_timestamp: env.block.time,
+                    payments: vec![],
                 },
             )
             .unwrap();
@@ -149,7 +166,14 @@ mod tests {
             .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
             .unwrap();
 
-        let res = handlers::execute::unsubscribe(depsmut, env, app, vec![bob.to_string()]).unwrap();
+        let res = handlers::execute::unsubscribe(
+            depsmut,
+            env,
+            message_info(&sender, &[]),
+            app,
+            vec![bob.to_string()],
+        )
+        .unwrap();
 
         let expected_msg = SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: alice.to_string(),
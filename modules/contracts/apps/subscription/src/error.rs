@@ -57,6 +57,12 @@ pub enum SubscriptionError {
     #[error("Subscriber emissions are not enabled")]
     SubscriberEmissionsNotEnabled {},
 
+    #[error("Income-based emissions are not enabled")]
+    IncomeEmissionsNotEnabled {},
+
+    #[error("Subscription term {0} not found")]
+    TermNotFound(String),
+
     #[error("Redundant unsubscribe call")]
     NoOneUnsubbed {},
 
@@ -1,15 +1,22 @@
 use abstract_app::sdk::{
-    cw_helpers::Clearable, AbstractResponse, AccountAction, Execution, TransferInterface,
+    cw_helpers::Clearable, features::AbstractNameService, AbstractResponse, AccountAction,
+    Execution, OracleInterface, TransferInterface,
+};
+use abstract_app::std::AbstractResult;
+use abstract_dex_adapter::DexInterface;
+use cosmwasm_std::{
+    Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128, Uint64,
 };
-use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
 use cw_asset::{Asset, AssetInfoUnchecked};
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
     msg::{SubscriptionExecuteMsg, UnsubscribedHookMsg},
     state::{
-        EmissionType, Subscriber, SubscriptionConfig, SubscriptionState, EXPIRED_SUBSCRIBERS,
-        INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        remove_subscriber, save_subscriber, EmissionType, PriceSource, PricedAsset, Subscriber,
+        SubscriptionConfig, SubscriptionPayment, SubscriptionState, SubscriptionTerm,
+        CONTRIBUTOR_EMISSIONS_CLAIM, EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS,
+        SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
     },
     SubscriptionError,
 };
@@ -24,41 +31,53 @@ pub fn execute_handler(
     msg: SubscriptionExecuteMsg,
 ) -> SubscriptionResult {
     match msg {
-        SubscriptionExecuteMsg::Pay { subscriber_addr } => {
+        SubscriptionExecuteMsg::Pay {
+            subscriber_addr,
+            term,
+        } => {
             let maybe_received_coin = info.funds.last();
             let subscriber_addr = subscriber_addr
                 .map(|human| deps.api.addr_validate(&human))
                 .transpose()?
                 .unwrap_or(info.sender.clone());
             if let Some(coin) = maybe_received_coin.cloned() {
-                try_pay(module, deps, env, Asset::from(coin), subscriber_addr)
+                try_pay(module, deps, env, Asset::from(coin), subscriber_addr, term)
             } else {
                 Err(SubscriptionError::NotUsingCW20Hook {})
             }
         }
         SubscriptionExecuteMsg::Unsubscribe { unsubscribe_addrs } => {
-            unsubscribe(deps, env, module, unsubscribe_addrs)
+            unsubscribe(deps, env, info, module, unsubscribe_addrs)
         }
         SubscriptionExecuteMsg::ClaimEmissions { addr } => {
             claim_subscriber_emissions(&module, &mut deps, &env, addr)
         }
+        SubscriptionExecuteMsg::ClaimContributorEmissions {} => {
+            claim_contributor_emissions(&module, deps, &env)
+        }
         SubscriptionExecuteMsg::UpdateSubscriptionConfig {
             payment_asset,
+            accepted_payment_assets,
             subscription_cost_per_second,
             subscription_per_second_emissions,
             unsubscribe_hook_addr,
+            grace_period,
+            terms,
         } => update_subscription_config(
             deps,
             env,
             info,
             module,
             payment_asset,
+            accepted_payment_assets,
             subscription_cost_per_second,
             subscription_per_second_emissions,
             unsubscribe_hook_addr,
+            grace_period,
+            terms,
         ),
         SubscriptionExecuteMsg::RefreshTWA {} => {
-            INCOME_TWA.try_update_value(&env, deps.storage)?;
+            refresh_income_twa(&env, deps.storage)?;
             Ok(Response::new())
         }
     }
@@ -72,50 +91,75 @@ pub fn try_pay(
     env: Env,
     asset: Asset,
     subscriber_addr: Addr,
+    term: Option<String>,
 ) -> SubscriptionResult {
     // Load all needed states
     let config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let twa_data = INCOME_TWA.load(deps.storage)?;
     let base_state = module.load_state(deps.storage)?;
-    // Construct deposit info
+    // Value of the payment in the reference asset
+    let payment_value = payment_value(&module, deps.as_ref(), &env, &config, &asset)?;
     let deposit_info = config.payment_asset;
 
-    // Assert payment asset and claimed asset infos are the same
-    if deposit_info != asset.info {
-        return Err(SubscriptionError::WrongToken(deposit_info));
-    }
-    // Minimum of one period worth to (re)-subscribe.
+    // Minimum of one period (or the chosen term) worth to (re)-subscribe.
     // prevents un- and re-subscribing all the time.
-    let required_payment = Uint128::from(twa_data.averaging_period)
-        .checked_mul_ceil(config.subscription_cost_per_second)?;
-    let paid_for_seconds = asset
-        .amount
-        .checked_div_floor(config.subscription_cost_per_second)?
-        .u128() as u64;
+    let (paid_for_seconds, required_payment) = match term {
+        Some(term) => {
+            let term = config
+                .terms
+                .iter()
+                .find(|t| t.name == term)
+                .ok_or(SubscriptionError::TermNotFound(term))?;
+            let term_price = term.price(config.subscription_cost_per_second)?;
+            if payment_value < term_price {
+                return Err(SubscriptionError::InsufficientPayment(
+                    term_price,
+                    deposit_info.to_string(),
+                ));
+            }
+            // Anything paid on top of the term is added at the regular cost
+            let extra_seconds = (payment_value - term_price)
+                .checked_div_floor(config.subscription_cost_per_second)?
+                .u128() as u64;
+            (term.duration + extra_seconds, term_price)
+        }
+        None => {
+            let paid_for_seconds = payment_value
+                .checked_div_floor(config.subscription_cost_per_second)?
+                .u128() as u64;
+            let required_payment = Uint128::from(twa_data.averaging_period)
+                .checked_mul_ceil(config.subscription_cost_per_second)?;
+            (paid_for_seconds, required_payment)
+        }
+    };
+    // Payments are refunded in the paid asset, at the price and discount they were made at
+    let payment = SubscriptionPayment {
+        asset: asset.clone(),
+        paid_for_seconds,
+    };
     if let Some(mut active_sub) = SUBSCRIBERS.may_load(deps.storage, &subscriber_addr)? {
-        // Subscriber is active, update balance
-        active_sub.extend(paid_for_seconds);
-        SUBSCRIBERS.save(deps.storage, &subscriber_addr, &active_sub)?;
+        // Subscriber is active or in the grace period, renew the subscription
+        active_sub.extend(&env.block, payment);
+        save_subscriber(deps.storage, &subscriber_addr, &active_sub)?;
     } else {
         // Subscriber is (re)activating his subscription.
-        if asset.amount < required_payment {
+        if payment_value < required_payment {
             return Err(SubscriptionError::InsufficientPayment(
                 required_payment,
                 deposit_info.to_string(),
             ));
         }
-        let subscriber = Subscriber::new(&env.block, paid_for_seconds);
+        let subscriber = Subscriber::new(&env.block, payment);
         let mut subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
         INCOME_TWA.accumulate(
             &env,
             deps.storage,
-            Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
-                * config.subscription_cost_per_second,
+            income_per_second(&subscription_state, &config)?,
         )?;
         // Remove from expired list in case it's re-sub
         EXPIRED_SUBSCRIBERS.remove(deps.storage, &subscriber_addr);
 
-        SUBSCRIBERS.save(deps.storage, &subscriber_addr, &subscriber)?;
+        save_subscriber(deps.storage, &subscriber_addr, &subscriber)?;
         subscription_state.active_subs += 1;
         SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
     }
@@ -129,9 +173,72 @@ pub fn try_pay(
         ))
 }
 
+/// Current income per second, denominated in the reference payment asset
+fn income_per_second(
+    subscription_state: &SubscriptionState,
+    subscription_config: &SubscriptionConfig,
+) -> SubscriptionResult<Decimal> {
+    Ok(
+        Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
+            * subscription_config.subscription_cost_per_second,
+    )
+}
+
+/// Accumulates the ongoing income and updates the income average once a full period has passed
+fn refresh_income_twa(env: &Env, storage: &mut dyn Storage) -> SubscriptionResult<()> {
+    let subscription_state = SUBSCRIPTION_STATE.load(storage)?;
+    let subscription_config = SUBSCRIPTION_CONFIG.load(storage)?;
+    INCOME_TWA.accumulate(
+        env,
+        storage,
+        income_per_second(&subscription_state, &subscription_config)?,
+    )?;
+    INCOME_TWA.try_update_value(env, storage)?;
+    Ok(())
+}
+
+/// Value of the payment in the reference payment asset.
+/// Other accepted payment assets are priced by their dex or oracle price source.
+fn payment_value(
+    module: &SubscriptionApp,
+    deps: Deps,
+    env: &Env,
+    config: &SubscriptionConfig,
+    asset: &Asset,
+) -> SubscriptionResult<Uint128> {
+    if asset.info == config.payment_asset {
+        return Ok(asset.amount);
+    }
+    let Some(PricedAsset { price_source, .. }) = config
+        .accepted_payment_assets
+        .iter()
+        .find(|priced| priced.asset == asset.info)
+    else {
+        return Err(SubscriptionError::WrongToken(config.payment_asset.clone()));
+    };
+    let name_service = module.name_service(deps);
+    let paid_asset = name_service.query(asset)?;
+    let reference_asset = name_service.query(&config.payment_asset)?;
+    let value = match price_source {
+        PriceSource::Dex { dex } => {
+            module
+                .ans_dex(deps, env, dex.clone())
+                .simulate_swap(paid_asset, reference_asset)?
+                .return_amount
+        }
+        PriceSource::Oracle { max_age } => {
+            module
+                .oracle(deps)
+                .value(env, &paid_asset, &reference_asset, *max_age)?
+        }
+    };
+    Ok(value)
+}
+
 pub fn unsubscribe(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     module: SubscriptionApp,
     unsubscribe_addrs: Vec<String>,
 ) -> SubscriptionResult {
@@ -145,48 +252,60 @@ pub fn unsubscribe(
     let mut subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
     let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let mut canceled_subs: Vec<String> = vec![];
-    let mut claim_actions: Vec<AccountAction> = vec![];
+    let mut account_actions: Vec<AccountAction> = vec![];
 
     // update income
     INCOME_TWA.accumulate(
         &env,
         deps.storage,
-        Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
-            * subscription_config.subscription_cost_per_second,
+        income_per_second(&subscription_state, &subscription_config)?,
     )?;
 
     for addr in unsubscribe_addrs.into_iter() {
         let mut subscriber = SUBSCRIBERS.load(deps.storage, &addr)?;
-        if subscriber.is_expired(&env.block) {
-            let maybe_claim_msg = match claim_emissions_msg(
-                &module,
-                deps.as_ref(),
-                &env,
-                &mut subscriber,
-                &addr,
-                subscription_config
-                    .subscription_per_second_emissions
-                    .clone(),
-                &subscription_state,
-            ) {
-                Ok(maybe_msg) => maybe_msg,
-                // If just claimed or not enabled - no claims
-                Err(SubscriptionError::EmissionsAlreadyClaimed {})
-                | Err(SubscriptionError::SubscriberEmissionsNotEnabled {}) => None,
-                Err(error) => {
-                    return Err(error);
-                }
+        let refund =
+            if subscriber.is_past_grace_period(&env.block, subscription_config.grace_period) {
+                None
+            } else if addr == info.sender {
+                // Subscriber cancels, remaining time gets refunded in the assets it was paid with
+                let refund = subscriber.refund(&env.block);
+                subscriber.expiration_timestamp = env.block.time;
+                subscriber.payments.clear();
+                Some(refund)
+            } else {
+                continue;
             };
+        let maybe_claim_msg = match claim_emissions_msg(
+            &module,
+            deps.as_ref(),
+            &env,
+            &mut subscriber,
+            &addr,
+            subscription_config
+                .subscription_per_second_emissions
+                .clone(),
+            &subscription_state,
+        ) {
+            Ok(maybe_msg) => maybe_msg,
+            // If just claimed or not enabled - no claims
+            Err(SubscriptionError::EmissionsAlreadyClaimed {})
+            | Err(SubscriptionError::SubscriberEmissionsNotEnabled {}) => None,
+            Err(error) => {
+                return Err(error);
+            }
+        };
 
-            subscription_state.active_subs -= 1;
-            SUBSCRIBERS.remove(deps.storage, &addr);
-            EXPIRED_SUBSCRIBERS.save(deps.storage, &addr, &subscriber)?;
-            canceled_subs.push(addr.into_string());
+        subscription_state.active_subs -= 1;
+        remove_subscriber(deps.storage, &addr)?;
+        EXPIRED_SUBSCRIBERS.save(deps.storage, &addr, &subscriber)?;
 
-            if let Some(msg) = maybe_claim_msg {
-                claim_actions.push(msg)
-            }
+        if let Some(msg) = maybe_claim_msg {
+            account_actions.push(msg)
         }
+        if let Some(refund) = refund.filter(|assets| !assets.is_empty()) {
+            account_actions.push(module.bank(deps.as_ref()).transfer(refund, &addr)?);
+        }
+        canceled_subs.push(addr.into_string());
     }
 
     // Error if no one unsubbed
@@ -199,7 +318,7 @@ pub fn unsubscribe(
 
     let mut response = module
         .response("unsubscribe")
-        .add_messages(module.executor(deps.as_ref()).execute(claim_actions));
+        .add_messages(module.executor(deps.as_ref()).execute(account_actions));
 
     if let Some(hook) = subscription_config.unsubscribe_hook_addr {
         let msg = UnsubscribedHookMsg {
@@ -246,6 +365,18 @@ pub fn claim_emissions_msg(
             let amount = Uint128::from(seconds_passed).mul_floor(per_user_emissions);
             Asset::new(token, amount)
         }
+        crate::state::EmissionType::IncomeBased {
+            subscriber_share,
+            token,
+            ..
+        } => {
+            let income_per_second = INCOME_TWA.get_value(deps.storage)?;
+            // active_sub can't be 0 as we already loaded one from storage
+            let amount = Uint128::from(seconds_passed)
+                .mul_floor(income_per_second * subscriber_share)
+                / Uint128::from(subscription_state.active_subs);
+            Asset::new(token, amount)
+        }
     };
 
     if !asset.amount.is_zero() {
@@ -267,6 +398,8 @@ pub fn claim_subscriber_emissions(
     addr: String,
 ) -> SubscriptionResult {
     let subscriber_addr = deps.api.addr_validate(&addr)?;
+    // Income-based emissions use the latest income average
+    refresh_income_twa(env, deps.storage)?;
     let subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
     let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let mut subscriber = SUBSCRIBERS.load(deps.storage, &subscriber_addr)?;
@@ -281,7 +414,7 @@ pub fn claim_subscriber_emissions(
         &subscription_state,
     )?;
 
-    SUBSCRIBERS.save(deps.storage, &subscriber_addr, &subscriber)?;
+    save_subscriber(deps.storage, &subscriber_addr, &subscriber)?;
     let mut response = module.response("claim_emissions");
     if let Some(action) = maybe_action {
         response = response.add_message(module.executor(deps.as_ref()).execute(vec![action])?);
@@ -289,23 +422,101 @@ pub fn claim_subscriber_emissions(
     Ok(response)
 }
 
+/// Sends the contributors' share of the income-based emissions
+pub fn claim_contributor_emissions(
+    module: &SubscriptionApp,
+    mut deps: DepsMut,
+    env: &Env,
+) -> SubscriptionResult {
+    let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    if !matches!(
+        subscription_config.subscription_per_second_emissions,
+        EmissionType::IncomeBased { .. }
+    ) {
+        return Err(SubscriptionError::IncomeEmissionsNotEnabled {});
+    }
+    refresh_income_twa(env, deps.storage)?;
+
+    let last_claim = CONTRIBUTOR_EMISSIONS_CLAIM
+        .may_load(deps.storage)?
+        .unwrap_or(env.block.time);
+    if last_claim >= env.block.time {
+        return Err(SubscriptionError::EmissionsAlreadyClaimed {});
+    }
+
+    let mut response = module.response("claim_contributor_emissions");
+    if let Some(send_action) = settle_contributor_emissions(
+        module,
+        deps.branch(),
+        env,
+        &subscription_config.subscription_per_second_emissions,
+    )? {
+        response = response.add_message(module.executor(deps.as_ref()).execute(vec![send_action])?);
+    }
+    Ok(response)
+}
+
+/// Sends the contributors their share of the income accrued since their last claim.
+/// Returns `None` when the emissions aren't income-based or nothing accrued yet.
+fn settle_contributor_emissions(
+    module: &SubscriptionApp,
+    deps: DepsMut,
+    env: &Env,
+    emissions: &EmissionType<Addr>,
+) -> SubscriptionResult<Option<AccountAction>> {
+    let EmissionType::IncomeBased {
+        subscriber_share,
+        contributors,
+        token,
+    } = emissions
+    else {
+        return Ok(None);
+    };
+    let last_claim = CONTRIBUTOR_EMISSIONS_CLAIM
+        .may_load(deps.storage)?
+        .unwrap_or(env.block.time);
+    let seconds_passed = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(last_claim.seconds());
+    let income_per_second = INCOME_TWA.get_value(deps.storage)?;
+    let amount = Uint128::from(seconds_passed)
+        .mul_floor(income_per_second * (Decimal::one() - *subscriber_share));
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    CONTRIBUTOR_EMISSIONS_CLAIM.save(deps.storage, &env.block.time)?;
+    let send_action = module
+        .bank(deps.as_ref())
+        .transfer(vec![Asset::new(token.clone(), amount)], contributors)?;
+    Ok(Some(send_action))
+}
+
 // Only Admin can execute it
 #[allow(clippy::too_many_arguments)]
 pub fn update_subscription_config(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     module: SubscriptionApp,
     payment_asset: Option<AssetInfoUnchecked>,
+    accepted_payment_assets: Option<Vec<PricedAsset<String>>>,
     subscription_cost_per_second: Option<Decimal>,
     subscription_per_second_emissions: Option<EmissionType<String>>,
     unsubscribe_hook_addr: Option<Clearable<String>>,
+    grace_period: Option<Uint64>,
+    terms: Option<Vec<SubscriptionTerm>>,
 ) -> SubscriptionResult {
     module
         .admin
         .assert_admin(deps.as_ref(), &env, &info.sender)?;
 
+    // Income accrued under the current config is accounted for before it changes
+    refresh_income_twa(&env, deps.storage)?;
     let mut config: SubscriptionConfig = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    let mut response = module.response("update_subscription_config");
 
     if let Some(subscription_cost_per_second) = subscription_cost_per_second {
         // validate address format
@@ -316,16 +527,46 @@ pub fn update_subscription_config(
         config.payment_asset = payment_asset.check(deps.api, None)?;
     }
 
+    if let Some(accepted_payment_assets) = accepted_payment_assets {
+        config.accepted_payment_assets = accepted_payment_assets
+            .into_iter()
+            .map(|priced| priced.check(deps.api))
+            .collect::<AbstractResult<_>>()?;
+    }
+
     if let Some(subscription_per_second_emissions) = subscription_per_second_emissions {
+        // Contributors get what they accrued under the previous emissions
+        if let Some(send_action) = settle_contributor_emissions(
+            &module,
+            deps.branch(),
+            &env,
+            &config.subscription_per_second_emissions,
+        )? {
+            response =
+                response.add_message(module.executor(deps.as_ref()).execute(vec![send_action])?);
+        }
         config.subscription_per_second_emissions =
             subscription_per_second_emissions.check(deps.api)?;
+        // Contributor emissions start accruing from the moment they're configured
+        CONTRIBUTOR_EMISSIONS_CLAIM.save(deps.storage, &env.block.time)?;
     }
 
     if let Some(clearable_hook_addr) = unsubscribe_hook_addr {
         config.unsubscribe_hook_addr = clearable_hook_addr.check(deps.api)?.into();
     }
 
+    if let Some(grace_period) = grace_period {
+        config.grace_period = grace_period.u64();
+    }
+
+    if let Some(terms) = terms {
+        for term in terms.iter() {
+            term.check()?;
+        }
+        config.terms = terms;
+    }
+
     SUBSCRIPTION_CONFIG.save(deps.storage, &config)?;
 
-    Ok(module.response("update_subscription_config"))
+    Ok(response)
 }
@@ -1,10 +1,12 @@
+use abstract_app::std::AbstractResult;
 use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
     msg::SubscriptionInstantiateMsg,
     state::{
-        SubscriptionConfig, SubscriptionState, INCOME_TWA, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        SubscriptionConfig, SubscriptionState, CONTRIBUTOR_EMISSIONS_CLAIM, INCOME_TWA,
+        SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
     },
     SubscriptionError,
 };
@@ -18,17 +20,28 @@ pub fn instantiate_handler(
 ) -> SubscriptionResult {
     let subscription_config: SubscriptionConfig = SubscriptionConfig {
         payment_asset: msg.payment_asset.check(deps.api, None)?,
+        accepted_payment_assets: msg
+            .accepted_payment_assets
+            .into_iter()
+            .map(|priced| priced.check(deps.api))
+            .collect::<AbstractResult<_>>()?,
         subscription_cost_per_second: msg.subscription_cost_per_second,
         subscription_per_second_emissions: msg.subscription_per_second_emissions.check(deps.api)?,
         unsubscribe_hook_addr: msg
             .unsubscribe_hook_addr
             .map(|human| deps.api.addr_validate(&human))
             .transpose()?,
+        grace_period: msg.grace_period.u64(),
+        terms: msg.terms,
     };
+    for term in subscription_config.terms.iter() {
+        term.check()?;
+    }
 
     let subscription_state: SubscriptionState = SubscriptionState { active_subs: 0 };
     SUBSCRIPTION_CONFIG.save(deps.storage, &subscription_config)?;
     SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
+    CONTRIBUTOR_EMISSIONS_CLAIM.save(deps.storage, &env.block.time)?;
 
     ensure!(
         !msg.income_averaging_period.is_zero(),
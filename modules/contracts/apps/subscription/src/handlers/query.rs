@@ -1,5 +1,7 @@
 use abstract_app::std::objects::voting::DEFAULT_LIMIT;
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdResult, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, Env, Order, StdResult, Timestamp, Uint128, Uint64,
+};
 use cw_asset::Asset;
 use cw_storage_plus::Bound;
 
@@ -10,13 +12,14 @@ use crate::{
         SubscriptionQueryMsg,
     },
     state::{
-        EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS, SUBSCRIBER_EXPIRATIONS, SUBSCRIPTION_CONFIG,
+        SUBSCRIPTION_STATE,
     },
 };
 
 pub fn query_handler(
     deps: Deps,
-    _env: Env,
+    env: Env,
     _module: &SubscriptionApp,
     msg: SubscriptionQueryMsg,
 ) -> SubscriptionResult<Binary> {
@@ -44,21 +47,41 @@ pub fn query_handler(
             let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
             to_json_binary(&subscription_config)
         }
-        SubscriptionQueryMsg::Subscriber { addr } => to_json_binary(&query_subscriber(deps, addr)?),
+        SubscriptionQueryMsg::Subscriber { addr } => {
+            to_json_binary(&query_subscriber(deps, env, addr)?)
+        }
         SubscriptionQueryMsg::Subscribers {
             start_after,
             limit,
             expired_subs,
-        } => to_json_binary(&query_subscribers(deps, start_after, limit, expired_subs)?),
+        } => to_json_binary(&query_subscribers(
+            deps,
+            env,
+            start_after,
+            limit,
+            expired_subs,
+        )?),
+        SubscriptionQueryMsg::ExpiringSubscribers {
+            within,
+            start_after,
+            limit,
+        } => to_json_binary(&query_expiring_subscribers(
+            deps,
+            env,
+            within,
+            start_after,
+            limit,
+        )?),
     }
     .map_err(Into::into)
 }
 
-fn query_subscriber(deps: Deps, addr: String) -> SubscriptionResult<SubscriberResponse> {
+fn query_subscriber(deps: Deps, env: Env, addr: String) -> SubscriptionResult<SubscriberResponse> {
     let addr = deps.api.addr_validate(&addr)?;
     let subscription_state = if let Some(sub) = SUBSCRIBERS.may_load(deps.storage, &addr)? {
+        // Subscribers in their grace period are not subscribed anymore
         SubscriberResponse {
-            currently_subscribed: true,
+            currently_subscribed: !sub.is_expired(&env.block),
             subscriber_details: Some(sub),
         }
     } else if let Some(sub) = EXPIRED_SUBSCRIBERS.may_load(deps.storage, &addr)? {
@@ -77,6 +100,7 @@ fn query_subscriber(deps: Deps, addr: String) -> SubscriptionResult<SubscriberRe
 
 fn query_subscribers(
     deps: Deps,
+    env: Env,
     start_after: Option<cosmwasm_std::Addr>,
     limit: Option<u64>,
     expired_subs: Option<bool>,
@@ -89,14 +113,14 @@ fn query_subscribers(
         false => EXPIRED_SUBSCRIBERS,
     };
     let subscribers = map
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, min, None, Order::Ascending)
         .take(limit as usize)
         .map(|entry| {
             entry.map(|(addr, sub)| {
                 (
                     addr,
                     SubscriberResponse {
-                        currently_subscribed: subscribed,
+                        currently_subscribed: subscribed && !sub.is_expired(&env.block),
                         subscriber_details: Some(sub),
                     },
                 )
@@ -105,3 +129,38 @@ fn query_subscribers(
         .collect::<StdResult<_>>()?;
     Ok(SubscribersResponse { subscribers })
 }
+
+fn query_expiring_subscribers(
+    deps: Deps,
+    env: Env,
+    within: Uint64,
+    start_after: Option<(Timestamp, Addr)>,
+    limit: Option<u64>,
+) -> SubscriptionResult<SubscribersResponse> {
+    let min = start_after
+        .as_ref()
+        .map(|(expiration, addr)| Bound::exclusive((expiration.seconds(), addr)));
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let expires_before = env.block.time.plus_seconds(within.u64()).seconds();
+    // Only the subscribers that expire in time are read from the expiration index
+    let subscribers = SUBSCRIBER_EXPIRATIONS
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take_while(|key| {
+            key.as_ref()
+                .map_or(true, |(expiration, _)| *expiration <= expires_before)
+        })
+        .take(limit as usize)
+        .map(|key| {
+            let (_, addr) = key?;
+            let sub = SUBSCRIBERS.load(deps.storage, &addr)?;
+            Ok((
+                addr,
+                SubscriberResponse {
+                    currently_subscribed: !sub.is_expired(&env.block),
+                    subscriber_details: Some(sub),
+                },
+            ))
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(SubscribersResponse { subscribers })
+}
@@ -16,7 +16,10 @@ pub fn receive_cw20(
     cw20_msg: Cw20ReceiveMsg,
 ) -> SubscriptionResult {
     match from_json(cw20_msg.msg)? {
-        DepositHookMsg::Pay { subscriber_addr } => {
+        DepositHookMsg::Pay {
+            subscriber_addr,
+            term,
+        } => {
             // Construct deposit asset
             let asset = Asset {
                 info: AssetInfo::Cw20(msg_info.sender.clone()),
@@ -25,7 +28,7 @@ pub fn receive_cw20(
             let subscriber_addr = deps
                 .api
                 .addr_validate(&subscriber_addr.unwrap_or(cw20_msg.sender))?;
-            execute::try_pay(module, deps, env, asset, subscriber_addr)
+            execute::try_pay(module, deps, env, asset, subscriber_addr, term)
         }
     }
 }
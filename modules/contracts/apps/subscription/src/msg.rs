@@ -16,6 +16,8 @@
 //! Because blockchains don't have a notion of monthly settlement we settled on a per-month payment schema.
 //! We use a [`TimeWeightedAverage`](crate::objects::time_weighted_average::TimeWeightedAverage) of the ongoing income to to determine a per-second income.
 //! We average the income over a monthly basis.
+//! Payments can be made in the reference payment asset or in any of the accepted payment assets, which are priced against the reference asset through the dex adapter or by the oracle sources registered on the ANS.
+//! The income is always tracked in the reference payment asset.
 //!
 //! ## Emissions
 //! Protocol emissions are an important part of creating a tight community of users and contributors around your product. The emissions feature of this
@@ -23,17 +25,19 @@
 //! These emission parameters are set when creating the module and are described on the [`EmissionType`] struct.
 //!
 //! ## Contributions
-//! The contribution feature of this contract can be used to provide direct incentives for users to join in building out your product.
-//! Each contributor is registered with his own [`Compensation`] parameters.
-//! * The total income of the system is shared between the DAO and the contributors. See [`ContributionConfig`].
-//! * (optional) Token emissions to contributor (and users) are dynamically set based on the protocol's income. Meaning that the token emissions will rise if demand/income falls and vice-versa.
+//! With [`EmissionType::IncomeBased`] emissions the protocol income is shared between the subscribers and the contributors.
+//! The contributors' share can be claimed with [`SubscriptionExecuteMsg::ClaimContributorEmissions`].
 
 use abstract_app::sdk::cw_helpers::Clearable;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{to_json_binary, Addr, Binary, CosmosMsg, Decimal, StdResult, Uint64, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Decimal, StdResult, Timestamp, Uint64, WasmMsg,
+};
 use cw_asset::{Asset, AssetInfoUnchecked};
 
-use super::state::{EmissionType, Subscriber, SubscriptionConfig, SubscriptionState};
+use super::state::{
+    EmissionType, PricedAsset, Subscriber, SubscriptionConfig, SubscriptionState, SubscriptionTerm,
+};
 use crate::contract::SubscriptionApp;
 
 abstract_app::app_msg_types!(
@@ -49,8 +53,10 @@ pub struct SubscriptionMigrateMsg {}
 /// Subscription instantiation message
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriptionInstantiateMsg {
-    /// Asset for payment
+    /// Reference asset for payment
     pub payment_asset: AssetInfoUnchecked,
+    /// Other assets accepted for payment, priced against the reference asset
+    pub accepted_payment_assets: Vec<PricedAsset<String>>,
     /// Cost of the subscription on a per-second basis.
     pub subscription_cost_per_second: Decimal,
     /// Subscription emissions per second
@@ -59,6 +65,10 @@ pub struct SubscriptionInstantiateMsg {
     pub income_averaging_period: Uint64,
     /// Unsubscription hook addr to send [unsubscribe message](`crate::msg::UnsubscribedHookMsg`)
     pub unsubscribe_hook_addr: Option<String>,
+    /// Seconds after expiration during which a subscriber can still renew
+    pub grace_period: Uint64,
+    /// Terms that can be paid up-front at a discount
+    pub terms: Vec<SubscriptionTerm>,
 }
 
 /// App execution messages
@@ -71,8 +81,11 @@ pub enum SubscriptionExecuteMsg {
         /// Address of new subscriber
         /// defaults to the sender
         subscriber_addr: Option<String>,
+        /// Name of the term to pay for up-front
+        term: Option<String>,
     },
     /// Unsubscribe inactive accounts
+    /// The sender can also cancel its own active subscription and get the remaining time refunded
     Unsubscribe {
         /// List of inactive accounts to move to the `DORMANT_SUBSCRIBERS` list
         unsubscribe_addrs: Vec<String>,
@@ -82,10 +95,18 @@ pub enum SubscriptionExecuteMsg {
         /// Address of subscriber
         addr: String,
     },
+    /// Claim the contributors' share of the income-based emissions
+    ClaimContributorEmissions {},
     /// Update config of subscription
     UpdateSubscriptionConfig {
-        /// New asset for payment
+        /// New reference asset for payment
         payment_asset: Option<AssetInfoUnchecked>,
+        /// New list of other accepted payment assets
+        accepted_payment_assets: Option<Vec<PricedAsset<String>>>,
+        /// New grace period
+        grace_period: Option<Uint64>,
+        /// New list of terms
+        terms: Option<Vec<SubscriptionTerm>>,
         /// new subscription_cost_per_second
         subscription_cost_per_second: Option<Decimal>,
         /// Subscription emissions per second
@@ -151,6 +172,17 @@ pub enum SubscriptionQueryMsg {
         /// Get list of expired(inactive) subscribers instead
         expired_subs: Option<bool>,
     },
+    /// Get list of subscribers that expire within the given time, including the ones in their grace period
+    /// Returns [`SubscribersResponse`]
+    #[returns(SubscribersResponse)]
+    ExpiringSubscribers {
+        /// Seconds from now
+        within: Uint64,
+        /// Start after this expiration and subscriber address
+        start_after: Option<(Timestamp, Addr)>,
+        /// Limit
+        limit: Option<u64>,
+    },
 }
 
 /// Cw20 hook message
@@ -161,6 +193,8 @@ pub enum DepositHookMsg {
         /// Subscriber Addr
         /// defaults to the sender
         subscriber_addr: Option<String>,
+        /// Name of the term to pay for up-front
+        term: Option<String>,
     },
 }
 
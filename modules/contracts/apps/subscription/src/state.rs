@@ -1,7 +1,13 @@
-use abstract_app::std::{objects::time_weighted_average::TimeWeightedAverage, AbstractResult};
-use cosmwasm_std::{Addr, Api, BlockInfo, Decimal, Timestamp};
+use abstract_app::std::{
+    objects::{time_weighted_average::TimeWeightedAverage, DexName},
+    AbstractResult,
+};
+use cosmwasm_std::{
+    Addr, Api, BlockInfo, CheckedMultiplyFractionError, Decimal, Empty, StdError, StdResult,
+    Storage, Timestamp, Uint128,
+};
 use cw_address_like::AddressLike;
-use cw_asset::{AssetInfo, AssetInfoBase};
+use cw_asset::{Asset, AssetInfo, AssetInfoBase};
 use cw_storage_plus::{Item, Map};
 
 /// Setting for protocol token emissions
@@ -14,11 +20,18 @@ pub enum EmissionType<T: AddressLike> {
     /// Each user receives a fixed number of tokens on a per-second basis.
     /// emission = second_per_user
     SecondPerUser(Decimal, AssetInfoBase<T>),
-    // TODO: subscription-contribution
-    // /// Requires contribution functionality to be active
-    // /// Emissions will be based on protocol income and user/contributor split.
-    // /// See [`ContributionConfig`]
-    // IncomeBased(AssetInfoBase<T>),
+    /// Emissions are based on the protocol income (see [`INCOME_TWA`]) and split between subscribers and contributors.
+    /// Each unit of income, denominated in the reference payment asset, emits one unit of `token`.
+    /// subscriber emission = income_per_second * subscriber_share / total_subscribers
+    /// contributor emission = income_per_second * (1 - subscriber_share)
+    IncomeBased {
+        /// Share of the emissions that goes to the subscribers
+        subscriber_share: Decimal,
+        /// Receiver of the contributors' share of the emissions
+        contributors: T,
+        /// Emitted token
+        token: AssetInfoBase<T>,
+    },
 }
 
 impl EmissionType<String> {
@@ -30,22 +43,106 @@ impl EmissionType<String> {
             }
             EmissionType::SecondPerUser(d, a) => {
                 Ok(EmissionType::SecondPerUser(d, a.check(api, None)?))
-            } // EmissionType::IncomeBased(a) => Ok(EmissionType::IncomeBased(a.check(api, None)?)),
+            }
+            EmissionType::IncomeBased {
+                subscriber_share,
+                contributors,
+                token,
+            } => {
+                if subscriber_share > Decimal::one() {
+                    return Err(
+                        StdError::generic_err("subscriber share can't be above 100%").into(),
+                    );
+                }
+                Ok(EmissionType::IncomeBased {
+                    subscriber_share,
+                    contributors: api.addr_validate(&contributors)?,
+                    token: token.check(api, None)?,
+                })
+            }
         }
     }
 }
 
+/// Payment asset that's accepted next to the reference payment asset.
+#[cosmwasm_schema::cw_serde]
+pub struct PricedAsset<T: AddressLike> {
+    /// Accepted asset
+    pub asset: AssetInfoBase<T>,
+    /// Source of the price of the asset against the reference payment asset
+    pub price_source: PriceSource,
+}
+
+impl PricedAsset<String> {
+    pub fn check(self, api: &dyn Api) -> AbstractResult<PricedAsset<Addr>> {
+        Ok(PricedAsset {
+            asset: self.asset.check(api, None)?,
+            price_source: self.price_source,
+        })
+    }
+}
+
+/// How an accepted payment asset is priced against the reference payment asset
+#[cosmwasm_schema::cw_serde]
+pub enum PriceSource {
+    /// Simulated swap to the reference asset through the dex adapter, which must be installed on the account
+    Dex { dex: DexName },
+    /// Oracle sources registered on the ANS
+    Oracle {
+        /// Maximum age in seconds of the price
+        max_age: u64,
+    },
+}
+
+/// Fixed-length subscription term, paid up-front at a discount
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionTerm {
+    /// Name of the term, e.g. "monthly"
+    pub name: String,
+    /// Length of the term in seconds
+    pub duration: u64,
+    /// Discount on the per-second cost of the subscription
+    pub discount: Decimal,
+}
+
+impl SubscriptionTerm {
+    pub fn check(&self) -> AbstractResult<()> {
+        if self.duration == 0 {
+            return Err(StdError::generic_err("term duration can't be zero").into());
+        }
+        if self.discount >= Decimal::one() {
+            return Err(StdError::generic_err("term discount must be below 100%").into());
+        }
+        Ok(())
+    }
+
+    /// Price of the term, denominated in the reference payment asset
+    pub fn price(&self, cost_per_second: Decimal) -> Result<Uint128, CheckedMultiplyFractionError> {
+        Uint128::from(self.duration)
+            .checked_mul_ceil(cost_per_second * (Decimal::one() - self.discount))
+    }
+}
+
 /// Config for subscriber functionality
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriptionConfig {
-    /// Asset that's accepted as payment
+    /// Reference asset that's accepted as payment, the subscription cost is denominated in it
     pub payment_asset: AssetInfo,
+    /// Other assets that are accepted as payment, priced against the `payment_asset` by their [`PriceSource`]
+    #[serde(default)]
+    pub accepted_payment_assets: Vec<PricedAsset<Addr>>,
     /// Cost of the subscription on a per-second basis.
     pub subscription_cost_per_second: Decimal,
     /// Subscription emissions per second
     pub subscription_per_second_emissions: EmissionType<Addr>,
     /// Unsubscription hook addr
     pub unsubscribe_hook_addr: Option<Addr>,
+    /// Seconds after expiration during which a subscriber can renew before being unsubscribed
+    #[serde(default)]
+    pub grace_period: u64,
+    /// Terms that can be paid up-front at a discount
+    #[serde(default)]
+    pub terms: Vec<SubscriptionTerm>,
 }
 
 /// Keeps track of the active subscribers.
@@ -57,6 +154,15 @@ pub struct SubscriptionState {
     pub active_subs: u32,
 }
 
+/// Payment of a subscriber, kept to refund the part of the subscription that's not used yet
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionPayment {
+    /// Asset the subscriber paid with
+    pub asset: Asset,
+    /// Seconds of subscription the payment bought, at the price and discount of the moment
+    pub paid_for_seconds: u64,
+}
+
 /// Stored info for each subscriber.
 #[cosmwasm_schema::cw_serde]
 pub struct Subscriber {
@@ -64,23 +170,76 @@ pub struct Subscriber {
     pub expiration_timestamp: Timestamp,
     /// last time emissions were claimed
     pub last_emission_claim_timestamp: Timestamp,
+    /// Payments that cover the subscription up to its expiration, oldest first
+    #[serde(default)]
+    pub payments: Vec<SubscriptionPayment>,
 }
 
 impl Subscriber {
-    pub fn new(block: &BlockInfo, paid_for_seconds: u64) -> Self {
+    pub fn new(block: &BlockInfo, payment: SubscriptionPayment) -> Self {
         Self {
-            expiration_timestamp: block.time.plus_seconds(paid_for_seconds),
+            expiration_timestamp: block.time.plus_seconds(payment.paid_for_seconds),
             last_emission_claim_timestamp: block.time,
+            payments: vec![payment],
+        }
+    }
+
+    pub fn extend(&mut self, block: &BlockInfo, payment: SubscriptionPayment) {
+        self.expiration_timestamp = self
+            .expiration_timestamp
+            .plus_seconds(payment.paid_for_seconds);
+        // Payments that are used up can't be refunded anymore
+        let unused_payments = self.unused_payments(block).len();
+        self.payments.drain(..self.payments.len() - unused_payments);
+        self.payments.push(payment);
+    }
+
+    /// Payments with the seconds of each that are not used yet, newest first.
+    /// The newest payments cover the end of the subscription.
+    fn unused_payments(&self, block: &BlockInfo) -> Vec<(&SubscriptionPayment, u64)> {
+        let mut remaining_seconds = self
+            .expiration_timestamp
+            .seconds()
+            .saturating_sub(block.time.seconds());
+        let mut unused_payments = vec![];
+        for payment in self.payments.iter().rev() {
+            if remaining_seconds == 0 {
+                break;
+            }
+            let unused_seconds = remaining_seconds.min(payment.paid_for_seconds);
+            unused_payments.push((payment, unused_seconds));
+            remaining_seconds -= unused_seconds;
         }
+        unused_payments
     }
 
-    pub fn extend(&mut self, paid_for_seconds: u64) {
-        self.expiration_timestamp = self.expiration_timestamp.plus_seconds(paid_for_seconds)
+    /// Refund of the remaining subscription, in the assets it got paid with
+    pub fn refund(&self, block: &BlockInfo) -> Vec<Asset> {
+        let mut refunds: Vec<Asset> = vec![];
+        for (payment, unused_seconds) in self.unused_payments(block) {
+            let amount = payment
+                .asset
+                .amount
+                .multiply_ratio(unused_seconds, payment.paid_for_seconds);
+            if amount.is_zero() {
+                continue;
+            }
+            match refunds.iter_mut().find(|r| r.info == payment.asset.info) {
+                Some(refund) => refund.amount += amount,
+                None => refunds.push(Asset::new(payment.asset.info.clone(), amount)),
+            }
+        }
+        refunds
     }
 
     pub fn is_expired(&self, block: &BlockInfo) -> bool {
         block.time >= self.expiration_timestamp
     }
+
+    /// Expired and the grace period to renew has passed
+    pub fn is_past_grace_period(&self, block: &BlockInfo, grace_period: u64) -> bool {
+        block.time >= self.expiration_timestamp.plus_seconds(grace_period)
+    }
 }
 
 /// Average number of subscribers
@@ -88,5 +247,35 @@ pub const SUBSCRIPTION_CONFIG: Item<SubscriptionConfig> = Item::new("config");
 pub const SUBSCRIPTION_STATE: Item<SubscriptionState> = Item::new("state");
 pub const SUBSCRIBERS: Map<&Addr, Subscriber> = Map::new("subs");
 pub const EXPIRED_SUBSCRIBERS: Map<&Addr, Subscriber> = Map::new("unsubs");
+/// Active subscribers indexed by (expiration in seconds, address)
+pub const SUBSCRIBER_EXPIRATIONS: Map<(u64, &Addr), Empty> = Map::new("sub_expirations");
+/// Last time the contributors claimed their income-based emissions
+pub const CONTRIBUTOR_EMISSIONS_CLAIM: Item<Timestamp> = Item::new("contrib_claim");
 
 pub const INCOME_TWA: TimeWeightedAverage = TimeWeightedAverage::new("twa");
+
+/// Saves an active subscriber and keeps its expiration indexed
+pub fn save_subscriber(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    subscriber: &Subscriber,
+) -> StdResult<()> {
+    if let Some(old) = SUBSCRIBERS.may_load(storage, addr)? {
+        SUBSCRIBER_EXPIRATIONS.remove(storage, (old.expiration_timestamp.seconds(), addr));
+    }
+    SUBSCRIBER_EXPIRATIONS.save(
+        storage,
+        (subscriber.expiration_timestamp.seconds(), addr),
+        &Empty {},
+    )?;
+    SUBSCRIBERS.save(storage, addr, subscriber)
+}
+
+/// Removes an active subscriber and its expiration index
+pub fn remove_subscriber(storage: &mut dyn Storage, addr: &Addr) -> StdResult<()> {
+    if let Some(old) = SUBSCRIBERS.may_load(storage, addr)? {
+        SUBSCRIBER_EXPIRATIONS.remove(storage, (old.expiration_timestamp.seconds(), addr));
+    }
+    SUBSCRIBERS.remove(storage, addr);
+    Ok(())
+}
@@ -1,21 +1,34 @@
 use std::str::FromStr;
 
-use abstract_app::std::objects::{
-    namespace::Namespace, time_weighted_average::TimeWeightedAverageData,
+use abstract_app::std::{
+    ans_host::ExecuteMsgFns,
+    objects::{
+        namespace::Namespace, time_weighted_average::TimeWeightedAverageData, AssetEntry,
+        OracleSource,
+    },
 };
 use abstract_client::{builder::cw20_builder, AbstractClient, Application, Environment, Publisher};
+use abstract_dex_adapter::{
+    contract::CONTRACT_VERSION, interface::DexAdapter, msg::DexInstantiateMsg, DEX_ADAPTER_ID,
+};
+use abstract_interface::{AdapterDeployer, DeployStrategy};
 use abstract_subscription::{
     contract::interface::SubscriptionInterface,
     msg::{SubscriptionExecuteMsgFns, SubscriptionInstantiateMsg, SubscriptionQueryMsgFns},
-    state::{EmissionType, Subscriber, SubscriptionConfig},
+    state::{
+        EmissionType, PriceSource, PricedAsset, Subscriber, SubscriptionConfig,
+        SubscriptionPayment, SubscriptionTerm,
+    },
     SubscriptionError,
 };
+use mockdex_bundle::{WynDex, EUR, USD, WYNDEX, WYND_TOKEN};
 
 pub const WEEK_IN_SECONDS: u64 = 7 * 24 * 60 * 60;
+pub const ONE_DAY_IN_SECONDS: u64 = 24 * 60 * 60;
 
-use cosmwasm_std::{coins, Decimal, StdError, Uint128, Uint64};
+use cosmwasm_std::{coin, coins, Decimal, StdError, Uint128, Uint64};
 use cw20_builder::{Cw20Base, Cw20Coin, ExecuteMsgInterfaceFns, QueryMsgInterfaceFns};
-use cw_asset::{AssetInfo, AssetInfoBase, AssetInfoUnchecked};
+use cw_asset::{Asset, AssetInfo, AssetInfoBase, AssetInfoUnchecked};
 // Use prelude to get all the necessary imports
 use cw_orch::{anyhow, prelude::*};
 
@@ -75,11 +88,14 @@ fn setup_cw20() -> anyhow::Result<Cw20Subscription> {
         publisher.account().install_app(
             &SubscriptionInstantiateMsg {
                 payment_asset: AssetInfoUnchecked::cw20(cw20_addr.clone()),
+                accepted_payment_assets: vec![],
                 subscription_cost_per_second: Decimal::from_str("0.000037")?,
                 subscription_per_second_emissions: EmissionType::None,
                 // 3 days
                 income_averaging_period: INCOME_AVERAGING_PERIOD,
                 unsubscribe_hook_addr: None,
+                grace_period: Uint64::zero(),
+                terms: vec![],
             },
             &[],
         )?;
@@ -109,6 +125,7 @@ fn setup_native<'a>(
         publisher.account().install_app(
             &SubscriptionInstantiateMsg {
                 payment_asset: AssetInfoUnchecked::native(DENOM),
+                accepted_payment_assets: vec![],
                 // https://github.com/AbstractSDK/abstract/pull/92#discussion_r1371693550
                 subscription_cost_per_second: Decimal::from_str("0.000037")?,
                 subscription_per_second_emissions: EmissionType::SecondShared(
@@ -117,6 +134,8 @@ fn setup_native<'a>(
                 ),
                 income_averaging_period: INCOME_AVERAGING_PERIOD,
                 unsubscribe_hook_addr: None,
+                grace_period: Uint64::zero(),
+                terms: vec![],
             },
             &[],
         )?;
@@ -151,12 +170,15 @@ fn successful_install() -> anyhow::Result<()> {
         config,
         SubscriptionConfig {
             payment_asset,
+            accepted_payment_assets: vec![],
             subscription_cost_per_second: Decimal::from_str("0.000037")?,
             subscription_per_second_emissions: EmissionType::SecondShared(
                 Decimal::from_str("0.00005")?,
                 AssetInfoBase::Cw20(addr)
             ),
-            unsubscribe_hook_addr: None
+            unsubscribe_hook_addr: None,
+            grace_period: 0,
+            terms: vec![],
         }
     );
 
@@ -171,9 +193,12 @@ fn successful_install() -> anyhow::Result<()> {
         config,
         SubscriptionConfig {
             payment_asset,
+            accepted_payment_assets: vec![],
             subscription_cost_per_second: Decimal::from_str("0.000037")?,
             subscription_per_second_emissions: EmissionType::None,
-            unsubscribe_hook_addr: None
+            unsubscribe_hook_addr: None,
+            grace_period: 0,
+            terms: vec![],
         }
     );
     Ok(())
@@ -209,10 +234,10 @@ fn subscribe() -> anyhow::Result<()> {
     // 2 people subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     let twa = query_twa(&client.environment(), subscription_addr.clone());
     // No income yet
     assert_eq!(twa.cumulative_value, 0);
@@ -223,7 +248,7 @@ fn subscribe() -> anyhow::Result<()> {
     // Third user subscribes
     subscription_app
         .call_as(&subscriber3)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     // refresh twa
     subscription_app.refresh_twa()?;
     // It should contain income of previous 2 subscribers
@@ -244,13 +269,18 @@ fn subscribe() -> anyhow::Result<()> {
 
     let twa = query_twa(&client.environment(), subscription_addr.clone());
 
-    // 0 new subscribers in this period
-    assert_eq!(twa.average_value, Decimal::percent(0));
+    // refresh accounts for the income of the 3 active subscribers
+    let three_subs_per_second = Decimal::from_str("0.000037")? * Decimal::from_str("3.0")?;
+    let expected_average = Decimal::from_ratio(
+        Uint128::from(INCOME_AVERAGING_PERIOD).mul_floor(three_subs_per_second),
+        INCOME_AVERAGING_PERIOD,
+    );
+    assert_eq!(twa.average_value, expected_average);
 
     // Fourth user subscribes
     subscription_app
         .call_as(&subscriber4)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     // two subscribers were subbed for two periods
     let first_two_subs =
         Uint128::from(INCOME_AVERAGING_PERIOD * Uint64::new(2)).mul_floor(two_subs_per_second);
@@ -290,10 +320,10 @@ fn claim_emissions_week_shared() -> anyhow::Result<()> {
     // 2 users subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -348,12 +378,12 @@ fn claim_emissions_none() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscription_app.account().owner()?)
-        .update_subscription_config(None, None, Some(EmissionType::None), None)?;
+        .update_subscription_config(None, None, None, None, Some(EmissionType::None), None, None)?;
 
     // 1 user subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -389,6 +419,8 @@ fn claim_emissions_week_per_user() -> anyhow::Result<()> {
     subscription_app
         .call_as(&subscription_app.account().owner()?)
         .update_subscription_config(
+            None,
+            None,
             None,
             None,
             Some(EmissionType::SecondPerUser(
@@ -396,15 +428,16 @@ fn claim_emissions_week_per_user() -> anyhow::Result<()> {
                 AssetInfoBase::Cw20(emission_cw20.addr_str()?),
             )),
             None,
+            None,
         )?;
 
     // 2 users subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -474,7 +507,7 @@ fn claim_emissions_errors() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -507,7 +540,7 @@ fn unsubscribe() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
     let current_time = client.block_info()?.time;
@@ -577,10 +610,10 @@ fn unsubscribe_part_of_list() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &coins(2200, DENOM))?;
+        .pay(None, None, &coins(2200, DENOM))?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &coins(220, DENOM))?;
+        .pay(None, None, &coins(220, DENOM))?;
     // 1 out of 10 weeks wait
     client.wait_seconds(WEEK_IN_SECONDS)?;
     // Un-sub on not-expired users should error
@@ -606,6 +639,506 @@ fn unsubscribe_part_of_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn claim_emissions_income_based() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let subscriber1 = mock.addr_make("subscriber1");
+    let subscriber2 = mock.addr_make("subscriber2");
+    let contributors = mock.addr_make("contributors");
+    let sub_amount = coins(500, DENOM);
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20,
+    } = setup_native(
+        &mock,
+        [
+            (&subscriber1, sub_amount.as_slice()),
+            (&subscriber2, sub_amount.as_slice()),
+        ],
+    )?;
+
+    subscription_app
+        .call_as(&subscription_app.account().owner()?)
+        .update_subscription_config(
+            None,
+            None,
+            None,
+            None,
+            Some(EmissionType::IncomeBased {
+                subscriber_share: Decimal::percent(50),
+                contributors: contributors.to_string(),
+                token: AssetInfoBase::Cw20(emission_cw20.addr_str()?),
+            }),
+            None,
+            None,
+        )?;
+
+    // contributors can't claim without income
+    let err: SubscriptionError = subscription_app
+        .claim_contributor_emissions()
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::EmissionsAlreadyClaimed {});
+
+    // 2 users subscribe
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &sub_amount)?;
+    subscription_app
+        .call_as(&subscriber2)
+        .pay(None, None, &sub_amount)?;
+
+    // refresh the income average after a full period
+    client.wait_seconds(INCOME_AVERAGING_PERIOD.u64())?;
+    subscription_app.refresh_twa()?;
+    let income_per_second =
+        query_twa(&client.environment(), subscription_app.address()?).average_value;
+    assert!(!income_per_second.is_zero());
+
+    // less than a period, so the average stays the same
+    client.wait_seconds(ONE_DAY_IN_SECONDS)?;
+    let seconds_passed = Uint128::from(INCOME_AVERAGING_PERIOD.u64() + ONE_DAY_IN_SECONDS);
+
+    // subscribers share half of the income
+    subscription_app.claim_emissions(subscriber1.to_string())?;
+    let balance = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(
+        balance.balance,
+        seconds_passed.mul_floor(income_per_second * Decimal::percent(50)) / Uint128::new(2)
+    );
+
+    // contributors receive the other half
+    subscription_app.claim_contributor_emissions()?;
+    let balance = emission_cw20.balance(contributors.to_string())?;
+    assert_eq!(
+        balance.balance,
+        seconds_passed.mul_floor(income_per_second * Decimal::percent(50))
+    );
+
+    // no double-claims
+    let err: SubscriptionError = subscription_app
+        .claim_contributor_emissions()
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::EmissionsAlreadyClaimed {});
+    Ok(())
+}
+
+#[test]
+fn income_emissions_settled_on_config_change() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+
+    let subscriber1 = mock.addr_make("subscriber1");
+    let contributors = mock.addr_make("contributors");
+    let sub_amount = coins(500, DENOM);
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20,
+    } = setup_native(&mock, [(&subscriber1, sub_amount.as_slice())])?;
+    let owner = subscription_app.account().owner()?;
+
+    subscription_app
+        .call_as(&owner)
+        .update_subscription_config(
+            None,
+            None,
+            None,
+            None,
+            Some(EmissionType::IncomeBased {
+                subscriber_share: Decimal::percent(50),
+                contributors: contributors.to_string(),
+                token: AssetInfoBase::Cw20(emission_cw20.addr_str()?),
+            }),
+            None,
+            None,
+        )?;
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &sub_amount)?;
+
+    client.wait_seconds(INCOME_AVERAGING_PERIOD.u64())?;
+    subscription_app.refresh_twa()?;
+    let income_per_second =
+        query_twa(&client.environment(), subscription_app.address()?).average_value;
+    client.wait_seconds(ONE_DAY_IN_SECONDS)?;
+    let seconds_passed = Uint128::from(INCOME_AVERAGING_PERIOD.u64() + ONE_DAY_IN_SECONDS);
+
+    // switching the emissions off pays out what the contributors accrued so far
+    subscription_app
+        .call_as(&owner)
+        .update_subscription_config(None, None, None, None, Some(EmissionType::None), None, None)?;
+    let balance = emission_cw20.balance(contributors.to_string())?;
+    assert_eq!(
+        balance.balance,
+        seconds_passed.mul_floor(income_per_second * Decimal::percent(50))
+    );
+
+    let err: SubscriptionError = subscription_app
+        .claim_contributor_emissions()
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::IncomeEmissionsNotEnabled {});
+    Ok(())
+}
+
+#[test]
+fn subscribe_with_priced_asset() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let subscriber1 = mock.addr_make("subscriber1");
+
+    let client = AbstractClient::builder(mock.clone()).build()?;
+    let WynDex {
+        eur_token,
+        usd_token,
+        ..
+    } = WynDex::deploy_on(mock.clone(), Empty {})?;
+    client.set_balances([(
+        &subscriber1,
+        [coin(500, EUR), coin(500, WYND_TOKEN)].as_slice(),
+    )])?;
+    // 1 EUR is worth 1.1 USD
+    client.name_service().update_oracle_sources(
+        vec![(
+            (AssetEntry::new(EUR), AssetEntry::new(USD)),
+            OracleSource::Fixed {
+                price: Decimal::percent(110),
+            },
+        )],
+        vec![],
+    )?;
+
+    let publisher: Publisher<MockBech32> = client
+        .fetch_account(Namespace::new("abstract")?)?
+        .publisher()?;
+    publisher.publish_app::<SubscriptionInterface<_>>()?;
+
+    let subscription_app: Application<_, SubscriptionInterface<_>> =
+        publisher.account().install_app(
+            &SubscriptionInstantiateMsg {
+                payment_asset: usd_token.clone().into(),
+                accepted_payment_assets: vec![PricedAsset {
+                    asset: eur_token.clone().into(),
+                    price_source: PriceSource::Oracle { max_age: 60 },
+                }],
+                subscription_cost_per_second: Decimal::from_str("0.000037")?,
+                subscription_per_second_emissions: EmissionType::None,
+                income_averaging_period: INCOME_AVERAGING_PERIOD,
+                unsubscribe_hook_addr: None,
+                grace_period: Uint64::zero(),
+                terms: vec![],
+            },
+            &[],
+        )?;
+
+    // only the accepted assets can be used for payment
+    let err: SubscriptionError = subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(500, WYND_TOKEN))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::WrongToken(usd_token));
+
+    // EUR is priced against USD
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(500, EUR))?;
+
+    let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
+    assert!(subscriber.currently_subscribed);
+    let current_time = client.block_info()?.time;
+    let expiration = subscriber.subscriber_details.unwrap().expiration_timestamp;
+    // 500 EUR is worth 550 USD
+    let paid_for_seconds = Uint128::new(550)
+        .checked_div_floor(Decimal::from_str("0.000037")?)?
+        .u128() as u64;
+    assert_eq!(expiration, current_time.plus_seconds(paid_for_seconds));
+
+    // EUR payment is sent to the account
+    let balance = mock.query_balance(&subscription_app.account().address()?, EUR)?;
+    assert_eq!(balance.u128(), 500);
+
+    // Cancelling right away refunds the full payment in EUR
+    subscription_app
+        .call_as(&subscriber1)
+        .unsubscribe(vec![subscriber1.to_string()])?;
+    let balance = mock.query_balance(&subscriber1, EUR)?;
+    assert_eq!(balance.u128(), 500);
+    let balance = mock.query_balance(&subscription_app.account().address()?, EUR)?;
+    assert!(balance.is_zero());
+    Ok(())
+}
+
+#[test]
+fn subscribe_with_dex_priced_asset() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let subscriber1 = mock.addr_make("subscriber1");
+
+    let client = AbstractClient::builder(mock.clone()).build()?;
+    let WynDex {
+        eur_token,
+        usd_token,
+        ..
+    } = WynDex::deploy_on(mock.clone(), Empty {})?;
+    client.set_balances([(&subscriber1, coins(500, EUR).as_slice())])?;
+
+    let dex_adapter = DexAdapter::new(DEX_ADAPTER_ID, mock.clone());
+    dex_adapter.deploy(
+        CONTRACT_VERSION.parse()?,
+        DexInstantiateMsg {
+            recipient_account: 0,
+            swap_fee: Decimal::percent(1),
+        },
+        DeployStrategy::Try,
+    )?;
+
+    let publisher: Publisher<MockBech32> = client
+        .fetch_account(Namespace::new("abstract")?)?
+        .publisher()?;
+    publisher.publish_app::<SubscriptionInterface<_>>()?;
+    publisher
+        .account()
+        .install_adapter::<DexAdapter<MockBech32>>(&[])?;
+
+    let subscription_app: Application<_, SubscriptionInterface<_>> =
+        publisher.account().install_app(
+            &SubscriptionInstantiateMsg {
+                payment_asset: usd_token.into(),
+                accepted_payment_assets: vec![PricedAsset {
+                    asset: eur_token.into(),
+                    price_source: PriceSource::Dex {
+                        dex: WYNDEX.to_owned(),
+                    },
+                }],
+                subscription_cost_per_second: Decimal::from_str("0.000037")?,
+                subscription_per_second_emissions: EmissionType::None,
+                income_averaging_period: INCOME_AVERAGING_PERIOD,
+                unsubscribe_hook_addr: None,
+                grace_period: Uint64::zero(),
+                terms: vec![],
+            },
+            &[],
+        )?;
+
+    // EUR is priced against USD by simulating a swap
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(500, EUR))?;
+
+    let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
+    assert!(subscriber.currently_subscribed);
+    let current_time = client.block_info()?.time;
+    let expiration = subscriber.subscriber_details.unwrap().expiration_timestamp;
+    let max_seconds = Uint128::new(500)
+        .checked_div_floor(Decimal::from_str("0.000037")?)?
+        .u128() as u64;
+    // less than 500 USD worth after slippage and fees
+    assert!(expiration > current_time.plus_seconds(max_seconds / 2));
+    assert!(expiration < current_time.plus_seconds(max_seconds));
+
+    // EUR payment is sent to the account
+    let balance = mock.query_balance(&subscription_app.account().address()?, EUR)?;
+    assert_eq!(balance.u128(), 500);
+    Ok(())
+}
+
+#[test]
+fn grace_period_and_refund() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let subscriber1 = mock.addr_make("subscriber1");
+    let subscriber2 = mock.addr_make("subscriber2");
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20: _,
+    } = setup_native(
+        &mock,
+        [
+            (&subscriber1, coins(180, DENOM).as_slice()),
+            (&subscriber2, coins(220, DENOM).as_slice()),
+        ],
+    )?;
+
+    subscription_app
+        .call_as(&subscription_app.account().owner()?)
+        .update_subscription_config(
+            None,
+            Some(Uint64::new(WEEK_IN_SECONDS)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+    // For 4 weeks with few hours
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(90, DENOM))?;
+    client.wait_seconds(WEEK_IN_SECONDS * 5)?;
+
+    // expired, but still in the grace period
+    let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
+    assert!(!subscriber.currently_subscribed);
+    let expiring = subscription_app.expiring_subscribers(None, None, Uint64::zero())?;
+    assert_eq!(expiring.subscribers.len(), 1);
+    assert_eq!(expiring.subscribers[0].0, subscriber1);
+    assert!(!expiring.subscribers[0].1.currently_subscribed);
+    let err: SubscriptionError = subscription_app
+        .unsubscribe(vec![subscriber1.to_string()])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::NoOneUnsubbed {});
+
+    // renew during the grace period
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(90, DENOM))?;
+    let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
+    assert!(subscriber.currently_subscribed);
+    assert!(
+        subscriber.subscriber_details.unwrap().expiration_timestamp
+            > client.block_info()?.time.plus_seconds(WEEK_IN_SECONDS * 3)
+    );
+
+    // subscriber cancels and gets the remaining time refunded
+    subscription_app
+        .call_as(&subscriber2)
+        .pay(None, None, &coins(220, DENOM))?;
+    client.wait_seconds(WEEK_IN_SECONDS)?;
+    subscription_app
+        .call_as(&subscriber2)
+        .unsubscribe(vec![subscriber2.to_string()])?;
+
+    // refunded at the price it was paid for
+    let cost = Decimal::from_str("0.000037")?;
+    let paid_for_seconds = Uint128::new(220).checked_div_floor(cost)?;
+    let expected_refund = Uint128::new(220).multiply_ratio(
+        paid_for_seconds - Uint128::from(WEEK_IN_SECONDS),
+        paid_for_seconds,
+    );
+    let balance = mock.query_balance(&subscriber2, DENOM)?;
+    assert_eq!(balance, expected_refund);
+
+    let subscriber = subscription_app.subscriber(subscriber2.to_string())?;
+    assert!(!subscriber.currently_subscribed);
+    Ok(())
+}
+
+#[test]
+fn term_subscription() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let subscriber1 = mock.addr_make("subscriber1");
+    let term = SubscriptionTerm {
+        name: "monthly".to_owned(),
+        duration: WEEK_IN_SECONDS * 4,
+        discount: Decimal::percent(10),
+    };
+    let cost = Decimal::from_str("0.000037")?;
+    let term_price = term.price(cost)?;
+
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset,
+        emission_cw20: _,
+    } = setup_native(
+        &mock,
+        [(&subscriber1, coins(term_price.u128(), DENOM).as_slice())],
+    )?;
+
+    subscription_app
+        .call_as(&subscription_app.account().owner()?)
+        .update_subscription_config(None, None, None, None, None, Some(vec![term.clone()]), None)?;
+
+    let err: SubscriptionError = subscription_app
+        .call_as(&subscriber1)
+        .pay(None, Some("yearly".to_owned()), &coins(10, DENOM))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, SubscriptionError::TermNotFound("yearly".to_owned()));
+
+    let err: SubscriptionError = subscription_app
+        .call_as(&subscriber1)
+        .pay(None, Some(term.name.clone()), &coins(10, DENOM))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubscriptionError::InsufficientPayment(term_price, payment_asset.to_string())
+    );
+
+    subscription_app.call_as(&subscriber1).pay(
+        None,
+        Some(term.name.clone()),
+        &coins(term_price.u128(), DENOM),
+    )?;
+    let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
+    let subscriber_details = subscriber.subscriber_details.unwrap();
+    assert_eq!(
+        subscriber_details.expiration_timestamp,
+        client.block_info()?.time.plus_seconds(term.duration)
+    );
+    assert_eq!(
+        subscriber_details.payments,
+        vec![SubscriptionPayment {
+            asset: Asset::native(DENOM, term_price),
+            paid_for_seconds: term.duration,
+        }]
+    );
+
+    // not expiring within a week
+    let expiring =
+        subscription_app.expiring_subscribers(None, None, Uint64::new(WEEK_IN_SECONDS))?;
+    assert!(expiring.subscribers.is_empty());
+
+    client.wait_seconds(WEEK_IN_SECONDS * 3)?;
+    let expiring =
+        subscription_app.expiring_subscribers(None, None, Uint64::new(WEEK_IN_SECONDS))?;
+    assert_eq!(expiring.subscribers.len(), 1);
+    assert_eq!(expiring.subscribers[0].0, subscriber1);
+
+    // paginated by expiration
+    let (expiring_addr, expiring_sub) = expiring.subscribers[0].clone();
+    let start_after = (
+        expiring_sub
+            .subscriber_details
+            .unwrap()
+            .expiration_timestamp,
+        expiring_addr,
+    );
+    let expiring = subscription_app.expiring_subscribers(
+        None,
+        Some(start_after),
+        Uint64::new(WEEK_IN_SECONDS),
+    )?;
+    assert!(expiring.subscribers.is_empty());
+
+    // refund is prorated at the discounted price the term was paid at
+    subscription_app
+        .call_as(&subscriber1)
+        .unsubscribe(vec![subscriber1.to_string()])?;
+    let expected_refund = term_price.multiply_ratio(WEEK_IN_SECONDS, term.duration);
+    let balance = mock.query_balance(&subscriber1, DENOM)?;
+    assert_eq!(balance, expected_refund);
+    Ok(())
+}
+
 // Helper to raw_query twa
 fn query_twa(chain: &MockBech32, subscription_addr: Addr) -> TimeWeightedAverageData {
     let app = chain.app.borrow();